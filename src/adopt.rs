@@ -15,7 +15,7 @@ fn ensure_repo(db: &Database, repo_info: &RepoInfo) -> Result<Repo> {
         return Ok(repo);
     }
 
-    db.insert_repo(
+    db.upsert_repo(
         &repo_info.name,
         repo_path_str,
         Some(&repo_info.default_branch),
@@ -83,6 +83,8 @@ pub fn resolve_only(
                     name: repo_info.name.clone(),
                     path: repo_path_str.to_string(),
                     default_base: Some(repo_info.default_branch.clone()),
+                    remote_url: repo_info.remote_url.clone(),
+                    template: None,
                     created_at: 0,
                 },
             };
@@ -0,0 +1,301 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+use crate::git;
+use crate::state::Database;
+
+/// Outcome of `trench clone`, shared by the human and JSON renderers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CloneResult {
+    pub name: String,
+    pub url: String,
+    /// Directory worktrees are created against (holds `.git` and `.bare`).
+    pub path: PathBuf,
+    pub default_branch: String,
+    pub dry_run: bool,
+}
+
+impl std::fmt::Display for CloneResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.dry_run {
+            return writeln!(f, "Would clone {} into {}", self.url, self.path.display());
+        }
+        writeln!(
+            f,
+            "Cloned '{}' into {} (bare repository in {})",
+            self.name,
+            self.path.display(),
+            git::BARE_DIR
+        )?;
+        writeln!(
+            f,
+            "Run `trench create <branch>` from there to add worktrees off '{}'.",
+            self.default_branch
+        )
+    }
+}
+
+/// Directory `git clone` would pick for `url`: its last path component
+/// without a trailing `.git`.
+pub fn default_clone_dir(url: &str) -> Option<String> {
+    let last = url
+        .trim_end_matches('/')
+        .rsplit(['/', ':'])
+        .next()?
+        .trim_end_matches(".git");
+    (!last.is_empty()).then(|| last.to_string())
+}
+
+/// Execute the `trench clone` command.
+///
+/// Clones `url` into the bare layout at `dir` (see [`git::clone_bare`]) and
+/// registers the repository, so `trench create` works from `dir` right
+/// away. If any step fails, whatever the clone put in `dir` is removed. With
+/// `dry_run`, only reports where the clone would go.
+pub fn execute(url: &str, dir: &Path, db: &Database, dry_run: bool) -> Result<CloneResult> {
+    if dry_run {
+        return Ok(CloneResult {
+            name: dir
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+            url: url.to_string(),
+            path: dir.to_path_buf(),
+            default_branch: String::new(),
+            dry_run,
+        });
+    }
+
+    // Only a directory the clone starts from scratch is ours to clean up;
+    // `clone_bare` refuses a non-empty one before touching it.
+    let existed = dir.exists();
+    let fresh = !existed || std::fs::read_dir(dir).is_ok_and(|mut d| d.next().is_none());
+
+    let cloned = (|| -> Result<_> {
+        git::clone_bare(url, dir).with_context(|| format!("failed to clone {url}"))?;
+        let repo_info = git::discover_repo(dir)?;
+        let repo_path = repo_info
+            .path
+            .to_str()
+            .ok_or_else(|| anyhow::anyhow!("repo path is not valid UTF-8"))?;
+        let repo = db.upsert_repo(&repo_info.name, repo_path, Some(&repo_info.default_branch))?;
+        if repo.remote_url != repo_info.remote_url {
+            db.set_repo_remote_url(repo.id, repo_info.remote_url.as_deref())?;
+        }
+        Ok(repo_info)
+    })();
+    let repo_info = match cloned {
+        Ok(repo_info) => repo_info,
+        Err(e) => {
+            if fresh {
+                rollback_clone(dir, existed);
+            }
+            return Err(e);
+        }
+    };
+
+    Ok(CloneResult {
+        name: repo_info.name,
+        url: url.to_string(),
+        path: repo_info.path,
+        default_branch: repo_info.default_branch,
+        dry_run,
+    })
+}
+
+/// Undo a clone into `dir` after a later step failed, leaving `dir` as it
+/// was before: absent, or empty when it `existed`.
+///
+/// Best-effort: the caller reports the original failure, so cleanup
+/// problems only warn, naming what is left behind.
+fn rollback_clone(dir: &Path, existed: bool) {
+    let removed = if existed {
+        std::fs::read_dir(dir).and_then(|mut entries| {
+            entries.try_for_each(|entry| {
+                let path = entry?.path();
+                if path.is_dir() {
+                    std::fs::remove_dir_all(&path)
+                } else {
+                    std::fs::remove_file(&path)
+                }
+            })
+        })
+    } else {
+        std::fs::remove_dir_all(dir)
+    };
+    match removed {
+        Ok(()) => {}
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+        Err(e) => eprintln!(
+            "warning: could not clean up partial clone at {}: {e}",
+            dir.display()
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A bare "remote" with one commit on `main` and one on `develop`.
+    fn init_remote(dir: &Path) -> String {
+        let repo = git2::Repository::init_bare(dir).unwrap();
+        repo.set_head("refs/heads/main").unwrap();
+        let sig = git2::Signature::now("Test", "test@test.com").unwrap();
+        let tree_id = repo.treebuilder(None).unwrap().write().unwrap();
+        let tree = repo.find_tree(tree_id).unwrap();
+        let commit = repo
+            .commit(Some("refs/heads/main"), &sig, &sig, "initial", &tree, &[])
+            .unwrap();
+        repo.reference("refs/heads/develop", commit, false, "test")
+            .unwrap();
+        dir.to_str().unwrap().to_string()
+    }
+
+    #[test]
+    fn clone_sets_up_bare_layout_that_create_works_against() {
+        let remote_dir = tempfile::tempdir().unwrap();
+        let url = init_remote(remote_dir.path());
+        let parent = tempfile::tempdir().unwrap();
+        let dir = parent.path().join("project");
+        let db = Database::open_in_memory().unwrap();
+
+        let result = execute(&url, &dir, &db, false).unwrap();
+
+        assert!(dir.join(git::BARE_DIR).join("HEAD").is_file());
+        assert_eq!(
+            std::fs::read_to_string(dir.join(".git")).unwrap(),
+            "gitdir: ./.bare\n"
+        );
+        assert_eq!(result.name, "project");
+        assert_eq!(result.default_branch, "main");
+
+        let info = git::discover_repo(&dir).unwrap();
+        assert_eq!(info.path, dir.canonicalize().unwrap());
+        assert_eq!(info.default_branch, "main");
+        assert_eq!(info.remote_url.as_deref(), Some(url.as_str()));
+        let repo = db
+            .get_repo_by_path(info.path.to_str().unwrap())
+            .unwrap()
+            .expect("clone should register the repo");
+        assert_eq!(repo.default_base.as_deref(), Some("main"));
+
+        let wt_root = tempfile::tempdir().unwrap();
+        let created = crate::cli::commands::create::execute(
+            "feature/bare",
+            Some("develop"),
+            &dir,
+            wt_root.path(),
+            crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
+            &db,
+        )
+        .expect("create should work in the bare layout");
+        assert!(created.path.join(".git").is_file());
+
+        // Commands run inside a worktree resolve back to the clone root.
+        let from_worktree = git::discover_repo(&created.path).unwrap();
+        assert_eq!(from_worktree.path, info.path);
+        let listed = git::list_worktrees(&info.path).unwrap();
+        assert_eq!(listed.len(), 1, "bare layout has no main checkout");
+        assert_eq!(listed[0].branch.as_deref(), Some("feature/bare"));
+    }
+
+    #[test]
+    fn sparse_worktree_keeps_the_bare_layout_working() {
+        let remote_dir = tempfile::tempdir().unwrap();
+        let url = init_remote(remote_dir.path());
+        let parent = tempfile::tempdir().unwrap();
+        let dir = parent.path().join("project");
+        let db = Database::open_in_memory().unwrap();
+        execute(&url, &dir, &db, false).unwrap();
+
+        let wt_root = tempfile::tempdir().unwrap();
+        for (branch, sparse) in [("sparse", Some(vec!["app".to_string()])), ("full", None)] {
+            crate::cli::commands::create::execute(
+                branch,
+                None,
+                &dir,
+                wt_root.path(),
+                crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+                sparse.as_deref(),
+                &db,
+            )
+            .unwrap();
+        }
+
+        // Enabling per-worktree config moved `core.bare` out of the shared
+        // config; the clone root must still resolve as the bare layout.
+        let bare = git2::Repository::open(dir.join(git::BARE_DIR)).unwrap();
+        assert!(bare.is_bare());
+        let info = git::discover_repo(&dir).unwrap();
+        assert_eq!(info.path, dir.canonicalize().unwrap());
+        assert_eq!(git::list_worktrees(&info.path).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn clone_refuses_non_empty_dir_and_dry_run_touches_nothing() {
+        let remote_dir = tempfile::tempdir().unwrap();
+        let url = init_remote(remote_dir.path());
+        let dir = tempfile::tempdir().unwrap();
+        let db = Database::open_in_memory().unwrap();
+
+        let preview = execute(&url, &dir.path().join("new"), &db, true).unwrap();
+        assert!(preview.to_string().starts_with("Would clone"));
+        assert!(!dir.path().join("new").exists());
+
+        std::fs::write(dir.path().join("existing.txt"), "x").unwrap();
+        let err = execute(&url, dir.path(), &db, false).unwrap_err();
+        assert!(format!("{err:#}").contains("not empty"), "{err:#}");
+        assert!(db.list_repos().unwrap().is_empty());
+        assert!(
+            dir.path().join("existing.txt").exists(),
+            "a refused clone must not touch the directory"
+        );
+    }
+
+    #[test]
+    fn failure_after_clone_removes_what_the_clone_created() {
+        let remote_dir = tempfile::tempdir().unwrap();
+        let url = init_remote(remote_dir.path());
+        let parent = tempfile::tempdir().unwrap();
+        let db = Database::open_in_memory().unwrap();
+        db.conn_for_test()
+            .execute_batch(
+                "CREATE TRIGGER fail_insert BEFORE INSERT ON repos
+                 BEGIN SELECT RAISE(ABORT, 'injected failure'); END;",
+            )
+            .unwrap();
+
+        let dir = parent.path().join("project");
+        let err = execute(&url, &dir, &db, false).unwrap_err();
+        assert!(format!("{err:#}").contains("injected failure"), "{err:#}");
+        assert!(!dir.exists(), "partial clone should be removed");
+
+        // A pre-existing empty directory is kept, but left empty.
+        let empty = parent.path().join("empty");
+        std::fs::create_dir(&empty).unwrap();
+        execute(&url, &empty, &db, false).unwrap_err();
+        assert!(empty.is_dir());
+        assert_eq!(std::fs::read_dir(&empty).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn default_clone_dir_strips_git_suffix() {
+        assert_eq!(
+            default_clone_dir("https://example.com/acme/app.git").as_deref(),
+            Some("app")
+        );
+        assert_eq!(
+            default_clone_dir("git@github.com:acme/tool").as_deref(),
+            Some("tool")
+        );
+        assert_eq!(
+            default_clone_dir("/srv/repos/lib.git/").as_deref(),
+            Some("lib")
+        );
+        assert_eq!(default_clone_dir(".git"), None);
+    }
+}
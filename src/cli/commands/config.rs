@@ -0,0 +1,84 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use crate::git;
+use crate::paths;
+use crate::state::Database;
+
+/// Execute `trench config set-template`.
+///
+/// Stores `template` as the current repo's worktree path template. It is used
+/// by `create` unless `.trench.toml` or a CLI flag sets `worktrees.root`.
+pub fn execute_set_template(cwd: &Path, db: &Database, template: &str) -> Result<String> {
+    let repo_info = git::discover_repo(cwd)?;
+    // Reject templates that would fail or collide later at create time.
+    paths::render_worktree_path(template, &repo_info.name, "branch")?;
+    paths::ensure_template_varies_by_branch(template)?;
+
+    let repo_path = repo_info
+        .path
+        .to_str()
+        .context("repository path is not valid UTF-8")?;
+    let repo = db.upsert_repo(&repo_info.name, repo_path, Some(&repo_info.default_branch))?;
+    db.set_repo_template(repo.id, Some(template))?;
+
+    Ok(format!(
+        "Set worktree template for '{}' to '{template}'\n",
+        repo_info.name
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn init_repo_with_commit(dir: &Path) -> git2::Repository {
+        let repo = git2::Repository::init(dir).expect("failed to init repo");
+        {
+            let sig = git2::Signature::now("Test", "test@test.com").unwrap();
+            let tree_id = repo.index().unwrap().write_tree().unwrap();
+            let tree = repo.find_tree(tree_id).unwrap();
+            repo.commit(Some("HEAD"), &sig, &sig, "initial commit", &tree, &[])
+                .unwrap();
+        }
+        repo
+    }
+
+    #[test]
+    fn set_template_persists_for_repo() {
+        let repo_dir = TempDir::new().unwrap();
+        init_repo_with_commit(repo_dir.path());
+        let db = Database::open_in_memory().unwrap();
+
+        let out = execute_set_template(repo_dir.path(), &db, "{{ repo }}/{{ branch | sanitize }}")
+            .unwrap();
+        assert!(out.contains("Set worktree template"), "{out}");
+
+        let repo_path = repo_dir.path().canonicalize().unwrap();
+        let repo = db
+            .get_repo_by_path(repo_path.to_str().unwrap())
+            .unwrap()
+            .expect("repo should be recorded");
+        assert_eq!(
+            repo.template.as_deref(),
+            Some("{{ repo }}/{{ branch | sanitize }}")
+        );
+    }
+
+    #[test]
+    fn set_template_rejects_invalid_template() {
+        let repo_dir = TempDir::new().unwrap();
+        init_repo_with_commit(repo_dir.path());
+        let db = Database::open_in_memory().unwrap();
+
+        let err = execute_set_template(repo_dir.path(), &db, "{{ repo").unwrap_err();
+        assert!(err.to_string().contains("template"), "{err}");
+        assert!(db.list_repos().unwrap().is_empty());
+
+        let err = execute_set_template(repo_dir.path(), &db, "{{ repo }}").unwrap_err();
+        assert!(err.to_string().contains("branch"), "{err}");
+        assert!(db.list_repos().unwrap().is_empty());
+    }
+}
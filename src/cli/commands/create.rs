@@ -7,13 +7,79 @@ use crate::config::HooksConfig;
 use crate::git;
 use crate::hooks::{self, HookEnvContext, HookEvent};
 use crate::paths;
-use crate::state::Database;
+use crate::state::{Database, WorktreeUpdate};
 
 /// Typed errors for the `create` command.
 #[derive(Debug, thiserror::Error)]
 pub enum CreateError {
     #[error("pre_create hook failed")]
     PreCreateHookFailed(#[source] anyhow::Error),
+    #[error("branch '{branch}' is already tracked by trench at {path}")]
+    AlreadyTracked { branch: String, path: String },
+    #[error(
+        "worktree name '{name}' for branch '{branch}' is already used by branch '{existing_branch}' at {path}; pass --name to choose another name"
+    )]
+    NameCollision {
+        branch: String,
+        name: String,
+        existing_branch: String,
+        path: String,
+    },
+    #[error("cannot create worktree directory: {} exists and is not a directory", path.display())]
+    ParentIsFile { path: PathBuf },
+    #[error("cannot create worktree directory: permission denied creating {}", path.display())]
+    ParentPermissionDenied { path: PathBuf },
+    #[error("cannot create worktree directory: {} is on a read-only filesystem", path.display())]
+    ParentReadOnly { path: PathBuf },
+    #[error(
+        "parent directory {} does not exist (create it, or drop --parents=false)",
+        path.display()
+    )]
+    ParentMissing { path: PathBuf },
+}
+
+/// Refuse to create a second tracked worktree for a branch trench already
+/// manages, or one whose name is taken by another branch (e.g.
+/// `feature/auth` vs `feature-auth`), before any git or hook side effects.
+fn ensure_not_tracked(
+    db: &Database,
+    repo_info: &git::RepoInfo,
+    branch: &str,
+    name: &str,
+) -> Result<()> {
+    let repo_path_str = path_to_utf8(&repo_info.path)?;
+    let Some(repo) = db.get_repo_by_path(repo_path_str)? else {
+        return Ok(());
+    };
+    if let Some(existing) = db.find_worktree_by_branch(repo.id, branch)? {
+        return Err(CreateError::AlreadyTracked {
+            branch: branch.to_string(),
+            path: existing.path,
+        }
+        .into());
+    }
+    if let Some(existing) = db.find_worktree_by_identifier(repo.id, name)? {
+        return Err(CreateError::NameCollision {
+            branch: branch.to_string(),
+            name: name.to_string(),
+            existing_branch: existing.branch,
+            path: existing.path,
+        }
+        .into());
+    }
+    Ok(())
+}
+
+/// Worktree name for `branch`: the explicit `--name`, validated, or the
+/// sanitized branch.
+fn worktree_name(branch: &str, name: Option<&str>) -> Result<String> {
+    match name {
+        Some(name) => {
+            paths::validate_worktree_name(name).map_err(|reason| anyhow::anyhow!(reason))?;
+            Ok(name.to_string())
+        }
+        None => Ok(paths::sanitize_branch(branch)),
+    }
 }
 
 /// Plan produced by `--dry-run` showing what `trench create` would do.
@@ -25,6 +91,11 @@ pub struct DryRunPlan {
     pub base_branch: String,
     pub worktree_path: String,
     pub repo_name: String,
+    /// Explicit worktree name from `--name`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sparse: Option<Vec<String>>,
     pub hooks: Option<HooksConfig>,
 }
 
@@ -34,6 +105,12 @@ impl fmt::Display for DryRunPlan {
         writeln!(f, "  Branch:    {}", self.branch)?;
         writeln!(f, "  Base:      {}", self.base_branch)?;
         writeln!(f, "  Worktree:  {}", self.worktree_path)?;
+        if let Some(name) = &self.name {
+            writeln!(f, "  Name:      {name}")?;
+        }
+        if let Some(sparse) = &self.sparse {
+            writeln!(f, "  Sparse:    {}", sparse.join(", "))?;
+        }
 
         match &self.hooks {
             Some(hooks) if hooks.pre_create.is_some() || hooks.post_create.is_some() => {
@@ -67,6 +144,10 @@ pub struct CreateResult {
     pub path: PathBuf,
     /// Base branch the worktree was created from.
     pub base_branch: String,
+    /// Name of the repository the worktree belongs to.
+    pub repo: String,
+    /// Database id of the new worktree row.
+    pub worktree_id: i64,
 }
 
 impl CreateResult {
@@ -77,7 +158,10 @@ impl CreateResult {
             branch: self.branch,
             path: self.path.to_string_lossy().to_string(),
             base_branch: self.base_branch,
+            repo: self.repo,
             hooks,
+            hook_steps: Vec::new(),
+            files_copied: 0,
         }
     }
 }
@@ -89,7 +173,13 @@ pub struct CreateJsonOutput {
     pub branch: String,
     pub path: String,
     pub base_branch: String,
+    pub repo: String,
     pub hooks: HooksStatus,
+    /// Per-step hook timings, present when hooks ran.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub hook_steps: Vec<hooks::progress::StepTiming>,
+    /// Number of files the post_create copy step copied.
+    pub files_copied: usize,
 }
 
 /// Hook execution status included in JSON output.
@@ -120,6 +210,61 @@ fn format_hook_def(f: &mut fmt::Formatter<'_>, hook: &crate::config::HookDef) ->
     Ok(())
 }
 
+/// Run [`execute_with_hooks`] while reporting hook progress.
+///
+/// Each hook step prints a line to `progress` as it starts and finishes;
+/// pass `None` to stay silent (e.g. under `--json`). Step timings are
+/// returned either way.
+#[allow(clippy::too_many_arguments)]
+pub async fn execute_with_progress<W: std::io::Write + Send + 'static>(
+    branch: &str,
+    from: Option<&str>,
+    cwd: &Path,
+    worktree_root: &Path,
+    template: &str,
+    sparse: Option<&[String]>,
+    parents: bool,
+    name: Option<&str>,
+    db: &Database,
+    hooks_config: Option<&HooksConfig>,
+    user_env: &[(String, String)],
+    no_hooks: bool,
+    progress: Option<W>,
+    cancel: Option<&hooks::runner::HookCancel>,
+) -> Result<(CreateWithHooksResult, Vec<hooks::progress::StepTiming>)> {
+    let steps = match hooks_config {
+        Some(h) if !no_hooks => hooks::progress::plan(&[
+            (HookEvent::PreCreate, h.pre_create.as_ref()),
+            (HookEvent::PostCreate, h.post_create.as_ref()),
+        ]),
+        _ => Vec::new(),
+    };
+    let (tx, rx) = std::sync::mpsc::channel();
+    let reporter = std::thread::spawn(move || hooks::progress::report(rx, steps, progress));
+
+    let outcome = execute_with_hooks(
+        branch,
+        from,
+        cwd,
+        worktree_root,
+        template,
+        sparse,
+        parents,
+        name,
+        db,
+        hooks_config,
+        user_env,
+        no_hooks,
+        Some(&tx),
+        cancel,
+    )
+    .await;
+    drop(tx);
+    let timings = reporter.join().unwrap_or_default();
+
+    Ok((outcome?, timings))
+}
+
 /// Execute a dry-run of `trench create <branch>`.
 ///
 /// Discovers the repo and resolves the worktree path, but performs no git
@@ -130,11 +275,21 @@ pub fn execute_dry_run(
     cwd: &Path,
     worktree_root: &Path,
     template: &str,
+    sparse: Option<&[String]>,
+    name: Option<&str>,
     hooks: Option<&HooksConfig>,
 ) -> Result<DryRunPlan> {
+    if let Some(paths) = sparse {
+        validate_sparse_paths(paths)?;
+    }
+    worktree_name(branch, name)?;
     let repo_info = git::discover_repo(cwd)?;
-    let relative_path = paths::render_worktree_path(template, &repo_info.name, branch)?;
-    let worktree_path = worktree_root.join(relative_path);
+    let worktree_path = paths::resolve_worktree_path(
+        worktree_root,
+        template,
+        &repo_info.name,
+        name.unwrap_or(branch),
+    )?;
     let base = from.unwrap_or(&repo_info.default_branch);
 
     Ok(DryRunPlan {
@@ -143,10 +298,24 @@ pub fn execute_dry_run(
         base_branch: base.to_string(),
         worktree_path: worktree_path.to_string_lossy().to_string(),
         repo_name: repo_info.name.clone(),
+        name: name.map(String::from),
+        sparse: sparse.map(<[String]>::to_vec),
         hooks: hooks.cloned(),
     })
 }
 
+/// Reject sparse-checkout configs that would produce an empty or ambiguous
+/// checkout: the list must be non-empty and every entry must name a path.
+fn validate_sparse_paths(paths: &[String]) -> Result<()> {
+    if paths.is_empty() {
+        anyhow::bail!("worktrees.sparse must list at least one path");
+    }
+    if paths.iter().any(|p| p.trim_matches('/').trim().is_empty()) {
+        anyhow::bail!("worktrees.sparse entries must be non-empty paths");
+    }
+    Ok(())
+}
+
 fn path_to_utf8(path: &Path) -> Result<&str> {
     path.to_str()
         .ok_or_else(|| anyhow::anyhow!("path is not valid UTF-8: {}", path.display()))
@@ -161,6 +330,24 @@ pub struct CreateWithHooksResult {
     /// If post_create hook failed, this contains the error.
     /// The worktree was still created successfully.
     pub post_create_error: Option<anyhow::Error>,
+    /// Files the post_create copy step copied (relative names).
+    pub copied_files: Vec<String>,
+}
+
+/// One-line summary of a copy step, e.g.
+/// `copied 3 files (.env, .env.local, config.toml)`; `None` when nothing
+/// was copied.
+pub fn format_copy_summary(files: &[String]) -> Option<String> {
+    let noun = match files.len() {
+        0 => return None,
+        1 => "file",
+        _ => "files",
+    };
+    Some(format!(
+        "copied {} {noun} ({})",
+        files.len(),
+        files.join(", ")
+    ))
 }
 
 /// Execute `trench create <branch>` with lifecycle hooks.
@@ -168,17 +355,28 @@ pub struct CreateWithHooksResult {
 /// Orchestrates: pre_create hook → worktree creation → post_create hook.
 /// - If `no_hooks` is true or no hooks configured, hooks are skipped.
 /// - Pre_create failure cancels the operation (worktree not created).
-/// - Post_create failure: worktree stays, error captured in result.
+/// - Post_create failure: worktree stays, error captured in result and a
+///   `hook_failed` event is recorded.
+/// - `user_env` (from `--env`) is added to every hook's environment.
+/// - If `cancel` fires (Ctrl-C), remaining hook steps are skipped; a worktree
+///   that was already created is kept and the interruption is reported via
+///   `post_create_error`.
+#[allow(clippy::too_many_arguments)]
 pub async fn execute_with_hooks(
     branch: &str,
     from: Option<&str>,
     cwd: &Path,
     worktree_root: &Path,
     template: &str,
+    sparse: Option<&[String]>,
+    parents: bool,
+    name: Option<&str>,
     db: &Database,
     hooks_config: Option<&HooksConfig>,
+    user_env: &[(String, String)],
     no_hooks: bool,
     hook_tx: Option<&std::sync::mpsc::Sender<crate::tui::screens::hook_log::HookOutputMessage>>,
+    cancel: Option<&hooks::runner::HookCancel>,
 ) -> Result<CreateWithHooksResult> {
     let has_hooks = hooks_config
         .map(|h| h.pre_create.is_some() || h.post_create.is_some())
@@ -191,11 +389,22 @@ pub async fn execute_with_hooks(
         } else {
             HooksStatus::None
         };
-        let result = execute(branch, from, cwd, worktree_root, template, db)?;
+        let result = execute_with_options(
+            branch,
+            from,
+            cwd,
+            worktree_root,
+            template,
+            sparse,
+            parents,
+            name,
+            db,
+        )?;
         return Ok(CreateWithHooksResult {
             result,
             hooks_status,
             post_create_error: None,
+            copied_files: Vec::new(),
         });
     }
 
@@ -203,21 +412,27 @@ pub async fn execute_with_hooks(
 
     // Pre-compute info needed for hooks
     let repo_info = git::discover_repo(cwd)?;
-    let relative_path = paths::render_worktree_path(template, &repo_info.name, branch)?;
-    let worktree_path = worktree_root.join(relative_path);
+    let sanitized_name = worktree_name(branch, name)?;
+    ensure_not_tracked(db, &repo_info, branch, &sanitized_name)?;
+    let worktree_path = paths::resolve_worktree_path(
+        worktree_root,
+        template,
+        &repo_info.name,
+        name.unwrap_or(branch),
+    )?;
     let base = from.unwrap_or(&repo_info.default_branch);
-    let sanitized_name = paths::sanitize_branch(branch);
+    if !parents {
+        // Fail before pre_create hooks run, not after.
+        prepare_parent_dir(&worktree_path, false)?;
+    }
 
     // Ensure repo in DB for hook event logging
     let repo_path_str = path_to_utf8(&repo_info.path)?;
-    let repo = match db.get_repo_by_path(repo_path_str)? {
-        Some(r) => r,
-        None => db.insert_repo(
-            &repo_info.name,
-            repo_path_str,
-            Some(&repo_info.default_branch),
-        )?,
-    };
+    let repo = db.upsert_repo(
+        &repo_info.name,
+        repo_path_str,
+        Some(&repo_info.default_branch),
+    )?;
 
     let env_ctx = HookEnvContext {
         worktree_path: worktree_path.to_string_lossy().to_string(),
@@ -226,11 +441,12 @@ pub async fn execute_with_hooks(
         repo_name: repo_info.name.clone(),
         repo_path: repo_info.path.to_string_lossy().to_string(),
         base_branch: base.to_string(),
+        user_env: user_env.to_vec(),
     };
 
     // Step 1: pre_create hook (cwd = repo path, no worktree_id yet)
     if let Some(pre_create) = &hooks.pre_create {
-        hooks::runner::execute_hook(
+        hooks::runner::execute_hook_with_cancel(
             &HookEvent::PreCreate,
             pre_create,
             &env_ctx,
@@ -240,21 +456,29 @@ pub async fn execute_with_hooks(
             repo.id,
             None,
             hook_tx,
+            cancel,
         )
         .await
         .map_err(CreateError::PreCreateHookFailed)?;
     }
 
     // Step 2: create worktree
-    let result = execute(branch, from, cwd, worktree_root, template, db)?;
+    let result = execute_with_options(
+        branch,
+        from,
+        cwd,
+        worktree_root,
+        template,
+        sparse,
+        parents,
+        name,
+        db,
+    )?;
 
     // Step 3: post_create hook (cwd = worktree path)
+    let mut copied_files = Vec::new();
     let post_create_error = if let Some(post_create) = &hooks.post_create {
-        // Look up worktree_id for DB logging
-        let wt = db.find_worktree_by_identifier(repo.id, branch)?;
-        let worktree_id = wt.map(|w| w.id);
-
-        match hooks::runner::execute_hook(
+        match hooks::runner::execute_hook_with_cancel(
             &HookEvent::PostCreate,
             post_create,
             &env_ctx,
@@ -262,13 +486,37 @@ pub async fn execute_with_hooks(
             &result.path,
             db,
             repo.id,
-            worktree_id,
+            Some(result.worktree_id),
             hook_tx,
+            cancel,
         )
         .await
         {
-            Ok(_) => None,
-            Err(e) => Some(e),
+            Ok(hook_result) => {
+                copied_files = hook_result.copied;
+                None
+            }
+            Err(e) => {
+                // The worktree stays; record the failure against it so
+                // `trench log` shows why setup is incomplete. Interruptions
+                // already have their own `interrupted` event.
+                if e.downcast_ref::<hooks::runner::HookInterruptedError>()
+                    .is_none()
+                {
+                    let payload = serde_json::json!({
+                        "hook": HookEvent::PostCreate.as_str(),
+                        "step": hooks::runner::failed_step(&e),
+                        "error": format!("{e:#}"),
+                    });
+                    db.insert_event(
+                        repo.id,
+                        Some(result.worktree_id),
+                        "hook_failed",
+                        Some(&payload),
+                    )?;
+                }
+                Some(e)
+            }
         }
     } else {
         None
@@ -278,6 +526,7 @@ pub async fn execute_with_hooks(
         result,
         hooks_status: HooksStatus::Ran,
         post_create_error,
+        copied_files,
     })
 }
 
@@ -285,63 +534,217 @@ pub async fn execute_with_hooks(
 ///
 /// Discovers the git repo, resolves the worktree path, creates the worktree
 /// on disk, persists the record to SQLite, and returns the created path.
+/// When `sparse` is set, the checkout is restricted to those directories.
 pub fn execute(
     branch: &str,
     from: Option<&str>,
     cwd: &Path,
     worktree_root: &Path,
     template: &str,
+    sparse: Option<&[String]>,
+    db: &Database,
+) -> Result<CreateResult> {
+    execute_with_options(
+        branch,
+        from,
+        cwd,
+        worktree_root,
+        template,
+        sparse,
+        true,
+        None,
+        db,
+    )
+}
+
+/// [`execute`] with the remaining `create` flags:
+/// - `parents` false (`--parents=false`): a missing parent directory is an
+///   error instead of being created.
+/// - `name` (`--name`): worktree name and directory, in place of the
+///   sanitized branch.
+#[allow(clippy::too_many_arguments)]
+pub fn execute_with_options(
+    branch: &str,
+    from: Option<&str>,
+    cwd: &Path,
+    worktree_root: &Path,
+    template: &str,
+    sparse: Option<&[String]>,
+    parents: bool,
+    name: Option<&str>,
     db: &Database,
 ) -> Result<CreateResult> {
+    if let Some(paths) = sparse {
+        validate_sparse_paths(paths)?;
+    }
     let repo_info = git::discover_repo(cwd)?;
-    let relative_path = paths::render_worktree_path(template, &repo_info.name, branch)?;
-    let worktree_path = worktree_root.join(relative_path);
+    let sanitized_name = worktree_name(branch, name)?;
+    ensure_not_tracked(db, &repo_info, branch, &sanitized_name)?;
+    let worktree_path = paths::resolve_worktree_path(
+        worktree_root,
+        template,
+        &repo_info.name,
+        name.unwrap_or(branch),
+    )?;
     let base = from.unwrap_or(&repo_info.default_branch);
 
-    if let Some(parent) = worktree_path.parent() {
-        std::fs::create_dir_all(parent).with_context(|| {
-            format!(
-                "failed to create worktree parent directory: {}",
-                parent.display()
-            )
-        })?;
-    }
+    prepare_parent_dir(&worktree_path, parents)?;
+
+    git::create_named_worktree(
+        &repo_info.path,
+        branch,
+        base,
+        &worktree_path,
+        &sanitized_name,
+    )?;
 
-    git::create_worktree(&repo_info.path, branch, base, &worktree_path)?;
+    // From here on the worktree exists in git; any failure undoes it so
+    // there is never a checkout on disk without its DB record.
+    let recorded = (|| -> Result<_> {
+        if let Some(paths) = sparse {
+            git::apply_sparse_checkout(&worktree_path, paths).with_context(|| {
+                format!(
+                    "failed to configure sparse-checkout in {}",
+                    worktree_path.display()
+                )
+            })?;
+        }
 
-    let repo_path_str = path_to_utf8(&repo_info.path)?;
-    let repo = match db.get_repo_by_path(repo_path_str)? {
-        Some(r) => r,
-        None => db.insert_repo(
+        let repo_path_str = path_to_utf8(&repo_info.path)?;
+        let repo = db.upsert_repo(
             &repo_info.name,
             repo_path_str,
             Some(&repo_info.default_branch),
-        )?,
-    };
-
-    let sanitized_name = paths::sanitize_branch(branch);
-    let canonical_worktree_path = worktree_path
-        .canonicalize()
-        .with_context(|| format!("failed to canonicalize {}", worktree_path.display()))?;
-    let worktree_path_str = path_to_utf8(&canonical_worktree_path)?;
-    let wt = db.insert_worktree(
-        repo.id,
-        &sanitized_name,
-        branch,
-        worktree_path_str,
-        Some(base),
-    )?;
+        )?;
+        if repo.remote_url != repo_info.remote_url {
+            db.set_repo_remote_url(repo.id, repo_info.remote_url.as_deref())?;
+        }
 
-    db.insert_event(repo.id, Some(wt.id), "created", None)?;
+        let canonical_worktree_path = worktree_path
+            .canonicalize()
+            .with_context(|| format!("failed to canonicalize {}", worktree_path.display()))?;
+        let worktree_path_str = path_to_utf8(&canonical_worktree_path)?;
+        let payload = sparse.map(|paths| serde_json::json!({ "sparse": paths }));
+        let wt = db.with_transaction(|tx| {
+            let wt = tx.insert_worktree(
+                repo.id,
+                &sanitized_name,
+                branch,
+                worktree_path_str,
+                Some(base),
+            )?;
+            tx.insert_event(repo.id, Some(wt.id), "created", payload.as_ref())?;
+            Ok(wt)
+        })?;
+        Ok((canonical_worktree_path, wt))
+    })();
+    let (canonical_worktree_path, wt) = match recorded {
+        Ok(recorded) => recorded,
+        Err(e) => {
+            rollback_created_worktree(&repo_info.path, &worktree_path, branch);
+            return Err(e);
+        }
+    };
 
     Ok(CreateResult {
         name: sanitized_name,
         branch: branch.to_string(),
         path: canonical_worktree_path,
         base_branch: base.to_string(),
+        repo: repo_info.name,
+        worktree_id: wt.id,
     })
 }
 
+/// Undo a worktree that `create` added to git before a later step failed:
+/// remove its directory and registration, then the branch it created.
+///
+/// Best-effort: the caller reports the original failure, so cleanup
+/// problems only warn, naming what is left behind.
+fn rollback_created_worktree(repo_path: &Path, worktree_path: &Path, branch: &str) {
+    if let Err(e) = git::remove_worktree(repo_path, worktree_path) {
+        eprintln!(
+            "warning: could not roll back worktree at {}: {e}",
+            worktree_path.display()
+        );
+        return;
+    }
+    if let Err(e) = git::delete_local_branch(repo_path, branch, true) {
+        eprintln!("warning: could not roll back branch '{branch}': {e}");
+    }
+}
+
+/// Make sure the directory that will hold `worktree_path` exists.
+///
+/// With `parents`, missing directories are created; otherwise a missing
+/// parent is [`CreateError::ParentMissing`]. Failures are classified so the
+/// user learns whether a path component is a file, is not writable, or sits
+/// on a read-only filesystem.
+fn prepare_parent_dir(worktree_path: &Path, parents: bool) -> Result<()> {
+    let Some(parent) = worktree_path.parent() else {
+        return Ok(());
+    };
+    if parent.is_dir() {
+        return Ok(());
+    }
+    if let Some(blocker) = parent.ancestors().find(|p| p.exists() && !p.is_dir()) {
+        return Err(CreateError::ParentIsFile {
+            path: blocker.to_path_buf(),
+        }
+        .into());
+    }
+    if !parents {
+        return Err(CreateError::ParentMissing {
+            path: parent.to_path_buf(),
+        }
+        .into());
+    }
+
+    std::fs::create_dir_all(parent).map_err(|e| classify_parent_error(parent, e))
+}
+
+fn classify_parent_error(parent: &Path, e: std::io::Error) -> anyhow::Error {
+    let path = parent.to_path_buf();
+    match e.kind() {
+        std::io::ErrorKind::PermissionDenied => CreateError::ParentPermissionDenied { path }.into(),
+        std::io::ErrorKind::ReadOnlyFilesystem => CreateError::ParentReadOnly { path }.into(),
+        std::io::ErrorKind::NotADirectory => CreateError::ParentIsFile { path }.into(),
+        _ => anyhow::Error::new(e).context(format!(
+            "failed to create worktree parent directory: {}",
+            parent.display()
+        )),
+    }
+}
+
+/// Re-point a freshly created worktree's recorded base at an upstream.
+///
+/// Prefers the new branch's own upstream, then the upstream of the base it
+/// was created from. Returns the stored base when one was found; otherwise
+/// the record is left untouched.
+pub fn record_upstream_base(
+    db: &Database,
+    repo_path: &Path,
+    result: &mut CreateResult,
+) -> Result<Option<String>> {
+    let upstream = match git::upstream_branch_name(&result.path, &result.branch)? {
+        Some(upstream) => Some(upstream),
+        None => git::upstream_branch_name(repo_path, &result.base_branch)?,
+    };
+    let Some(upstream) = upstream else {
+        return Ok(None);
+    };
+
+    db.update_worktree(
+        result.worktree_id,
+        &WorktreeUpdate {
+            base_branch: Some(Some(upstream.clone())),
+            ..Default::default()
+        },
+    )?;
+    result.base_branch = upstream.clone();
+    Ok(Some(upstream))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -397,6 +800,7 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
         .expect("create should succeed");
@@ -469,6 +873,7 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         );
 
@@ -513,6 +918,7 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         );
 
@@ -540,6 +946,7 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
         .expect("first create should succeed");
@@ -550,6 +957,7 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
         .expect("second create should succeed");
@@ -609,6 +1017,7 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
         .expect("create with --from develop should succeed");
@@ -665,6 +1074,7 @@ mod tests {
             local_dir.path(),
             wt_root.path(),
             paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         );
 
@@ -713,6 +1123,8 @@ mod tests {
             wt_root.path(),
             paths::DEFAULT_WORKTREE_TEMPLATE,
             None,
+            None,
+            None,
         )
         .expect("dry-run should succeed");
 
@@ -764,6 +1176,8 @@ mod tests {
             base_branch: "main".to_string(),
             worktree_path: "/home/.worktrees/repo/my-feature".to_string(),
             repo_name: "repo".to_string(),
+            name: None,
+            sparse: None,
             hooks: None,
         };
 
@@ -788,6 +1202,8 @@ mod tests {
             base_branch: "main".to_string(),
             worktree_path: "/home/.worktrees/repo/my-feature".to_string(),
             repo_name: "repo".to_string(),
+            name: None,
+            sparse: None,
             hooks: None,
         };
 
@@ -828,6 +1244,8 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
+            None,
             Some(&hooks),
         )
         .expect("dry-run should succeed");
@@ -853,6 +1271,8 @@ mod tests {
             base_branch: "main".to_string(),
             worktree_path: "/tmp/wt/foo".to_string(),
             repo_name: "repo".to_string(),
+            name: None,
+            sparse: None,
             hooks: Some(HooksConfig {
                 post_create: Some(HookDef {
                     copy: Some(vec![".env*".to_string()]),
@@ -882,6 +1302,8 @@ mod tests {
             base_branch: "main".to_string(),
             worktree_path: "/tmp/wt/foo".to_string(),
             repo_name: "repo".to_string(),
+            name: None,
+            sparse: None,
             hooks: Some(HooksConfig {
                 post_create: Some(HookDef {
                     run: Some(vec!["bun install".to_string()]),
@@ -907,6 +1329,8 @@ mod tests {
             base_branch: "main".to_string(),
             worktree_path: "/tmp/wt/foo".to_string(),
             repo_name: "repo".to_string(),
+            name: None,
+            sparse: None,
             hooks: Some(HooksConfig {
                 post_create: Some(HookDef {
                     run: Some(vec!["bun install".to_string()]),
@@ -931,6 +1355,8 @@ mod tests {
             base_branch: "main".to_string(),
             worktree_path: "/tmp/wt/foo".to_string(),
             repo_name: "repo".to_string(),
+            name: None,
+            sparse: None,
             hooks: Some(crate::config::HooksConfig::default()),
         };
 
@@ -955,6 +1381,7 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
         .expect("create should succeed");
@@ -966,167 +1393,479 @@ mod tests {
     }
 
     #[test]
-    fn create_result_serializes_to_json_with_all_required_fields() {
-        use crate::output::json::format_json_value;
-
-        let result = CreateResult {
-            name: "my-feature".to_string(),
-            branch: "my-feature".to_string(),
-            path: std::path::PathBuf::from("/home/.worktrees/repo/my-feature"),
-            base_branch: "main".to_string(),
-        };
-
-        let hooks = HooksStatus::None;
-        let json_output = result.to_json_output(hooks);
-        let json_str = format_json_value(&json_output).expect("should serialize to JSON");
-        let parsed: serde_json::Value =
-            serde_json::from_str(&json_str).expect("should be valid JSON");
-
-        assert_eq!(parsed["worktree"], "my-feature");
-        assert_eq!(parsed["branch"], "my-feature");
-        assert_eq!(parsed["path"], "/home/.worktrees/repo/my-feature");
-        assert_eq!(parsed["base_branch"], "main");
-        assert_eq!(parsed["hooks"]["status"], "none");
-    }
-
-    #[test]
-    fn integration_create_json_output_matches_real_worktree() {
-        use crate::output::json::format_json_value;
-
+    fn create_with_absolute_template_bypasses_worktree_root() {
         let repo_dir = tempfile::tempdir().unwrap();
         let _repo = init_repo_with_commit(repo_dir.path());
         let wt_root = tempfile::tempdir().unwrap();
-        let db_dir = tempfile::tempdir().unwrap();
-        let db = Database::open(&db_dir.path().join("test.db")).unwrap();
+        let fast_disk = tempfile::tempdir().unwrap();
+        let db = Database::open_in_memory().unwrap();
+        let template = format!("{}/{{{{ branch | sanitize }}}}", fast_disk.path().display());
 
         let result = execute(
-            "json-test",
+            "feature/abs",
             None,
             repo_dir.path(),
             wt_root.path(),
-            paths::DEFAULT_WORKTREE_TEMPLATE,
+            &template,
+            None,
             &db,
         )
         .expect("create should succeed");
 
-        let json_output = result.to_json_output(HooksStatus::None);
-        let json_str = format_json_value(&json_output).expect("should serialize");
-        let parsed: serde_json::Value = serde_json::from_str(&json_str).expect("valid JSON");
-
-        // worktree name is the sanitized branch name
-        assert_eq!(parsed["worktree"], "json-test");
-        // branch is the original branch name
-        assert_eq!(parsed["branch"], "json-test");
-        // path is a real path on disk that exists
-        let path_str = parsed["path"].as_str().unwrap();
-        assert!(
-            std::path::Path::new(path_str).exists(),
-            "path should exist on disk"
-        );
-        // base_branch is set to the repo's default
-        assert!(!parsed["base_branch"].as_str().unwrap().is_empty());
-        // hooks status reflects no hooks configured
-        assert_eq!(parsed["hooks"]["status"], "none");
+        assert_eq!(result.path, fast_disk.path().join("feature-abs"));
+        assert!(result.path.exists());
+        assert_eq!(std::fs::read_dir(wt_root.path()).unwrap().count(), 0);
     }
 
     #[test]
-    fn dry_run_with_from_shows_custom_base() {
+    fn base_from_upstream_records_tracking_branch_as_base() {
         let repo_dir = tempfile::tempdir().unwrap();
         let repo = init_repo_with_commit(repo_dir.path());
-        let wt_root = tempfile::tempdir().unwrap();
-
-        // Create a "develop" branch so --from has something valid
-        let head_commit = repo.head().unwrap().peel_to_commit().unwrap();
-        repo.branch("develop", &head_commit, false).unwrap();
+        let head = repo.head().unwrap().peel_to_commit().unwrap();
+        repo.remote("origin", "/nonexistent/origin.git").unwrap();
+        repo.reference("refs/remotes/origin/release", head.id(), false, "test")
+            .unwrap();
+        let mut topic = repo.branch("topic", &head, false).unwrap();
+        topic.set_upstream(Some("origin/release")).unwrap();
 
-        let plan = execute_dry_run(
-            "my-feature",
-            Some("develop"),
+        let wt_root = tempfile::tempdir().unwrap();
+        let db = Database::open_in_memory().unwrap();
+        let mut result = execute(
+            "feature/tracked",
+            Some("topic"),
             repo_dir.path(),
             wt_root.path(),
             paths::DEFAULT_WORKTREE_TEMPLATE,
             None,
+            &db,
         )
-        .expect("dry-run with --from should succeed");
+        .expect("create should succeed");
+        assert_eq!(result.base_branch, "topic");
 
-        assert_eq!(
-            plan.base_branch, "develop",
-            "base should reflect --from override"
-        );
+        let repo_path = git::discover_repo(repo_dir.path()).unwrap().path;
+        let stored = record_upstream_base(&db, &repo_path, &mut result).unwrap();
+        assert_eq!(stored.as_deref(), Some("release"));
+        assert_eq!(result.base_branch, "release");
+
+        let repo_row = db.list_repos().unwrap().remove(0);
+        let wt = db
+            .find_worktree_by_branch(repo_row.id, "feature/tracked")
+            .unwrap()
+            .unwrap();
+        assert_eq!(wt.base_branch.as_deref(), Some("release"));
     }
 
     #[test]
-    fn create_with_from_stores_default_branch_not_from_override() {
+    fn base_from_upstream_leaves_base_when_no_upstream() {
         let repo_dir = tempfile::tempdir().unwrap();
-        let repo = init_repo_with_commit(repo_dir.path());
+        let _repo = init_repo_with_commit(repo_dir.path());
         let wt_root = tempfile::tempdir().unwrap();
-        let db_dir = tempfile::tempdir().unwrap();
-        let db = Database::open(&db_dir.path().join("test.db")).unwrap();
-
-        // Determine HEAD branch name (the repo's true default)
-        let head_branch = repo.head().unwrap().shorthand().unwrap().to_string();
-
-        // Create a second branch "develop" to use as --from
-        let head_commit = repo.head().unwrap().peel_to_commit().unwrap();
-        repo.branch("develop", &head_commit, false).unwrap();
-
-        let _result = execute(
-            "my-feature",
-            Some("develop"),
+        let db = Database::open_in_memory().unwrap();
+        let mut result = execute(
+            "feature/plain",
+            None,
             repo_dir.path(),
             wt_root.path(),
-            crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
-        .expect("create with --from should succeed");
-
-        let repo_path_str = repo_dir
-            .path()
-            .canonicalize()
-            .unwrap()
-            .to_str()
-            .unwrap()
-            .to_string();
-        let db_repo = db
-            .get_repo_by_path(&repo_path_str)
-            .unwrap()
-            .expect("repo should be in DB");
+        .unwrap();
+        let original_base = result.base_branch.clone();
 
-        assert_eq!(
-            db_repo.default_base.as_deref(),
-            Some(head_branch.as_str()),
-            "repos.default_base should be the HEAD branch, not the --from override"
-        );
+        let repo_path = git::discover_repo(repo_dir.path()).unwrap().path;
+        let stored = record_upstream_base(&db, &repo_path, &mut result).unwrap();
+        assert!(stored.is_none());
+        assert_eq!(result.base_branch, original_base);
     }
 
-    #[tokio::test(flavor = "current_thread")]
-    async fn execute_with_hooks_no_hooks_configured_returns_none_status() {
+    #[test]
+    fn create_result_carries_worktree_id_and_repo() {
         let repo_dir = tempfile::tempdir().unwrap();
         let _repo = init_repo_with_commit(repo_dir.path());
         let wt_root = tempfile::tempdir().unwrap();
-        let db_dir = tempfile::tempdir().unwrap();
-        let db = Database::open(&db_dir.path().join("test.db")).unwrap();
+        let db = Database::open_in_memory().unwrap();
 
-        let result = execute_with_hooks(
-            "my-feature",
+        let result = execute(
+            "feature/outcome",
             None,
             repo_dir.path(),
             wt_root.path(),
             paths::DEFAULT_WORKTREE_TEMPLATE,
-            &db,
-            None,  // no hooks configured
-            false, // no_hooks flag = false
             None,
+            &db,
         )
-        .await
-        .expect("should succeed");
-
-        assert!(matches!(result.hooks_status, HooksStatus::None));
-        assert!(result.result.path.exists(), "worktree should be created");
-        assert!(result.post_create_error.is_none());
-    }
+        .unwrap();
 
-    #[tokio::test(flavor = "current_thread")]
+        let repo = db.list_repos().unwrap().remove(0);
+        let row = db
+            .find_worktree_by_branch(repo.id, "feature/outcome")
+            .unwrap()
+            .unwrap();
+        assert_eq!(result.worktree_id, row.id);
+        assert_eq!(result.repo, repo.name);
+        assert_eq!(
+            Some(result.base_branch.as_str()),
+            row.base_branch.as_deref()
+        );
+    }
+
+    #[test]
+    fn db_failure_after_git_create_rolls_back_worktree_and_branch() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let repo = init_repo_with_commit(repo_dir.path());
+        let wt_root = tempfile::tempdir().unwrap();
+        let db = Database::open_in_memory().unwrap();
+        db.conn_for_test()
+            .execute_batch(
+                "CREATE TRIGGER fail_insert BEFORE INSERT ON worktrees
+                 BEGIN SELECT RAISE(ABORT, 'injected failure'); END;",
+            )
+            .unwrap();
+        let create = || {
+            execute(
+                "feature/rollback",
+                None,
+                repo_dir.path(),
+                wt_root.path(),
+                paths::DEFAULT_WORKTREE_TEMPLATE,
+                None,
+                &db,
+            )
+        };
+
+        let err = create().expect_err("DB insert failure must fail create");
+
+        assert!(format!("{err:#}").contains("injected failure"), "{err:#}");
+        let planned = paths::resolve_worktree_path(
+            wt_root.path(),
+            paths::DEFAULT_WORKTREE_TEMPLATE,
+            &git::discover_repo(repo_dir.path()).unwrap().name,
+            "feature/rollback",
+        )
+        .unwrap();
+        assert!(!planned.exists(), "worktree dir should be removed");
+        assert!(repo.worktrees().unwrap().is_empty(), "git should forget it");
+        assert!(repo
+            .find_branch("feature/rollback", git2::BranchType::Local)
+            .is_err());
+
+        // With the fault gone, the same create succeeds.
+        db.conn_for_test()
+            .execute_batch("DROP TRIGGER fail_insert;")
+            .unwrap();
+        create().expect("create should succeed after rollback");
+    }
+
+    #[test]
+    fn create_rejects_branch_already_tracked() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let _repo = init_repo_with_commit(repo_dir.path());
+        let wt_root = tempfile::tempdir().unwrap();
+        let db = Database::open_in_memory().unwrap();
+        let create = |branch: &str| {
+            execute(
+                branch,
+                None,
+                repo_dir.path(),
+                wt_root.path(),
+                paths::DEFAULT_WORKTREE_TEMPLATE,
+                None,
+                &db,
+            )
+        };
+
+        let first = create("feature/dup").expect("first create should succeed");
+
+        let err = create("feature/dup").expect_err("duplicate branch must be rejected");
+        match err.downcast_ref::<CreateError>() {
+            Some(CreateError::AlreadyTracked { branch, path }) => {
+                assert_eq!(branch, "feature/dup");
+                assert_eq!(Path::new(path), first.path);
+            }
+            other => panic!("expected AlreadyTracked, got: {other:?} ({err:#})"),
+        }
+
+        create("feature/other").expect("distinct branch should be unaffected");
+        let repo = db.list_repos().unwrap().remove(0);
+        assert_eq!(db.list_worktrees(repo.id).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn create_with_sparse_path_materializes_only_that_directory() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let repo = init_repo_with_commit(repo_dir.path());
+        for file in ["services/api/main.rs", "services/web/index.ts"] {
+            let path = repo_dir.path().join(file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(&path, "content").unwrap();
+        }
+        {
+            let mut index = repo.index().unwrap();
+            index.add_path(Path::new("services/api/main.rs")).unwrap();
+            index.add_path(Path::new("services/web/index.ts")).unwrap();
+            index.write().unwrap();
+            let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+            let sig = git2::Signature::now("Test", "test@test.com").unwrap();
+            let parent = repo.head().unwrap().peel_to_commit().unwrap();
+            repo.commit(Some("HEAD"), &sig, &sig, "add services", &tree, &[&parent])
+                .unwrap();
+        }
+        let wt_root = tempfile::tempdir().unwrap();
+        let db_dir = tempfile::tempdir().unwrap();
+        let db = Database::open(&db_dir.path().join("test.db")).unwrap();
+        let sparse = vec!["services/api".to_string()];
+
+        let result = execute(
+            "sparse-feature",
+            None,
+            repo_dir.path(),
+            wt_root.path(),
+            paths::DEFAULT_WORKTREE_TEMPLATE,
+            Some(&sparse),
+            &db,
+        )
+        .expect("sparse create should succeed");
+
+        assert!(result.path.join("services/api/main.rs").exists());
+        assert!(
+            !result.path.join("services/web").exists(),
+            "directories outside the sparse set should not be checked out"
+        );
+
+        let wt = db
+            .find_worktree_by_identifier(
+                db.get_repo_by_path(repo_dir.path().canonicalize().unwrap().to_str().unwrap())
+                    .unwrap()
+                    .unwrap()
+                    .id,
+                "sparse-feature",
+            )
+            .unwrap()
+            .unwrap();
+        let events = db.list_events(wt.id, 10).unwrap();
+        let payload: serde_json::Value =
+            serde_json::from_str(events[0].payload.as_deref().unwrap()).unwrap();
+        assert_eq!(payload["sparse"], serde_json::json!(["services/api"]));
+    }
+
+    #[test]
+    fn create_rejects_blank_sparse_path_before_touching_git() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let _repo = init_repo_with_commit(repo_dir.path());
+        let wt_root = tempfile::tempdir().unwrap();
+        let db = Database::open_in_memory().unwrap();
+        let sparse = vec!["  ".to_string()];
+
+        let err = execute(
+            "blank-sparse",
+            None,
+            repo_dir.path(),
+            wt_root.path(),
+            paths::DEFAULT_WORKTREE_TEMPLATE,
+            Some(&sparse),
+            &db,
+        )
+        .expect_err("blank sparse path should be rejected");
+
+        assert!(err.to_string().contains("worktrees.sparse"), "got: {err}");
+        assert!(
+            git2::Repository::open(repo_dir.path())
+                .unwrap()
+                .find_branch("blank-sparse", git2::BranchType::Local)
+                .is_err(),
+            "no branch should be created when validation fails"
+        );
+    }
+
+    #[test]
+    fn create_result_serializes_to_json_with_all_required_fields() {
+        use crate::output::json::format_json_value;
+
+        let result = CreateResult {
+            name: "my-feature".to_string(),
+            branch: "my-feature".to_string(),
+            path: std::path::PathBuf::from("/home/.worktrees/repo/my-feature"),
+            base_branch: "main".to_string(),
+            repo: "repo".to_string(),
+            worktree_id: 1,
+        };
+
+        let hooks = HooksStatus::None;
+        let json_output = result.to_json_output(hooks);
+        let json_str = format_json_value(&json_output).expect("should serialize to JSON");
+        let parsed: serde_json::Value =
+            serde_json::from_str(&json_str).expect("should be valid JSON");
+
+        assert_eq!(parsed["worktree"], "my-feature");
+        assert_eq!(parsed["branch"], "my-feature");
+        assert_eq!(parsed["path"], "/home/.worktrees/repo/my-feature");
+        assert_eq!(parsed["base_branch"], "main");
+        assert_eq!(parsed["repo"], "repo");
+        assert_eq!(parsed["hooks"]["status"], "none");
+    }
+
+    #[test]
+    fn integration_create_json_output_matches_real_worktree() {
+        use crate::output::json::format_json_value;
+
+        let repo_dir = tempfile::tempdir().unwrap();
+        let _repo = init_repo_with_commit(repo_dir.path());
+        let wt_root = tempfile::tempdir().unwrap();
+        let db_dir = tempfile::tempdir().unwrap();
+        let db = Database::open(&db_dir.path().join("test.db")).unwrap();
+
+        let result = execute(
+            "json-test",
+            None,
+            repo_dir.path(),
+            wt_root.path(),
+            paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
+            &db,
+        )
+        .expect("create should succeed");
+
+        let json_output = result.to_json_output(HooksStatus::None);
+        let json_str = format_json_value(&json_output).expect("should serialize");
+        let parsed: serde_json::Value = serde_json::from_str(&json_str).expect("valid JSON");
+
+        // worktree name is the sanitized branch name
+        assert_eq!(parsed["worktree"], "json-test");
+        // branch is the original branch name
+        assert_eq!(parsed["branch"], "json-test");
+        // path is a real path on disk that exists
+        let path_str = parsed["path"].as_str().unwrap();
+        assert!(
+            std::path::Path::new(path_str).exists(),
+            "path should exist on disk"
+        );
+        // base_branch is set to the repo's default
+        assert!(!parsed["base_branch"].as_str().unwrap().is_empty());
+        // repo is the discovered repository name
+        let repo_name = repo_dir.path().canonicalize().unwrap();
+        assert_eq!(
+            parsed["repo"],
+            repo_name.file_name().unwrap().to_str().unwrap()
+        );
+        // hooks status reflects no hooks configured
+        assert_eq!(parsed["hooks"]["status"], "none");
+        assert_eq!(parsed["files_copied"], 0);
+        let keys: Vec<&str> = parsed
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(String::as_str)
+            .collect();
+        assert_eq!(keys.len(), 7, "unexpected create --json shape: {keys:?}");
+    }
+
+    #[test]
+    fn dry_run_with_from_shows_custom_base() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let repo = init_repo_with_commit(repo_dir.path());
+        let wt_root = tempfile::tempdir().unwrap();
+
+        // Create a "develop" branch so --from has something valid
+        let head_commit = repo.head().unwrap().peel_to_commit().unwrap();
+        repo.branch("develop", &head_commit, false).unwrap();
+
+        let plan = execute_dry_run(
+            "my-feature",
+            Some("develop"),
+            repo_dir.path(),
+            wt_root.path(),
+            paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
+            None,
+            None,
+        )
+        .expect("dry-run with --from should succeed");
+
+        assert_eq!(
+            plan.base_branch, "develop",
+            "base should reflect --from override"
+        );
+    }
+
+    #[test]
+    fn create_with_from_stores_default_branch_not_from_override() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let repo = init_repo_with_commit(repo_dir.path());
+        let wt_root = tempfile::tempdir().unwrap();
+        let db_dir = tempfile::tempdir().unwrap();
+        let db = Database::open(&db_dir.path().join("test.db")).unwrap();
+
+        // Determine HEAD branch name (the repo's true default)
+        let head_branch = repo.head().unwrap().shorthand().unwrap().to_string();
+
+        // Create a second branch "develop" to use as --from
+        let head_commit = repo.head().unwrap().peel_to_commit().unwrap();
+        repo.branch("develop", &head_commit, false).unwrap();
+
+        let _result = execute(
+            "my-feature",
+            Some("develop"),
+            repo_dir.path(),
+            wt_root.path(),
+            crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
+            &db,
+        )
+        .expect("create with --from should succeed");
+
+        let repo_path_str = repo_dir
+            .path()
+            .canonicalize()
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_string();
+        let db_repo = db
+            .get_repo_by_path(&repo_path_str)
+            .unwrap()
+            .expect("repo should be in DB");
+
+        assert_eq!(
+            db_repo.default_base.as_deref(),
+            Some(head_branch.as_str()),
+            "repos.default_base should be the HEAD branch, not the --from override"
+        );
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn execute_with_hooks_no_hooks_configured_returns_none_status() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let _repo = init_repo_with_commit(repo_dir.path());
+        let wt_root = tempfile::tempdir().unwrap();
+        let db_dir = tempfile::tempdir().unwrap();
+        let db = Database::open(&db_dir.path().join("test.db")).unwrap();
+
+        let result = execute_with_hooks(
+            "my-feature",
+            None,
+            repo_dir.path(),
+            wt_root.path(),
+            paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
+            true,
+            None,
+            &db,
+            None, // no hooks configured
+            &[],
+            false, // no_hooks flag = false
+            None,
+            None,
+        )
+        .await
+        .expect("should succeed");
+
+        assert!(matches!(result.hooks_status, HooksStatus::None));
+        assert!(result.result.path.exists(), "worktree should be created");
+        assert!(result.post_create_error.is_none());
+    }
+
+    #[tokio::test(flavor = "current_thread")]
     async fn execute_with_hooks_no_hooks_flag_returns_skipped_status() {
         let repo_dir = tempfile::tempdir().unwrap();
         let _repo = init_repo_with_commit(repo_dir.path());
@@ -1148,10 +1887,15 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
+            true,
+            None,
             &db,
             Some(&hooks),
+            &[],
             true, // no_hooks = true → skip
             None,
+            None,
         )
         .await
         .expect("should succeed");
@@ -1184,6 +1928,131 @@ mod tests {
         );
     }
 
+    /// `Write` sink that can be moved to the progress thread and read back.
+    #[derive(Clone, Default)]
+    struct SharedBuf(std::sync::Arc<std::sync::Mutex<Vec<u8>>>);
+
+    impl std::io::Write for SharedBuf {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn multi_step_post_create() -> HooksConfig {
+        HooksConfig {
+            post_create: Some(HookDef {
+                copy: Some(vec![".env*".into()]),
+                run: Some(vec!["true".into(), "echo installed".into()]),
+                ..HookDef::default()
+            }),
+            ..HooksConfig::default()
+        }
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn progress_lines_printed_for_each_hook_step() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let _repo = init_repo_with_commit(repo_dir.path());
+        std::fs::write(repo_dir.path().join(".env.local"), "KEY=1").unwrap();
+        let wt_root = tempfile::tempdir().unwrap();
+        let db = Database::open_in_memory().unwrap();
+        let buf = SharedBuf::default();
+
+        let (outcome, timings) = execute_with_progress(
+            "progress",
+            None,
+            repo_dir.path(),
+            wt_root.path(),
+            paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
+            true,
+            None,
+            &db,
+            Some(&multi_step_post_create()),
+            &[],
+            false,
+            Some(buf.clone()),
+            None,
+        )
+        .await
+        .expect("create should succeed");
+
+        assert!(outcome.post_create_error.is_none());
+        let text = String::from_utf8(buf.0.lock().unwrap().clone()).unwrap();
+        assert!(text.contains("post_create: copying .env* …"), "{text}");
+        assert!(text.contains("post_create: copying .env* ✓"), "{text}");
+        assert!(
+            text.contains("post_create: running true && echo installed …"),
+            "{text}"
+        );
+        assert!(
+            text.contains("post_create: running true && echo installed ✓"),
+            "{text}"
+        );
+        assert_eq!(timings.len(), 2);
+        assert_eq!(outcome.copied_files, [".env.local"]);
+        assert_eq!(
+            format_copy_summary(&outcome.copied_files).as_deref(),
+            Some("copied 1 file (.env.local)")
+        );
+    }
+
+    #[test]
+    fn copy_summary_lists_file_names() {
+        let files = [".env", ".env.local", "config.toml"].map(String::from);
+        assert_eq!(
+            format_copy_summary(&files).as_deref(),
+            Some("copied 3 files (.env, .env.local, config.toml)")
+        );
+        assert_eq!(format_copy_summary(&[]), None);
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn json_mode_has_no_progress_but_reports_step_timings() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let _repo = init_repo_with_commit(repo_dir.path());
+        std::fs::write(repo_dir.path().join(".env"), "KEY=1").unwrap();
+        let wt_root = tempfile::tempdir().unwrap();
+        let db = Database::open_in_memory().unwrap();
+
+        let (outcome, timings) = execute_with_progress::<SharedBuf>(
+            "progress-json",
+            None,
+            repo_dir.path(),
+            wt_root.path(),
+            paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
+            true,
+            None,
+            &db,
+            Some(&multi_step_post_create()),
+            &[],
+            false,
+            None,
+            None,
+        )
+        .await
+        .expect("create should succeed");
+
+        let files_copied = outcome.copied_files.len();
+        let mut json_output = outcome.result.to_json_output(outcome.hooks_status);
+        json_output.hook_steps = timings;
+        json_output.files_copied = files_copied;
+        let json = serde_json::to_value(&json_output).unwrap();
+        assert_eq!(json["files_copied"], 1);
+        let steps = json["hook_steps"].as_array().expect("hook_steps array");
+        assert_eq!(steps.len(), 2);
+        assert_eq!(steps[0]["hook"], "post_create");
+        assert_eq!(steps[0]["step"], "copy");
+        assert_eq!(steps[1]["step"], "run");
+        assert!(steps[1]["duration_ms"].is_u64());
+        assert!(!json.to_string().contains('…'), "no progress text in JSON");
+    }
+
     #[tokio::test(flavor = "current_thread")]
     async fn pre_create_hook_runs_before_worktree_creation() {
         let repo_dir = tempfile::tempdir().unwrap();
@@ -1208,10 +2077,15 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
+            true,
+            None,
             &db,
             Some(&hooks),
+            &[],
             false,
             None,
+            None,
         )
         .await
         .expect("should succeed");
@@ -1224,6 +2098,46 @@ mod tests {
         assert!(matches!(result.hooks_status, HooksStatus::Ran));
     }
 
+    #[tokio::test(flavor = "current_thread")]
+    async fn user_env_reaches_run_commands() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let _repo = init_repo_with_commit(repo_dir.path());
+        let wt_root = tempfile::tempdir().unwrap();
+        let db_dir = tempfile::tempdir().unwrap();
+        let db = Database::open(&db_dir.path().join("test.db")).unwrap();
+
+        let hooks = HooksConfig {
+            post_create: Some(HookDef {
+                run: Some(vec!["echo \"$TICKET $TRENCH_BRANCH\" > ticket.txt".into()]),
+                ..HookDef::default()
+            }),
+            ..HooksConfig::default()
+        };
+
+        let result = execute_with_hooks(
+            "my-feature",
+            None,
+            repo_dir.path(),
+            wt_root.path(),
+            paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
+            true,
+            None,
+            &db,
+            Some(&hooks),
+            &[("TICKET".to_string(), "ABC-123".to_string())],
+            false,
+            None,
+            None,
+        )
+        .await
+        .expect("should succeed");
+
+        assert!(result.post_create_error.is_none());
+        let written = std::fs::read_to_string(result.result.path.join("ticket.txt")).unwrap();
+        assert_eq!(written.trim(), "ABC-123 my-feature");
+    }
+
     #[tokio::test(flavor = "current_thread")]
     async fn pre_create_failure_cancels_worktree_creation() {
         let repo_dir = tempfile::tempdir().unwrap();
@@ -1246,10 +2160,15 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
+            true,
+            None,
             &db,
             Some(&hooks),
+            &[],
             false,
             None,
+            None,
         )
         .await
         .expect_err("should fail when pre_create hook fails");
@@ -1300,10 +2219,15 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
+            true,
+            None,
             &db,
             Some(&hooks),
+            &[],
             false,
             None,
+            None,
         )
         .await
         .expect("should succeed");
@@ -1362,10 +2286,15 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
+            true,
+            None,
             &db,
             Some(&hooks),
+            &[],
             false,
             None,
+            None,
         )
         .await
         .expect("should succeed (worktree stays despite hook failure)");
@@ -1383,6 +2312,17 @@ mod tests {
         );
 
         assert!(matches!(result.hooks_status, HooksStatus::Ran));
+
+        // The failure is surfaced as a hook_failed event on the worktree
+        let events = db.list_events(result.result.worktree_id, 10).unwrap();
+        let failed = events
+            .iter()
+            .find(|e| e.event_type == "hook_failed")
+            .expect("hook_failed event should be recorded");
+        let payload: serde_json::Value =
+            serde_json::from_str(failed.payload.as_deref().unwrap()).unwrap();
+        assert_eq!(payload["hook"], "post_create");
+        assert_eq!(payload["step"], "run");
     }
 
     #[tokio::test(flavor = "current_thread")]
@@ -1422,10 +2362,15 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
+            true,
+            None,
             &db,
             Some(&hooks),
+            &[],
             false,
             None,
+            None,
         )
         .await
         .expect("should succeed");
@@ -1504,10 +2449,15 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
+            true,
+            None,
             &db,
             Some(&hooks),
+            &[],
             false,
             None,
+            None,
         )
         .await
         .expect_err("should fail when pre_create hook fails");
@@ -1518,4 +2468,251 @@ mod tests {
             "expected CreateError::PreCreateHookFailed, got: {err:?}"
         );
     }
+
+    #[test]
+    fn parent_that_is_a_file_is_reported() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        init_repo_with_commit(repo_dir.path());
+        let wt_root = tempfile::tempdir().unwrap();
+        std::fs::write(wt_root.path().join("blocker"), "not a dir").unwrap();
+        let db = Database::open_in_memory().unwrap();
+
+        let err = execute(
+            "feat",
+            None,
+            repo_dir.path(),
+            wt_root.path(),
+            "blocker/nested/{{ branch }}",
+            None,
+            &db,
+        )
+        .unwrap_err();
+
+        match err.downcast_ref::<CreateError>() {
+            Some(CreateError::ParentIsFile { path }) => {
+                assert_eq!(path, &wt_root.path().join("blocker"))
+            }
+            other => panic!("expected ParentIsFile, got {other:?}: {err:#}"),
+        }
+    }
+
+    #[test]
+    fn parent_io_errors_are_classified() {
+        let parent = Path::new("/wt/repo");
+        let classify = |kind| {
+            let err = classify_parent_error(parent, std::io::Error::from(kind));
+            err.downcast::<CreateError>().map(|e| e.to_string())
+        };
+
+        let denied = classify(std::io::ErrorKind::PermissionDenied).unwrap();
+        assert!(denied.contains("permission denied"), "{denied}");
+        let read_only = classify(std::io::ErrorKind::ReadOnlyFilesystem).unwrap();
+        assert!(read_only.contains("read-only filesystem"), "{read_only}");
+        let not_dir = classify(std::io::ErrorKind::NotADirectory).unwrap();
+        assert!(not_dir.contains("is not a directory"), "{not_dir}");
+        assert!(
+            classify(std::io::ErrorKind::Other).is_err(),
+            "unclassified errors stay generic"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn unwritable_root_reports_permission_denied() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let repo_dir = tempfile::tempdir().unwrap();
+        init_repo_with_commit(repo_dir.path());
+        let wt_root = tempfile::tempdir().unwrap();
+        std::fs::set_permissions(wt_root.path(), std::fs::Permissions::from_mode(0o555)).unwrap();
+        // root ignores directory permissions, so there is nothing to observe.
+        let probe = std::fs::create_dir(wt_root.path().join("probe"));
+        if probe.is_ok() {
+            return;
+        }
+        let db = Database::open_in_memory().unwrap();
+
+        let err = execute(
+            "feat",
+            None,
+            repo_dir.path(),
+            wt_root.path(),
+            "team/{{ branch }}",
+            None,
+            &db,
+        )
+        .unwrap_err();
+        std::fs::set_permissions(wt_root.path(), std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        assert!(
+            matches!(
+                err.downcast_ref::<CreateError>(),
+                Some(CreateError::ParentPermissionDenied { .. })
+            ),
+            "{err:#}"
+        );
+    }
+
+    #[test]
+    fn strict_parents_refuses_missing_directories() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        init_repo_with_commit(repo_dir.path());
+        let wt_root = tempfile::tempdir().unwrap();
+        let db = Database::open_in_memory().unwrap();
+
+        let err = execute_with_options(
+            "feat",
+            None,
+            repo_dir.path(),
+            wt_root.path(),
+            "team/{{ branch }}",
+            None,
+            false,
+            None,
+            &db,
+        )
+        .unwrap_err();
+        match err.downcast_ref::<CreateError>() {
+            Some(CreateError::ParentMissing { path }) => {
+                assert_eq!(path, &wt_root.path().join("team"))
+            }
+            other => panic!("expected ParentMissing, got {other:?}: {err:#}"),
+        }
+        assert!(!wt_root.path().join("team").exists(), "nothing created");
+
+        std::fs::create_dir(wt_root.path().join("team")).unwrap();
+        let created = execute_with_options(
+            "feat",
+            None,
+            repo_dir.path(),
+            wt_root.path(),
+            "team/{{ branch }}",
+            None,
+            false,
+            None,
+            &db,
+        )
+        .expect("existing parent is fine in strict mode");
+        assert!(created.path.ends_with("team/feat"));
+    }
+
+    #[test]
+    fn sanitized_name_collision_is_detected() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        init_repo_with_commit(repo_dir.path());
+        let wt_root = tempfile::tempdir().unwrap();
+        let db = Database::open_in_memory().unwrap();
+        let first = execute(
+            "feature/auth",
+            None,
+            repo_dir.path(),
+            wt_root.path(),
+            crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
+            &db,
+        )
+        .unwrap();
+
+        let err = execute(
+            "feature-auth",
+            None,
+            repo_dir.path(),
+            wt_root.path(),
+            crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
+            &db,
+        )
+        .unwrap_err();
+
+        match err.downcast_ref::<CreateError>() {
+            Some(CreateError::NameCollision {
+                name,
+                existing_branch,
+                ..
+            }) => {
+                assert_eq!(name, "feature-auth");
+                assert_eq!(existing_branch, "feature/auth");
+            }
+            other => panic!("expected NameCollision, got {other:?}: {err:#}"),
+        }
+        let repo = git2::Repository::open(repo_dir.path()).unwrap();
+        assert!(
+            repo.find_branch("feature-auth", git2::BranchType::Local)
+                .is_err(),
+            "no branch should be created on collision"
+        );
+        let db_repo = db
+            .get_repo_by_path(repo_dir.path().canonicalize().unwrap().to_str().unwrap())
+            .unwrap()
+            .unwrap();
+        let worktrees = db.list_worktrees(db_repo.id).unwrap();
+        assert_eq!(worktrees.len(), 1);
+        assert_eq!(worktrees[0].branch, "feature/auth");
+        assert!(first.path.exists());
+    }
+
+    #[test]
+    fn explicit_name_sets_directory_and_identifier() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        init_repo_with_commit(repo_dir.path());
+        let wt_root = tempfile::tempdir().unwrap();
+        let db = Database::open_in_memory().unwrap();
+        let branch = "feature/JIRA-1234-rework-the-session-refresh-flow";
+
+        let created = execute_with_options(
+            branch,
+            None,
+            repo_dir.path(),
+            wt_root.path(),
+            crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
+            true,
+            Some("short"),
+            &db,
+        )
+        .unwrap();
+
+        assert_eq!(created.name, "short");
+        assert_eq!(created.branch, branch);
+        assert_eq!(created.path.file_name().unwrap(), "short");
+
+        let switched =
+            crate::cli::commands::switch::execute("short", repo_dir.path(), &db).unwrap();
+        assert_eq!(switched.name, "short");
+        assert_eq!(switched.path, created.path.to_string_lossy());
+
+        let db_repo = db
+            .get_repo_by_path(repo_dir.path().canonicalize().unwrap().to_str().unwrap())
+            .unwrap()
+            .unwrap();
+        let wt = db
+            .find_worktree_by_identifier(db_repo.id, "short")
+            .unwrap()
+            .expect("tracked under the explicit name");
+        assert_eq!(wt.branch, branch);
+    }
+
+    #[test]
+    fn explicit_name_must_be_sanitized_form() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        init_repo_with_commit(repo_dir.path());
+        let wt_root = tempfile::tempdir().unwrap();
+        let db = Database::open_in_memory().unwrap();
+
+        let err = execute_with_options(
+            "feat",
+            None,
+            repo_dir.path(),
+            wt_root.path(),
+            crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
+            true,
+            Some("a/b"),
+            &db,
+        )
+        .unwrap_err();
+
+        assert!(err.to_string().contains("use 'a-b'"), "{err}");
+        assert!(std::fs::read_dir(wt_root.path()).unwrap().next().is_none());
+    }
 }
@@ -0,0 +1,78 @@
+use anyhow::{Context, Result};
+
+use crate::state::export::{self, ImportSummary};
+use crate::state::Database;
+
+/// Execute `trench db export`, returning the pretty-printed JSON document.
+pub fn export(db: &Database) -> Result<String> {
+    let doc = db.export_json()?;
+    Ok(serde_json::to_string_pretty(&doc)? + "\n")
+}
+
+/// Execute `trench db import` with the contents of an export file.
+///
+/// With `dry_run`, the document is validated and counted but nothing is
+/// written.
+pub fn import(db: &Database, contents: &str, dry_run: bool) -> Result<ImportSummary> {
+    let doc: serde_json::Value =
+        serde_json::from_str(contents).context("export file is not valid JSON")?;
+    if dry_run {
+        return export::count_export_rows(&doc);
+    }
+    db.import_json(&doc)
+}
+
+/// Render an import summary for human output.
+pub fn format_import_summary(summary: &ImportSummary, dry_run: bool) -> String {
+    let verb = if dry_run { "Would import" } else { "Imported" };
+    format!(
+        "{verb} {} repo(s), {} worktree(s), {} event(s), {} tag(s).\n",
+        summary.repos, summary.worktrees, summary.events, summary.tags
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn export_then_import_restores_into_fresh_db() {
+        let source = Database::open_in_memory().unwrap();
+        let repo = source.insert_repo("r", "/r", Some("main")).unwrap();
+        source
+            .insert_worktree(repo.id, "wt", "feature/wt", "/wt", None)
+            .unwrap();
+        let dump = export(&source).unwrap();
+
+        let target = Database::open_in_memory().unwrap();
+        let summary = import(&target, &dump, false).unwrap();
+
+        assert_eq!(summary.repos, 1);
+        assert_eq!(summary.worktrees, 1);
+        assert_eq!(export(&target).unwrap(), dump);
+    }
+
+    #[test]
+    fn import_dry_run_writes_nothing() {
+        let source = Database::open_in_memory().unwrap();
+        source.insert_repo("r", "/r", None).unwrap();
+        let dump = export(&source).unwrap();
+
+        let target = Database::open_in_memory().unwrap();
+        let summary = import(&target, &dump, true).unwrap();
+
+        assert_eq!(summary.repos, 1);
+        assert!(target.get_repo_by_path("/r").unwrap().is_none());
+        assert_eq!(
+            format_import_summary(&summary, true),
+            "Would import 1 repo(s), 0 worktree(s), 0 event(s), 0 tag(s).\n"
+        );
+    }
+
+    #[test]
+    fn import_rejects_invalid_json() {
+        let target = Database::open_in_memory().unwrap();
+        let err = import(&target, "not json", false).unwrap_err();
+        assert!(err.to_string().contains("not valid JSON"), "got: {err}");
+    }
+}
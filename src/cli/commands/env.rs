@@ -0,0 +1,137 @@
+use std::path::Path;
+
+use anyhow::Result;
+
+use crate::git;
+use crate::hooks::{self, HookEnvContext, HookEvent};
+use crate::state::Database;
+
+/// Build the hook environment context for a worktree.
+///
+/// Resolution is read-only: an untracked worktree is described from live git
+/// state instead of being adopted into the database.
+fn gather(cwd: &Path, db: &Database, identifier: &str) -> Result<HookEnvContext> {
+    let repo_info = git::discover_repo(cwd)?;
+    let worktree = crate::live_worktree::resolve_read_only(identifier, &repo_info, Some(db))?;
+    let base_branch = crate::live_worktree::base_branch(&repo_info, &worktree);
+    let (name, path) = match &worktree.metadata {
+        Some(meta) => (meta.name.clone(), meta.path.clone()),
+        None => (
+            worktree.entry.name.clone(),
+            worktree.entry.path.to_string_lossy().into_owned(),
+        ),
+    };
+
+    Ok(HookEnvContext {
+        worktree_path: path,
+        worktree_name: name,
+        branch: worktree.entry.branch.clone().unwrap_or_default(),
+        repo_name: repo_info.name.clone(),
+        repo_path: repo_info.path.to_string_lossy().into_owned(),
+        base_branch,
+        user_env: Vec::new(),
+    })
+}
+
+/// Render an environment as sorted `KEY=VALUE` lines, or as shell-quoted
+/// `export KEY=VALUE` lines that can be `eval`ed.
+pub fn format_env(ctx: &HookEnvContext, event: &HookEvent, export: bool) -> String {
+    let mut vars: Vec<(String, String)> = hooks::build_env(ctx, event).into_iter().collect();
+    vars.sort();
+    let mut out = String::new();
+    for (key, value) in vars {
+        if export {
+            out.push_str(&format!("export {key}={}\n", shell_words::quote(&value)));
+        } else {
+            out.push_str(&format!("{key}={value}\n"));
+        }
+    }
+    out
+}
+
+/// Execute `trench env <branch>`.
+pub fn execute(
+    cwd: &Path,
+    db: &Database,
+    identifier: &str,
+    event: &HookEvent,
+    export: bool,
+) -> Result<String> {
+    Ok(format_env(&gather(cwd, db, identifier)?, event, export))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn init_repo_with_commit(dir: &Path) {
+        let repo = git2::Repository::init(dir).expect("failed to init repo");
+        let sig = git2::Signature::now("Test", "test@test.com").unwrap();
+        let tree_id = repo.index().unwrap().write_tree().unwrap();
+        let tree = repo.find_tree(tree_id).unwrap();
+        repo.commit(Some("HEAD"), &sig, &sig, "initial commit", &tree, &[])
+            .unwrap();
+    }
+
+    #[test]
+    fn prints_all_seven_vars_for_resolved_worktree() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        init_repo_with_commit(repo_dir.path());
+        let wt_root = tempfile::tempdir().unwrap();
+        let db = Database::open_in_memory().unwrap();
+        let created = crate::cli::commands::create::execute(
+            "feature/env",
+            None,
+            repo_dir.path(),
+            wt_root.path(),
+            crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
+            &db,
+        )
+        .unwrap();
+
+        let output = execute(
+            repo_dir.path(),
+            &db,
+            "feature/env",
+            &HookEvent::PreSync,
+            false,
+        )
+        .unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines.len(), 7, "{output}");
+        assert!(lines.contains(&"TRENCH_BRANCH=feature/env"), "{output}");
+        assert!(lines.contains(&"TRENCH_WORKTREE_NAME=feature-env"));
+        assert!(
+            lines.contains(&format!("TRENCH_WORKTREE_PATH={}", created.path.display()).as_str())
+        );
+        assert!(lines.contains(&"TRENCH_EVENT=pre_sync"));
+        for key in [
+            "TRENCH_REPO_NAME=",
+            "TRENCH_REPO_PATH=",
+            "TRENCH_BASE_BRANCH=",
+        ] {
+            assert!(lines.iter().any(|l| l.starts_with(key)), "missing {key}");
+        }
+    }
+
+    #[test]
+    fn export_quotes_values() {
+        let ctx = HookEnvContext {
+            worktree_path: "/tmp/my worktree".into(),
+            worktree_name: "w".into(),
+            branch: "b".into(),
+            repo_name: "r".into(),
+            repo_path: "/r".into(),
+            base_branch: "main".into(),
+            user_env: Vec::new(),
+        };
+
+        let output = format_env(&ctx, &HookEvent::PostCreate, true);
+
+        assert!(output.contains("export TRENCH_WORKTREE_PATH='/tmp/my worktree'\n"));
+        assert!(output.contains("export TRENCH_EVENT=post_create\n"));
+        assert!(output.lines().all(|l| l.starts_with("export TRENCH_")));
+    }
+}
@@ -0,0 +1,355 @@
+use std::path::Path;
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::cli::commands::log::format_timestamp;
+use crate::git;
+use crate::output::json::format_json_value;
+use crate::state::Database;
+
+/// Number of recent events included in `trench info`.
+const RECENT_EVENTS: usize = 10;
+
+/// Everything trench knows about one worktree.
+#[derive(Debug, Serialize)]
+struct Info {
+    name: String,
+    branch: String,
+    path: String,
+    base_branch: String,
+    /// `owner/repo` of the `origin` remote, when it is a hosted URL.
+    remote: Option<String>,
+    tracked: bool,
+    id: Option<i64>,
+    managed: Option<bool>,
+    created_at: Option<i64>,
+    adopted_at: Option<i64>,
+    last_accessed: Option<i64>,
+    head: Option<HeadJson>,
+    ahead: Option<usize>,
+    behind: Option<usize>,
+    dirty: DirtyJson,
+    tags: Vec<String>,
+    events: Vec<EventJson>,
+}
+
+#[derive(Debug, Serialize)]
+struct HeadJson {
+    hash: String,
+    message: String,
+    author: String,
+    time: i64,
+}
+
+/// Changed-file counts by status label (see `git::changed_files`).
+#[derive(Debug, Default, Serialize)]
+struct DirtyJson {
+    total: usize,
+    new: usize,
+    modified: usize,
+    deleted: usize,
+    renamed: usize,
+    typechange: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct EventJson {
+    event_type: String,
+    created_at: i64,
+    payload: Option<serde_json::Value>,
+}
+
+fn gather(cwd: &Path, db: &Database, identifier: &str) -> Result<Info> {
+    let repo_info = git::discover_repo(cwd)?;
+    let worktree = crate::live_worktree::resolve(identifier, &repo_info, db)?;
+    let base_branch = crate::live_worktree::base_branch(&repo_info, &worktree);
+    let branch = worktree
+        .entry
+        .branch
+        .clone()
+        .unwrap_or_else(|| "(detached)".to_string());
+    let wt_path = worktree.entry.path.as_path();
+    let meta = worktree.metadata.as_ref();
+
+    let head = git::commit_summary(wt_path)
+        .ok()
+        .flatten()
+        .map(|c| HeadJson {
+            hash: c.short_id,
+            message: c.summary,
+            author: c.author,
+            time: c.time,
+        });
+
+    let (ahead, behind) = git::ahead_behind(&repo_info.path, &branch, Some(&base_branch))
+        .ok()
+        .flatten()
+        .map_or((None, None), |(a, b)| (Some(a), Some(b)));
+
+    let mut dirty = DirtyJson::default();
+    for file in git::changed_files(wt_path).unwrap_or_default() {
+        dirty.total += 1;
+        match file.status {
+            "new" => dirty.new += 1,
+            "modified" => dirty.modified += 1,
+            "deleted" => dirty.deleted += 1,
+            "renamed" => dirty.renamed += 1,
+            "typechange" => dirty.typechange += 1,
+            _ => {}
+        }
+    }
+
+    let (tags, events) = match meta {
+        Some(wt) => (
+            db.list_tags(wt.id)?,
+            db.list_events(wt.id, RECENT_EVENTS)?
+                .into_iter()
+                .map(|e| EventJson {
+                    event_type: e.event_type,
+                    created_at: e.created_at,
+                    payload: e.payload.and_then(|p| serde_json::from_str(&p).ok()),
+                })
+                .collect(),
+        ),
+        None => (Vec::new(), Vec::new()),
+    };
+
+    Ok(Info {
+        name: worktree.entry.name.clone(),
+        branch,
+        path: wt_path.to_string_lossy().into_owned(),
+        base_branch,
+        remote: repo_info
+            .remote_url
+            .as_deref()
+            .and_then(git::normalize_remote_url)
+            .map(|r| r.slug()),
+        tracked: meta.is_some(),
+        id: meta.map(|w| w.id),
+        managed: meta.map(|w| w.managed),
+        created_at: meta.map(|w| w.created_at),
+        adopted_at: meta.and_then(|w| w.adopted_at),
+        last_accessed: meta.and_then(|w| w.last_accessed),
+        head,
+        ahead,
+        behind,
+        dirty,
+        tags,
+        events,
+    })
+}
+
+fn format_optional_timestamp(ts: Option<i64>) -> String {
+    ts.map_or_else(|| "-".to_string(), format_timestamp)
+}
+
+/// Execute `trench info <branch>` as human-readable key/value output.
+pub fn execute(cwd: &Path, db: &Database, identifier: &str) -> Result<String> {
+    let info = gather(cwd, db, identifier)?;
+
+    let mut out = String::new();
+    out.push_str(&format!("Name:          {}\n", info.name));
+    out.push_str(&format!("Branch:        {}\n", info.branch));
+    out.push_str(&format!("Path:          {}\n", info.path));
+    out.push_str(&format!("Base:          {}\n", info.base_branch));
+    if let Some(remote) = &info.remote {
+        out.push_str(&format!("Remote:        {remote}\n"));
+    }
+    let tracking = match info.managed {
+        Some(true) => "managed",
+        Some(false) => "adopted",
+        None => "untracked",
+    };
+    out.push_str(&format!("Tracking:      {tracking}\n"));
+    out.push_str(&format!(
+        "Created:       {}\n",
+        format_optional_timestamp(info.created_at)
+    ));
+    if info.adopted_at.is_some() {
+        out.push_str(&format!(
+            "Adopted:       {}\n",
+            format_optional_timestamp(info.adopted_at)
+        ));
+    }
+    out.push_str(&format!(
+        "Last accessed: {}\n",
+        info.last_accessed
+            .map_or_else(|| "never".to_string(), format_timestamp)
+    ));
+    match &info.head {
+        Some(head) => out.push_str(&format!(
+            "HEAD:          {} {} ({}, {})\n",
+            head.hash,
+            head.message,
+            head.author,
+            format_timestamp(head.time)
+        )),
+        None => out.push_str("HEAD:          -\n"),
+    }
+    let ab = match (info.ahead, info.behind) {
+        (Some(a), Some(b)) => format!("+{a}/-{b}"),
+        _ => "-".to_string(),
+    };
+    out.push_str(&format!("Ahead/Behind:  {ab}\n"));
+    let d = &info.dirty;
+    if d.total == 0 {
+        out.push_str("Dirty:         clean\n");
+    } else {
+        let parts: Vec<String> = [
+            ("new", d.new),
+            ("modified", d.modified),
+            ("deleted", d.deleted),
+            ("renamed", d.renamed),
+            ("typechange", d.typechange),
+        ]
+        .into_iter()
+        .filter(|(_, n)| *n > 0)
+        .map(|(label, n)| format!("{n} {label}"))
+        .collect();
+        out.push_str(&format!(
+            "Dirty:         {} ({})\n",
+            d.total,
+            parts.join(", ")
+        ));
+    }
+    let tags = if info.tags.is_empty() {
+        "-".to_string()
+    } else {
+        info.tags.join(", ")
+    };
+    out.push_str(&format!("Tags:          {tags}\n"));
+
+    if !info.events.is_empty() {
+        out.push_str("\nRecent events:\n");
+        for ev in &info.events {
+            out.push_str(&format!(
+                "  {}  {}\n",
+                format_timestamp(ev.created_at),
+                ev.event_type
+            ));
+        }
+    }
+
+    Ok(out)
+}
+
+/// Execute `trench info <branch> --json` as a single object.
+pub fn execute_json(cwd: &Path, db: &Database, identifier: &str) -> Result<String> {
+    format_json_value(&gather(cwd, db, identifier)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn init_repo_with_commit(dir: &Path) -> git2::Repository {
+        let repo = git2::Repository::init(dir).expect("failed to init repo");
+        {
+            let sig = git2::Signature::now("Test", "test@test.com").unwrap();
+            let tree_id = repo.index().unwrap().write_tree().unwrap();
+            let tree = repo.find_tree(tree_id).unwrap();
+            repo.commit(Some("HEAD"), &sig, &sig, "initial commit", &tree, &[])
+                .unwrap();
+        }
+        repo
+    }
+
+    #[test]
+    fn json_contains_db_fields_and_events() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let _repo = init_repo_with_commit(repo_dir.path());
+        let wt_root = tempfile::tempdir().unwrap();
+        let db = Database::open_in_memory().unwrap();
+        let created = crate::cli::commands::create::execute(
+            "feature/info",
+            None,
+            repo_dir.path(),
+            wt_root.path(),
+            crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
+            &db,
+        )
+        .unwrap();
+        std::fs::write(created.path.join("scratch.txt"), "wip").unwrap();
+
+        let output = execute_json(repo_dir.path(), &db, "feature/info").unwrap();
+        let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+        assert!(json.is_object(), "info --json must be a single object");
+        assert_eq!(json["name"], "feature-info");
+        assert_eq!(json["branch"], "feature/info");
+        assert_eq!(json["tracked"], true);
+        assert_eq!(json["managed"], true);
+        assert!(json["id"].is_i64());
+        assert!(json["created_at"].is_i64());
+        assert_eq!(json["head"]["message"], "initial commit");
+        assert_eq!(json["head"]["hash"].as_str().unwrap().len(), 7);
+        assert_eq!(json["head"]["author"], "Test");
+        assert!(json["head"]["time"].is_i64());
+        assert_eq!(json["ahead"], 0);
+        assert!(json["remote"].is_null(), "no origin configured");
+        assert_eq!(json["dirty"]["total"], 1);
+        assert_eq!(json["dirty"]["new"], 1);
+        let events = json["events"].as_array().unwrap();
+        assert!(
+            events.iter().any(|e| e["event_type"] == "created"),
+            "expected a created event, got: {events:?}"
+        );
+    }
+
+    #[test]
+    fn text_output_lists_fields_and_recent_events() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let _repo = init_repo_with_commit(repo_dir.path());
+        let wt_root = tempfile::tempdir().unwrap();
+        let db = Database::open_in_memory().unwrap();
+        crate::cli::commands::create::execute(
+            "feature/text",
+            None,
+            repo_dir.path(),
+            wt_root.path(),
+            crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
+            &db,
+        )
+        .unwrap();
+
+        let output = execute(repo_dir.path(), &db, "feature-text").unwrap();
+
+        assert!(output.contains("Branch:        feature/text"));
+        assert!(output.contains("Tracking:      managed"));
+        assert!(output.contains("Dirty:         clean"));
+        assert!(output.contains("initial commit (Test, "), "{output}");
+        assert!(output.contains("Recent events:"));
+        assert!(output.contains("created"));
+    }
+
+    #[test]
+    fn remote_is_shown_as_owner_slash_repo() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let repo = init_repo_with_commit(repo_dir.path());
+        repo.remote("origin", "git@github.com:acme/app.git")
+            .unwrap();
+        let wt_root = tempfile::tempdir().unwrap();
+        let db = Database::open_in_memory().unwrap();
+        crate::cli::commands::create::execute(
+            "remote-info",
+            None,
+            repo_dir.path(),
+            wt_root.path(),
+            crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
+            &db,
+        )
+        .unwrap();
+
+        let output = execute(repo_dir.path(), &db, "remote-info").unwrap();
+        assert!(output.contains("Remote:        acme/app"), "{output}");
+
+        let json: serde_json::Value =
+            serde_json::from_str(&execute_json(repo_dir.path(), &db, "remote-info").unwrap())
+                .unwrap();
+        assert_eq!(json["remote"], "acme/app");
+    }
+}
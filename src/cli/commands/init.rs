@@ -28,6 +28,8 @@ const SCAFFOLD: &str = r#"# trench — project configuration
 # show_ahead_behind = true
 # show_dirty_count = true
 # auto_refresh = true
+# table_width = 120            # fixed column width for tables (default: terminal width)
+# open_cmd = "code {path}"     # command for `trench open`; path appended if no {path}
 
 # ─── Git ─────────────────────────────────────────────────────────────
 
@@ -35,12 +37,19 @@ const SCAFFOLD: &str = r#"# trench — project configuration
 # default_base = "main"          # Base branch for new worktrees
 # auto_prune = false              # Prune stale remote-tracking branches
 # fetch_on_open = true            # Fetch from remote when opening a worktree
+# base_from_upstream = false      # Record the upstream branch as a new worktree's base
+# sync_strategy = "rebase"        # Default for `trench sync`: rebase, merge, or ff-only
+# interactive_base = false        # Pick the base from a list when `create` has no --from
 
 # ─── Worktrees ───────────────────────────────────────────────────────
 
 # [worktrees]
 # root = "{{ repo }}/{{ branch | sanitize }}"   # Path template for worktree dirs
 # scan = []                                      # Extra directories to scan for worktrees
+# current_symlink = "~/code/myrepo-current"      # Repointed at the target on switch/open
+#
+# [worktrees.templates]                          # Named layouts for `create --template <name>`
+# flat = "{{ repo }}-{{ branch | sanitize }}"
 
 # ─── Hooks ───────────────────────────────────────────────────────────
 #
@@ -48,10 +57,14 @@ const SCAFFOLD: &str = r#"# trench — project configuration
 # pre_remove, post_remove.
 #
 # Each hook supports:
-#   copy         — glob patterns to copy from repo root (prefix with ! to exclude)
-#   run          — commands to execute sequentially
-#   shell        — a shell script to run
-#   timeout_secs — max seconds for run + shell combined (default: 120)
+#   copy           — glob patterns to copy from repo root (prefix with ! to exclude)
+#   copy_overwrite — existing files: "always" (default), "never", or "if-newer"
+#   copy_options   — glob matching flags, all false by default:
+#                    { case_insensitive, literal_separator, empty_alternates }
+#                    (brace alternation like "{*.json,*.toml}" always works)
+#   run            — commands to execute sequentially
+#   shell          — a shell script to run
+#   timeout_secs   — max seconds for run + shell combined (default: 120)
 #
 # Execution order within a hook: copy → run → shell
 # If any step fails (non-zero exit), the hook stops.
@@ -64,6 +77,7 @@ const SCAFFOLD: &str = r#"# trench — project configuration
 
 # [hooks.post_create]
 # copy = [".env*", "!.env.example"]
+# copy_overwrite = "never"
 # run = ["bun install"]
 # shell = ""
 # timeout_secs = 300
@@ -81,12 +95,57 @@ const SCAFFOLD: &str = r#"# trench — project configuration
 # run = []
 "#;
 
-/// Execute `trench init` — scaffold a commented `.trench.toml` at the repo root.
-pub fn execute(repo_root: &Path, force: bool) -> Result<PathBuf> {
+/// Commented `post_create` example in [`SCAFFOLD`], replaced by
+/// [`ACTIVE_POST_CREATE`] under `--with-hooks`.
+const COMMENTED_POST_CREATE: &str = r#"# [hooks.post_create]
+# copy = [".env*", "!.env.example"]
+# copy_overwrite = "never"
+# run = ["bun install"]
+# shell = ""
+# timeout_secs = 300
+"#;
+
+const ACTIVE_POST_CREATE: &str = r#"# Starter hook from `trench init --with-hooks`: replace the placeholder
+# with your setup command (e.g. "bun install").
+[hooks.post_create]
+copy = [".env*", "!.env.example"]
+copy_overwrite = "never"
+run = ["echo 'post_create: edit .trench.toml to set up new worktrees'"]
+"#;
+
+/// Commented `pre_remove` example in [`SCAFFOLD`], replaced by
+/// [`ACTIVE_PRE_REMOVE`] under `--with-hooks`.
+const COMMENTED_PRE_REMOVE: &str = r#"# [hooks.pre_remove]
+# shell = "pkill -f 'next dev' || true"
+"#;
+
+const ACTIVE_PRE_REMOVE: &str = r#"# Starter hook from `trench init --with-hooks`: stop anything still
+# running in the worktree before it is removed.
+[hooks.pre_remove]
+run = ["echo 'pre_remove: edit .trench.toml to clean up before removal'"]
+"#;
+
+/// The `.trench.toml` contents to write: the commented scaffold, with
+/// active `post_create` and `pre_remove` hooks when `with_hooks` is set.
+fn scaffold(with_hooks: bool) -> String {
+    if !with_hooks {
+        return SCAFFOLD.to_string();
+    }
+    debug_assert!(SCAFFOLD.contains(COMMENTED_POST_CREATE));
+    debug_assert!(SCAFFOLD.contains(COMMENTED_PRE_REMOVE));
+    SCAFFOLD
+        .replace(COMMENTED_POST_CREATE, ACTIVE_POST_CREATE)
+        .replace(COMMENTED_PRE_REMOVE, ACTIVE_PRE_REMOVE)
+}
+
+/// Execute `trench init` — scaffold a commented `.trench.toml` at the repo
+/// root, optionally with active starter hooks.
+pub fn execute(repo_root: &Path, force: bool, with_hooks: bool) -> Result<PathBuf> {
     let path = repo_root.join(PROJECT_CONFIG_FILENAME);
+    let contents = scaffold(with_hooks);
 
     if force {
-        std::fs::write(&path, SCAFFOLD)?;
+        std::fs::write(&path, contents)?;
         return Ok(path);
     }
 
@@ -102,7 +161,7 @@ pub fn execute(repo_root: &Path, force: bool) -> Result<PathBuf> {
         Err(e) => return Err(e.into()),
     };
 
-    file.write_all(SCAFFOLD.as_bytes())?;
+    file.write_all(contents.as_bytes())?;
     Ok(path)
 }
 
@@ -115,7 +174,7 @@ mod tests {
     fn init_creates_trench_toml_at_repo_root() {
         let dir = TempDir::new().unwrap();
 
-        let result = execute(dir.path(), false);
+        let result = execute(dir.path(), false, false);
 
         assert!(result.is_ok(), "init should succeed: {:?}", result.err());
         let created_path = result.unwrap();
@@ -129,7 +188,7 @@ mod tests {
     #[test]
     fn scaffold_contains_all_config_sections_commented_out() {
         let dir = TempDir::new().unwrap();
-        let path = execute(dir.path(), false).unwrap();
+        let path = execute(dir.path(), false, false).unwrap();
         let contents = std::fs::read_to_string(path).unwrap();
 
         // All config sections should be present as comments
@@ -211,7 +270,7 @@ mod tests {
         let existing = dir.path().join(".trench.toml");
         std::fs::write(&existing, "# existing config\n").unwrap();
 
-        let result = execute(dir.path(), false);
+        let result = execute(dir.path(), false, false);
 
         assert!(result.is_err(), "init should fail when file exists");
         let err = result.unwrap_err();
@@ -271,7 +330,7 @@ mod tests {
                 .unwrap();
         }
 
-        let path = execute(dir.path(), false).unwrap();
+        let path = execute(dir.path(), false, false).unwrap();
         assert_eq!(path, dir.path().join(".trench.toml"));
         assert!(path.exists());
 
@@ -286,13 +345,42 @@ mod tests {
         assert!(config.hooks.is_none());
     }
 
+    #[test]
+    fn with_hooks_scaffold_loads_with_active_hooks() {
+        let dir = TempDir::new().unwrap();
+
+        let path = execute(dir.path(), false, true).unwrap();
+
+        let config = crate::config::load_project_config(dir.path())
+            .expect("--with-hooks output should parse")
+            .expect("file exists, so should return Some");
+        let hooks = config.hooks.expect("hooks should be active");
+        let post_create = hooks.post_create.expect("post_create should be active");
+        assert_eq!(
+            post_create.copy.as_deref(),
+            Some(&[".env*".to_string(), "!.env.example".to_string()][..])
+        );
+        assert_eq!(post_create.run.as_ref().map(Vec::len), Some(1));
+        assert!(hooks
+            .pre_remove
+            .expect("pre_remove should be active")
+            .run
+            .is_some());
+        assert!(hooks.pre_create.is_none());
+        assert!(config.ui.is_none(), "other sections stay commented");
+
+        let contents = std::fs::read_to_string(path).unwrap();
+        assert!(contents.contains("# [hooks.post_sync]"), "{contents}");
+        assert!(!contents.contains("# [hooks.post_create]"), "{contents}");
+    }
+
     #[test]
     fn init_force_overwrites_existing_file() {
         let dir = TempDir::new().unwrap();
         let existing = dir.path().join(".trench.toml");
         std::fs::write(&existing, "# old config\n").unwrap();
 
-        let result = execute(dir.path(), true);
+        let result = execute(dir.path(), true, false);
 
         assert!(
             result.is_ok(),
@@ -4,10 +4,11 @@ use anyhow::Result;
 use serde::Serialize;
 
 use crate::git;
-use crate::output::json::format_json;
-use crate::output::porcelain::{format_porcelain, PorcelainRecord};
-use crate::output::table::Table;
-use crate::state::Database;
+use crate::output::json::{format_json, format_json_value, format_jsonl};
+use crate::output::porcelain::{format_porcelain_as, PorcelainRecord, PorcelainVersion};
+use crate::output::table::{Align, Table};
+use crate::paths::PathStyle;
+use crate::state::{Database, WorktreeOrder};
 
 /// A unified worktree entry for list output, joined from live git state plus
 /// optional trench metadata.
@@ -18,19 +19,45 @@ struct ListEntry {
     base_branch: Option<String>,
     tags: Vec<String>,
     is_current: bool,
+    locked: bool,
+    /// Whether trench created (rather than adopted or merely saw) the worktree.
+    managed: bool,
+    /// Creation time of a managed worktree's DB row; `None` when unmanaged.
+    created_at: Option<i64>,
 }
 
+/// Collect the worktrees to list, optionally filtered by `tag`.
+///
+/// Entries come in git's order unless `order` is given; then tracked
+/// worktrees are sorted by it, after the untracked ones (such as the main
+/// checkout), which keep git's order.
 fn fetch_all_worktrees(
     cwd: &Path,
     db: &Database,
     tag: Option<&str>,
     scan_paths: &[String],
+    order: Option<WorktreeOrder>,
 ) -> Result<(PathBuf, Vec<ListEntry>)> {
     let repo_info = git::discover_repo(cwd)?;
     let current_path = git::current_worktree_root(cwd)
         .ok()
         .map(|path| path.to_string_lossy().into_owned());
-    let live_worktrees = crate::live_worktree::list(&repo_info, db, scan_paths)?;
+    let mut live_worktrees = crate::live_worktree::list(&repo_info, db, scan_paths)?;
+    if let Some(order) = order {
+        let ranked: Vec<i64> = match live_worktrees.iter().find_map(|wt| wt.metadata.as_ref()) {
+            Some(metadata) => db
+                .list_worktrees_ordered(metadata.repo_id, order)?
+                .into_iter()
+                .map(|wt| wt.id)
+                .collect(),
+            None => Vec::new(),
+        };
+        live_worktrees.sort_by_key(|wt| {
+            wt.metadata
+                .as_ref()
+                .and_then(|metadata| ranked.iter().position(|id| *id == metadata.id))
+        });
+    }
 
     let mut entries = Vec::with_capacity(live_worktrees.len());
     for worktree in live_worktrees {
@@ -47,6 +74,9 @@ fn fetch_all_worktrees(
             }
         }
 
+        let locked = !worktree.entry.is_main
+            && git::is_worktree_locked(&repo_info.path, &worktree.entry.name).unwrap_or(false);
+
         entries.push(ListEntry {
             name: worktree.entry.name.clone(),
             branch: worktree
@@ -60,6 +90,16 @@ fn fetch_all_worktrees(
             is_current: current_path
                 .as_deref()
                 .is_some_and(|path| path == worktree.entry.path.to_string_lossy()),
+            locked,
+            managed: worktree
+                .metadata
+                .as_ref()
+                .is_some_and(|metadata| metadata.managed),
+            created_at: worktree
+                .metadata
+                .as_ref()
+                .filter(|metadata| metadata.managed)
+                .map(|metadata| metadata.created_at),
         });
     }
 
@@ -73,34 +113,52 @@ struct GitStatus {
     dirty: usize,
 }
 
-/// Compute git status for a worktree. Expected "no upstream" cases silently
-/// yield `None`; unexpected errors print a warning and fall back to defaults.
-fn compute_git_status(repo_path: &Path, entry: &ListEntry) -> GitStatus {
-    let wt_path = Path::new(&entry.path);
-
-    let (ahead, behind) =
-        match git::ahead_behind(repo_path, &entry.branch, entry.base_branch.as_deref()) {
-            Ok(Some((a, b))) => (Some(a), Some(b)),
-            Ok(None) => (None, None),
-            Err(e) => {
-                eprintln!("warning: ahead/behind for '{}': {e}", entry.branch);
-                (None, None)
-            }
-        };
-
-    let dirty = match git::dirty_count(wt_path) {
-        Ok(n) => n,
-        Err(e) => {
-            eprintln!("warning: dirty count for '{}': {e}", wt_path.display());
-            0
-        }
+/// Compute git status for every worktree. Ahead/behind is computed in one
+/// batch; expected "no upstream" cases silently yield `None`, and unexpected
+/// errors print a warning and fall back to defaults.
+fn compute_git_statuses(repo_path: &Path, entries: &[ListEntry]) -> Vec<GitStatus> {
+    let items: Vec<(&str, Option<&str>)> = entries
+        .iter()
+        .map(|entry| (entry.branch.as_str(), entry.base_branch.as_deref()))
+        .collect();
+    let ahead_behind = match git::ahead_behind_batch(repo_path, &items) {
+        Ok(results) => results.into_iter().map(Ok).collect(),
+        // Retry per branch so one bad ref only blanks its own row.
+        Err(_) => items
+            .iter()
+            .map(|(branch, base)| git::ahead_behind(repo_path, branch, *base))
+            .collect::<Vec<_>>(),
     };
 
-    GitStatus {
-        ahead,
-        behind,
-        dirty,
-    }
+    entries
+        .iter()
+        .zip(ahead_behind)
+        .map(|(entry, ab)| {
+            let (ahead, behind) = match ab {
+                Ok(Some((a, b))) => (Some(a), Some(b)),
+                Ok(None) => (None, None),
+                Err(e) => {
+                    eprintln!("warning: ahead/behind for '{}': {e}", entry.branch);
+                    (None, None)
+                }
+            };
+
+            let wt_path = Path::new(&entry.path);
+            let dirty = match git::dirty_count(wt_path) {
+                Ok(n) => n,
+                Err(e) => {
+                    eprintln!("warning: dirty count for '{}': {e}", wt_path.display());
+                    0
+                }
+            };
+
+            GitStatus {
+                ahead,
+                behind,
+                dirty,
+            }
+        })
+        .collect()
 }
 
 /// Format ahead/behind as a display string (e.g., "+3/-1" or "-").
@@ -111,6 +169,44 @@ fn format_ahead_behind(ahead: Option<usize>, behind: Option<usize>) -> String {
     }
 }
 
+/// Measure every worktree directory concurrently. Failures print a warning
+/// and yield `None` for that entry.
+fn compute_sizes(entries: &[ListEntry]) -> Vec<Option<u64>> {
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = entries
+            .iter()
+            .map(|entry| scope.spawn(move || crate::paths::dir_size(Path::new(&entry.path))))
+            .collect();
+        handles
+            .into_iter()
+            .zip(entries)
+            .map(|(handle, entry)| match handle.join() {
+                Ok(Ok(size)) => Some(size),
+                Ok(Err(e)) => {
+                    eprintln!("warning: size for '{}': {e:#}", entry.path);
+                    None
+                }
+                Err(_) => None,
+            })
+            .collect()
+    })
+}
+
+/// Format a byte count with binary units (e.g., "512 B", "1.5 MiB").
+fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.1} {}", UNITS[unit])
+}
+
 /// Format dirty count as a display string (e.g., "~5" or "clean").
 fn format_dirty(dirty: usize) -> String {
     if dirty == 0 {
@@ -129,9 +225,16 @@ struct WorktreeJson {
     ahead: Option<usize>,
     behind: Option<usize>,
     dirty: usize,
+    locked: bool,
     tags: Vec<String>,
     process_count: usize,
     processes: Vec<String>,
+    /// On-disk size in bytes; only present with `--size`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    size_bytes: Option<u64>,
+    /// Only rendered by `--porcelain=v2`; the JSON shape is unchanged.
+    #[serde(skip)]
+    managed: bool,
 }
 
 impl PorcelainRecord for WorktreeJson {
@@ -146,6 +249,37 @@ impl PorcelainRecord for WorktreeJson {
             self.dirty.to_string(),
         ]
     }
+
+    fn porcelain_pairs(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("name", self.name.clone()),
+            ("branch", self.branch.clone()),
+            ("path", self.path.clone()),
+            ("status", self.status.clone()),
+            (
+                "ahead",
+                self.ahead.map_or("-".to_string(), |v| v.to_string()),
+            ),
+            (
+                "behind",
+                self.behind.map_or("-".to_string(), |v| v.to_string()),
+            ),
+            ("dirty", self.dirty.to_string()),
+            ("managed", self.managed.to_string()),
+            ("locked", self.locked.to_string()),
+            ("tags", self.tags.join(",")),
+        ]
+    }
+}
+
+/// Width to render tables at: an explicit `--width`/`ui.table_width` when
+/// set, otherwise the detected terminal width.
+fn effective_width(table_width: Option<usize>) -> Option<usize> {
+    table_width.or_else(|| {
+        crossterm::terminal::size()
+            .ok()
+            .map(|(cols, _)| cols as usize)
+    })
 }
 
 /// Execute the `trench list` command.
@@ -157,11 +291,53 @@ pub fn execute(
     db: &Database,
     tag: Option<&str>,
     scan_paths: &[String],
+    order: Option<WorktreeOrder>,
+    table_width: Option<usize>,
+    path_style: &PathStyle,
 ) -> Result<String> {
-    let max_width = crossterm::terminal::size()
-        .ok()
-        .map(|(cols, _)| cols as usize);
-    render_table(cwd, db, tag, max_width, scan_paths)
+    render_table(
+        cwd,
+        db,
+        tag,
+        effective_width(table_width),
+        scan_paths,
+        order,
+        path_style,
+    )
+}
+
+/// Optional columns appended to the `trench list` table.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ListColumns {
+    /// On-disk size (`--size`).
+    pub size: bool,
+    /// Time since trench created the worktree (`--age`).
+    pub age: bool,
+}
+
+/// Execute `trench list --size`/`--age`: the table plus the requested
+/// optional columns.
+#[allow(clippy::too_many_arguments)]
+pub fn execute_with_columns(
+    cwd: &Path,
+    db: &Database,
+    tag: Option<&str>,
+    scan_paths: &[String],
+    order: Option<WorktreeOrder>,
+    table_width: Option<usize>,
+    path_style: &PathStyle,
+    columns: ListColumns,
+) -> Result<String> {
+    render_table_with(
+        cwd,
+        db,
+        tag,
+        effective_width(table_width),
+        scan_paths,
+        order,
+        path_style,
+        columns,
+    )
 }
 
 fn render_table(
@@ -170,14 +346,39 @@ fn render_table(
     tag: Option<&str>,
     max_width: Option<usize>,
     scan_paths: &[String],
+    order: Option<WorktreeOrder>,
+    path_style: &PathStyle,
+) -> Result<String> {
+    render_table_with(
+        cwd,
+        db,
+        tag,
+        max_width,
+        scan_paths,
+        order,
+        path_style,
+        ListColumns::default(),
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn render_table_with(
+    cwd: &Path,
+    db: &Database,
+    tag: Option<&str>,
+    max_width: Option<usize>,
+    scan_paths: &[String],
+    order: Option<WorktreeOrder>,
+    path_style: &PathStyle,
+    columns: ListColumns,
 ) -> Result<String> {
-    let (repo_path, entries) = fetch_all_worktrees(cwd, db, tag, scan_paths)?;
+    let (repo_path, entries) = fetch_all_worktrees(cwd, db, tag, scan_paths, order)?;
 
     if entries.is_empty() {
         return Ok("No worktrees. Use `trench create` to get started.\n".to_string());
     }
 
-    let mut table = Table::new(vec![
+    let mut headers = vec![
         "Name",
         "Branch",
         "Path",
@@ -185,10 +386,39 @@ fn render_table(
         "Ahead/Behind",
         "Procs",
         "Tags",
-    ]);
-    for entry in &entries {
+    ];
+    let sizes = if columns.size {
+        headers.push("Size");
+        compute_sizes(&entries)
+    } else {
+        Vec::new()
+    };
+    if columns.age {
+        headers.push("Age");
+    }
+    let now = crate::state::unix_epoch_secs() as i64;
+    let statuses = compute_git_statuses(&repo_path, &entries);
+    // Names (with their `*`/`[locked]` badges) are never cut while the path
+    // and other columns can still give up space.
+    let name_width = entries
+        .iter()
+        .map(|e| display_name(e).chars().count())
+        .max()
+        .unwrap_or(0);
+    let mut table = Table::new(headers)
+        .min_width(0, name_width)
+        .shrink_first(2)
+        .align(3, Align::Right)
+        .align(4, Align::Right)
+        .align(5, Align::Right);
+    if columns.size {
+        table = table.align(7, Align::Right);
+    }
+    if columns.age {
+        table = table.align(if columns.size { 8 } else { 7 }, Align::Right);
+    }
+    for (i, (entry, status)) in entries.iter().zip(statuses).enumerate() {
         let tags_str = entry.tags.join(", ");
-        let status = compute_git_status(&repo_path, entry);
         let dirty_str = format_dirty(status.dirty);
         let ab_str = format_ahead_behind(status.ahead, status.behind);
         let procs = crate::process::detect_processes(&entry.path);
@@ -197,15 +427,32 @@ fn render_table(
         } else {
             procs.len().to_string()
         };
-        table = table.row(vec![
-            &display_name(entry),
+        let name = display_name(entry);
+        let path = crate::paths::display_path(Path::new(&entry.path), path_style);
+        let mut row = vec![
+            name.as_str(),
             &entry.branch,
-            &entry.path,
+            &path,
             &dirty_str,
             &ab_str,
             &procs_str,
             &tags_str,
-        ]);
+        ];
+        let size_str = sizes
+            .get(i)
+            .map(|size| size.map_or("-".to_string(), format_size));
+        if let Some(size_str) = size_str.as_deref() {
+            row.push(size_str);
+        }
+        let age_str = columns.age.then(|| {
+            entry.created_at.map_or("-".to_string(), |created_at| {
+                crate::output::humanize_duration(now.saturating_sub(created_at).max(0) as u64)
+            })
+        });
+        if let Some(age_str) = age_str.as_deref() {
+            row.push(age_str);
+        }
+        table = table.row(row);
     }
 
     if let Some(width) = max_width {
@@ -214,7 +461,26 @@ fn render_table(
 
     let rendered = table.render();
 
-    Ok(rendered + "\n")
+    match tag {
+        Some(tag_name) => {
+            let header = tag_count_header(db, &repo_path, tag_name)?;
+            Ok(format!("{header}\n{rendered}\n"))
+        }
+        None => Ok(rendered + "\n"),
+    }
+}
+
+/// `# N worktrees tagged 'x'` line shown above a tag-filtered table.
+fn tag_count_header(db: &Database, repo_path: &Path, tag: &str) -> Result<String> {
+    let count = match repo_path.to_str() {
+        Some(path) => match db.get_repo_by_path(path)? {
+            Some(repo) => db.count_worktrees_by_tag(repo.id, tag)?,
+            None => 0,
+        },
+        None => 0,
+    };
+    let noun = if count == 1 { "worktree" } else { "worktrees" };
+    Ok(format!("# {count} {noun} tagged '{tag}'"))
 }
 
 /// Build a `WorktreeJson` from a list entry and computed git status.
@@ -230,20 +496,56 @@ fn build_worktree_json(entry: &ListEntry, status: GitStatus) -> WorktreeJson {
         ahead: status.ahead,
         behind: status.behind,
         dirty: status.dirty,
+        locked: entry.locked,
         tags: entry.tags.clone(),
         process_count,
         processes: process_names,
+        size_bytes: None,
+        managed: entry.managed,
     }
 }
 
 fn display_name(entry: &ListEntry) -> String {
-    if entry.is_current {
+    let name = if entry.is_current {
         format!("* {}", entry.name)
     } else {
         entry.name.clone()
+    };
+    if entry.locked {
+        format!("{name} [locked]")
+    } else {
+        name
     }
 }
 
+/// Build the `--json` objects for every listed worktree, optionally with
+/// `size_bytes` filled in.
+fn collect_json_items(
+    cwd: &Path,
+    db: &Database,
+    tag: Option<&str>,
+    scan_paths: &[String],
+    order: Option<WorktreeOrder>,
+    size: bool,
+) -> Result<Vec<WorktreeJson>> {
+    let (repo_path, entries) = fetch_all_worktrees(cwd, db, tag, scan_paths, order)?;
+    let sizes = if size {
+        compute_sizes(&entries)
+    } else {
+        vec![None; entries.len()]
+    };
+
+    Ok(entries
+        .iter()
+        .zip(compute_git_statuses(&repo_path, &entries))
+        .zip(sizes)
+        .map(|((entry, status), size)| WorktreeJson {
+            size_bytes: size,
+            ..build_worktree_json(entry, status)
+        })
+        .collect())
+}
+
 /// Execute the `trench list --json` command.
 ///
 /// Returns JSON array of worktree objects including tags.
@@ -252,38 +554,107 @@ pub fn execute_json(
     db: &Database,
     tag: Option<&str>,
     scan_paths: &[String],
+    order: Option<WorktreeOrder>,
 ) -> Result<String> {
-    let (repo_path, entries) = fetch_all_worktrees(cwd, db, tag, scan_paths)?;
+    format_json(&collect_json_items(cwd, db, tag, scan_paths, order, false)?)
+}
 
-    let mut json_items = Vec::new();
-    for entry in &entries {
-        let status = compute_git_status(&repo_path, entry);
-        json_items.push(build_worktree_json(entry, status));
-    }
+/// Execute `trench list --json --size`, adding `size_bytes` to each object.
+pub fn execute_json_with_size(
+    cwd: &Path,
+    db: &Database,
+    tag: Option<&str>,
+    scan_paths: &[String],
+    order: Option<WorktreeOrder>,
+) -> Result<String> {
+    format_json(&collect_json_items(cwd, db, tag, scan_paths, order, true)?)
+}
+
+/// Version of the `list --json --schema` envelope. Bump it whenever a
+/// worktree object changes shape incompatibly.
+pub const LIST_JSON_SCHEMA: u32 = 1;
+
+/// Versioned `list --json --schema` output, so tools can detect the shape.
+#[derive(Serialize)]
+struct ListJsonEnvelope {
+    schema: u32,
+    worktrees: Vec<WorktreeJson>,
+}
+
+/// Execute `trench list --json --schema`: the worktree objects wrapped in
+/// `{"schema": N, "worktrees": [...]}`, with `size_bytes` when `size`.
+pub fn execute_json_envelope(
+    cwd: &Path,
+    db: &Database,
+    tag: Option<&str>,
+    scan_paths: &[String],
+    order: Option<WorktreeOrder>,
+    size: bool,
+) -> Result<String> {
+    format_json_value(&ListJsonEnvelope {
+        schema: LIST_JSON_SCHEMA,
+        worktrees: collect_json_items(cwd, db, tag, scan_paths, order, size)?,
+    })
+}
 
-    format_json(&json_items)
+/// Execute `trench list --jsonl`: one compact worktree object per line.
+pub fn execute_jsonl(
+    cwd: &Path,
+    db: &Database,
+    tag: Option<&str>,
+    scan_paths: &[String],
+    order: Option<WorktreeOrder>,
+    size: bool,
+) -> Result<String> {
+    format_jsonl(&collect_json_items(cwd, db, tag, scan_paths, order, size)?)
 }
 
-/// Execute the `trench list --porcelain` command.
+/// Execute the `trench list --porcelain[=<version>]` command.
 ///
-/// Returns colon-separated lines: `name:branch:path:status:ahead:behind:dirty`.
+/// v1 returns colon-separated lines:
+/// `name:branch:path:status:ahead:behind:dirty`. v2 emits one line of
+/// `key=value` pairs per worktree.
 pub fn execute_porcelain(
     cwd: &Path,
     db: &Database,
     tag: Option<&str>,
     scan_paths: &[String],
+    order: Option<WorktreeOrder>,
+    version: PorcelainVersion,
 ) -> Result<String> {
-    let (repo_path, entries) = fetch_all_worktrees(cwd, db, tag, scan_paths)?;
+    let (repo_path, entries) = fetch_all_worktrees(cwd, db, tag, scan_paths, order)?;
 
     let items: Vec<WorktreeJson> = entries
         .iter()
-        .map(|entry| {
-            let status = compute_git_status(&repo_path, entry);
-            build_worktree_json(entry, status)
-        })
+        .zip(compute_git_statuses(&repo_path, &entries))
+        .map(|(entry, status)| build_worktree_json(entry, status))
         .collect();
 
-    Ok(format_porcelain(&items))
+    Ok(format_porcelain_as(&items, version))
+}
+
+#[derive(Serialize)]
+struct CountJson {
+    count: usize,
+}
+
+/// Execute `trench list --count`: just the number of matching worktrees,
+/// or `{"count": n}` under `--json`.
+pub fn execute_count(
+    cwd: &Path,
+    db: &Database,
+    tag: Option<&str>,
+    scan_paths: &[String],
+    json: bool,
+) -> Result<String> {
+    let (_, entries) = fetch_all_worktrees(cwd, db, tag, scan_paths, None)?;
+    if json {
+        format_json_value(&CountJson {
+            count: entries.len(),
+        })
+    } else {
+        Ok(entries.len().to_string())
+    }
 }
 
 #[cfg(test)]
@@ -315,12 +686,64 @@ mod tests {
             repo_dir,
             wt_root,
             crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             db,
         )
         .expect("create should succeed")
         .path
     }
 
+    #[test]
+    fn configured_table_width_truncates_regardless_of_terminal() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let _repo = init_repo_with_commit(repo_dir.path());
+        let wt_root = tempfile::tempdir().unwrap();
+        let db = Database::open_in_memory().unwrap();
+        create_live_worktree(repo_dir.path(), wt_root.path(), &db, "feature/auth");
+
+        let first = execute(
+            repo_dir.path(),
+            &db,
+            None,
+            &[],
+            None,
+            Some(60),
+            &PathStyle::Full,
+        )
+        .unwrap();
+        let second = execute(
+            repo_dir.path(),
+            &db,
+            None,
+            &[],
+            None,
+            Some(60),
+            &PathStyle::Full,
+        )
+        .unwrap();
+        assert_eq!(first, second, "fixed width should render identically");
+        for line in first.lines() {
+            assert!(
+                line.chars().count() <= 60,
+                "line exceeds configured width: {line:?}"
+            );
+        }
+        assert_eq!(
+            first,
+            render_table(
+                repo_dir.path(),
+                &db,
+                None,
+                Some(60),
+                &[],
+                None,
+                &PathStyle::Full
+            )
+            .unwrap(),
+            "configured width must bypass terminal detection"
+        );
+    }
+
     #[test]
     fn displays_worktrees_in_formatted_table() {
         let repo_dir = tempfile::tempdir().unwrap();
@@ -330,8 +753,16 @@ mod tests {
         create_live_worktree(repo_dir.path(), wt_root.path(), &db, "feature/auth");
         create_live_worktree(repo_dir.path(), wt_root.path(), &db, "fix/bug");
 
-        let output =
-            render_table(repo_dir.path(), &db, None, None, &[]).expect("list should succeed");
+        let output = render_table(
+            repo_dir.path(),
+            &db,
+            None,
+            None,
+            &[],
+            None,
+            &PathStyle::Full,
+        )
+        .expect("list should succeed");
 
         // Should contain column headers
         assert!(output.contains("Name"), "output should have Name header");
@@ -376,6 +807,7 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
         .expect("first create should succeed");
@@ -386,12 +818,21 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
         .expect("second create should succeed");
 
-        let output =
-            render_table(repo_dir.path(), &db, None, None, &[]).expect("list should succeed");
+        let output = render_table(
+            repo_dir.path(),
+            &db,
+            None,
+            None,
+            &[],
+            None,
+            &PathStyle::Full,
+        )
+        .expect("list should succeed");
 
         assert!(
             output.contains("feature-one"),
@@ -413,8 +854,16 @@ mod tests {
         let _repo = init_repo_with_commit(repo_dir.path());
         let db = Database::open_in_memory().unwrap();
 
-        let output =
-            render_table(repo_dir.path(), &db, None, None, &[]).expect("list should succeed");
+        let output = render_table(
+            repo_dir.path(),
+            &db,
+            None,
+            None,
+            &[],
+            None,
+            &PathStyle::Full,
+        )
+        .expect("list should succeed");
 
         let repo_path = repo_dir.path().canonicalize().unwrap();
         let repo_name = repo_path.file_name().unwrap().to_str().unwrap();
@@ -443,6 +892,7 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
         .unwrap();
@@ -452,13 +902,22 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
         .unwrap();
         remove::execute("feature-removed", repo_dir.path(), &db, false).unwrap();
 
-        let output =
-            render_table(repo_dir.path(), &db, None, None, &[]).expect("list should succeed");
+        let output = render_table(
+            repo_dir.path(),
+            &db,
+            None,
+            None,
+            &[],
+            None,
+            &PathStyle::Full,
+        )
+        .expect("list should succeed");
 
         assert!(
             output.contains("feature-active"),
@@ -494,14 +953,23 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
         .expect("create should succeed");
 
         remove::execute("ephemeral", repo_dir.path(), &db, false).expect("remove should succeed");
 
-        let output =
-            render_table(repo_dir.path(), &db, None, None, &[]).expect("list should succeed");
+        let output = render_table(
+            repo_dir.path(),
+            &db,
+            None,
+            None,
+            &[],
+            None,
+            &PathStyle::Full,
+        )
+        .expect("list should succeed");
 
         let repo_path = repo_dir.path().canonicalize().unwrap();
         let repo_name = repo_path.file_name().unwrap().to_str().unwrap();
@@ -527,14 +995,23 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
         .expect("create should succeed");
 
         std::fs::remove_dir_all(&created.path).expect("manual delete should succeed");
 
-        let output =
-            render_table(repo_dir.path(), &db, None, None, &[]).expect("list should succeed");
+        let output = render_table(
+            repo_dir.path(),
+            &db,
+            None,
+            None,
+            &[],
+            None,
+            &PathStyle::Full,
+        )
+        .expect("list should succeed");
 
         assert!(
             !output.contains("ephemeral"),
@@ -560,7 +1037,16 @@ mod tests {
         )
         .unwrap();
 
-        let output = execute(repo_dir.path(), &db, Some("wip"), &[]).unwrap();
+        let output = execute(
+            repo_dir.path(),
+            &db,
+            Some("wip"),
+            &[],
+            None,
+            None,
+            &PathStyle::Full,
+        )
+        .unwrap();
 
         assert!(
             output.contains("feature-tagged"),
@@ -572,7 +1058,103 @@ mod tests {
         );
 
         let lines: Vec<&str> = output.lines().collect();
-        assert_eq!(lines.len(), 3, "expected header + separator + 1 data row");
+        assert_eq!(
+            lines.len(),
+            4,
+            "expected count line + header + separator + 1 data row"
+        );
+    }
+
+    #[test]
+    fn count_respects_tag_filter_and_matches_data_rows() {
+        use crate::cli::commands::tag;
+
+        let repo_dir = tempfile::tempdir().unwrap();
+        let _repo = init_repo_with_commit(repo_dir.path());
+        let wt_root = tempfile::tempdir().unwrap();
+        let db = Database::open_in_memory().unwrap();
+        create_live_worktree(repo_dir.path(), wt_root.path(), &db, "feature/tagged");
+        create_live_worktree(repo_dir.path(), wt_root.path(), &db, "feature/untagged");
+        tag::execute(
+            "feature-tagged",
+            &["+wip".to_string()],
+            repo_dir.path(),
+            &db,
+        )
+        .unwrap();
+
+        let all = execute_count(repo_dir.path(), &db, None, &[], false).unwrap();
+        let porcelain_rows =
+            execute_porcelain(repo_dir.path(), &db, None, &[], None, PorcelainVersion::V1)
+                .unwrap()
+                .lines()
+                .count();
+        assert_eq!(all, porcelain_rows.to_string(), "main + 2 linked worktrees");
+        assert_eq!(all, "3");
+
+        let tagged = execute_count(repo_dir.path(), &db, Some("wip"), &[], false).unwrap();
+        assert_eq!(tagged, "1");
+
+        let json = execute_count(repo_dir.path(), &db, Some("wip"), &[], true).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, serde_json::json!({ "count": 1 }));
+    }
+
+    #[test]
+    fn tag_filter_prepends_count_header_only_when_filtering() {
+        use crate::cli::commands::tag;
+
+        let repo_dir = tempfile::tempdir().unwrap();
+        let _repo = init_repo_with_commit(repo_dir.path());
+        let wt_root = tempfile::tempdir().unwrap();
+        let db = Database::open_in_memory().unwrap();
+        for branch in ["feature/a", "feature/b", "feature/c"] {
+            create_live_worktree(repo_dir.path(), wt_root.path(), &db, branch);
+        }
+        for name in ["feature-a", "feature-b"] {
+            tag::execute(name, &["+wip".to_string()], repo_dir.path(), &db).unwrap();
+        }
+
+        let filtered = render_table(
+            repo_dir.path(),
+            &db,
+            Some("wip"),
+            None,
+            &[],
+            None,
+            &PathStyle::Full,
+        )
+        .unwrap();
+        assert_eq!(
+            filtered.lines().next(),
+            Some("# 2 worktrees tagged 'wip'"),
+            "got: {filtered}"
+        );
+
+        let unfiltered = render_table(
+            repo_dir.path(),
+            &db,
+            None,
+            None,
+            &[],
+            None,
+            &PathStyle::Full,
+        )
+        .unwrap();
+        assert!(!unfiltered.contains("tagged"), "got: {unfiltered}");
+
+        let porcelain = execute_porcelain(
+            repo_dir.path(),
+            &db,
+            Some("wip"),
+            &[],
+            None,
+            PorcelainVersion::V1,
+        )
+        .unwrap();
+        assert!(!porcelain.contains('#'), "got: {porcelain}");
+        let json = execute_json(repo_dir.path(), &db, Some("wip"), &[], None).unwrap();
+        assert!(!json.contains("tagged"), "got: {json}");
     }
 
     #[test]
@@ -586,7 +1168,16 @@ mod tests {
         db.insert_repo(repo_name, repo_path.to_str().unwrap(), Some("main"))
             .unwrap();
 
-        let output = execute(repo_dir.path(), &db, Some("nonexistent"), &[]).unwrap();
+        let output = execute(
+            repo_dir.path(),
+            &db,
+            Some("nonexistent"),
+            &[],
+            None,
+            None,
+            &PathStyle::Full,
+        )
+        .unwrap();
         assert!(output.contains("No worktrees"));
     }
 
@@ -607,7 +1198,16 @@ mod tests {
         )
         .unwrap();
 
-        let output = execute(repo_dir.path(), &db, None, &[]).unwrap();
+        let output = execute(
+            repo_dir.path(),
+            &db,
+            None,
+            &[],
+            None,
+            None,
+            &PathStyle::Full,
+        )
+        .unwrap();
 
         assert!(output.contains("Tags"), "output should have Tags header");
         assert!(
@@ -616,6 +1216,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn list_json_envelope_has_schema_and_worktrees() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let _repo = init_repo_with_commit(repo_dir.path());
+        let wt_root = tempfile::tempdir().unwrap();
+        let db = Database::open_in_memory().unwrap();
+        create_live_worktree(repo_dir.path(), wt_root.path(), &db, "enveloped");
+
+        let output = execute_json_envelope(repo_dir.path(), &db, None, &[], None, false).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+        assert_eq!(parsed["schema"].as_u64(), Some(u64::from(LIST_JSON_SCHEMA)));
+        let worktrees = parsed["worktrees"].as_array().expect("worktrees array");
+        let bare: serde_json::Value =
+            serde_json::from_str(&execute_json(repo_dir.path(), &db, None, &[], None).unwrap())
+                .unwrap();
+        assert_eq!(worktrees, bare.as_array().unwrap());
+        assert!(worktrees.iter().any(|w| w["name"] == "enveloped"));
+    }
+
     #[test]
     fn list_json_includes_tags() {
         use crate::cli::commands::tag;
@@ -627,7 +1247,7 @@ mod tests {
         create_live_worktree(repo_dir.path(), wt_root.path(), &db, "my-branch");
         tag::execute("my-branch", &["+wip".to_string()], repo_dir.path(), &db).unwrap();
 
-        let json_output = execute_json(repo_dir.path(), &db, None, &[]).unwrap();
+        let json_output = execute_json(repo_dir.path(), &db, None, &[], None).unwrap();
         let parsed: serde_json::Value = serde_json::from_str(&json_output).unwrap();
 
         let worktrees = parsed.as_array().expect("should be an array");
@@ -660,6 +1280,7 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
         .unwrap();
@@ -669,6 +1290,7 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
         .unwrap();
@@ -684,18 +1306,45 @@ mod tests {
         tag::execute("feature-beta", &["+wip".to_string()], repo_dir.path(), &db).unwrap();
 
         // List all — both should appear with tags
-        let all_output = render_table(repo_dir.path(), &db, None, None, &[]).unwrap();
+        let all_output = render_table(
+            repo_dir.path(),
+            &db,
+            None,
+            None,
+            &[],
+            None,
+            &PathStyle::Full,
+        )
+        .unwrap();
         assert!(all_output.contains("feature-alpha"));
         assert!(all_output.contains("feature-beta"));
         assert!(all_output.contains("Tags"), "should have Tags header");
 
         // Filter by wip — both should appear
-        let wip_output = render_table(repo_dir.path(), &db, Some("wip"), None, &[]).unwrap();
+        let wip_output = render_table(
+            repo_dir.path(),
+            &db,
+            Some("wip"),
+            None,
+            &[],
+            None,
+            &PathStyle::Full,
+        )
+        .unwrap();
         assert!(wip_output.contains("feature-alpha"));
         assert!(wip_output.contains("feature-beta"));
 
         // Filter by review — only alpha
-        let review_output = render_table(repo_dir.path(), &db, Some("review"), None, &[]).unwrap();
+        let review_output = render_table(
+            repo_dir.path(),
+            &db,
+            Some("review"),
+            None,
+            &[],
+            None,
+            &PathStyle::Full,
+        )
+        .unwrap();
         assert!(review_output.contains("feature-alpha"));
         assert!(!review_output.contains("feature-beta"));
 
@@ -703,12 +1352,21 @@ mod tests {
         tag::execute("feature-alpha", &["-wip".to_string()], repo_dir.path(), &db).unwrap();
 
         // Filter by wip — only beta now
-        let wip_after = render_table(repo_dir.path(), &db, Some("wip"), None, &[]).unwrap();
+        let wip_after = render_table(
+            repo_dir.path(),
+            &db,
+            Some("wip"),
+            None,
+            &[],
+            None,
+            &PathStyle::Full,
+        )
+        .unwrap();
         assert!(!wip_after.contains("feature-alpha"));
         assert!(wip_after.contains("feature-beta"));
 
         // JSON output should include tags (includes main worktree too)
-        let json_output = execute_json(repo_dir.path(), &db, None, &[]).unwrap();
+        let json_output = execute_json(repo_dir.path(), &db, None, &[], None).unwrap();
         let parsed: serde_json::Value = serde_json::from_str(&json_output).unwrap();
         let items = parsed.as_array().unwrap();
         // 2 managed + 1 main worktree + 2 git worktrees for the created branches
@@ -751,11 +1409,12 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
         .expect("create should succeed");
 
-        let json_output = execute_json(repo_dir.path(), &db, None, &[]).unwrap();
+        let json_output = execute_json(repo_dir.path(), &db, None, &[], None).unwrap();
         let parsed: serde_json::Value = serde_json::from_str(&json_output).unwrap();
 
         let items = parsed.as_array().expect("should be an array");
@@ -782,6 +1441,163 @@ mod tests {
         assert_eq!(wt["dirty"], serde_json::json!(0));
     }
 
+    #[test]
+    fn size_column_appears_only_when_requested() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let _repo = init_repo_with_commit(repo_dir.path());
+        let wt_root = tempfile::tempdir().unwrap();
+        let db = Database::open_in_memory().unwrap();
+        let path = create_live_worktree(repo_dir.path(), wt_root.path(), &db, "sized");
+        std::fs::write(path.join("blob.bin"), vec![0u8; 2048]).unwrap();
+
+        let plain = render_table(
+            repo_dir.path(),
+            &db,
+            None,
+            None,
+            &[],
+            None,
+            &PathStyle::Full,
+        )
+        .unwrap();
+        assert!(!plain.contains("Size"), "got:\n{plain}");
+        let sized = render_table_with(
+            repo_dir.path(),
+            &db,
+            None,
+            None,
+            &[],
+            None,
+            &PathStyle::Full,
+            ListColumns {
+                size: true,
+                ..ListColumns::default()
+            },
+        )
+        .unwrap();
+        assert!(sized.contains("Size"), "got:\n{sized}");
+        assert!(sized.contains("2.0 KiB"), "got:\n{sized}");
+
+        let plain_json: serde_json::Value =
+            serde_json::from_str(&execute_json(repo_dir.path(), &db, None, &[], None).unwrap())
+                .unwrap();
+        assert!(plain_json[0].get("size_bytes").is_none());
+        let sized_json: serde_json::Value = serde_json::from_str(
+            &execute_json_with_size(repo_dir.path(), &db, None, &[], None).unwrap(),
+        )
+        .unwrap();
+        let item = sized_json
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|i| i["name"] == "sized")
+            .unwrap();
+        assert_eq!(item["size_bytes"], 2048);
+    }
+
+    #[test]
+    fn age_column_appears_only_when_requested() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let _repo = init_repo_with_commit(repo_dir.path());
+        let wt_root = tempfile::tempdir().unwrap();
+        let db = Database::open_in_memory().unwrap();
+        create_live_worktree(repo_dir.path(), wt_root.path(), &db, "aged");
+        let repo_path = repo_dir.path().canonicalize().unwrap();
+        let repo = db
+            .get_repo_by_path(repo_path.to_str().unwrap())
+            .unwrap()
+            .unwrap();
+        let wt = db
+            .find_worktree_by_identifier(repo.id, "aged")
+            .unwrap()
+            .unwrap();
+        let three_days_ago = crate::state::unix_epoch_secs() as i64 - 3 * 86_400 - 60;
+        db.conn_for_test()
+            .execute(
+                "UPDATE worktrees SET created_at = ?1 WHERE id = ?2",
+                rusqlite::params![three_days_ago, wt.id],
+            )
+            .unwrap();
+
+        let plain = render_table(
+            repo_dir.path(),
+            &db,
+            None,
+            None,
+            &[],
+            None,
+            &PathStyle::Full,
+        )
+        .unwrap();
+        assert!(!plain.contains("Age"), "got:\n{plain}");
+
+        let aged = render_table_with(
+            repo_dir.path(),
+            &db,
+            None,
+            None,
+            &[],
+            None,
+            &PathStyle::Full,
+            ListColumns {
+                age: true,
+                ..ListColumns::default()
+            },
+        )
+        .unwrap();
+        assert!(aged.lines().next().unwrap().contains("Age"), "got:\n{aged}");
+        let row = |name: &str| {
+            aged.lines()
+                .find(|l| l.trim_start_matches("* ").starts_with(name))
+                .unwrap_or_else(|| panic!("no row for {name}:\n{aged}"))
+                .trim_end()
+                .to_string()
+        };
+        assert!(row("aged").ends_with("3d"), "got:\n{aged}");
+        // The main worktree has no managed DB row.
+        let main_name = repo_path.file_name().unwrap().to_str().unwrap();
+        assert!(row(main_name).ends_with('-'), "got:\n{aged}");
+    }
+
+    #[test]
+    fn format_size_uses_binary_units() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(1536), "1.5 KiB");
+        assert_eq!(format_size(5 * 1024 * 1024), "5.0 MiB");
+    }
+
+    #[test]
+    fn list_marks_locked_worktrees_in_table_and_json() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let repo = init_repo_with_commit(repo_dir.path());
+        let wt_root = tempfile::tempdir().unwrap();
+        let db = Database::open_in_memory().unwrap();
+        create_live_worktree(repo_dir.path(), wt_root.path(), &db, "held");
+        create_live_worktree(repo_dir.path(), wt_root.path(), &db, "free");
+        repo.find_worktree("held").unwrap().lock(None).unwrap();
+
+        let table = render_table(
+            repo_dir.path(),
+            &db,
+            None,
+            None,
+            &[],
+            None,
+            &PathStyle::Full,
+        )
+        .unwrap();
+        assert!(table.contains("held [locked]"), "got:\n{table}");
+        assert!(!table.contains("free [locked]"), "got:\n{table}");
+
+        let json_output = execute_json(repo_dir.path(), &db, None, &[], None).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json_output).unwrap();
+        let items = parsed.as_array().unwrap();
+        let locked_of =
+            |name: &str| items.iter().find(|i| i["name"] == name).unwrap()["locked"].clone();
+        assert_eq!(locked_of("held"), serde_json::json!(true));
+        assert_eq!(locked_of("free"), serde_json::json!(false));
+    }
+
     #[test]
     fn list_json_shows_correct_ahead_behind_and_dirty_values() {
         use crate::cli::commands::create;
@@ -798,6 +1614,7 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
         .expect("create should succeed");
@@ -827,7 +1644,7 @@ mod tests {
         // Create an untracked file in the worktree (makes it dirty)
         std::fs::write(wt_path.join("untracked.txt"), "dirty").unwrap();
 
-        let json_output = execute_json(repo_dir.path(), &db, None, &[]).unwrap();
+        let json_output = execute_json(repo_dir.path(), &db, None, &[], None).unwrap();
         let parsed: serde_json::Value = serde_json::from_str(&json_output).unwrap();
         let wt_json = parsed
             .as_array()
@@ -871,7 +1688,7 @@ mod tests {
         opts.reference(Some(branch_ref.get()));
         repo.worktree("orphan-wt", &wt_path, Some(&opts)).unwrap();
 
-        let json_output = execute_json(repo_dir.path(), &db, None, &[]).unwrap();
+        let json_output = execute_json(repo_dir.path(), &db, None, &[], None).unwrap();
         let parsed: serde_json::Value = serde_json::from_str(&json_output).unwrap();
 
         let wt = parsed
@@ -903,7 +1720,16 @@ mod tests {
         repo.worktree("no-upstream-wt", &wt_path, Some(&opts))
             .unwrap();
 
-        let output = execute(repo_dir.path(), &db, None, &[]).expect("list should succeed");
+        let output = execute(
+            repo_dir.path(),
+            &db,
+            None,
+            &[],
+            None,
+            None,
+            &PathStyle::Full,
+        )
+        .expect("list should succeed");
 
         // The Ahead/Behind column should show "-" for no upstream
         let row = output
@@ -932,11 +1758,21 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
         .expect("create should succeed");
 
-        let output = execute(repo_dir.path(), &db, None, &[]).expect("list should succeed");
+        let output = execute(
+            repo_dir.path(),
+            &db,
+            None,
+            &[],
+            None,
+            None,
+            &PathStyle::Full,
+        )
+        .expect("list should succeed");
 
         assert!(
             output.contains("Ahead/Behind"),
@@ -958,7 +1794,8 @@ mod tests {
             create_live_worktree(repo_dir.path(), wt_root.path(), &db, "feature/auth");
         let fix_bug = create_live_worktree(repo_dir.path(), wt_root.path(), &db, "fix/bug");
 
-        let output = execute_porcelain(repo_dir.path(), &db, None, &[]).unwrap();
+        let output =
+            execute_porcelain(repo_dir.path(), &db, None, &[], None, PorcelainVersion::V1).unwrap();
         let lines: Vec<&str> = output.lines().collect();
 
         // 2 linked + 1 main worktree
@@ -984,13 +1821,76 @@ mod tests {
         assert_eq!(fix_bug_fields[3], "clean");
     }
 
+    #[test]
+    fn order_sorts_tracked_worktrees_after_main_checkout() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let _repo = init_repo_with_commit(repo_dir.path());
+        let wt_root = tempfile::tempdir().unwrap();
+        let db = Database::open_in_memory().unwrap();
+        create_live_worktree(repo_dir.path(), wt_root.path(), &db, "zeta");
+        create_live_worktree(repo_dir.path(), wt_root.path(), &db, "alpha");
+
+        let names = |order| {
+            execute_porcelain(repo_dir.path(), &db, None, &[], order, PorcelainVersion::V1)
+                .unwrap()
+                .lines()
+                .map(|line| line.split(':').next().unwrap().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        let main_name = names(None)[0].clone();
+        assert_eq!(
+            names(Some(WorktreeOrder::CreatedAsc)),
+            [main_name.as_str(), "zeta", "alpha"]
+        );
+        assert_eq!(
+            names(Some(WorktreeOrder::NameAsc)),
+            [main_name.as_str(), "alpha", "zeta"]
+        );
+    }
+
+    #[test]
+    fn list_porcelain_v2_outputs_key_value_pairs() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let _repo = init_repo_with_commit(repo_dir.path());
+        let wt_root = tempfile::tempdir().unwrap();
+        let db = Database::open_in_memory().unwrap();
+        create_live_worktree(repo_dir.path(), wt_root.path(), &db, "feature/auth");
+
+        let output =
+            execute_porcelain(repo_dir.path(), &db, None, &[], None, PorcelainVersion::V2).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines.len(), 2, "{output}");
+        assert!(!output.contains('\x1b'), "porcelain v2 must be ANSI-free");
+        for line in &lines {
+            assert!(line.starts_with("name="), "{line}");
+            assert!(line.contains(" managed="), "{line}");
+        }
+        let feature = lines
+            .iter()
+            .find(|line| line.starts_with("name=feature-auth "))
+            .expect("feature-auth should appear in porcelain v2");
+        let tokens: Vec<&str> = feature.split(' ').collect();
+        for expected in [
+            "branch=feature/auth",
+            "status=clean",
+            "dirty=0",
+            "managed=true",
+            "locked=false",
+        ] {
+            assert!(tokens.contains(&expected), "missing {expected}: {feature}");
+        }
+    }
+
     #[test]
     fn list_porcelain_shows_main_worktree_when_no_linked_worktrees() {
         let repo_dir = tempfile::tempdir().unwrap();
         let _repo = init_repo_with_commit(repo_dir.path());
         let db = Database::open_in_memory().unwrap();
 
-        let output = execute_porcelain(repo_dir.path(), &db, None, &[]).unwrap();
+        let output =
+            execute_porcelain(repo_dir.path(), &db, None, &[], None, PorcelainVersion::V1).unwrap();
         let lines: Vec<&str> = output.lines().collect();
         assert_eq!(lines.len(), 1, "should have 1 line for main worktree");
         assert_eq!(lines[0].split(':').count(), 7);
@@ -1004,7 +1904,7 @@ mod tests {
         let db = Database::open_in_memory().unwrap();
         create_live_worktree(repo_dir.path(), wt_root.path(), &db, "my-branch");
 
-        let json_output = execute_json(repo_dir.path(), &db, None, &[]).unwrap();
+        let json_output = execute_json(repo_dir.path(), &db, None, &[], None).unwrap();
         let parsed: serde_json::Value = serde_json::from_str(&json_output).unwrap();
 
         let worktrees = parsed.as_array().expect("should be an array");
@@ -1025,12 +1925,26 @@ mod tests {
         // Create a worktree via git directly (simulating manual `git worktree add`)
         let wt_dir = tempfile::tempdir().unwrap();
         let target = wt_dir.path().join("manually-added");
-        git::create_worktree(repo_dir.path(), "manually-added", &base, &target)
-            .expect("should create worktree via git");
+        git::create_named_worktree(
+            repo_dir.path(),
+            "manually-added",
+            &base,
+            &target,
+            "manually-added",
+        )
+        .expect("should create worktree via git");
 
         // Table output should include the manual worktree.
-        let table_output =
-            render_table(repo_dir.path(), &db, None, None, &[]).expect("table list should succeed");
+        let table_output = render_table(
+            repo_dir.path(),
+            &db,
+            None,
+            None,
+            &[],
+            None,
+            &PathStyle::Full,
+        )
+        .expect("table list should succeed");
         assert!(
             table_output.contains("manually-added"),
             "table should show manually-added worktree, got: {table_output}"
@@ -1038,7 +1952,7 @@ mod tests {
         assert!(!table_output.contains("[unmanaged]"));
 
         let json_output =
-            execute_json(repo_dir.path(), &db, None, &[]).expect("json list should succeed");
+            execute_json(repo_dir.path(), &db, None, &[], None).expect("json list should succeed");
         let parsed: serde_json::Value = serde_json::from_str(&json_output).unwrap();
         let items = parsed.as_array().unwrap();
         let manual_wt = items
@@ -1050,8 +1964,9 @@ mod tests {
         assert!(manual_wt.get("dirty").is_some());
         assert!(manual_wt.get("status").is_some());
 
-        let porcelain_output = execute_porcelain(repo_dir.path(), &db, None, &[])
-            .expect("porcelain list should succeed");
+        let porcelain_output =
+            execute_porcelain(repo_dir.path(), &db, None, &[], None, PorcelainVersion::V1)
+                .expect("porcelain list should succeed");
         let manual_line = porcelain_output
             .lines()
             .find(|l| l.starts_with("manually-added:"))
@@ -1073,6 +1988,37 @@ mod tests {
         assert!(main_json.get("managed").is_none());
     }
 
+    #[test]
+    fn narrow_list_ellipsizes_paths_but_keeps_names() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let _repo = init_repo_with_commit(repo_dir.path());
+        let wt_root = tempfile::tempdir().unwrap();
+        let db = Database::open_in_memory().unwrap();
+        create_live_worktree(
+            repo_dir.path(),
+            wt_root.path(),
+            &db,
+            "a-rather-long-worktree-name",
+        );
+
+        let output = render_table(
+            repo_dir.path(),
+            &db,
+            None,
+            Some(70),
+            &[],
+            None,
+            &PathStyle::Full,
+        )
+        .expect("list should succeed");
+
+        assert!(output.contains("a-rather-long-worktree-name"), "{output}");
+        assert!(output.contains('…'), "path should be ellipsized: {output}");
+        for line in output.lines() {
+            assert!(line.chars().count() <= 70, "line too wide: {line:?}");
+        }
+    }
+
     #[test]
     fn list_table_omits_unmanaged_badge_and_dim_codes() {
         let repo_dir = tempfile::tempdir().unwrap();
@@ -1081,8 +2027,16 @@ mod tests {
         let db = Database::open_in_memory().unwrap();
         create_live_worktree(repo_dir.path(), wt_root.path(), &db, "managed-wt");
 
-        let output =
-            render_table(repo_dir.path(), &db, None, None, &[]).expect("list should succeed");
+        let output = render_table(
+            repo_dir.path(),
+            &db,
+            None,
+            None,
+            &[],
+            None,
+            &PathStyle::Full,
+        )
+        .expect("list should succeed");
         assert!(!output.contains("[unmanaged]"));
         assert!(!output.contains("\x1b[2m"));
     }
@@ -1097,10 +2051,17 @@ mod tests {
         // Create a worktree via git directly
         let wt_dir = tempfile::tempdir().unwrap();
         let target = wt_dir.path().join("porcelain-external");
-        git::create_worktree(repo_dir.path(), "porcelain-external", &base, &target)
-            .expect("should create worktree via git");
+        git::create_named_worktree(
+            repo_dir.path(),
+            "porcelain-external",
+            &base,
+            &target,
+            "porcelain-external",
+        )
+        .expect("should create worktree via git");
 
-        let output = execute_porcelain(repo_dir.path(), &db, None, &[]).unwrap();
+        let output =
+            execute_porcelain(repo_dir.path(), &db, None, &[], None, PorcelainVersion::V1).unwrap();
         let lines: Vec<&str> = output.lines().collect();
 
         assert!(
@@ -1137,10 +2098,16 @@ mod tests {
         // Create a worktree via git directly (not through trench)
         let wt_dir = tempfile::tempdir().unwrap();
         let target = wt_dir.path().join("git-only-wt");
-        git::create_worktree(repo_dir.path(), "git-only-wt", &base, &target)
-            .expect("should create worktree via git");
+        git::create_named_worktree(
+            repo_dir.path(),
+            "git-only-wt",
+            &base,
+            &target,
+            "git-only-wt",
+        )
+        .expect("should create worktree via git");
 
-        let json_output = execute_json(repo_dir.path(), &db, None, &[]).unwrap();
+        let json_output = execute_json(repo_dir.path(), &db, None, &[], None).unwrap();
         let parsed: serde_json::Value = serde_json::from_str(&json_output).unwrap();
         let items = parsed.as_array().expect("should be an array");
 
@@ -1174,12 +2141,26 @@ mod tests {
         // Create a worktree via git directly (not through trench)
         let wt_dir = tempfile::tempdir().unwrap();
         let target = wt_dir.path().join("external-wt");
-        git::create_worktree(repo_dir.path(), "external-wt", &base, &target)
-            .expect("should create worktree via git");
+        git::create_named_worktree(
+            repo_dir.path(),
+            "external-wt",
+            &base,
+            &target,
+            "external-wt",
+        )
+        .expect("should create worktree via git");
 
         // Use render_table with no max_width to avoid terminal truncation
-        let output =
-            render_table(repo_dir.path(), &db, None, None, &[]).expect("list should succeed");
+        let output = render_table(
+            repo_dir.path(),
+            &db,
+            None,
+            None,
+            &[],
+            None,
+            &PathStyle::Full,
+        )
+        .expect("list should succeed");
 
         assert!(
             output.contains("external-wt"),
@@ -1195,8 +2176,16 @@ mod tests {
         let db = Database::open_in_memory().unwrap();
 
         // Use render_table with no max_width to avoid terminal truncation
-        let output =
-            render_table(repo_dir.path(), &db, None, None, &[]).expect("list should succeed");
+        let output = render_table(
+            repo_dir.path(),
+            &db,
+            None,
+            None,
+            &[],
+            None,
+            &PathStyle::Full,
+        )
+        .expect("list should succeed");
 
         let repo_path = repo_dir.path().canonicalize().unwrap();
         let repo_name = repo_path.file_name().unwrap().to_str().unwrap().to_string();
@@ -1220,10 +2209,17 @@ mod tests {
         let target = wt_dir.path().join("linked-wt");
         let db = Database::open_in_memory().unwrap();
 
-        crate::git::create_worktree(repo_dir.path(), "linked-wt", &base, &target)
-            .expect("should create linked worktree");
+        crate::git::create_named_worktree(
+            repo_dir.path(),
+            "linked-wt",
+            &base,
+            &target,
+            "linked-wt",
+        )
+        .expect("should create linked worktree");
 
-        let output = render_table(&target, &db, None, None, &[]).expect("list should succeed");
+        let output = render_table(&target, &db, None, None, &[], None, &PathStyle::Full)
+            .expect("list should succeed");
         let main_path = repo_dir
             .path()
             .canonicalize()
@@ -1251,7 +2247,16 @@ mod tests {
         let _repo = init_repo_with_commit(repo_dir.path());
         let db = Database::open_in_memory().unwrap();
 
-        let output = execute(repo_dir.path(), &db, None, &[]).expect("list should succeed");
+        let output = execute(
+            repo_dir.path(),
+            &db,
+            None,
+            &[],
+            None,
+            None,
+            &PathStyle::Full,
+        )
+        .expect("list should succeed");
 
         assert!(
             output.ends_with('\n'),
@@ -1275,6 +2280,7 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
         .expect("first create should succeed");
@@ -1285,12 +2291,13 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
         .expect("second create should succeed");
 
         // Verify JSON output
-        let json_output = execute_json(repo_dir.path(), &db, None, &[]).unwrap();
+        let json_output = execute_json(repo_dir.path(), &db, None, &[], None).unwrap();
         let parsed: serde_json::Value =
             serde_json::from_str(&json_output).expect("JSON output must be valid JSON");
 
@@ -1309,7 +2316,8 @@ mod tests {
         let first = items.iter().find(|i| i["name"] == "feature-json").unwrap();
         assert!(first.get("managed").is_none());
 
-        let porcelain_output = execute_porcelain(repo_dir.path(), &db, None, &[]).unwrap();
+        let porcelain_output =
+            execute_porcelain(repo_dir.path(), &db, None, &[], None, PorcelainVersion::V1).unwrap();
         let lines: Vec<&str> = porcelain_output.lines().collect();
         assert!(lines.len() >= 3, "should have at least 3 porcelain lines");
 
@@ -1356,7 +2364,7 @@ mod tests {
         let db = Database::open_in_memory().unwrap();
 
         // JSON output: branch should be "(detached)", not ""
-        let json_output = execute_json(repo_dir.path(), &db, None, &[])
+        let json_output = execute_json(repo_dir.path(), &db, None, &[], None)
             .expect("json list should succeed for unborn repo");
         let parsed: serde_json::Value = serde_json::from_str(&json_output).unwrap();
         let items = parsed.as_array().expect("should be an array");
@@ -1371,8 +2379,16 @@ mod tests {
         );
 
         // Table output: should also show "(detached)"
-        let table_output = render_table(repo_dir.path(), &db, None, None, &[])
-            .expect("table list should succeed for unborn repo");
+        let table_output = render_table(
+            repo_dir.path(),
+            &db,
+            None,
+            None,
+            &[],
+            None,
+            &PathStyle::Full,
+        )
+        .expect("table list should succeed for unborn repo");
         assert!(
             table_output.contains("(detached)"),
             "table should show (detached) for unborn HEAD, got: {table_output}"
@@ -1389,13 +2405,27 @@ mod tests {
         // Create a worktree in a custom scan directory (outside default root)
         let scan_dir = tempfile::tempdir().unwrap();
         let wt_path = scan_dir.path().join("scan-feature");
-        git::create_worktree(repo_dir.path(), "scan-feature", &base, &wt_path)
-            .expect("should create worktree");
+        git::create_named_worktree(
+            repo_dir.path(),
+            "scan-feature",
+            &base,
+            &wt_path,
+            "scan-feature",
+        )
+        .expect("should create worktree");
 
         let scan_paths = vec![scan_dir.path().to_string_lossy().into_owned()];
 
-        let output = render_table(repo_dir.path(), &db, None, None, &scan_paths)
-            .expect("list with scan paths should succeed");
+        let output = render_table(
+            repo_dir.path(),
+            &db,
+            None,
+            None,
+            &scan_paths,
+            None,
+            &PathStyle::Full,
+        )
+        .expect("list with scan paths should succeed");
 
         assert!(
             output.contains("scan-feature"),
@@ -1415,14 +2445,36 @@ mod tests {
         let scan_dir = tempfile::tempdir().unwrap();
         let wt_a = scan_dir.path().join("feature-alpha");
         let wt_b = scan_dir.path().join("feature-beta");
-        git::create_worktree(repo_dir.path(), "feature-alpha", &base, &wt_a).expect("create alpha");
-        git::create_worktree(repo_dir.path(), "feature-beta", &base, &wt_b).expect("create beta");
+        git::create_named_worktree(
+            repo_dir.path(),
+            "feature-alpha",
+            &base,
+            &wt_a,
+            "feature-alpha",
+        )
+        .expect("create alpha");
+        git::create_named_worktree(
+            repo_dir.path(),
+            "feature-beta",
+            &base,
+            &wt_b,
+            "feature-beta",
+        )
+        .expect("create beta");
 
         let scan_paths = vec![scan_dir.path().to_string_lossy().into_owned()];
 
         // Table output should include both scanned worktrees
-        let table_output = render_table(repo_dir.path(), &db, None, None, &scan_paths)
-            .expect("table with scan paths should succeed");
+        let table_output = render_table(
+            repo_dir.path(),
+            &db,
+            None,
+            None,
+            &scan_paths,
+            None,
+            &PathStyle::Full,
+        )
+        .expect("table with scan paths should succeed");
         assert!(
             table_output.contains("feature-alpha"),
             "table should contain feature-alpha, got: {table_output}"
@@ -1432,7 +2484,7 @@ mod tests {
             "table should contain feature-beta, got: {table_output}"
         );
 
-        let json_output = execute_json(repo_dir.path(), &db, None, &scan_paths)
+        let json_output = execute_json(repo_dir.path(), &db, None, &scan_paths, None)
             .expect("json with scan paths should succeed");
         let parsed: serde_json::Value = serde_json::from_str(&json_output).unwrap();
         let items = parsed.as_array().unwrap();
@@ -1451,8 +2503,15 @@ mod tests {
         assert!(beta.get("managed").is_none());
 
         // Porcelain output should include scanned worktrees
-        let porcelain_output = execute_porcelain(repo_dir.path(), &db, None, &scan_paths)
-            .expect("porcelain with scan paths should succeed");
+        let porcelain_output = execute_porcelain(
+            repo_dir.path(),
+            &db,
+            None,
+            &scan_paths,
+            None,
+            PorcelainVersion::V1,
+        )
+        .expect("porcelain with scan paths should succeed");
         assert!(
             porcelain_output.contains("feature-alpha"),
             "porcelain should contain feature-alpha"
@@ -1478,13 +2537,13 @@ mod tests {
         // Create a worktree in a scan dir — this is ALSO known to git
         let scan_dir = tempfile::tempdir().unwrap();
         let wt_path = scan_dir.path().join("known-wt");
-        git::create_worktree(repo_dir.path(), "known-wt", &base, &wt_path)
+        git::create_named_worktree(repo_dir.path(), "known-wt", &base, &wt_path, "known-wt")
             .expect("create known-wt");
 
         let scan_paths = vec![scan_dir.path().to_string_lossy().into_owned()];
 
-        let json_output =
-            execute_json(repo_dir.path(), &db, None, &scan_paths).expect("json should succeed");
+        let json_output = execute_json(repo_dir.path(), &db, None, &scan_paths, None)
+            .expect("json should succeed");
         let parsed: serde_json::Value = serde_json::from_str(&json_output).unwrap();
         let items = parsed.as_array().unwrap();
 
@@ -1502,8 +2561,16 @@ mod tests {
         let _repo = init_repo_with_commit(repo_dir.path());
         let db = Database::open_in_memory().unwrap();
 
-        let output =
-            render_table(repo_dir.path(), &db, None, None, &[]).expect("list should succeed");
+        let output = render_table(
+            repo_dir.path(),
+            &db,
+            None,
+            None,
+            &[],
+            None,
+            &PathStyle::Full,
+        )
+        .expect("list should succeed");
 
         assert!(
             output.contains("Procs"),
@@ -1517,7 +2584,7 @@ mod tests {
         let _repo = init_repo_with_commit(repo_dir.path());
         let db = Database::open_in_memory().unwrap();
 
-        let json_output = execute_json(repo_dir.path(), &db, None, &[]).unwrap();
+        let json_output = execute_json(repo_dir.path(), &db, None, &[], None).unwrap();
         let parsed: serde_json::Value = serde_json::from_str(&json_output).unwrap();
 
         let worktrees = parsed.as_array().expect("should be an array");
@@ -1553,7 +2620,15 @@ mod tests {
         let scan_paths = vec!["/nonexistent/scan/path/xyz".to_string()];
 
         // Should not error — non-existent paths are warnings
-        let result = render_table(repo_dir.path(), &db, None, None, &scan_paths);
+        let result = render_table(
+            repo_dir.path(),
+            &db,
+            None,
+            None,
+            &scan_paths,
+            None,
+            &PathStyle::Full,
+        );
         assert!(
             result.is_ok(),
             "non-existent scan path should not cause error"
@@ -1,9 +1,9 @@
 use anyhow::Result;
 use serde::Serialize;
 
-use crate::output::json::{format_json, format_json_value};
-use crate::output::table::Table;
-use crate::state::{Database, LogEntry};
+use crate::output::json::{format_json, format_json_value, format_jsonl};
+use crate::output::table::{Align, Table};
+use crate::state::{Database, EventFilter, LogEntry};
 
 /// Extract duration_secs from a LogEntry's JSON payload, if present.
 fn extract_duration(entry: &LogEntry) -> Option<f64> {
@@ -16,7 +16,7 @@ fn extract_exit_code(entry: &LogEntry) -> Option<i64> {
 }
 
 /// Format a Unix timestamp as a human-readable datetime string.
-fn format_timestamp(ts: i64) -> String {
+pub(crate) fn format_timestamp(ts: i64) -> String {
     let days = ts.div_euclid(86400);
     let time_of_day = ts.rem_euclid(86400);
     let hours = time_of_day / 3600;
@@ -47,14 +47,109 @@ fn days_to_ymd(days: i64) -> (i64, i64, i64) {
     (y, m as i64, d as i64)
 }
 
+/// Convert a civil date to days since Unix epoch (inverse of `days_to_ymd`).
+fn days_from_ymd(year: i64, month: i64, day: i64) -> i64 {
+    // Algorithm from Howard Hinnant's days_from_civil
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = y - era * 400;
+    let mp = if month > 2 { month - 3 } else { month + 9 };
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+/// Parse a `--since`/`--until` bound into unix seconds.
+///
+/// Accepts relative durations counted back from `now` (`30s`, `15m`, `24h`,
+/// `7d`, `2w`), RFC 3339 timestamps (`2024-05-01T12:00:00Z`,
+/// `2024-05-01T12:00:00+02:00`), and bare dates (`2024-05-01`, midnight UTC).
+pub fn parse_time_bound(input: &str, now: i64) -> Result<i64> {
+    let input = input.trim();
+    if let Some(secs) = parse_relative_duration(input) {
+        return Ok(now - secs);
+    }
+    parse_rfc3339(input).ok_or_else(|| {
+        anyhow::anyhow!(
+            "invalid time '{input}': expected RFC 3339 (e.g. 2024-05-01T12:00:00Z), \
+             a date (2024-05-01), or a relative duration (e.g. 7d, 24h)"
+        )
+    })
+}
+
+/// Parse `<N><unit>` where unit is one of `s`, `m`, `h`, `d`, `w`.
+fn parse_relative_duration(input: &str) -> Option<i64> {
+    let unit = input.chars().last()?;
+    let multiplier = match unit {
+        's' => 1,
+        'm' => 60,
+        'h' => 3600,
+        'd' => 86400,
+        'w' => 7 * 86400,
+        _ => return None,
+    };
+    let digits = &input[..input.len() - 1];
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse::<i64>().ok()?.checked_mul(multiplier)
+}
+
+fn parse_rfc3339(input: &str) -> Option<i64> {
+    let num = |s: &str| -> Option<i64> {
+        if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        s.parse().ok()
+    };
+
+    let (date, time) = match input.find(['T', 't', ' ']) {
+        Some(i) => (&input[..i], Some(&input[i + 1..])),
+        None => (input, None),
+    };
+
+    let mut parts = date.split('-');
+    let (year, month, day) = (
+        num(parts.next()?)?,
+        num(parts.next()?)?,
+        num(parts.next()?)?,
+    );
+    if parts.next().is_some() || !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    let mut ts = days_from_ymd(year, month, day) * 86400;
+
+    if let Some(time) = time {
+        let (clock, offset) = if let Some(clock) = time.strip_suffix(['Z', 'z']) {
+            (clock, 0)
+        } else {
+            let i = time.rfind(['+', '-'])?;
+            let sign = if time.as_bytes()[i] == b'+' { 1 } else { -1 };
+            let (oh, om) = time[i + 1..].split_once(':')?;
+            (&time[..i], sign * (num(oh)? * 3600 + num(om)? * 60))
+        };
+        // Fractional seconds are accepted but ignored.
+        let clock = clock.split('.').next()?;
+        let mut hms = clock.split(':');
+        let (h, m, sec) = (num(hms.next()?)?, num(hms.next()?)?, num(hms.next()?)?);
+        if hms.next().is_some() || h > 23 || m > 59 || sec > 60 {
+            return None;
+        }
+        ts += h * 3600 + m * 60 + sec - offset;
+    }
+
+    Some(ts)
+}
+
 pub fn execute(
     db: &Database,
     repo_id: i64,
     use_color: bool,
     worktree: Option<&str>,
     tail: Option<usize>,
+    filter: &EventFilter,
 ) -> Result<String> {
-    let entries = db.list_events_filtered(repo_id, worktree, tail)?;
+    let entries = db.list_events_matching(repo_id, worktree, tail, filter)?;
 
     if entries.is_empty() {
         return Ok("No events.\n".to_string());
@@ -319,8 +414,9 @@ pub fn execute_summary(
     repo_id: i64,
     worktree: Option<&str>,
     tail: Option<usize>,
+    filter: &EventFilter,
 ) -> Result<String> {
-    let entries = db.list_events_filtered(repo_id, worktree, tail)?;
+    let entries = db.list_events_matching(repo_id, worktree, tail, filter)?;
 
     if entries.is_empty() {
         return Ok("No events recorded yet.\n".to_string());
@@ -352,6 +448,39 @@ pub fn execute_summary(
     Ok(out)
 }
 
+/// Execute `trench log --stats`: a table of event counts per type, only
+/// counting events at or after `since` when given.
+pub fn execute_stats(db: &Database, repo_id: i64, since: Option<i64>) -> Result<String> {
+    let counts = db.event_counts_by_type(repo_id, since)?;
+    if counts.is_empty() {
+        return Ok("No events.\n".to_string());
+    }
+
+    let count_strs: Vec<String> = counts.iter().map(|(_, n)| n.to_string()).collect();
+    let total: i64 = counts.iter().map(|(_, n)| n).sum();
+    let mut table = Table::new(vec!["Type", "Count"]).align(1, Align::Right);
+    for ((event_type, _), count) in counts.iter().zip(&count_strs) {
+        table = table.row(vec![event_type, count]);
+    }
+    Ok(format!("{}\nTotal: {total}\n", table.render()))
+}
+
+#[derive(Serialize)]
+struct EventCountJson {
+    event_type: String,
+    count: i64,
+}
+
+/// JSON output for `trench log --stats`: `[{"event_type", "count"}, ...]`.
+pub fn execute_stats_json(db: &Database, repo_id: i64, since: Option<i64>) -> Result<String> {
+    let counts: Vec<EventCountJson> = db
+        .event_counts_by_type(repo_id, since)?
+        .into_iter()
+        .map(|(event_type, count)| EventCountJson { event_type, count })
+        .collect();
+    format_json(&counts)
+}
+
 #[derive(Serialize)]
 struct SummaryJson {
     total_events: usize,
@@ -374,8 +503,9 @@ pub fn execute_summary_json(
     repo_id: i64,
     worktree: Option<&str>,
     tail: Option<usize>,
+    filter: &EventFilter,
 ) -> Result<String> {
-    let entries = db.list_events_filtered(repo_id, worktree, tail)?;
+    let entries = db.list_events_matching(repo_id, worktree, tail, filter)?;
     let stats = compute_summary(&entries);
 
     let summary = SummaryJson {
@@ -398,12 +528,26 @@ pub fn execute_json(
     repo_id: i64,
     worktree: Option<&str>,
     tail: Option<usize>,
+    filter: &EventFilter,
 ) -> Result<String> {
-    let entries = db.list_events_filtered(repo_id, worktree, tail)?;
+    let entries = db.list_events_matching(repo_id, worktree, tail, filter)?;
     let json_entries: Vec<LogEntryJson> = entries.iter().map(to_json_entry).collect();
     format_json(&json_entries)
 }
 
+/// Execute `trench log --jsonl`: one compact event object per line.
+pub fn execute_jsonl(
+    db: &Database,
+    repo_id: i64,
+    worktree: Option<&str>,
+    tail: Option<usize>,
+    filter: &EventFilter,
+) -> Result<String> {
+    let entries = db.list_events_matching(repo_id, worktree, tail, filter)?;
+    let json_entries: Vec<LogEntryJson> = entries.iter().map(to_json_entry).collect();
+    format_jsonl(&json_entries)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -413,13 +557,47 @@ mod tests {
         let db = Database::open_in_memory().unwrap();
         let repo = db.insert_repo("r", "/r", None).unwrap();
 
-        let output = execute_summary(&db, repo.id, None, None).unwrap();
+        let output = execute_summary(&db, repo.id, None, None, &EventFilter::default()).unwrap();
         assert!(
             output.contains("No events"),
             "should indicate no events: {output}"
         );
     }
 
+    #[test]
+    fn execute_stats_renders_counts_per_event_type() {
+        let db = Database::open_in_memory().unwrap();
+        let repo = db.insert_repo("r", "/r", None).unwrap();
+        for event_type in ["created", "synced", "created", "hook_failed", "created"] {
+            db.insert_event(repo.id, None, event_type, None).unwrap();
+        }
+
+        let text = execute_stats(&db, repo.id, None).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert!(lines[0].starts_with("Type"), "{text}");
+        let created = lines.iter().position(|l| l.starts_with("created")).unwrap();
+        let synced = lines.iter().position(|l| l.starts_with("synced")).unwrap();
+        assert!(created < synced, "highest count first: {text}");
+        assert!(lines[created].trim_end().ends_with('3'), "{text}");
+        assert!(text.ends_with("Total: 5\n"), "{text}");
+
+        let json: serde_json::Value =
+            serde_json::from_str(&execute_stats_json(&db, repo.id, None).unwrap()).unwrap();
+        assert_eq!(json[0]["event_type"], "created");
+        assert_eq!(json[0]["count"], 3);
+        assert_eq!(json.as_array().unwrap().len(), 3);
+
+        let future = crate::state::unix_epoch_secs() as i64 + 3600;
+        assert_eq!(
+            execute_stats(&db, repo.id, Some(future)).unwrap(),
+            "No events.\n"
+        );
+        assert_eq!(
+            execute_stats_json(&db, repo.id, Some(future)).unwrap(),
+            "[]"
+        );
+    }
+
     #[test]
     fn execute_summary_computes_correct_aggregate_stats() {
         let db = Database::open_in_memory().unwrap();
@@ -461,7 +639,7 @@ mod tests {
         db.insert_event(repo.id, Some(wt_b.id), "created", None)
             .unwrap();
 
-        let output = execute_summary(&db, repo.id, None, None).unwrap();
+        let output = execute_summary(&db, repo.id, None, None, &EventFilter::default()).unwrap();
 
         // Total events: 6 (2 plain + 3 hooks + 1 plain)
         assert!(
@@ -499,7 +677,8 @@ mod tests {
         let db = Database::open_in_memory().unwrap();
         let repo = db.insert_repo("r", "/r", None).unwrap();
 
-        let output = execute_summary_json(&db, repo.id, None, None).unwrap();
+        let output =
+            execute_summary_json(&db, repo.id, None, None, &EventFilter::default()).unwrap();
         let parsed: serde_json::Value = serde_json::from_str(&output).expect("valid JSON");
 
         assert_eq!(parsed["total_events"], 0);
@@ -551,7 +730,8 @@ mod tests {
         db.insert_event(repo.id, Some(wt_b.id), "created", None)
             .unwrap();
 
-        let output = execute_summary_json(&db, repo.id, None, None).unwrap();
+        let output =
+            execute_summary_json(&db, repo.id, None, None, &EventFilter::default()).unwrap();
         let parsed: serde_json::Value = serde_json::from_str(&output).expect("valid JSON");
 
         assert_eq!(parsed["total_events"], 6);
@@ -595,7 +775,8 @@ mod tests {
             .unwrap();
 
         // Filter to alpha only
-        let output = execute_summary(&db, repo.id, Some("alpha"), None).unwrap();
+        let output =
+            execute_summary(&db, repo.id, Some("alpha"), None, &EventFilter::default()).unwrap();
         assert!(
             output.contains("Total events:       3"),
             "should show 3 alpha events: {output}"
@@ -619,7 +800,8 @@ mod tests {
                 .unwrap();
         }
 
-        let output = execute_summary_json(&db, repo.id, None, Some(2)).unwrap();
+        let output =
+            execute_summary_json(&db, repo.id, None, Some(2), &EventFilter::default()).unwrap();
         let parsed: serde_json::Value = serde_json::from_str(&output).expect("valid JSON");
         assert_eq!(parsed["total_events"], 2, "tail=2 should limit to 2 events");
     }
@@ -769,7 +951,7 @@ mod tests {
         let db = Database::open_in_memory().unwrap();
         let repo = db.insert_repo("r", "/r", None).unwrap();
 
-        let output = execute(&db, repo.id, false, None, None).unwrap();
+        let output = execute(&db, repo.id, false, None, None, &EventFilter::default()).unwrap();
         assert_eq!(output, "No events.\n");
     }
 
@@ -789,7 +971,7 @@ mod tests {
         db.insert_event(repo.id, Some(wt.id), "created", None)
             .unwrap();
 
-        let output = execute(&db, repo.id, false, None, None).unwrap();
+        let output = execute(&db, repo.id, false, None, None, &EventFilter::default()).unwrap();
 
         // Should have headers
         assert!(output.contains("Timestamp"), "should show Timestamp header");
@@ -819,7 +1001,7 @@ mod tests {
         db.insert_event(repo.id, Some(wt.id), "created", None)
             .unwrap();
 
-        let output = execute(&db, repo.id, false, None, None).unwrap();
+        let output = execute(&db, repo.id, false, None, None, &EventFilter::default()).unwrap();
         assert!(
             !output.contains("\x1b"),
             "no-color output must not contain ANSI escapes"
@@ -838,7 +1020,7 @@ mod tests {
         db.insert_event(repo.id, Some(wt.id), "hook:post_create", Some(&payload))
             .unwrap();
 
-        let output = execute(&db, repo.id, true, None, None).unwrap();
+        let output = execute(&db, repo.id, true, None, None, &EventFilter::default()).unwrap();
         assert!(
             output.contains("\x1b[32m"),
             "success events should be green"
@@ -857,7 +1039,7 @@ mod tests {
         db.insert_event(repo.id, Some(wt.id), "hook:pre_create", Some(&payload))
             .unwrap();
 
-        let output = execute(&db, repo.id, true, None, None).unwrap();
+        let output = execute(&db, repo.id, true, None, None, &EventFilter::default()).unwrap();
         assert!(output.contains("\x1b[31m"), "failure events should be red");
     }
 
@@ -875,7 +1057,7 @@ mod tests {
         db.insert_event(repo.id, Some(wt.id), "created", None)
             .unwrap();
 
-        let output = execute_json(&db, repo.id, None, None).unwrap();
+        let output = execute_json(&db, repo.id, None, None, &EventFilter::default()).unwrap();
         let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
         let arr = parsed.as_array().expect("should be array");
 
@@ -901,7 +1083,7 @@ mod tests {
         let db = Database::open_in_memory().unwrap();
         let repo = db.insert_repo("r", "/r", None).unwrap();
 
-        let output = execute_json(&db, repo.id, None, None).unwrap();
+        let output = execute_json(&db, repo.id, None, None, &EventFilter::default()).unwrap();
         assert_eq!(output, "[]");
     }
 
@@ -917,7 +1099,7 @@ mod tests {
                 .unwrap();
         }
 
-        let output = execute(&db, repo.id, false, None, Some(2)).unwrap();
+        let output = execute(&db, repo.id, false, None, Some(2), &EventFilter::default()).unwrap();
         // Header + 2 data rows
         let data_lines: Vec<&str> = output.lines().skip(1).filter(|l| !l.is_empty()).collect();
         assert_eq!(data_lines.len(), 2, "should only show 2 events");
@@ -941,7 +1123,8 @@ mod tests {
         db.insert_event(repo.id, Some(wt_b.id), "created", None)
             .unwrap();
 
-        let output = execute_json(&db, repo.id, Some("alpha"), None).unwrap();
+        let output =
+            execute_json(&db, repo.id, Some("alpha"), None, &EventFilter::default()).unwrap();
         let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
         let arr = parsed.as_array().unwrap();
         assert_eq!(arr.len(), 2, "should only show alpha's 2 events");
@@ -987,6 +1170,47 @@ mod tests {
         assert_eq!(extract_exit_code(&entry), None);
     }
 
+    #[test]
+    fn parse_time_bound_relative_durations() {
+        let now = 1_000_000;
+        assert_eq!(parse_time_bound("7d", now).unwrap(), now - 7 * 86400);
+        assert_eq!(parse_time_bound("24h", now).unwrap(), now - 24 * 3600);
+        assert_eq!(parse_time_bound("15m", now).unwrap(), now - 900);
+        assert_eq!(parse_time_bound("30s", now).unwrap(), now - 30);
+        assert_eq!(parse_time_bound("2w", now).unwrap(), now - 14 * 86400);
+        assert!(parse_time_bound("d", now).is_err());
+        assert!(parse_time_bound("-3d", now).is_err());
+        assert!(parse_time_bound("3y", now).is_err());
+    }
+
+    #[test]
+    fn parse_time_bound_rfc3339_and_dates() {
+        assert_eq!(parse_time_bound("1970-01-01T00:00:00Z", 0).unwrap(), 0);
+        assert_eq!(
+            parse_time_bound("2024-05-01T12:30:15Z", 0).unwrap(),
+            1_714_566_615
+        );
+        assert_eq!(
+            parse_time_bound("2024-05-01T14:30:15+02:00", 0).unwrap(),
+            1_714_566_615
+        );
+        assert_eq!(
+            parse_time_bound("2024-05-01T12:30:15.250Z", 0).unwrap(),
+            1_714_566_615
+        );
+        assert_eq!(parse_time_bound("2024-05-01", 0).unwrap(), 1_714_521_600);
+        assert!(parse_time_bound("2024-13-01", 0).is_err());
+        assert!(parse_time_bound("yesterday", 0).is_err());
+    }
+
+    #[test]
+    fn days_from_ymd_round_trips_days_to_ymd() {
+        for days in [-800_000, -1, 0, 59, 11_016, 19_844, 2_932_896] {
+            let (y, m, d) = days_to_ymd(days);
+            assert_eq!(days_from_ymd(y, m, d), days);
+        }
+    }
+
     #[test]
     fn format_timestamp_produces_valid_datetime() {
         // 2023-11-14 22:13:20 UTC
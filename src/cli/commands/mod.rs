@@ -1,12 +1,23 @@
+pub mod clone;
 pub mod completions;
+pub mod config;
 pub mod create;
+pub mod db;
+pub mod env;
+pub mod info;
 pub mod init;
 pub mod list;
 pub mod log;
 pub mod open;
+pub mod path;
+pub mod reattach;
+pub mod relink;
 pub mod remove;
+pub mod set_base;
 pub mod shell_init;
 pub mod status;
 pub mod switch;
 pub mod sync;
 pub mod tag;
+pub mod url;
+pub mod which;
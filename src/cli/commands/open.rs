@@ -16,7 +16,7 @@ pub struct OpenResult {
 }
 
 /// Resolve the editor command from the fallback chain:
-/// config override → $EDITOR → $VISUAL → error.
+/// config override (`open_cmd`, then `[editor] command`) → $EDITOR → $VISUAL → error.
 fn resolve_editor(config_editor: Option<&str>) -> Result<String> {
     if let Some(cmd) = config_editor.map(str::trim).filter(|s| !s.is_empty()) {
         return Ok(cmd.to_string());
@@ -38,6 +38,29 @@ fn resolve_editor(config_editor: Option<&str>) -> Result<String> {
     )
 }
 
+/// Placeholder in an open command that is replaced by the worktree path.
+pub const PATH_PLACEHOLDER: &str = "{path}";
+
+/// Tokenize an open/editor command and insert the worktree path.
+///
+/// Every `{path}` occurrence is replaced by `path`; when the command has no
+/// placeholder the path is appended as the final argument.
+pub fn build_open_command(command: &str, path: &str) -> Result<Vec<String>> {
+    let mut parts = shell_words::split(command)
+        .map_err(|e| anyhow::anyhow!("invalid editor command '{command}': {e}"))?;
+    if parts.is_empty() {
+        anyhow::bail!("editor command is empty after parsing");
+    }
+    if parts.iter().any(|p| p.contains(PATH_PLACEHOLDER)) {
+        for part in &mut parts {
+            *part = part.replace(PATH_PLACEHOLDER, path);
+        }
+    } else {
+        parts.push(path.to_string());
+    }
+    Ok(parts)
+}
+
 /// Resolve the worktree and editor for `trench open <identifier>`.
 ///
 /// Does NOT launch the editor — returns the resolved information so the
@@ -139,6 +162,7 @@ mod tests {
             repo_dir,
             wt_root.path(),
             crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             db,
         )
         .expect("create should succeed");
@@ -223,6 +247,35 @@ mod tests {
         assert_eq!(result.editor, "code", "config should override env vars");
     }
 
+    #[test]
+    fn build_open_command_appends_path_without_placeholder() {
+        let argv = build_open_command("code --new-window", "/wt/my feature").unwrap();
+        assert_eq!(argv, vec!["code", "--new-window", "/wt/my feature"]);
+    }
+
+    #[test]
+    fn build_open_command_substitutes_path_placeholder() {
+        let argv =
+            build_open_command("tmux new-window -c {path} 'nvim .'", "/wt/my-feature").unwrap();
+        assert_eq!(
+            argv,
+            vec!["tmux", "new-window", "-c", "/wt/my-feature", "nvim ."]
+        );
+
+        let argv = build_open_command("idea --dir={path}", "/wt/x").unwrap();
+        assert_eq!(
+            argv,
+            vec!["idea", "--dir=/wt/x"],
+            "path must not be appended"
+        );
+    }
+
+    #[test]
+    fn build_open_command_rejects_empty_and_unbalanced_quotes() {
+        assert!(build_open_command("   ", "/wt/x").is_err());
+        assert!(build_open_command("code 'unterminated", "/wt/x").is_err());
+    }
+
     #[test]
     fn resolve_not_found_returns_error() {
         let repo_dir = tempfile::tempdir().unwrap();
@@ -307,7 +360,8 @@ mod tests {
         let wt_dir = tempfile::tempdir().unwrap();
         let wt_path = wt_dir.path().join("git-only");
 
-        crate::git::create_worktree(repo_dir.path(), "git-only", &base, &wt_path).unwrap();
+        crate::git::create_named_worktree(repo_dir.path(), "git-only", &base, &wt_path, "git-only")
+            .unwrap();
 
         let result = resolve("git-only", repo_dir.path(), &db, Some("code")).unwrap();
         assert_eq!(
@@ -333,7 +387,8 @@ mod tests {
         let wt_dir = tempfile::tempdir().unwrap();
         let wt_path = wt_dir.path().join("git-only");
 
-        crate::git::create_worktree(repo_dir.path(), "git-only", &base, &wt_path).unwrap();
+        crate::git::create_named_worktree(repo_dir.path(), "git-only", &base, &wt_path, "git-only")
+            .unwrap();
 
         record_open_for_identifier("git-only", repo_dir.path(), &db).unwrap();
 
@@ -0,0 +1,127 @@
+use std::path::Path;
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::git;
+use crate::output::json::format_json_value;
+use crate::paths;
+
+/// Where `trench create` would place a worktree, emitted as-is under `--json`.
+#[derive(Debug, Serialize)]
+pub struct PathPreview {
+    pub branch: String,
+    /// The path template that was rendered.
+    pub template: String,
+    pub path: String,
+}
+
+/// Render `template` for `branch` in the current repository without touching
+/// the filesystem, git, or the database.
+fn preview(cwd: &Path, worktree_root: &Path, template: &str, branch: &str) -> Result<PathPreview> {
+    paths::validate_branch_name(branch).map_err(anyhow::Error::msg)?;
+    let repo_info = git::discover_repo(cwd)?;
+    let path = paths::resolve_worktree_path(worktree_root, template, &repo_info.name, branch)?;
+    Ok(PathPreview {
+        branch: branch.to_string(),
+        template: template.to_string(),
+        path: path.to_string_lossy().into_owned(),
+    })
+}
+
+/// Execute `trench path <branch>`: the absolute path a new worktree would get.
+pub fn execute(cwd: &Path, worktree_root: &Path, template: &str, branch: &str) -> Result<String> {
+    Ok(preview(cwd, worktree_root, template, branch)?.path)
+}
+
+/// Execute `trench path <branch> --json`.
+pub fn execute_json(
+    cwd: &Path,
+    worktree_root: &Path,
+    template: &str,
+    branch: &str,
+) -> Result<String> {
+    format_json_value(&serde_json::to_value(preview(
+        cwd,
+        worktree_root,
+        template,
+        branch,
+    )?)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn init_repo_with_commit(dir: &Path) {
+        let repo = git2::Repository::init(dir).expect("failed to init repo");
+        let sig = git2::Signature::now("Test", "test@test.com").unwrap();
+        let tree_id = repo.index().unwrap().write_tree().unwrap();
+        let tree = repo.find_tree(tree_id).unwrap();
+        repo.commit(Some("HEAD"), &sig, &sig, "initial commit", &tree, &[])
+            .unwrap();
+    }
+
+    #[test]
+    fn renders_template_under_worktree_root() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        init_repo_with_commit(repo_dir.path());
+        let repo_name = git::discover_repo(repo_dir.path()).unwrap().name;
+        let root = Path::new("/srv/worktrees");
+
+        let path = execute(
+            repo_dir.path(),
+            root,
+            paths::DEFAULT_WORKTREE_TEMPLATE,
+            "feature/login",
+        )
+        .unwrap();
+
+        assert_eq!(
+            Path::new(&path),
+            root.join(&repo_name).join("feature-login")
+        );
+        assert!(!root.exists(), "preview must not create anything");
+    }
+
+    #[test]
+    fn json_reports_branch_template_and_absolute_render() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        init_repo_with_commit(repo_dir.path());
+        let repo_name = git::discover_repo(repo_dir.path()).unwrap().name;
+
+        let json: serde_json::Value = serde_json::from_str(
+            &execute_json(
+                repo_dir.path(),
+                Path::new("/unused"),
+                "/mnt/wt/{{ repo }}-{{ branch | sanitize }}",
+                "fix/bug",
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(json["branch"], "fix/bug");
+        assert_eq!(
+            json["template"],
+            "/mnt/wt/{{ repo }}-{{ branch | sanitize }}"
+        );
+        assert_eq!(json["path"], format!("/mnt/wt/{repo_name}-fix-bug"));
+    }
+
+    #[test]
+    fn rejects_invalid_branch_names() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        init_repo_with_commit(repo_dir.path());
+
+        let err = execute(
+            repo_dir.path(),
+            Path::new("/unused"),
+            paths::DEFAULT_WORKTREE_TEMPLATE,
+            "bad..name",
+        )
+        .unwrap_err();
+
+        assert!(err.to_string().contains(".."), "got: {err}");
+    }
+}
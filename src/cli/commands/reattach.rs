@@ -0,0 +1,212 @@
+use std::path::Path;
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::git;
+use crate::paths;
+use crate::state::Database;
+
+/// A worktree that `trench reattach` restored (or would restore) to the DB.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ReattachedWorktree {
+    pub name: String,
+    pub branch: String,
+    pub path: String,
+}
+
+/// Outcome of `trench reattach`, shared by the human and JSON renderers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ReattachResult {
+    pub reattached: Vec<ReattachedWorktree>,
+    pub dry_run: bool,
+}
+
+impl std::fmt::Display for ReattachResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let verb = if self.dry_run {
+            "Would reattach"
+        } else {
+            "Reattached"
+        };
+        for wt in &self.reattached {
+            writeln!(f, "{verb} '{}' ({}) at {}", wt.name, wt.branch, wt.path)?;
+        }
+        writeln!(f, "{verb} {} worktree(s).", self.reattached.len())
+    }
+}
+
+/// Name trench would have given a worktree on `branch` whose git admin
+/// directory is `git_name`.
+///
+/// `create` names the git worktree after the trench name (including
+/// `--name` overrides), so a valid git name is reused; anything else falls
+/// back to the sanitized branch.
+fn infer_name(git_name: &str, branch: &str) -> String {
+    if paths::validate_worktree_name(git_name).is_ok() {
+        git_name.to_string()
+    } else {
+        paths::sanitize_branch(branch)
+    }
+}
+
+/// Execute the `trench reattach` command.
+///
+/// Re-inserts managed rows for linked worktrees of the current repository
+/// that live under `worktree_root` but have no active DB record, e.g. after
+/// the database was reset. Worktrees elsewhere on disk, detached checkouts,
+/// and already-tracked worktrees are left alone. With `dry_run`, only
+/// reports what would be reattached.
+pub fn execute(
+    cwd: &Path,
+    db: &Database,
+    worktree_root: &Path,
+    dry_run: bool,
+) -> Result<ReattachResult> {
+    let repo_info = git::discover_repo(cwd)?;
+    let repo_path = repo_info
+        .path
+        .to_str()
+        .ok_or_else(|| anyhow::anyhow!("repo path is not valid UTF-8"))?;
+    let root = worktree_root
+        .canonicalize()
+        .unwrap_or_else(|_| worktree_root.to_path_buf());
+
+    let repo = db.get_repo_by_path(repo_path)?;
+    let mut reattached = Vec::new();
+    for gw in git::list_worktrees(&repo_info.path)? {
+        let Some(branch) = gw.branch.as_deref() else {
+            continue;
+        };
+        if gw.is_main || !gw.path.starts_with(&root) {
+            continue;
+        }
+        let path = gw.path.to_string_lossy().into_owned();
+        if let Some(repo) = &repo {
+            if db.find_worktree_by_path(repo.id, &path)?.is_some()
+                || db.find_worktree_by_branch(repo.id, branch)?.is_some()
+            {
+                continue;
+            }
+        }
+        reattached.push(ReattachedWorktree {
+            name: infer_name(&gw.name, branch),
+            branch: branch.to_string(),
+            path,
+        });
+    }
+
+    if !dry_run && !reattached.is_empty() {
+        let repo = match repo {
+            Some(repo) => repo,
+            None => db.upsert_repo(&repo_info.name, repo_path, Some(&repo_info.default_branch))?,
+        };
+        for wt in &reattached {
+            db.insert_worktree(repo.id, &wt.name, &wt.branch, &wt.path, None)?;
+        }
+    }
+
+    Ok(ReattachResult {
+        reattached,
+        dry_run,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn init_repo_with_commit(dir: &Path) {
+        let repo = git2::Repository::init(dir).expect("failed to init repo");
+        let sig = git2::Signature::now("Test", "test@test.com").unwrap();
+        let tree_id = repo.index().unwrap().write_tree().unwrap();
+        let tree = repo.find_tree(tree_id).unwrap();
+        repo.commit(Some("HEAD"), &sig, &sig, "initial commit", &tree, &[])
+            .unwrap();
+    }
+
+    #[test]
+    fn reattach_restores_managed_rows_after_db_reset() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        init_repo_with_commit(repo_dir.path());
+        let wt_root = tempfile::tempdir().unwrap();
+        let old_db = Database::open_in_memory().unwrap();
+        let created = crate::cli::commands::create::execute(
+            "feature/reattach",
+            None,
+            repo_dir.path(),
+            wt_root.path(),
+            paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
+            &old_db,
+        )
+        .unwrap();
+
+        // A fresh database stands in for one that was reset.
+        let db = Database::open_in_memory().unwrap();
+        let result = execute(repo_dir.path(), &db, wt_root.path(), false).unwrap();
+
+        assert_eq!(result.reattached.len(), 1, "{result}");
+        assert_eq!(result.reattached[0].name, "feature-reattach");
+        assert_eq!(result.reattached[0].branch, "feature/reattach");
+        let repo_path = repo_dir.path().canonicalize().unwrap();
+        let repo = db
+            .get_repo_by_path(repo_path.to_str().unwrap())
+            .unwrap()
+            .unwrap();
+        let wt = db
+            .find_worktree_by_identifier(repo.id, "feature-reattach")
+            .unwrap()
+            .expect("worktree should be tracked again");
+        assert!(wt.managed);
+        assert!(wt.adopted_at.is_none(), "reattached rows are not adoptions");
+        assert_eq!(
+            Path::new(&wt.path).canonicalize().unwrap(),
+            created.path.canonicalize().unwrap()
+        );
+
+        // Running again finds nothing left to reattach.
+        let again = execute(repo_dir.path(), &db, wt_root.path(), false).unwrap();
+        assert!(again.reattached.is_empty());
+    }
+
+    #[test]
+    fn reattach_ignores_worktrees_outside_root_and_dry_run_writes_nothing() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        init_repo_with_commit(repo_dir.path());
+        let wt_root = tempfile::tempdir().unwrap();
+        let elsewhere = tempfile::tempdir().unwrap();
+        let scratch = Database::open_in_memory().unwrap();
+        for (branch, root) in [("inside", wt_root.path()), ("outside", elsewhere.path())] {
+            crate::cli::commands::create::execute(
+                branch,
+                None,
+                repo_dir.path(),
+                root,
+                paths::DEFAULT_WORKTREE_TEMPLATE,
+                None,
+                &scratch,
+            )
+            .unwrap();
+        }
+
+        let db = Database::open_in_memory().unwrap();
+        let result = execute(repo_dir.path(), &db, wt_root.path(), true).unwrap();
+
+        let branches: Vec<&str> = result
+            .reattached
+            .iter()
+            .map(|wt| wt.branch.as_str())
+            .collect();
+        assert_eq!(branches, ["inside"]);
+        assert!(result.to_string().contains("Would reattach 1 worktree(s)."));
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["dry_run"], true);
+        assert_eq!(json["reattached"][0]["name"], "inside");
+        assert_eq!(json["reattached"][0]["branch"], "inside");
+        assert!(
+            db.list_repos().unwrap().is_empty(),
+            "dry run must not write"
+        );
+    }
+}
@@ -0,0 +1,264 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+use crate::git;
+use crate::state::{Database, Repo};
+
+/// Outcome of `trench relink`, shared by the human and JSON renderers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RelinkResult {
+    pub name: String,
+    pub old_path: String,
+    pub new_path: String,
+    pub worktrees_repaired: usize,
+    pub dry_run: bool,
+}
+
+impl std::fmt::Display for RelinkResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let verb = if self.dry_run {
+            "Would relink"
+        } else {
+            "Relinked"
+        };
+        writeln!(
+            f,
+            "{verb} '{}': {} -> {}",
+            self.name, self.old_path, self.new_path
+        )?;
+        if self.worktrees_repaired > 0 {
+            writeln!(f, "Repaired {} worktree link(s).", self.worktrees_repaired)?;
+        }
+        Ok(())
+    }
+}
+
+/// Find the tracked repo that a checkout with `remote_url` was moved from.
+///
+/// A candidate's recorded path must no longer exist on disk and its recorded
+/// origin URL must match. Errors when there is no candidate or more than one.
+pub fn find_moved_repo(db: &Database, remote_url: &str) -> Result<Repo> {
+    let mut candidates: Vec<Repo> = db
+        .list_repos()?
+        .into_iter()
+        .filter(|r| r.remote_url.as_deref() == Some(remote_url) && !Path::new(&r.path).exists())
+        .collect();
+
+    match candidates.len() {
+        0 => anyhow::bail!("no moved repository with remote '{remote_url}' found"),
+        1 => Ok(candidates.remove(0)),
+        _ => {
+            let paths: Vec<&str> = candidates.iter().map(|r| r.path.as_str()).collect();
+            anyhow::bail!(
+                "multiple moved repositories match remote '{remote_url}': {}",
+                paths.join(", ")
+            )
+        }
+    }
+}
+
+/// Execute the `trench relink` command.
+///
+/// Points the stored repo row at the repository containing `cwd`, renames it
+/// after the new directory, and repairs linked worktrees so they follow the
+/// move. The DB writes only commit once the repair succeeded. With
+/// `dry_run`, only reports which row would be relinked.
+pub fn execute(cwd: &Path, db: &Database, dry_run: bool) -> Result<RelinkResult> {
+    let repo_info = git::discover_repo(cwd)?;
+    let new_path = repo_info
+        .path
+        .to_str()
+        .ok_or_else(|| anyhow::anyhow!("repo path is not valid UTF-8"))?;
+
+    if db.get_repo_by_path(new_path)?.is_some() {
+        anyhow::bail!("repository at {new_path} is already tracked; nothing to relink");
+    }
+    let remote_url = repo_info.remote_url.as_deref().ok_or_else(|| {
+        anyhow::anyhow!("repository has no origin remote; cannot match it to a moved repository")
+    })?;
+
+    let repo = find_moved_repo(db, remote_url)?;
+    let mut result = RelinkResult {
+        name: repo.name.clone(),
+        old_path: repo.path.clone(),
+        new_path: new_path.to_string(),
+        worktrees_repaired: 0,
+        dry_run,
+    };
+    if dry_run {
+        return Ok(result);
+    }
+
+    result.worktrees_repaired = db.with_transaction(|db| {
+        db.update_repo_path(repo.id, new_path, &repo_info.name)?;
+        let payload = serde_json::json!({ "from": repo.path, "to": new_path });
+        db.insert_event(repo.id, None, "relinked", Some(&payload))?;
+        git::repair_worktree_links(&repo_info.path).context("failed to repair worktree links")
+    })?;
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REMOTE: &str = "https://example.com/acme/app.git";
+
+    /// Helper: init a repo with an initial commit and an origin remote.
+    fn init_repo_with_origin(dir: &Path) -> git2::Repository {
+        let repo = git2::Repository::init(dir).unwrap();
+        {
+            let sig = git2::Signature::now("Test", "test@test.com").unwrap();
+            let tree_id = repo.index().unwrap().write_tree().unwrap();
+            let tree = repo.find_tree(tree_id).unwrap();
+            repo.commit(Some("HEAD"), &sig, &sig, "initial commit", &tree, &[])
+                .unwrap();
+        }
+        repo.remote("origin", REMOTE).unwrap();
+        repo
+    }
+
+    #[test]
+    fn relink_updates_moved_repo_instead_of_orphaning_worktrees() {
+        let root = tempfile::tempdir().unwrap();
+        let old_path = root.path().join("old");
+        std::fs::create_dir_all(&old_path).unwrap();
+        init_repo_with_origin(&old_path);
+        let old_path = old_path.canonicalize().unwrap();
+        let wt_root = root.path().join("worktrees");
+        let db = Database::open_in_memory().unwrap();
+
+        let created = crate::cli::commands::create::execute(
+            "feature",
+            None,
+            &old_path,
+            &wt_root,
+            crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
+            &db,
+        )
+        .unwrap();
+        let repo = db
+            .get_repo_by_path(old_path.to_str().unwrap())
+            .unwrap()
+            .unwrap();
+        assert_eq!(repo.remote_url.as_deref(), Some(REMOTE));
+
+        let new_path = root.path().join("new");
+        std::fs::rename(&old_path, &new_path).unwrap();
+
+        let result = execute(&new_path, &db, false).unwrap();
+
+        let new_path = new_path.canonicalize().unwrap();
+        assert_eq!(result.old_path, old_path.to_str().unwrap());
+        assert_eq!(result.new_path, new_path.to_str().unwrap());
+        assert_eq!(result.worktrees_repaired, 1);
+
+        let relinked = db
+            .get_repo_by_path(new_path.to_str().unwrap())
+            .unwrap()
+            .expect("repo row should follow the move");
+        assert_eq!(relinked.id, repo.id);
+        assert_eq!(
+            relinked.name, "new",
+            "repo name should follow the directory"
+        );
+        assert_eq!(db.list_repos().unwrap().len(), 1, "no duplicate repo row");
+        let worktrees = db.list_worktrees(relinked.id).unwrap();
+        assert_eq!(worktrees.len(), 1);
+        assert!(git2::Repository::open(&created.path).is_ok());
+    }
+
+    #[test]
+    fn relink_leaves_row_untouched_when_a_write_fails() {
+        let root = tempfile::tempdir().unwrap();
+        let new_path = root.path().join("new");
+        std::fs::create_dir_all(&new_path).unwrap();
+        init_repo_with_origin(&new_path);
+        let db = Database::open_in_memory().unwrap();
+        let repo = db.insert_repo("old", "/gone/old", Some("main")).unwrap();
+        db.set_repo_remote_url(repo.id, Some(REMOTE)).unwrap();
+        db.conn_for_test()
+            .execute_batch(
+                "CREATE TRIGGER fail_event BEFORE INSERT ON events
+                 BEGIN SELECT RAISE(ABORT, 'injected failure'); END;",
+            )
+            .unwrap();
+
+        let err = execute(&new_path, &db, false).unwrap_err();
+
+        assert!(format!("{err:#}").contains("injected failure"), "{err:#}");
+        let stored = db.get_repo(repo.id).unwrap().unwrap();
+        assert_eq!(stored.path, "/gone/old");
+        assert_eq!(stored.name, "old");
+    }
+
+    #[test]
+    fn relink_dry_run_leaves_row_untouched() {
+        let root = tempfile::tempdir().unwrap();
+        let old_path = root.path().join("old");
+        std::fs::create_dir_all(&old_path).unwrap();
+        init_repo_with_origin(&old_path);
+        let db = Database::open_in_memory().unwrap();
+        let repo = db.insert_repo("app", "/gone/app", Some("main")).unwrap();
+        db.set_repo_remote_url(repo.id, Some(REMOTE)).unwrap();
+
+        let result = execute(&old_path, &db, true).unwrap();
+
+        assert!(result.dry_run);
+        assert_eq!(result.old_path, "/gone/app");
+        let stored = db.get_repo(repo.id).unwrap().unwrap();
+        assert_eq!(stored.path, "/gone/app");
+    }
+
+    #[test]
+    fn relink_ignores_repos_whose_path_still_exists() {
+        let root = tempfile::tempdir().unwrap();
+        let repo_path = root.path().join("repo");
+        std::fs::create_dir_all(&repo_path).unwrap();
+        init_repo_with_origin(&repo_path);
+        let elsewhere = tempfile::tempdir().unwrap();
+        let db = Database::open_in_memory().unwrap();
+        let other = db
+            .insert_repo("app", elsewhere.path().to_str().unwrap(), None)
+            .unwrap();
+        db.set_repo_remote_url(other.id, Some(REMOTE)).unwrap();
+
+        let err = execute(&repo_path, &db, false).unwrap_err();
+
+        assert!(
+            err.to_string().contains("no moved repository"),
+            "got: {err}"
+        );
+    }
+
+    #[test]
+    fn relink_rejects_ambiguous_matches() {
+        let db = Database::open_in_memory().unwrap();
+        for path in ["/gone/a", "/gone/b"] {
+            let repo = db.insert_repo("app", path, None).unwrap();
+            db.set_repo_remote_url(repo.id, Some(REMOTE)).unwrap();
+        }
+
+        let err = find_moved_repo(&db, REMOTE).unwrap_err();
+
+        assert!(err.to_string().contains("/gone/a, /gone/b"), "got: {err}");
+    }
+
+    #[test]
+    fn relink_refuses_when_current_path_already_tracked() {
+        let root = tempfile::tempdir().unwrap();
+        init_repo_with_origin(root.path());
+        let repo_path = root.path().canonicalize().unwrap();
+        let db = Database::open_in_memory().unwrap();
+        db.insert_repo("app", repo_path.to_str().unwrap(), None)
+            .unwrap();
+
+        let err = execute(&repo_path, &db, false).unwrap_err();
+
+        assert!(err.to_string().contains("already tracked"), "got: {err}");
+    }
+}
@@ -14,6 +14,18 @@ use crate::state::{Database, Repo, Worktree};
 pub enum RemoveError {
     #[error("pre_remove hook failed")]
     PreRemoveHookFailed(#[source] anyhow::Error),
+
+    #[error(
+        "worktree '{name}' has {count} uncommitted change(s); commit or stash them, \
+         or pass --force to remove anyway"
+    )]
+    DirtyWorktree { name: String, count: usize },
+
+    #[error(
+        "refusing to remove the main worktree at {path}; \
+         only linked worktrees can be removed"
+    )]
+    MainWorktree { path: String },
 }
 
 /// Hook execution status for the remove operation.
@@ -54,6 +66,8 @@ pub struct RemoveResult {
     pub branch_delete_forced: bool,
     /// Error from local branch deletion, if requested but not completed.
     pub branch_delete_error: Option<String>,
+    /// Whether the directory was left on disk (`--keep-dir`).
+    pub kept_dir: bool,
 }
 
 /// JSON-serializable output for `trench remove --json`.
@@ -68,9 +82,27 @@ pub struct RemoveJsonOutput {
     pub branch_delete_forced: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub branch_delete_error: Option<String>,
+    pub kept_dir: bool,
 }
 
 impl RemoveResult {
+    /// Payload for the `removed` event; records a kept directory or branch
+    /// deletion when requested.
+    fn event_payload(&self) -> Option<serde_json::Value> {
+        if self.kept_dir {
+            return Some(serde_json::json!({ "kept_dir": true }));
+        }
+        if !self.delete_branch_requested {
+            return None;
+        }
+        Some(serde_json::json!({
+            "branch": self.branch,
+            "branch_deleted": self.branch_deleted,
+            "branch_delete_forced": self.branch_delete_forced,
+            "branch_delete_error": self.branch_delete_error,
+        }))
+    }
+
     pub fn to_json_output(self, hooks: RemoveHooksStatus) -> RemoveJsonOutput {
         RemoveJsonOutput {
             worktree: self.name,
@@ -80,6 +112,7 @@ impl RemoveResult {
             branch_deleted: self.branch_deleted,
             branch_delete_forced: self.branch_delete_forced,
             branch_delete_error: self.branch_delete_error,
+            kept_dir: self.kept_dir,
         }
     }
 }
@@ -94,6 +127,7 @@ pub struct RemoveDryRunPlan {
     pub path: String,
     pub delete_branch_requested: bool,
     pub force: bool,
+    pub keep_dir: bool,
     pub hooks: Option<RemoveDryRunHooks>,
 }
 
@@ -113,6 +147,9 @@ impl fmt::Display for RemoveDryRunPlan {
             }
         )?;
         writeln!(f, "  Force:     {}", if self.force { "yes" } else { "no" })?;
+        if self.keep_dir {
+            writeln!(f, "  Keep dir:  yes (unregister only, files stay on disk)")?;
+        }
 
         match &self.hooks {
             Some(hooks) if hooks.pre_remove.is_some() || hooks.post_remove.is_some() => {
@@ -164,6 +201,19 @@ pub struct RemoveDryRunHooks {
     pub post_remove: Option<crate::config::HookDef>,
 }
 
+/// Flags of `trench remove` that shape what a removal does.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RemoveOptions {
+    /// `--force`: remove dirty or locked worktrees, force-delete branches.
+    pub force: bool,
+    /// `--delete-branch`: also delete the local branch.
+    pub delete_branch: bool,
+    /// `--no-hooks`: skip `pre_remove`/`post_remove`.
+    pub no_hooks: bool,
+    /// `--keep-dir`: stop tracking the worktree but keep its files.
+    pub keep_dir: bool,
+}
+
 /// Execute a dry-run of `trench remove <identifier>`.
 ///
 /// Resolves the worktree and builds a plan, but performs no git operations,
@@ -172,11 +222,15 @@ pub fn execute_dry_run(
     identifier: &str,
     cwd: &Path,
     db: Option<&Database>,
-    delete_branch_requested: bool,
-    force: bool,
     hooks_config: Option<&HooksConfig>,
-    no_hooks: bool,
+    options: RemoveOptions,
 ) -> Result<RemoveDryRunPlan> {
+    let RemoveOptions {
+        force,
+        delete_branch,
+        no_hooks,
+        keep_dir,
+    } = options;
     let repo_info = crate::git::discover_repo(cwd)?;
     let live = crate::live_worktree::resolve_read_only(identifier, &repo_info, db)?;
     let branch = live
@@ -185,7 +239,8 @@ pub fn execute_dry_run(
         .clone()
         .unwrap_or_else(|| live.entry.name.clone());
 
-    let hooks = if no_hooks {
+    // --keep-dir deletes nothing, so remove hooks never run for it.
+    let hooks = if no_hooks || keep_dir {
         None
     } else {
         hooks_config.and_then(|h| {
@@ -206,12 +261,100 @@ pub fn execute_dry_run(
         name: live.entry.name.clone(),
         branch,
         path: live.entry.path.to_string_lossy().to_string(),
-        delete_branch_requested,
+        delete_branch_requested: delete_branch,
         force,
+        keep_dir,
         hooks,
     })
 }
 
+/// Refuse to remove a locked or dirty worktree unless `force` is set.
+///
+/// With `force`, uncommitted changes are discarded and the lock is released
+/// by the removal itself (see [`release_lock`]); this check changes nothing.
+pub fn ensure_removable(live: &LiveWorktree, repo_info: &RepoInfo, force: bool) -> Result<()> {
+    ensure_unlocked(live, repo_info, force)?;
+
+    if !force && live.entry.path.exists() {
+        let count = git::dirty_count(&live.entry.path)?;
+        if count > 0 {
+            return Err(RemoveError::DirtyWorktree {
+                name: live.entry.name.clone(),
+                count,
+            }
+            .into());
+        }
+    }
+    Ok(())
+}
+
+/// Refuse to operate on the repository's main worktree.
+///
+/// Checks the resolved entry and, for callers that built the entry from DB
+/// metadata alone, git's own worktree list, so the repository checkout is
+/// never handed to `remove_dir_all`.
+pub fn ensure_not_main(live: &LiveWorktree, repo_info: &RepoInfo) -> Result<()> {
+    let path = live
+        .entry
+        .path
+        .canonicalize()
+        .unwrap_or_else(|_| live.entry.path.clone());
+    let is_main = live.entry.is_main
+        || git::list_worktrees(&repo_info.path)?
+            .iter()
+            .any(|wt| wt.is_main && wt.path == path);
+    if is_main {
+        return Err(RemoveError::MainWorktree {
+            path: live.entry.path.to_string_lossy().into_owned(),
+        }
+        .into());
+    }
+    Ok(())
+}
+
+/// Refuse to unregister a locked worktree unless `force` is set.
+///
+/// This is the only check `--keep-dir` needs: uncommitted changes stay on
+/// disk with the directory.
+pub fn ensure_unlocked(live: &LiveWorktree, repo_info: &RepoInfo, force: bool) -> Result<()> {
+    ensure_not_main(live, repo_info)?;
+    if let Some(reason) = lock_reason(live, repo_info)? {
+        if !force {
+            return Err(git::GitError::WorktreeLocked {
+                name: live.entry.name.clone(),
+                reason: Some(reason).filter(|r| !r.is_empty()),
+            }
+            .into());
+        }
+    }
+    Ok(())
+}
+
+/// Lock reason of the worktree's admin entry, `None` when it is unlocked.
+fn lock_reason(live: &LiveWorktree, repo_info: &RepoInfo) -> Result<Option<String>> {
+    if live.entry.is_main {
+        return Ok(None);
+    }
+    match git::worktree_lock_reason(&repo_info.path, &live.entry.name) {
+        Ok(reason) => Ok(reason),
+        // Scanned or foreign entries have no admin entry in this repo.
+        Err(git::GitError::WorktreeNotFound { .. }) => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Release the worktree's lock so git can prune its admin entry.
+///
+/// Called right before the git removal, after `pre_remove` ran, so a
+/// cancelled removal leaves the lock in place. [`ensure_unlocked`] has
+/// already refused locked worktrees unless `force` was given.
+fn release_lock(live: &LiveWorktree, repo_info: &RepoInfo) -> Result<()> {
+    if lock_reason(live, repo_info)?.is_some() {
+        git::unlock_worktree(&repo_info.path, &live.entry.name)?;
+    }
+    Ok(())
+}
+
 /// Execute the `trench remove <identifier>` command.
 ///
 /// Resolves the worktree from live git state, removes it from disk, and
@@ -224,6 +367,7 @@ pub fn execute(
 ) -> Result<RemoveResult> {
     let repo_info = git::discover_repo(cwd)?;
     let live = crate::live_worktree::resolve(identifier, &repo_info, db)?;
+    ensure_removable(&live, &repo_info, false)?;
     execute_live_resolved(&live, &repo_info, db, delete_branch, false)
 }
 
@@ -238,7 +382,9 @@ pub fn execute_live_resolved(
     delete_branch: bool,
     force_delete_branch: bool,
 ) -> Result<RemoveResult> {
+    ensure_not_main(live, repo_info)?;
     let worktree_path = live.entry.path.as_path();
+    release_lock(live, repo_info)?;
 
     // Remove worktree from disk and prune git references
     if worktree_path.exists() {
@@ -247,17 +393,6 @@ pub fn execute_live_resolved(
         eprintln!("warning: worktree directory already removed from disk");
     }
 
-    if let Some(metadata) = live.metadata.as_ref() {
-        let now = crate::state::unix_epoch_secs() as i64;
-        db.archive_removed_worktree(metadata.id, &archived_path(worktree_path, now), now)
-            .context("failed to archive removed worktree metadata")?;
-        let repo = db.get_repo(metadata.repo_id)?.ok_or_else(|| {
-            anyhow::anyhow!("repo metadata missing for worktree '{}'", metadata.name)
-        })?;
-        db.insert_event(repo.id, Some(metadata.id), "removed", None)
-            .context("failed to insert removed event")?;
-    }
-
     let branch = live.entry.branch.clone();
     let mut branch_deleted = false;
     let mut branch_delete_error = None;
@@ -271,14 +406,74 @@ pub fn execute_live_resolved(
         }
     }
 
-    Ok(RemoveResult {
+    let result = RemoveResult {
         name: live.entry.name.clone(),
         branch,
         delete_branch_requested: delete_branch,
         branch_deleted,
         branch_delete_forced: delete_branch && force_delete_branch,
         branch_delete_error,
-    })
+        kept_dir: false,
+    };
+
+    if let Some(metadata) = live.metadata.as_ref() {
+        let now = crate::state::unix_epoch_secs() as i64;
+        db.archive_removed_worktree(metadata.id, &archived_path(worktree_path, now), now)
+            .context("failed to archive removed worktree metadata")?;
+        let repo = db.get_repo(metadata.repo_id)?.ok_or_else(|| {
+            anyhow::anyhow!("repo metadata missing for worktree '{}'", metadata.name)
+        })?;
+        db.insert_event(
+            repo.id,
+            Some(metadata.id),
+            "removed",
+            result.event_payload().as_ref(),
+        )
+        .context("failed to insert removed event")?;
+    }
+
+    Ok(result)
+}
+
+/// Execute `trench remove --keep-dir`: stop tracking a worktree without
+/// deleting anything on disk.
+///
+/// Git's registration is pruned and trench's row is archived exactly as for
+/// a normal remove, but the directory and its contents are left in place
+/// (e.g. to hand them to another tool). No hooks run.
+pub fn execute_keep_dir(
+    live: &LiveWorktree,
+    repo_info: &RepoInfo,
+    db: &Database,
+) -> Result<RemoveResult> {
+    ensure_not_main(live, repo_info)?;
+    release_lock(live, repo_info)?;
+    git::unregister_worktree(&repo_info.path, &live.entry.name)?;
+
+    let result = RemoveResult {
+        name: live.entry.name.clone(),
+        branch: live.entry.branch.clone(),
+        delete_branch_requested: false,
+        branch_deleted: false,
+        branch_delete_forced: false,
+        branch_delete_error: None,
+        kept_dir: true,
+    };
+
+    if let Some(metadata) = live.metadata.as_ref() {
+        let now = crate::state::unix_epoch_secs() as i64;
+        db.archive_removed_worktree(metadata.id, &archived_path(&live.entry.path, now), now)
+            .context("failed to archive removed worktree metadata")?;
+        db.insert_event(
+            metadata.repo_id,
+            Some(metadata.id),
+            "removed",
+            result.event_payload().as_ref(),
+        )
+        .context("failed to insert removed event")?;
+    }
+
+    Ok(result)
 }
 
 pub fn execute_resolved(
@@ -317,6 +512,7 @@ pub async fn execute_live_resolved_with_hooks(
     no_hooks: bool,
     hook_tx: Option<&std::sync::mpsc::Sender<crate::tui::screens::hook_log::HookOutputMessage>>,
 ) -> Result<RemoveWithHooksResult> {
+    ensure_not_main(live, repo_info)?;
     let has_hooks = hooks_config
         .map(|h| h.pre_remove.is_some() || h.post_remove.is_some())
         .unwrap_or(false);
@@ -348,6 +544,7 @@ pub async fn execute_live_resolved_with_hooks(
         repo_name: repo.name.clone(),
         repo_path: repo_info.path.to_string_lossy().to_string(),
         base_branch,
+        user_env: Vec::new(),
     };
 
     // Step 1: pre_remove hook (cwd = worktree path, FR-22)
@@ -378,6 +575,7 @@ pub async fn execute_live_resolved_with_hooks(
     // Inlined from execute_resolved so that post_remove fires immediately after
     // disk deletion, regardless of whether DB bookkeeping succeeds.
     let worktree_path = Path::new(&wt.path);
+    release_lock(live, repo_info)?;
     if worktree_path.exists() {
         git::remove_worktree(&repo_info.path, worktree_path)?;
     } else {
@@ -406,13 +604,6 @@ pub async fn execute_live_resolved_with_hooks(
         None
     };
 
-    // Step 4: archive metadata after hook execution
-    let now = crate::state::unix_epoch_secs() as i64;
-    db.archive_removed_worktree(wt.id, &archived_path(worktree_path, now), now)
-        .context("failed to archive removed worktree metadata")?;
-    db.insert_event(repo.id, Some(wt.id), "removed", None)
-        .context("failed to insert removed event")?;
-
     let mut branch_deleted = false;
     let mut branch_delete_error = None;
     if delete_branch {
@@ -425,15 +616,30 @@ pub async fn execute_live_resolved_with_hooks(
         }
     }
 
+    let result = RemoveResult {
+        name: wt.name.clone(),
+        branch: Some(wt.branch.clone()),
+        delete_branch_requested: delete_branch,
+        branch_deleted,
+        branch_delete_forced: delete_branch && force_delete_branch,
+        branch_delete_error,
+        kept_dir: false,
+    };
+
+    // Step 4: archive metadata after hook execution
+    let now = crate::state::unix_epoch_secs() as i64;
+    db.archive_removed_worktree(wt.id, &archived_path(worktree_path, now), now)
+        .context("failed to archive removed worktree metadata")?;
+    db.insert_event(
+        repo.id,
+        Some(wt.id),
+        "removed",
+        result.event_payload().as_ref(),
+    )
+    .context("failed to insert removed event")?;
+
     Ok(RemoveWithHooksResult {
-        result: RemoveResult {
-            name: wt.name.clone(),
-            branch: Some(wt.branch.clone()),
-            delete_branch_requested: delete_branch,
-            branch_deleted,
-            branch_delete_forced: delete_branch && force_delete_branch,
-            branch_delete_error,
-        },
+        result,
         hooks_status: RemoveHooksStatus::Ran,
         post_remove_warning,
     })
@@ -506,6 +712,7 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
         .expect("create should succeed");
@@ -575,6 +782,7 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
         .expect("create should succeed");
@@ -618,6 +826,7 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
         .expect("create should succeed");
@@ -666,6 +875,66 @@ mod tests {
             .unwrap();
     }
 
+    fn removed_event_payload(db: &Database, repo_dir: &Path) -> serde_json::Value {
+        let repo_path = repo_dir.canonicalize().unwrap();
+        let repo = db
+            .get_repo_by_path(repo_path.to_str().unwrap())
+            .unwrap()
+            .unwrap();
+        let event = db
+            .list_events_matching(repo.id, None, None, &crate::state::EventFilter::default())
+            .unwrap()
+            .into_iter()
+            .find(|e| e.event_type == "removed")
+            .expect("removed event should be recorded");
+        serde_json::from_str(event.payload.as_deref().expect("payload")).unwrap()
+    }
+
+    #[test]
+    fn removed_event_records_merged_branch_deletion() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let repo = init_repo_with_commit(repo_dir.path());
+        let wt_root = tempfile::tempdir().unwrap();
+        let db = Database::open_in_memory().unwrap();
+        create_for_remove(repo_dir.path(), wt_root.path(), &db, "merged");
+
+        let result = execute("merged", repo_dir.path(), &db, true).unwrap();
+
+        assert!(result.branch_deleted);
+        assert!(repo.find_branch("merged", git2::BranchType::Local).is_err());
+        let payload = removed_event_payload(&db, repo_dir.path());
+        assert_eq!(payload["branch"], "merged");
+        assert_eq!(payload["branch_deleted"], true);
+        assert_eq!(payload["branch_delete_forced"], false);
+    }
+
+    #[test]
+    fn removed_event_records_refused_unmerged_branch_deletion() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let repo = init_repo_with_commit(repo_dir.path());
+        let wt_root = tempfile::tempdir().unwrap();
+        let db = Database::open_in_memory().unwrap();
+        let path = create_for_remove(repo_dir.path(), wt_root.path(), &db, "unmerged");
+        let wt_repo = git2::Repository::open(&path).unwrap();
+        commit_file(&wt_repo, "work.txt", "work", "unmerged work");
+
+        let result = execute("unmerged", repo_dir.path(), &db, true).unwrap();
+
+        assert!(!result.branch_deleted);
+        assert!(result.branch_delete_error.is_some());
+        assert!(repo
+            .find_branch("unmerged", git2::BranchType::Local)
+            .is_ok());
+        let payload = removed_event_payload(&db, repo_dir.path());
+        assert_eq!(payload["branch_deleted"], false);
+        assert!(
+            payload["branch_delete_error"]
+                .as_str()
+                .is_some_and(|e| e.contains("not fully merged")),
+            "got: {payload}"
+        );
+    }
+
     #[test]
     fn remove_with_delete_branch_deletes_local_branch() {
         let (clone_dir, _remote_dir) = setup_repo_with_remote();
@@ -680,6 +949,7 @@ mod tests {
             clone_dir.path(),
             wt_root.path(),
             crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
         .expect("create should succeed");
@@ -729,6 +999,7 @@ mod tests {
             clone_dir.path(),
             wt_root.path(),
             crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
         .expect("create should succeed");
@@ -780,6 +1051,7 @@ mod tests {
             clone_dir.path(),
             wt_root.path(),
             crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
         .expect("create should succeed");
@@ -870,6 +1142,7 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
         .expect("create should succeed");
@@ -927,6 +1200,7 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
         .expect("create should succeed");
@@ -957,12 +1231,18 @@ mod tests {
                 run: Some(vec!["echo pre_remove_ran".to_string()]),
                 shell: None,
                 timeout_secs: Some(30),
+                copy_overwrite: None,
+                copy_options: None,
+                rewrite: None,
             }),
             post_remove: Some(crate::config::HookDef {
                 copy: None,
                 run: Some(vec!["echo post_remove_ran".to_string()]),
                 shell: None,
                 timeout_secs: Some(30),
+                copy_overwrite: None,
+                copy_options: None,
+                rewrite: None,
             }),
             ..Default::default()
         }
@@ -983,6 +1263,7 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
         .expect("create should succeed");
@@ -1024,6 +1305,7 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
         .expect("create should succeed");
@@ -1081,6 +1363,7 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
         .expect("create should succeed");
@@ -1095,6 +1378,9 @@ mod tests {
                 run: Some(vec!["echo pre_remove_executed".to_string()]),
                 shell: None,
                 timeout_secs: Some(30),
+                copy_overwrite: None,
+                copy_options: None,
+                rewrite: None,
             }),
             ..Default::default()
         };
@@ -1155,6 +1441,7 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
         .expect("create should succeed");
@@ -1169,6 +1456,9 @@ mod tests {
                 run: Some(vec!["exit 1".to_string()]),
                 shell: None,
                 timeout_secs: Some(30),
+                copy_overwrite: None,
+                copy_options: None,
+                rewrite: None,
             }),
             ..Default::default()
         };
@@ -1221,6 +1511,7 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
         .expect("create should succeed");
@@ -1236,6 +1527,9 @@ mod tests {
                 run: Some(vec![format!("echo done > {}", marker.display())]),
                 shell: None,
                 timeout_secs: Some(30),
+                copy_overwrite: None,
+                copy_options: None,
+                rewrite: None,
             }),
             ..Default::default()
         };
@@ -1286,6 +1580,7 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
         .expect("create should succeed");
@@ -1304,6 +1599,9 @@ mod tests {
                 run: Some(vec!["echo should_not_run".to_string()]),
                 shell: None,
                 timeout_secs: Some(30),
+                copy_overwrite: None,
+                copy_options: None,
+                rewrite: None,
             }),
             ..Default::default()
         };
@@ -1348,6 +1646,7 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
         .expect("create should succeed");
@@ -1362,6 +1661,9 @@ mod tests {
                 run: Some(vec!["exit 42".to_string()]),
                 shell: None,
                 timeout_secs: Some(30),
+                copy_overwrite: None,
+                copy_options: None,
+                rewrite: None,
             }),
             ..Default::default()
         };
@@ -1396,6 +1698,97 @@ mod tests {
         assert!(wt_record.removed_at.is_some(), "removed_at should be set");
     }
 
+    #[test]
+    fn main_worktree_is_refused_before_touching_disk() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let repo = init_repo_with_commit(repo_dir.path());
+        let main_branch = repo.head().unwrap().shorthand().unwrap().to_string();
+        let marker = repo_dir.path().join("keep.txt");
+        std::fs::write(&marker, "precious").unwrap();
+        let db = Database::open_in_memory().unwrap();
+        let repo_info = git::discover_repo(repo_dir.path()).unwrap();
+        let live = crate::live_worktree::resolve(&main_branch, &repo_info, &db).unwrap();
+        assert!(
+            live.entry.is_main,
+            "resolved entry should be the main worktree"
+        );
+
+        let err = execute(&main_branch, repo_dir.path(), &db, false).unwrap_err();
+        assert!(
+            matches!(
+                err.downcast_ref::<RemoveError>(),
+                Some(RemoveError::MainWorktree { .. })
+            ),
+            "got: {err}"
+        );
+        assert!(ensure_removable(&live, &repo_info, true).is_err());
+
+        // A DB-built entry that hides `is_main` is caught via git's list.
+        let disguised = LiveWorktree {
+            entry: GitWorktreeEntry {
+                is_main: false,
+                ..live.entry.clone()
+            },
+            metadata: None,
+        };
+        assert!(execute_live_resolved(&disguised, &repo_info, &db, false, false).is_err());
+        assert!(execute_keep_dir(&disguised, &repo_info, &db).is_err());
+
+        assert_eq!(std::fs::read_to_string(&marker).unwrap(), "precious");
+        assert!(repo_dir.path().join(".git").exists());
+    }
+
+    #[test]
+    fn keep_dir_unregisters_but_leaves_directory() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let _repo = init_repo_with_commit(repo_dir.path());
+        let wt_root = tempfile::tempdir().unwrap();
+        let db_dir = tempfile::tempdir().unwrap();
+        let db = Database::open(&db_dir.path().join("test.db")).unwrap();
+        let created = crate::cli::commands::create::execute(
+            "handoff",
+            None,
+            repo_dir.path(),
+            wt_root.path(),
+            crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
+            &db,
+        )
+        .unwrap();
+        std::fs::write(created.path.join("notes.txt"), "keep me").unwrap();
+        let repo_info = git::discover_repo(repo_dir.path()).unwrap();
+        let live = crate::live_worktree::resolve("handoff", &repo_info, &db).unwrap();
+        let wt_id = live.metadata.as_ref().unwrap().id;
+
+        let result = execute_keep_dir(&live, &repo_info, &db).unwrap();
+
+        assert!(result.kept_dir);
+        assert_eq!(
+            std::fs::read_to_string(created.path.join("notes.txt")).unwrap(),
+            "keep me",
+            "directory and its files should survive"
+        );
+        let listed =
+            crate::cli::commands::list::execute_json(repo_dir.path(), &db, None, &[], None)
+                .unwrap();
+        assert!(!listed.contains("handoff"), "should leave list: {listed}");
+        assert!(db
+            .get_worktree(wt_id)
+            .unwrap()
+            .unwrap()
+            .removed_at
+            .is_some());
+        let events = db.list_events(wt_id, 10).unwrap();
+        let removed = events.iter().find(|e| e.event_type == "removed").unwrap();
+        let payload: serde_json::Value =
+            serde_json::from_str(removed.payload.as_deref().unwrap()).unwrap();
+        assert_eq!(payload["kept_dir"], true);
+
+        let json = serde_json::to_value(result.to_json_output(RemoveHooksStatus::None)).unwrap();
+        assert_eq!(json["kept_dir"], true);
+        assert_eq!(json["branch_deleted"], false);
+    }
+
     // ── Dry-run tests ──────────────────────────────────────────────────
 
     fn create_worktree_for_dry_run(
@@ -1418,6 +1811,7 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
         .expect("create should succeed");
@@ -1435,10 +1829,8 @@ mod tests {
             "dry-run-test",
             repo_dir.path(),
             Some(&db),
-            false, // delete_branch_requested
-            false, // force
             Some(&hooks),
-            false, // no_hooks
+            RemoveOptions::default(),
         )
         .expect("dry-run should succeed");
 
@@ -1464,10 +1856,11 @@ mod tests {
             "no-hooks-dry",
             repo_dir.path(),
             Some(&db),
-            false,
-            false,
             Some(&hooks),
-            true, // no_hooks = true
+            RemoveOptions {
+                no_hooks: true,
+                ..Default::default()
+            },
         )
         .expect("dry-run should succeed");
 
@@ -1486,10 +1879,12 @@ mod tests {
             "display-test",
             repo_dir.path(),
             Some(&db),
-            true,
-            true,
             Some(&hooks),
-            false,
+            RemoveOptions {
+                force: true,
+                delete_branch: true,
+                ..Default::default()
+            },
         )
         .expect("dry-run should succeed");
 
@@ -1524,10 +1919,8 @@ mod tests {
             "json-test",
             repo_dir.path(),
             Some(&db),
-            false,
-            false,
             Some(&hooks),
-            false,
+            RemoveOptions::default(),
         )
         .expect("dry-run should succeed");
 
@@ -1552,10 +1945,12 @@ mod tests {
             "delete-branch-dry",
             repo_dir.path(),
             Some(&db),
-            true, // delete_branch_requested
-            true, // force
             None,
-            false,
+            RemoveOptions {
+                force: true,
+                delete_branch: true,
+                ..Default::default()
+            },
         )
         .expect("dry-run should succeed");
 
@@ -1567,6 +1962,46 @@ mod tests {
         assert!(plan.hooks.is_none(), "no hooks configured");
     }
 
+    #[test]
+    fn dry_run_does_not_remove_worktree_branch_or_write_events() {
+        let (repo_dir, _wt_root, _db_dir, db) = create_worktree_for_dry_run("untouched-dry");
+        let repo_info = crate::git::discover_repo(repo_dir.path()).unwrap();
+        let live = crate::live_worktree::resolve("untouched-dry", &repo_info, &db).unwrap();
+        let wt = live.metadata.as_ref().expect("worktree should be tracked");
+        let events_before = db.list_events(wt.id, 100).unwrap();
+
+        execute_dry_run(
+            "untouched-dry",
+            repo_dir.path(),
+            Some(&db),
+            Some(&sample_hooks_config()),
+            RemoveOptions {
+                force: true,
+                delete_branch: true,
+                ..Default::default()
+            },
+        )
+        .expect("dry-run should succeed");
+
+        assert!(
+            live.entry.path.exists(),
+            "dry-run must not delete the worktree"
+        );
+        let repo = git2::Repository::open(repo_dir.path()).unwrap();
+        assert!(
+            repo.find_branch("untouched-dry", git2::BranchType::Local)
+                .is_ok(),
+            "dry-run must not delete the branch"
+        );
+        let record = db.get_worktree(wt.id).unwrap().unwrap();
+        assert!(record.removed_at.is_none(), "dry-run must not mark removed");
+        assert_eq!(
+            db.list_events(wt.id, 100).unwrap().len(),
+            events_before.len(),
+            "dry-run must not write events to DB"
+        );
+    }
+
     #[test]
     fn dry_run_empty_hooks_config_normalizes_to_none() {
         let (repo_dir, _wt_root, _db_dir, db) = create_worktree_for_dry_run("empty-hooks");
@@ -1585,10 +2020,8 @@ mod tests {
             "empty-hooks",
             repo_dir.path(),
             Some(&db),
-            false,
-            false,
             Some(&empty_hooks),
-            false,
+            RemoveOptions::default(),
         )
         .expect("dry-run should succeed");
 
@@ -1611,10 +2044,8 @@ mod tests {
             "stale-dry-run",
             repo_dir.path(),
             Some(&db),
-            false,
-            false,
             None,
-            false,
+            RemoveOptions::default(),
         )
         .expect_err("dry run should ignore stale DB-only worktree");
         assert!(
@@ -1622,4 +2053,111 @@ mod tests {
             "unexpected error: {err:#}"
         );
     }
+
+    fn create_for_remove(
+        repo_dir: &Path,
+        wt_root: &Path,
+        db: &Database,
+        branch: &str,
+    ) -> std::path::PathBuf {
+        crate::cli::commands::create::execute(
+            branch,
+            None,
+            repo_dir,
+            wt_root,
+            crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
+            db,
+        )
+        .unwrap()
+        .path
+    }
+
+    #[test]
+    fn remove_clean_worktree_passes_dirty_guard() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let _repo = init_repo_with_commit(repo_dir.path());
+        let wt_root = tempfile::tempdir().unwrap();
+        let db = Database::open_in_memory().unwrap();
+        let path = create_for_remove(repo_dir.path(), wt_root.path(), &db, "clean");
+
+        execute("clean", repo_dir.path(), &db, false).unwrap();
+
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn remove_dirty_worktree_requires_force() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let _repo = init_repo_with_commit(repo_dir.path());
+        let wt_root = tempfile::tempdir().unwrap();
+        let db = Database::open_in_memory().unwrap();
+        let path = create_for_remove(repo_dir.path(), wt_root.path(), &db, "dirty");
+        std::fs::write(path.join("notes.txt"), "unsaved").unwrap();
+        std::fs::write(path.join("todo.txt"), "unsaved").unwrap();
+
+        let err = execute("dirty", repo_dir.path(), &db, false).unwrap_err();
+        assert!(
+            matches!(
+                err.downcast_ref::<RemoveError>(),
+                Some(RemoveError::DirtyWorktree { count: 2, .. })
+            ),
+            "got: {err}"
+        );
+        assert!(err.to_string().contains("--force"), "got: {err}");
+        assert!(path.exists(), "dirty worktree must be kept");
+
+        let repo_info = git::discover_repo(repo_dir.path()).unwrap();
+        let live = crate::live_worktree::resolve("dirty", &repo_info, &db).unwrap();
+        ensure_removable(&live, &repo_info, true).unwrap();
+        execute_live_resolved(&live, &repo_info, &db, false, false).unwrap();
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn remove_refuses_locked_worktree_unless_forced() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let repo = init_repo_with_commit(repo_dir.path());
+        let wt_root = tempfile::tempdir().unwrap();
+        let db = Database::open_in_memory().unwrap();
+        let created = crate::cli::commands::create::execute(
+            "locked-feature",
+            None,
+            repo_dir.path(),
+            wt_root.path(),
+            crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
+            &db,
+        )
+        .unwrap();
+        repo.find_worktree("locked-feature")
+            .unwrap()
+            .lock(Some("in use"))
+            .unwrap();
+
+        let err = execute("locked-feature", repo_dir.path(), &db, false).unwrap_err();
+        assert!(
+            matches!(
+                err.downcast_ref::<git::GitError>(),
+                Some(git::GitError::WorktreeLocked { .. })
+            ),
+            "got: {err}"
+        );
+        assert!(err.to_string().contains("(in use)"), "got: {err}");
+        assert!(created.path.exists(), "locked worktree must be kept");
+
+        let repo_info = git::discover_repo(repo_dir.path()).unwrap();
+        let live = crate::live_worktree::resolve("locked-feature", &repo_info, &db).unwrap();
+        ensure_removable(&live, &repo_info, true).unwrap();
+        assert!(
+            git::is_worktree_locked(repo_dir.path(), "locked-feature").unwrap(),
+            "the check alone must not release the lock"
+        );
+        execute_live_resolved(&live, &repo_info, &db, false, false).unwrap();
+        assert!(!created.path.exists());
+        assert!(
+            repo.find_worktree("locked-feature").is_err(),
+            "admin entry pruned"
+        );
+    }
 }
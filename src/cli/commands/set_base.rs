@@ -0,0 +1,183 @@
+use std::path::Path;
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::git;
+use crate::state::{Database, WorktreeUpdate};
+
+/// Outcome of `trench set-base`, shared by the human and JSON renderers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SetBaseResult {
+    pub name: String,
+    pub branch: String,
+    /// Base recorded before the change; `None` when none was stored.
+    pub old_base: Option<String>,
+    pub new_base: String,
+    pub dry_run: bool,
+}
+
+impl std::fmt::Display for SetBaseResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let verb = if self.dry_run { "Would set" } else { "Set" };
+        write!(f, "{verb} base of '{}' to '{}'", self.name, self.new_base)?;
+        match &self.old_base {
+            Some(old) => writeln!(f, " (was '{old}')"),
+            None => writeln!(f),
+        }
+    }
+}
+
+/// Execute the `trench set-base` command.
+///
+/// Records `new_base` as the base branch of the worktree matching
+/// `identifier`, so ahead/behind and sync compare against it. The base must
+/// exist locally or as `origin/<base>`. An untracked worktree is adopted
+/// first. With `dry_run`, validates and reports without writing.
+pub fn execute(
+    cwd: &Path,
+    db: &Database,
+    identifier: &str,
+    new_base: &str,
+    dry_run: bool,
+) -> Result<SetBaseResult> {
+    let repo_info = git::discover_repo(cwd)?;
+    git::ensure_base_branch(&repo_info.path, new_base)?;
+
+    if dry_run {
+        let live = crate::live_worktree::resolve_read_only(identifier, &repo_info, Some(db))?;
+        let (name, old_base) = match &live.metadata {
+            Some(meta) => (meta.name.clone(), meta.base_branch.clone()),
+            None => (live.entry.name.clone(), None),
+        };
+        return Ok(SetBaseResult {
+            name,
+            branch: live.entry.branch.clone().unwrap_or_default(),
+            old_base,
+            new_base: new_base.to_string(),
+            dry_run,
+        });
+    }
+
+    let live = crate::live_worktree::resolve(identifier, &repo_info, db)?;
+    let (repo, wt) = crate::live_worktree::ensure_metadata(db, &repo_info, &live.entry)?;
+    db.update_worktree(
+        wt.id,
+        &WorktreeUpdate {
+            base_branch: Some(Some(new_base.to_string())),
+            ..Default::default()
+        },
+    )?;
+    let payload = serde_json::json!({ "from": wt.base_branch, "to": new_base });
+    db.insert_event(repo.id, Some(wt.id), "base_changed", Some(&payload))?;
+
+    Ok(SetBaseResult {
+        name: wt.name,
+        branch: wt.branch,
+        old_base: wt.base_branch,
+        new_base: new_base.to_string(),
+        dry_run,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Repo with `main` one commit ahead of `trunk`, plus a worktree on
+    /// `feature` created from `trunk`.
+    fn setup() -> (tempfile::TempDir, tempfile::TempDir, Database) {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let repo = git2::Repository::init(repo_dir.path()).unwrap();
+        let sig = git2::Signature::now("Test", "test@test.com").unwrap();
+        let tree_id = repo.index().unwrap().write_tree().unwrap();
+        let tree = repo.find_tree(tree_id).unwrap();
+        let first = repo
+            .commit(Some("HEAD"), &sig, &sig, "initial commit", &tree, &[])
+            .unwrap();
+        let first = repo.find_commit(first).unwrap();
+        repo.branch("trunk", &first, false).unwrap();
+        repo.commit(Some("HEAD"), &sig, &sig, "second", &tree, &[&first])
+            .unwrap();
+        let head = repo.head().unwrap().shorthand().unwrap().to_string();
+        if head != "main" {
+            repo.branch(
+                "main",
+                &repo.head().unwrap().peel_to_commit().unwrap(),
+                true,
+            )
+            .unwrap();
+        }
+
+        let wt_root = tempfile::tempdir().unwrap();
+        let db = Database::open_in_memory().unwrap();
+        crate::cli::commands::create::execute(
+            "feature",
+            Some("trunk"),
+            repo_dir.path(),
+            wt_root.path(),
+            crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
+            &db,
+        )
+        .unwrap();
+        (repo_dir, wt_root, db)
+    }
+
+    #[test]
+    fn set_base_updates_row_and_ahead_behind_follows() {
+        let (repo_dir, _wt_root, db) = setup();
+        let repo_path = repo_dir.path().canonicalize().unwrap();
+        assert_eq!(
+            git::ahead_behind(&repo_path, "feature", Some("trunk")).unwrap(),
+            Some((0, 0))
+        );
+
+        let result = execute(repo_dir.path(), &db, "feature", "main", false).unwrap();
+
+        assert_eq!(result.old_base.as_deref(), Some("trunk"));
+        assert_eq!(result.new_base, "main");
+        assert_eq!(
+            result.to_string(),
+            "Set base of 'feature' to 'main' (was 'trunk')\n"
+        );
+        let repo = db
+            .get_repo_by_path(repo_path.to_str().unwrap())
+            .unwrap()
+            .unwrap();
+        let wt = db
+            .find_worktree_by_identifier(repo.id, "feature")
+            .unwrap()
+            .unwrap();
+        assert_eq!(wt.base_branch.as_deref(), Some("main"));
+        assert_eq!(
+            git::ahead_behind(&repo_path, "feature", wt.base_branch.as_deref()).unwrap(),
+            Some((0, 1)),
+            "ahead/behind should now compare against main"
+        );
+        assert_eq!(db.count_events(wt.id, Some("base_changed")).unwrap(), 1);
+    }
+
+    #[test]
+    fn set_base_rejects_unknown_base_and_dry_run_writes_nothing() {
+        let (repo_dir, _wt_root, db) = setup();
+
+        let err = execute(repo_dir.path(), &db, "feature", "mian", false).unwrap_err();
+        assert!(err.to_string().contains("did you mean 'main'?"), "{err}");
+
+        let result = execute(repo_dir.path(), &db, "feature", "main", true).unwrap();
+        assert!(result.dry_run);
+        assert_eq!(result.old_base.as_deref(), Some("trunk"));
+        let repo_path = repo_dir.path().canonicalize().unwrap();
+        let repo = db
+            .get_repo_by_path(repo_path.to_str().unwrap())
+            .unwrap()
+            .unwrap();
+        let wt = db
+            .find_worktree_by_identifier(repo.id, "feature")
+            .unwrap()
+            .unwrap();
+        assert_eq!(wt.base_branch.as_deref(), Some("trunk"));
+        assert_eq!(db.count_events(wt.id, Some("base_changed")).unwrap(), 0);
+    }
+}
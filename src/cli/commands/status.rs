@@ -5,8 +5,8 @@ use serde::Serialize;
 
 use crate::git;
 use crate::output::json::{format_json, format_json_value};
-use crate::output::porcelain::{format_porcelain, PorcelainRecord};
-use crate::output::table::Table;
+use crate::output::porcelain::{format_porcelain_as, PorcelainRecord, PorcelainVersion};
+use crate::output::table::{Align, Table};
 use crate::state::Database;
 
 /// A unified worktree entry for status output.
@@ -19,11 +19,23 @@ struct StatusEntry {
 }
 
 fn fetch_all_worktrees(cwd: &Path, db: &Database) -> Result<(PathBuf, Vec<StatusEntry>)> {
+    fetch_worktrees(cwd, db, false)
+}
+
+/// Collect live worktrees; with `managed_only`, keep only those trench tracks.
+fn fetch_worktrees(
+    cwd: &Path,
+    db: &Database,
+    managed_only: bool,
+) -> Result<(PathBuf, Vec<StatusEntry>)> {
     let repo_info = git::discover_repo(cwd)?;
     let live_worktrees = crate::live_worktree::list(&repo_info, db, &[])?;
     let mut entries = Vec::with_capacity(live_worktrees.len());
 
     for worktree in live_worktrees {
+        if managed_only && worktree.metadata.is_none() {
+            continue;
+        }
         let base_branch = crate::live_worktree::base_branch(&repo_info, &worktree);
         let db_id = worktree.metadata.as_ref().map(|metadata| metadata.id);
         entries.push(StatusEntry {
@@ -77,6 +89,36 @@ fn compute_git_status(repo_path: &Path, entry: &StatusEntry) -> GitStatus {
     }
 }
 
+/// Diff stat of a worktree's branch against its base, or `None` when there
+/// is no base to compare with.
+fn compute_diffstat(entry: &StatusEntry) -> Option<git::DiffStat> {
+    let base = entry.base_branch.as_deref()?;
+    match git::diff_stat_vs_base(Path::new(&entry.path), base) {
+        Ok(stat) => stat,
+        Err(e) => {
+            eprintln!("warning: diff stat for '{}': {e}", entry.branch);
+            None
+        }
+    }
+}
+
+fn format_diffstat(stat: Option<git::DiffStat>) -> String {
+    match stat {
+        Some(stat) => {
+            let noun = if stat.files_changed == 1 {
+                "file"
+            } else {
+                "files"
+            };
+            format!(
+                "{} {noun} +{}/-{}",
+                stat.files_changed, stat.insertions, stat.deletions
+            )
+        }
+        None => "-".to_string(),
+    }
+}
+
 fn format_ahead_behind(ahead: Option<usize>, behind: Option<usize>) -> String {
     match (ahead, behind) {
         (Some(a), Some(b)) => format!("+{a}/-{b}"),
@@ -97,6 +139,7 @@ fn render_summary_table(
     db: &Database,
     max_width: Option<usize>,
     use_color: bool,
+    diffstat: bool,
 ) -> Result<String> {
     let (repo_path, entries) = fetch_all_worktrees(cwd, db)?;
 
@@ -104,13 +147,26 @@ fn render_summary_table(
         return Ok("No worktrees.\n".to_string());
     }
 
-    let mut table = Table::new(vec!["Name", "Branch", "Status", "Ahead/Behind"]);
+    let mut headers = vec!["Name", "Branch", "Status", "Ahead/Behind"];
+    if diffstat {
+        headers.push("Diff");
+    }
+    let mut table = Table::new(headers)
+        .align(2, Align::Right)
+        .align(3, Align::Right);
+    if diffstat {
+        table = table.align(4, Align::Right);
+    }
 
     for entry in &entries {
         let status = compute_git_status(&repo_path, entry);
         let dirty_str = format_dirty(status.dirty);
         let ab_str = format_ahead_behind(status.ahead, status.behind);
-        table = table.row(vec![&entry.name, &entry.branch, &dirty_str, &ab_str]);
+        let mut row = vec![entry.name.clone(), entry.branch.clone(), dirty_str, ab_str];
+        if diffstat {
+            row.push(format_diffstat(compute_diffstat(entry)));
+        }
+        table = table.row(row.iter().map(String::as_str).collect());
     }
 
     if let Some(width) = max_width {
@@ -144,7 +200,7 @@ fn resolve_worktree(cwd: &Path, db: &Database, identifier: &str) -> Result<(Path
     ))
 }
 
-fn render_deep(cwd: &Path, db: &Database, identifier: &str) -> Result<String> {
+fn render_deep(cwd: &Path, db: &Database, identifier: &str, diffstat: bool) -> Result<String> {
     let (repo_path, entry) = resolve_worktree(cwd, db, identifier)?;
     let status = compute_git_status(&repo_path, &entry);
 
@@ -156,6 +212,10 @@ fn render_deep(cwd: &Path, db: &Database, identifier: &str) -> Result<String> {
     }
     let ab = format_ahead_behind(status.ahead, status.behind);
     out.push_str(&format!("Ahead/Behind: {ab}\n"));
+    if diffstat {
+        let diff = format_diffstat(compute_diffstat(&entry));
+        out.push_str(&format!("Diff:         {diff}\n"));
+    }
     out.push_str(&format!("Status:       {}\n", format_dirty(status.dirty)));
 
     // Changed files
@@ -191,14 +251,23 @@ fn render_deep(cwd: &Path, db: &Database, identifier: &str) -> Result<String> {
     Ok(out)
 }
 
-pub fn execute(cwd: &Path, db: &Database, branch: Option<&str>, use_color: bool) -> Result<String> {
+/// Execute `trench status`. With `diffstat`, also show files changed,
+/// insertions, and deletions of each branch relative to its base.
+pub fn execute(
+    cwd: &Path,
+    db: &Database,
+    branch: Option<&str>,
+    use_color: bool,
+    diffstat: bool,
+) -> Result<String> {
     match branch {
-        Some(id) => render_deep(cwd, db, id),
+        Some(id) => render_deep(cwd, db, id, diffstat),
         None => render_summary_table(
             cwd,
             db,
             crossterm::terminal::size().ok().map(|(c, _)| c as usize),
             use_color,
+            diffstat,
         ),
     }
 }
@@ -213,6 +282,9 @@ struct SummaryJson {
     ahead: Option<usize>,
     behind: Option<usize>,
     dirty: usize,
+    /// Only present with `--diffstat`; `null` when there is no base.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    diffstat: Option<Option<git::DiffStat>>,
 }
 
 impl PorcelainRecord for SummaryJson {
@@ -227,6 +299,24 @@ impl PorcelainRecord for SummaryJson {
             self.dirty.to_string(),
         ]
     }
+
+    fn porcelain_pairs(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("name", self.name.clone()),
+            ("branch", self.branch.clone()),
+            ("path", self.path.clone()),
+            ("status", self.status.clone()),
+            (
+                "ahead",
+                self.ahead.map_or("-".to_string(), |v| v.to_string()),
+            ),
+            (
+                "behind",
+                self.behind.map_or("-".to_string(), |v| v.to_string()),
+            ),
+            ("dirty", self.dirty.to_string()),
+        ]
+    }
 }
 
 fn build_summary_json(entry: &StatusEntry, status: GitStatus) -> SummaryJson {
@@ -238,6 +328,7 @@ fn build_summary_json(entry: &StatusEntry, status: GitStatus) -> SummaryJson {
         ahead: status.ahead,
         behind: status.behind,
         dirty: status.dirty,
+        diffstat: None,
     }
 }
 
@@ -252,6 +343,9 @@ struct DeepJson {
     behind: Option<usize>,
     dirty: usize,
     status: String,
+    /// Only present with `--diffstat`; `null` when there is no base.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    diffstat: Option<Option<git::DiffStat>>,
     changed_files: Vec<String>,
     recent_commits: Vec<String>,
     hook_history: Vec<String>,
@@ -286,18 +380,27 @@ fn build_deep_json(entry: &StatusEntry, status: GitStatus, db: &Database) -> Dee
         behind: status.behind,
         dirty: status.dirty,
         status: format_dirty(status.dirty),
+        diffstat: None,
         changed_files: changed,
         recent_commits: commits,
         hook_history,
     }
 }
 
-pub fn execute_json(cwd: &Path, db: &Database, branch: Option<&str>) -> Result<String> {
+pub fn execute_json(
+    cwd: &Path,
+    db: &Database,
+    branch: Option<&str>,
+    diffstat: bool,
+) -> Result<String> {
     match branch {
         Some(id) => {
             let (repo_path, entry) = resolve_worktree(cwd, db, id)?;
             let status = compute_git_status(&repo_path, &entry);
-            let json_obj = build_deep_json(&entry, status, db);
+            let json_obj = DeepJson {
+                diffstat: diffstat.then(|| compute_diffstat(&entry)),
+                ..build_deep_json(&entry, status, db)
+            };
             format_json_value(&json_obj)
         }
         None => {
@@ -306,7 +409,10 @@ pub fn execute_json(cwd: &Path, db: &Database, branch: Option<&str>) -> Result<S
                 .iter()
                 .map(|e| {
                     let status = compute_git_status(&repo_path, e);
-                    build_summary_json(e, status)
+                    SummaryJson {
+                        diffstat: diffstat.then(|| compute_diffstat(e)),
+                        ..build_summary_json(e, status)
+                    }
                 })
                 .collect();
             format_json(&items)
@@ -314,13 +420,19 @@ pub fn execute_json(cwd: &Path, db: &Database, branch: Option<&str>) -> Result<S
     }
 }
 
-pub fn execute_porcelain(cwd: &Path, db: &Database, branch: Option<&str>) -> Result<String> {
+/// Execute `trench status --porcelain[=<version>]`.
+pub fn execute_porcelain(
+    cwd: &Path,
+    db: &Database,
+    branch: Option<&str>,
+    version: PorcelainVersion,
+) -> Result<String> {
     match branch {
         Some(id) => {
             let (repo_path, entry) = resolve_worktree(cwd, db, id)?;
             let status = compute_git_status(&repo_path, &entry);
             let item = build_summary_json(&entry, status);
-            Ok(format_porcelain(&[item]))
+            Ok(format_porcelain_as(&[item], version))
         }
         None => {
             let (repo_path, entries) = fetch_all_worktrees(cwd, db)?;
@@ -331,11 +443,65 @@ pub fn execute_porcelain(cwd: &Path, db: &Database, branch: Option<&str>) -> Res
                     build_summary_json(e, status)
                 })
                 .collect();
-            Ok(format_porcelain(&items))
+            Ok(format_porcelain_as(&items, version))
         }
     }
 }
 
+/// Execute `trench status --all`: one compact line per managed worktree.
+pub fn execute_all(cwd: &Path, db: &Database) -> Result<String> {
+    let (repo_path, entries) = fetch_worktrees(cwd, db, true)?;
+    if entries.is_empty() {
+        return Ok("No managed worktrees. Use `trench create` to get started.\n".to_string());
+    }
+
+    let rows: Vec<[String; 4]> = entries
+        .iter()
+        .map(|entry| {
+            let status = compute_git_status(&repo_path, entry);
+            [
+                entry.name.clone(),
+                entry.branch.clone(),
+                format_ahead_behind(status.ahead, status.behind),
+                format_dirty(status.dirty),
+            ]
+        })
+        .collect();
+    let name_width = rows.iter().map(|r| r[0].chars().count()).max().unwrap_or(0);
+    let branch_width = rows.iter().map(|r| r[1].chars().count()).max().unwrap_or(0);
+    let ab_width = rows.iter().map(|r| r[2].chars().count()).max().unwrap_or(0);
+
+    let mut out = String::new();
+    for [name, branch, ab, dirty] in &rows {
+        out.push_str(&format!(
+            "{name:<name_width$}  {branch:<branch_width$}  {ab:<ab_width$}  {dirty}\n"
+        ));
+    }
+    Ok(out)
+}
+
+fn managed_summaries(cwd: &Path, db: &Database) -> Result<Vec<SummaryJson>> {
+    let (repo_path, entries) = fetch_worktrees(cwd, db, true)?;
+    Ok(entries
+        .iter()
+        .map(|e| build_summary_json(e, compute_git_status(&repo_path, e)))
+        .collect())
+}
+
+/// Execute `trench status --all --json`: an array of managed worktree summaries.
+pub fn execute_all_json(cwd: &Path, db: &Database) -> Result<String> {
+    format_json(&managed_summaries(cwd, db)?)
+}
+
+/// Execute `trench status --all --porcelain[=<version>]`.
+pub fn execute_all_porcelain(
+    cwd: &Path,
+    db: &Database,
+    version: PorcelainVersion,
+) -> Result<String> {
+    Ok(format_porcelain_as(&managed_summaries(cwd, db)?, version))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -365,12 +531,59 @@ mod tests {
             repo_dir,
             wt_root.path(),
             crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             db,
         )
         .expect("create should succeed");
         (wt_root, result.path)
     }
 
+    /// Commit a new two-line file on the worktree's branch.
+    fn commit_in_worktree(wt_path: &Path) {
+        let wt_repo = git2::Repository::open(wt_path).unwrap();
+        std::fs::write(wt_path.join("notes.txt"), "one\ntwo\n").unwrap();
+        let mut index = wt_repo.index().unwrap();
+        index.add_path(Path::new("notes.txt")).unwrap();
+        index.write().unwrap();
+        let tree = wt_repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let sig = git2::Signature::now("Test", "test@test.com").unwrap();
+        let parent = wt_repo.head().unwrap().peel_to_commit().unwrap();
+        wt_repo
+            .commit(Some("HEAD"), &sig, &sig, "add notes", &tree, &[&parent])
+            .unwrap();
+    }
+
+    #[test]
+    fn diffstat_counts_branch_changes_against_base() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let _repo = init_repo_with_commit(repo_dir.path());
+        let db = Database::open_in_memory().unwrap();
+        let (_wt_root, wt_path) = create_live_worktree(repo_dir.path(), &db, "feature/diff");
+        commit_in_worktree(&wt_path);
+
+        let deep = render_deep(repo_dir.path(), &db, "feature-diff", true).unwrap();
+        assert!(deep.contains("Diff:         1 file +2/-0\n"), "{deep}");
+
+        let output = execute_json(repo_dir.path(), &db, Some("feature-diff"), true).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(parsed["diffstat"]["files_changed"], 1);
+        assert_eq!(parsed["diffstat"]["insertions"], 2);
+        assert_eq!(parsed["diffstat"]["deletions"], 0);
+
+        let summary = execute_json(repo_dir.path(), &db, None, true).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&summary).unwrap();
+        let feature = parsed
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|wt| wt["name"] == "feature-diff")
+            .unwrap();
+        assert_eq!(feature["diffstat"]["insertions"], 2);
+
+        let plain = execute_json(repo_dir.path(), &db, None, false).unwrap();
+        assert!(!plain.contains("diffstat"), "only present with --diffstat");
+    }
+
     #[test]
     fn summary_shows_all_worktrees() {
         let repo_dir = tempfile::tempdir().unwrap();
@@ -379,7 +592,7 @@ mod tests {
         let (_feature_auth_root, _) = create_live_worktree(repo_dir.path(), &db, "feature/auth");
         let (_fix_bug_root, _) = create_live_worktree(repo_dir.path(), &db, "fix/bug");
 
-        let output = render_summary_table(repo_dir.path(), &db, None, false)
+        let output = render_summary_table(repo_dir.path(), &db, None, false, false)
             .expect("summary should succeed");
 
         assert!(output.contains("Name"), "should have Name header");
@@ -398,7 +611,7 @@ mod tests {
         let db = Database::open_in_memory().unwrap();
 
         let output =
-            render_summary_table(repo_dir.path(), &db, None, false).expect("should succeed");
+            render_summary_table(repo_dir.path(), &db, None, false, false).expect("should succeed");
         assert!(
             !output.contains("\x1b"),
             "should not contain ANSI escape codes when color is disabled, got:\n{output}"
@@ -412,7 +625,8 @@ mod tests {
         let db = Database::open_in_memory().unwrap();
         let (_wt_root, _) = create_live_worktree(repo_dir.path(), &db, "feature/auth");
 
-        let output = execute_json(repo_dir.path(), &db, None).expect("summary json should succeed");
+        let output =
+            execute_json(repo_dir.path(), &db, None, false).expect("summary json should succeed");
         let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
         let arr = parsed.as_array().expect("should be array");
 
@@ -431,6 +645,51 @@ mod tests {
         assert!(wt["path"].is_string());
     }
 
+    #[test]
+    fn status_all_lists_managed_worktrees_with_dirty_indicators() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let _repo = init_repo_with_commit(repo_dir.path());
+        let db = Database::open_in_memory().unwrap();
+        let (_clean_root, _) = create_live_worktree(repo_dir.path(), &db, "feature/clean");
+        let (_dirty_root, dirty_path) = create_live_worktree(repo_dir.path(), &db, "feature/dirty");
+        std::fs::write(dirty_path.join("a.txt"), "a").unwrap();
+        std::fs::write(dirty_path.join("b.txt"), "b").unwrap();
+
+        let output = execute_all(repo_dir.path(), &db).unwrap();
+
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 2, "only managed worktrees, got:\n{output}");
+        let clean = lines
+            .iter()
+            .find(|l| l.starts_with("feature-clean"))
+            .unwrap();
+        assert!(clean.contains("feature/clean") && clean.ends_with("clean"));
+        let dirty = lines
+            .iter()
+            .find(|l| l.starts_with("feature-dirty"))
+            .unwrap();
+        assert!(dirty.ends_with("~2"), "got: {dirty}");
+
+        let json: serde_json::Value =
+            serde_json::from_str(&execute_all_json(repo_dir.path(), &db).unwrap()).unwrap();
+        let items = json.as_array().unwrap();
+        assert_eq!(items.len(), 2);
+        let dirty_json = items.iter().find(|i| i["name"] == "feature-dirty").unwrap();
+        assert_eq!(dirty_json["dirty"], 2);
+        assert_eq!(dirty_json["status"], "~2");
+    }
+
+    #[test]
+    fn status_all_without_managed_worktrees_prints_hint() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let _repo = init_repo_with_commit(repo_dir.path());
+        let db = Database::open_in_memory().unwrap();
+
+        let output = execute_all(repo_dir.path(), &db).unwrap();
+
+        assert!(output.starts_with("No managed worktrees."), "got: {output}");
+    }
+
     #[test]
     fn deep_view_includes_changed_files() {
         let repo_dir = tempfile::tempdir().unwrap();
@@ -475,7 +734,7 @@ mod tests {
         .unwrap();
 
         let output =
-            render_deep(repo_dir.path(), &db, "test-changes").expect("deep should succeed");
+            render_deep(repo_dir.path(), &db, "test-changes", false).expect("deep should succeed");
 
         assert!(
             output.contains("Changed files"),
@@ -549,7 +808,7 @@ mod tests {
         .unwrap();
 
         let output =
-            render_deep(repo_dir.path(), &db, "test-commits").expect("deep should succeed");
+            render_deep(repo_dir.path(), &db, "test-commits", false).expect("deep should succeed");
 
         assert!(
             output.contains("Recent commits"),
@@ -585,7 +844,7 @@ mod tests {
             .unwrap();
 
         let output =
-            render_deep(repo_dir.path(), &db, "feature-auth").expect("deep should succeed");
+            render_deep(repo_dir.path(), &db, "feature-auth", false).expect("deep should succeed");
 
         assert!(
             output.contains("Hook history"),
@@ -608,7 +867,7 @@ mod tests {
         let db = Database::open_in_memory().unwrap();
         let (_wt_root, wt_path) = create_live_worktree(repo_dir.path(), &db, "feature/auth");
 
-        let output = execute_json(repo_dir.path(), &db, Some("feature-auth"))
+        let output = execute_json(repo_dir.path(), &db, Some("feature-auth"), false)
             .expect("deep json should succeed");
         let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
 
@@ -634,7 +893,7 @@ mod tests {
         db.insert_repo(repo_name, repo_path.to_str().unwrap(), Some("main"))
             .unwrap();
 
-        let result = render_deep(repo_dir.path(), &db, "nonexistent");
+        let result = render_deep(repo_dir.path(), &db, "nonexistent", false);
         assert!(result.is_err(), "should error for nonexistent worktree");
         let msg = result.unwrap_err().to_string();
         assert!(
@@ -651,7 +910,7 @@ mod tests {
         let (_wt_root, wt_path) = create_live_worktree(repo_dir.path(), &db, "feature/auth");
 
         let output =
-            render_deep(repo_dir.path(), &db, "feature-auth").expect("deep should succeed");
+            render_deep(repo_dir.path(), &db, "feature-auth", false).expect("deep should succeed");
 
         assert!(output.contains("Branch:"), "should show Branch label");
         assert!(output.contains("feature/auth"), "should show branch name");
@@ -680,13 +939,15 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
         .expect("create should succeed");
 
         std::fs::remove_dir_all(&created.path).expect("manual delete should succeed");
 
-        let output = execute(repo_dir.path(), &db, None, false).expect("status should succeed");
+        let output =
+            execute(repo_dir.path(), &db, None, false, false).expect("status should succeed");
 
         assert!(
             !output.contains("ephemeral"),
@@ -4,6 +4,66 @@ use anyhow::Result;
 
 use crate::state::Database;
 
+/// Typed errors for `trench switch` that callers map to structured output.
+#[derive(Debug, thiserror::Error)]
+pub enum SwitchError {
+    #[error(
+        "worktree '{name}' is orphaned: {path} no longer exists. \
+         Run `git worktree prune` to drop it, or recreate it with `git worktree add {path} {branch}`"
+    )]
+    Orphaned {
+        name: String,
+        branch: String,
+        path: String,
+    },
+}
+
+impl SwitchError {
+    /// Stable machine-readable code for `--json` error output.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Orphaned { .. } => "orphaned",
+        }
+    }
+}
+
+/// Fail with [`SwitchError::Orphaned`] when trench still tracks a worktree
+/// for `identifier` whose directory has been deleted.
+///
+/// Must run before live resolution, which purges such stale rows.
+fn check_orphaned(identifier: &str, repo_path: &str, db: &Database) -> Result<()> {
+    let Some(repo) = db.get_repo_by_path(repo_path)? else {
+        return Ok(());
+    };
+    if let Some(wt) = db.find_worktree_by_identifier(repo.id, identifier)? {
+        if !Path::new(&wt.path).exists() {
+            return Err(SwitchError::Orphaned {
+                name: wt.name,
+                branch: wt.branch,
+                path: wt.path,
+            }
+            .into());
+        }
+    }
+    Ok(())
+}
+
+/// Repoint the configured `worktrees.current_symlink` at `worktree_path`.
+///
+/// Returns the link that was updated, or `None` when no symlink is
+/// configured.
+pub fn update_current_symlink(
+    config: &crate::config::ResolvedConfig,
+    worktree_path: &Path,
+) -> Result<Option<std::path::PathBuf>> {
+    let Some(link) = config.worktrees.current_symlink.as_deref() else {
+        return Ok(None);
+    };
+    let link = crate::paths::expand_path(link);
+    crate::paths::update_symlink(&link, worktree_path)?;
+    Ok(Some(link))
+}
+
 /// Result of a successful switch operation.
 #[derive(Debug)]
 pub struct SwitchResult {
@@ -18,8 +78,15 @@ pub struct SwitchResult {
 /// Resolves the worktree by sanitized name or branch name, updates
 /// `last_accessed` and session state, and returns the worktree path.
 /// If the worktree is unmanaged (not in DB), it is silently adopted.
+/// A tracked worktree whose directory is gone fails with
+/// [`SwitchError::Orphaned`] and leaves its metadata untouched.
 pub fn execute(identifier: &str, cwd: &Path, db: &Database) -> Result<SwitchResult> {
     let repo_info = crate::git::discover_repo(cwd)?;
+    let repo_path = repo_info
+        .path
+        .to_str()
+        .ok_or_else(|| anyhow::anyhow!("repo path is not valid UTF-8"))?;
+    check_orphaned(identifier, repo_path, db)?;
     let live = crate::live_worktree::resolve(identifier, &repo_info, db)?;
     let (repo, wt) = crate::live_worktree::ensure_metadata(db, &repo_info, &live.entry)?;
 
@@ -75,6 +142,7 @@ mod tests {
             repo_dir,
             wt_root.path(),
             crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             db,
         )
         .expect("create should succeed");
@@ -333,6 +401,7 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
         .expect("create should succeed");
@@ -371,4 +440,73 @@ mod tests {
         let event_count = db.count_events(wt_before.id, Some("switched")).unwrap();
         assert_eq!(event_count, 1, "exactly one 'switched' event should exist");
     }
+
+    #[test]
+    fn switch_reports_orphaned_worktree_without_touching_it() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let _repo = init_repo_with_commit(repo_dir.path());
+        let db = Database::open_in_memory().unwrap();
+        let repo_path = repo_dir.path().canonicalize().unwrap();
+        let db_repo = db
+            .insert_repo("my-project", repo_path.to_str().unwrap(), Some("main"))
+            .unwrap();
+        let missing = repo_dir.path().join("gone");
+        let wt = db
+            .insert_worktree(
+                db_repo.id,
+                "ghost",
+                "feature/ghost",
+                missing.to_str().unwrap(),
+                None,
+            )
+            .unwrap();
+
+        let err = execute("ghost", repo_dir.path(), &db).unwrap_err();
+
+        let switch_err = err
+            .downcast_ref::<SwitchError>()
+            .expect("should be a typed SwitchError");
+        assert_eq!(switch_err.code(), "orphaned");
+        assert!(err.to_string().contains("no longer exists"), "got: {err}");
+        let stored = db.get_worktree(wt.id).unwrap().expect("row kept");
+        assert!(stored.last_accessed.is_none(), "last_accessed untouched");
+        assert_eq!(db.count_events(wt.id, Some("switched")).unwrap(), 0);
+    }
+
+    #[test]
+    fn current_symlink_is_noop_without_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let config =
+            crate::config::resolve_config(None, None, &crate::config::GlobalConfig::default());
+
+        let updated = update_current_symlink(&config, dir.path()).unwrap();
+
+        assert!(updated.is_none());
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn current_symlink_follows_switch_target() {
+        let dir = tempfile::tempdir().unwrap();
+        let link = dir.path().join("repo-current");
+        let global: crate::config::GlobalConfig = toml::from_str(&format!(
+            "[worktrees]\ncurrent_symlink = \"{}\"\n",
+            link.display()
+        ))
+        .unwrap();
+        let config = crate::config::resolve_config(None, None, &global);
+        let (one, two) = (dir.path().join("one"), dir.path().join("two"));
+        std::fs::create_dir(&one).unwrap();
+        std::fs::create_dir(&two).unwrap();
+
+        assert_eq!(
+            update_current_symlink(&config, &one).unwrap(),
+            Some(link.clone())
+        );
+        assert_eq!(std::fs::read_link(&link).unwrap(), one);
+
+        update_current_symlink(&config, &two).unwrap();
+        assert_eq!(std::fs::read_link(&link).unwrap(), two);
+    }
 }
@@ -15,6 +15,11 @@ use crate::state::{Database, Repo, Worktree};
 pub enum SyncError {
     #[error("pre_sync hook failed")]
     PreSyncHookFailed(#[source] anyhow::Error),
+    #[error(
+        "worktree '{name}' has {count} uncommitted change(s); commit or stash before syncing, \
+         or pass --autostash"
+    )]
+    DirtyWorktree { name: String, count: usize },
 }
 
 /// Hook execution status for the sync operation.
@@ -40,19 +45,16 @@ pub struct SyncWithHooksResult {
 }
 
 /// Sync strategy.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum Strategy {
-    Rebase,
-    Merge,
-}
-
-impl std::fmt::Display for Strategy {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Strategy::Rebase => write!(f, "rebase"),
-            Strategy::Merge => write!(f, "merge"),
-        }
-    }
+pub use crate::git::SyncStrategy as Strategy;
+
+/// What happened to changes stashed by `--autostash`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AutostashStatus {
+    /// The stash was popped cleanly after the sync.
+    Applied,
+    /// The stash conflicts with the synced branch and was left in place.
+    Conflict,
 }
 
 /// Result of a sync operation.
@@ -70,6 +72,8 @@ pub struct SyncResult {
     pub after_ahead: usize,
     /// Behind count after sync.
     pub after_behind: usize,
+    /// Set when local changes were stashed around the sync (`--autostash`).
+    pub autostash: Option<AutostashStatus>,
 }
 
 /// JSON representation of a sync result.
@@ -79,6 +83,8 @@ pub struct SyncResultJson {
     pub strategy: String,
     pub before: AheadBehind,
     pub after: AheadBehind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub autostash: Option<AutostashStatus>,
 }
 
 #[derive(Debug, Serialize)]
@@ -100,13 +106,25 @@ impl SyncResult {
                 ahead: self.after_ahead,
                 behind: self.after_behind,
             },
+            autostash: self.autostash,
         }
     }
+
+    /// Warning to show when autostashed changes could not be re-applied.
+    pub fn autostash_warning(&self) -> Option<String> {
+        (self.autostash == Some(AutostashStatus::Conflict)).then(|| {
+            format!(
+                "warning: stashed changes in '{}' conflict with the synced branch; \
+                 resolve the conflicts, then run `git stash drop` in the worktree",
+                self.name
+            )
+        })
+    }
 }
 
 /// Error returned when `--all` is used without `--strategy`.
 #[derive(Debug, thiserror::Error)]
-#[error("Batch sync requires an explicit strategy. Use --strategy rebase or --strategy merge.")]
+#[error("Batch sync requires an explicit strategy. Use --strategy rebase, --strategy merge, or --strategy ff-only.")]
 pub struct BatchSyncMissingStrategy;
 
 /// Explicit status for a batch sync entry.
@@ -114,6 +132,9 @@ pub struct BatchSyncMissingStrategy;
 pub enum BatchSyncStatus {
     Success,
     Failure,
+    /// Rebase or merge stopped on conflicts.
+    Conflict,
+    /// Not attempted because the worktree has uncommitted changes.
     Skipped,
 }
 
@@ -148,6 +169,7 @@ impl BatchSyncEntry {
             status: match self.status {
                 BatchSyncStatus::Success => "success",
                 BatchSyncStatus::Failure => "failure",
+                BatchSyncStatus::Conflict => "conflict",
                 BatchSyncStatus::Skipped => "skipped",
             }
             .to_string(),
@@ -155,6 +177,91 @@ impl BatchSyncEntry {
             error: self.error.clone(),
         }
     }
+
+    /// Classify a failed sync: conflicts and dirty worktrees get their own
+    /// status so batch output can tell them apart from hard failures.
+    pub fn from_error(name: String, err: &anyhow::Error) -> Self {
+        let status = if matches!(
+            err.downcast_ref::<crate::git::GitError>(),
+            Some(crate::git::GitError::MergeConflict { .. })
+        ) {
+            BatchSyncStatus::Conflict
+        } else if matches!(
+            err.downcast_ref::<SyncError>(),
+            Some(SyncError::DirtyWorktree { .. })
+        ) {
+            BatchSyncStatus::Skipped
+        } else {
+            BatchSyncStatus::Failure
+        };
+        Self {
+            name,
+            status,
+            result: None,
+            error: Some(format!("{err:#}")),
+        }
+    }
+
+    /// Short outcome label for the batch summary.
+    pub fn outcome(&self) -> &'static str {
+        match self.status {
+            BatchSyncStatus::Success => match &self.result {
+                Some(r) if r.before_behind == 0 => "up-to-date",
+                _ => "updated",
+            },
+            BatchSyncStatus::Failure => "failed",
+            BatchSyncStatus::Conflict => "conflict",
+            BatchSyncStatus::Skipped => "skipped-dirty",
+        }
+    }
+}
+
+/// Whether a batch sync should exit non-zero: any conflict or hard failure.
+/// Worktrees skipped for being dirty do not fail the batch.
+pub fn batch_has_failures(results: &[BatchSyncEntry]) -> bool {
+    results.iter().any(|r| {
+        matches!(
+            r.status,
+            BatchSyncStatus::Conflict | BatchSyncStatus::Failure
+        )
+    })
+}
+
+/// Human-readable per-worktree summary for `trench sync --all`.
+pub fn format_batch_summary(results: &[BatchSyncEntry]) -> String {
+    let mut out = String::new();
+    for entry in results {
+        out.push_str(&format!("{}: {}", entry.name, entry.outcome()));
+        if let Some(result) = &entry.result {
+            out.push_str(&format!(
+                " via {} (behind {} -> {})",
+                result.strategy, result.before_behind, result.after_behind
+            ));
+        }
+        out.push('\n');
+        if let Some(err) = &entry.error {
+            out.push_str(&format!("  {err}\n"));
+        }
+        if let Some(warning) = entry
+            .result
+            .as_ref()
+            .and_then(SyncResult::autostash_warning)
+        {
+            out.push_str(&format!("  {warning}\n"));
+        }
+    }
+
+    let count = |status| results.iter().filter(|r| r.status == status).count();
+    let updated = results.iter().filter(|r| r.outcome() == "updated").count();
+    out.push_str(&format!(
+        "\nBatch sync: {updated} updated, {} up-to-date, {} conflict, {} skipped-dirty, {} failed ({} total)\n",
+        count(BatchSyncStatus::Success) - updated,
+        count(BatchSyncStatus::Conflict),
+        count(BatchSyncStatus::Skipped),
+        count(BatchSyncStatus::Failure),
+        results.len()
+    ));
+    out
 }
 
 /// Execute `trench sync --all`: sync every worktree in the list.
@@ -165,10 +272,11 @@ pub fn execute_all_live(
     repo_info: &RepoInfo,
     db: &Database,
     strategy: Strategy,
+    autostash: bool,
 ) -> Vec<BatchSyncEntry> {
     let mut results = Vec::new();
     for live in worktrees {
-        match execute_live_resolved(live, repo_info, db, strategy) {
+        match execute_live_resolved(live, repo_info, db, strategy, autostash) {
             Ok(sync_result) => {
                 results.push(BatchSyncEntry {
                     name: live.entry.name.clone(),
@@ -178,12 +286,7 @@ pub fn execute_all_live(
                 });
             }
             Err(e) => {
-                results.push(BatchSyncEntry {
-                    name: live.entry.name.clone(),
-                    status: BatchSyncStatus::Failure,
-                    result: None,
-                    error: Some(format!("{e:#}")),
-                });
+                results.push(BatchSyncEntry::from_error(live.entry.name.clone(), &e));
             }
         }
     }
@@ -202,18 +305,22 @@ pub fn execute(
 ) -> Result<SyncResult> {
     let repo_info = crate::git::discover_repo(cwd)?;
     let live = crate::live_worktree::resolve(identifier, &repo_info, db)?;
-    execute_live_resolved(&live, &repo_info, db, strategy)
+    execute_live_resolved(&live, &repo_info, db, strategy, false)
 }
 
 /// Execute sync with pre-resolved worktree data.
 ///
 /// Use this when the caller has already resolved the worktree (e.g. for
 /// hook context) to avoid a redundant DB/git round-trip.
+///
+/// A dirty worktree is refused unless `autostash` is set, in which case the
+/// changes are stashed before the sync and popped afterwards.
 pub fn execute_live_resolved(
     live: &LiveWorktree,
     repo_info: &RepoInfo,
     db: &Database,
     strategy: Strategy,
+    autostash: bool,
 ) -> Result<SyncResult> {
     let branch = live
         .entry
@@ -221,13 +328,21 @@ pub fn execute_live_resolved(
         .as_deref()
         .unwrap_or(live.entry.name.as_str());
     let dirty = crate::git::dirty_count(live.entry.path.as_path())?;
-    if dirty > 0 {
-        anyhow::bail!(
-            "worktree '{}' has {} uncommitted change(s); commit or stash before syncing",
-            live.entry.name,
-            dirty
-        );
+    if dirty > 0 && !autostash {
+        return Err(SyncError::DirtyWorktree {
+            name: live.entry.name.clone(),
+            count: dirty,
+        }
+        .into());
     }
+    let stash = AutostashGuard {
+        path: live.entry.path.as_path(),
+        stash: if dirty > 0 {
+            crate::git::stash_save(live.entry.path.as_path(), "trench sync autostash")?
+        } else {
+            None
+        },
+    };
 
     let base_branch = crate::live_worktree::base_branch(repo_info, live);
 
@@ -240,15 +355,9 @@ pub fn execute_live_resolved(
         crate::git::ahead_behind(Path::new(&repo_info.path), branch, Some(&base_branch))?
             .unwrap_or((0, 0));
 
-    // Perform sync
-    match strategy {
-        Strategy::Rebase => {
-            crate::git::sync_rebase(live.entry.path.as_path(), branch, &base_branch)?;
-        }
-        Strategy::Merge => {
-            crate::git::sync_merge(live.entry.path.as_path(), branch, &base_branch)?;
-        }
-    }
+    // Perform sync; on failure the guard puts the user's changes back.
+    crate::git::sync_worktree(live.entry.path.as_path(), branch, &base_branch, strategy)?;
+    let autostash = stash.pop()?;
 
     // Get after counts
     let (after_ahead, after_behind) =
@@ -257,12 +366,15 @@ pub fn execute_live_resolved(
 
     // Insert synced event
     let (repo, wt) = crate::live_worktree::ensure_metadata(db, repo_info, &live.entry)?;
-    let payload = serde_json::json!({
+    let mut payload = serde_json::json!({
         "strategy": strategy.to_string(),
         "base_branch": base_branch,
         "before": { "ahead": before_ahead, "behind": before_behind },
         "after": { "ahead": after_ahead, "behind": after_behind },
     });
+    if let Some(status) = autostash {
+        payload["autostash"] = serde_json::to_value(status)?;
+    }
     db.insert_event(repo.id, Some(wt.id), "synced", Some(&payload))?;
 
     Ok(SyncResult {
@@ -272,9 +384,47 @@ pub fn execute_live_resolved(
         before_behind,
         after_ahead,
         after_behind,
+        autostash,
     })
 }
 
+/// Changes stashed by `--autostash`, restored when the guard is dropped so
+/// every early return out of the sync puts them back.
+struct AutostashGuard<'a> {
+    path: &'a Path,
+    stash: Option<git2::Oid>,
+}
+
+impl AutostashGuard<'_> {
+    /// Re-apply the stash after a successful sync. A conflicting pop keeps
+    /// the stash and is reported rather than treated as an error.
+    fn pop(mut self) -> Result<Option<AutostashStatus>> {
+        let Some(stash_id) = self.stash.take() else {
+            return Ok(None);
+        };
+        match crate::git::stash_pop(self.path, stash_id) {
+            Ok(()) => Ok(Some(AutostashStatus::Applied)),
+            Err(crate::git::GitError::StashConflict { .. }) => Ok(Some(AutostashStatus::Conflict)),
+            Err(e) => Err(anyhow::Error::new(e).context(format!(
+                "could not restore autostashed changes; they are kept in stash {stash_id}"
+            ))),
+        }
+    }
+}
+
+impl Drop for AutostashGuard<'_> {
+    fn drop(&mut self) {
+        if let Some(stash_id) = self.stash.take() {
+            if let Err(e) = crate::git::stash_pop(self.path, stash_id) {
+                eprintln!(
+                    "warning: could not restore autostashed changes: {e}; \
+                     they are kept in stash {stash_id} (`git stash apply {stash_id}`)"
+                );
+            }
+        }
+    }
+}
+
 pub fn execute_resolved(
     repo: &Repo,
     wt: &Worktree,
@@ -292,7 +442,7 @@ pub fn execute_resolved(
         metadata: Some(wt.clone()),
     };
     let _ = repo;
-    execute_live_resolved(&live, repo_info, db, strategy)
+    execute_live_resolved(&live, repo_info, db, strategy, false)
 }
 
 pub fn execute_all(
@@ -316,7 +466,7 @@ pub fn execute_all(
         })
         .collect();
     let _ = repo;
-    execute_all_live(&live, repo_info, db, strategy)
+    execute_all_live(&live, repo_info, db, strategy, false)
 }
 
 /// Plan produced by `--dry-run` showing what `trench sync` would do.
@@ -328,6 +478,8 @@ pub struct SyncDryRunPlan {
     pub branch: String,
     pub base_branch: String,
     pub strategy: String,
+    /// Remote that would be fetched before syncing, if one is configured.
+    pub fetch_remote: Option<String>,
     pub hooks: Option<SyncDryRunHooks>,
 }
 
@@ -347,6 +499,11 @@ impl fmt::Display for SyncDryRunPlan {
         writeln!(f, "  Branch:    {}", self.branch)?;
         writeln!(f, "  Base:      {}", self.base_branch)?;
         writeln!(f, "  Strategy:  {}", self.strategy)?;
+        writeln!(
+            f,
+            "  Fetch:     {}",
+            self.fetch_remote.as_deref().unwrap_or("(no remote)")
+        )?;
 
         match &self.hooks {
             Some(hooks) if hooks.pre_sync.is_some() || hooks.post_sync.is_some() => {
@@ -421,6 +578,7 @@ pub fn execute_dry_run(
         branch,
         base_branch,
         strategy: strategy.to_string(),
+        fetch_remote: crate::git::fetch_remote_name(&repo_info.path),
         hooks,
     })
 }
@@ -444,6 +602,8 @@ pub fn execute_all_dry_run_live(
         })
     };
 
+    let fetch_remote = crate::git::fetch_remote_name(&repo_info.path);
+
     worktrees
         .iter()
         .map(|live| {
@@ -458,6 +618,7 @@ pub fn execute_all_dry_run_live(
                 branch,
                 base_branch: crate::live_worktree::base_branch(repo_info, live),
                 strategy: strategy.to_string(),
+                fetch_remote: fetch_remote.clone(),
                 hooks: hooks.clone(),
             }
         })
@@ -489,6 +650,17 @@ pub fn execute_all_dry_run(
     execute_all_dry_run_live(&live, repo_info, strategy, hooks_config, no_hooks)
 }
 
+/// Flags of `trench sync` that shape how a worktree is synced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyncOptions {
+    /// `--strategy`: rebase onto or merge the base branch.
+    pub strategy: Strategy,
+    /// `--autostash`: stash local changes around the sync.
+    pub autostash: bool,
+    /// `--no-hooks`: skip `pre_sync`/`post_sync`.
+    pub no_hooks: bool,
+}
+
 /// Execute `trench sync <identifier>` with lifecycle hooks.
 ///
 /// Orchestrates: pre_sync hook → sync → post_sync hook.
@@ -499,11 +671,15 @@ pub async fn execute_with_hooks(
     identifier: &str,
     cwd: &Path,
     db: &Database,
-    strategy: Strategy,
+    options: SyncOptions,
     hooks_config: Option<&HooksConfig>,
-    no_hooks: bool,
     hook_tx: Option<&std::sync::mpsc::Sender<crate::tui::screens::hook_log::HookOutputMessage>>,
 ) -> Result<SyncWithHooksResult> {
+    let SyncOptions {
+        strategy,
+        autostash,
+        no_hooks,
+    } = options;
     let has_hooks = hooks_config
         .map(|h| h.pre_sync.is_some() || h.post_sync.is_some())
         .unwrap_or(false);
@@ -515,7 +691,9 @@ pub async fn execute_with_hooks(
         } else {
             SyncHooksStatus::None
         };
-        let result = execute(identifier, cwd, db, strategy)?;
+        let repo_info = crate::git::discover_repo(cwd)?;
+        let live = crate::live_worktree::resolve(identifier, &repo_info, db)?;
+        let result = execute_live_resolved(&live, &repo_info, db, strategy, autostash)?;
         return Ok(SyncWithHooksResult {
             result,
             hooks_status,
@@ -538,6 +716,7 @@ pub async fn execute_with_hooks(
         repo_name: repo.name.clone(),
         repo_path: repo_info.path.to_string_lossy().to_string(),
         base_branch: base_branch.to_string(),
+        user_env: Vec::new(),
     };
 
     // Step 1: pre_sync hook (cwd = worktree path)
@@ -558,7 +737,7 @@ pub async fn execute_with_hooks(
     }
 
     // Step 2: perform sync (reuse already-resolved data)
-    let result = execute_live_resolved(&live, &repo_info, db, strategy)?;
+    let result = execute_live_resolved(&live, &repo_info, db, strategy, autostash)?;
 
     // Step 3: post_sync hook (cwd = worktree path)
     let post_sync_error = if let Some(post_sync) = &hooks.post_sync {
@@ -774,6 +953,27 @@ mod tests {
         assert_eq!(head.parent_count(), 2, "merge commit should have 2 parents");
     }
 
+    #[test]
+    fn sync_ff_only_rejects_diverged_branch_without_conflict() {
+        let f = setup_diverged_repo();
+        let wt_repo = git2::Repository::open(&f.wt_path).unwrap();
+        let head_before = wt_repo.head().unwrap().target().unwrap();
+
+        let err = execute("feature", f._repo_dir.path(), &f.db, Strategy::FfOnly)
+            .expect_err("ff-only should refuse a diverged branch");
+
+        match err.downcast_ref::<crate::git::GitError>() {
+            Some(crate::git::GitError::NotFastForward { branch, base }) => {
+                assert_eq!(branch, "feature");
+                assert_eq!(base, "main");
+            }
+            other => panic!("expected NotFastForward, got: {other:?}"),
+        }
+        assert!(err.to_string().contains("diverged"), "{err}");
+        assert_eq!(wt_repo.head().unwrap().target().unwrap(), head_before);
+        assert!(!f.wt_path.join("upstream.txt").exists());
+    }
+
     #[test]
     fn sync_writes_synced_event_to_db() {
         let f = setup_diverged_repo();
@@ -895,9 +1095,10 @@ mod tests {
         assert!(
             matches!(
                 err.downcast_ref::<crate::git::GitError>(),
-                Some(crate::git::GitError::MergeConflict { branch }) if branch == "conflict-feat"
+                Some(crate::git::GitError::MergeConflict { branch, files })
+                    if branch == "conflict-feat" && files == &["conflict.txt"]
             ),
-            "should be GitError::MergeConflict for 'conflict-feat'"
+            "should be GitError::MergeConflict for 'conflict-feat' listing conflict.txt"
         );
     }
 
@@ -985,9 +1186,10 @@ mod tests {
         assert!(
             matches!(
                 err.downcast_ref::<crate::git::GitError>(),
-                Some(crate::git::GitError::MergeConflict { branch }) if branch == "merge-conflict"
+                Some(crate::git::GitError::MergeConflict { branch, files })
+                    if branch == "merge-conflict" && files == &["shared.txt"]
             ),
-            "should be GitError::MergeConflict for 'merge-conflict'"
+            "should be GitError::MergeConflict for 'merge-conflict' listing shared.txt"
         );
 
         // After merge conflict error, MERGE_HEAD should be preserved so users can resolve
@@ -1008,6 +1210,7 @@ mod tests {
             before_behind: 3,
             after_ahead: 2,
             after_behind: 0,
+            autostash: None,
         };
 
         let json = result.to_json();
@@ -1019,6 +1222,14 @@ mod tests {
         assert_eq!(serialized["before"]["behind"], 3);
         assert_eq!(serialized["after"]["ahead"], 2);
         assert_eq!(serialized["after"]["behind"], 0);
+        assert!(serialized.get("autostash").is_none());
+
+        let stashed = SyncResult {
+            autostash: Some(AutostashStatus::Conflict),
+            ..result
+        };
+        let serialized = serde_json::to_value(stashed.to_json()).unwrap();
+        assert_eq!(serialized["autostash"], "conflict");
     }
 
     #[test]
@@ -1030,6 +1241,7 @@ mod tests {
             before_behind: 1,
             after_ahead: 2,
             after_behind: 0,
+            autostash: None,
         };
 
         let json = result.to_json();
@@ -1047,6 +1259,20 @@ mod tests {
         assert_eq!(Strategy::Merge.to_string(), "merge");
     }
 
+    #[test]
+    fn ff_only_strategy_parses_from_cli_and_config() {
+        use clap::ValueEnum;
+        assert_eq!(
+            Strategy::from_str("ff-only", false).unwrap(),
+            Strategy::FfOnly
+        );
+        assert_eq!(Strategy::FfOnly.to_string(), "ff-only");
+        assert!(Strategy::from_str("fast-forward", false).is_err());
+
+        let git: crate::config::GitConfig = toml::from_str("sync_strategy = \"ff-only\"").unwrap();
+        assert_eq!(git.sync_strategy, Some(Strategy::FfOnly));
+    }
+
     #[test]
     fn sync_rebase_shows_correct_ahead_counts() {
         let f = setup_diverged_repo();
@@ -1231,6 +1457,107 @@ mod tests {
         );
     }
 
+    #[test]
+    fn sync_autostash_preserves_dirty_changes_after_rebase() {
+        let f = setup_diverged_repo();
+        std::fs::write(f.wt_path.join("feature.txt"), "edited locally").unwrap();
+        std::fs::write(f.wt_path.join("notes.txt"), "untracked").unwrap();
+
+        let repo_info = crate::git::discover_repo(f._repo_dir.path()).unwrap();
+        let live = crate::live_worktree::resolve("feature", &repo_info, &f.db).unwrap();
+        let result = execute_live_resolved(&live, &repo_info, &f.db, Strategy::Rebase, true)
+            .expect("autostash sync should succeed");
+
+        assert_eq!(result.after_behind, 0, "should be rebased onto main");
+        assert_eq!(result.autostash, Some(AutostashStatus::Applied));
+        assert!(f.wt_path.join("upstream.txt").exists());
+        assert_eq!(
+            std::fs::read_to_string(f.wt_path.join("feature.txt")).unwrap(),
+            "edited locally"
+        );
+        assert_eq!(
+            std::fs::read_to_string(f.wt_path.join("notes.txt")).unwrap(),
+            "untracked"
+        );
+
+        let mut wt_repo = git2::Repository::open(&f.wt_path).unwrap();
+        let mut stashes = 0;
+        wt_repo
+            .stash_foreach(|_, _, _| {
+                stashes += 1;
+                true
+            })
+            .unwrap();
+        assert_eq!(stashes, 0, "stash should be dropped after a clean pop");
+        assert_eq!(result.to_json().autostash, Some(AutostashStatus::Applied));
+    }
+
+    #[test]
+    fn autostash_guard_restores_changes_when_dropped() {
+        let f = setup_diverged_repo();
+        std::fs::write(f.wt_path.join("feature.txt"), "edited locally").unwrap();
+
+        let stash = crate::git::stash_save(&f.wt_path, "test").unwrap();
+        assert!(stash.is_some());
+        assert_ne!(
+            std::fs::read_to_string(f.wt_path.join("feature.txt")).unwrap(),
+            "edited locally"
+        );
+        // Dropped without `pop`, as on an early `?` return.
+        drop(AutostashGuard {
+            path: &f.wt_path,
+            stash,
+        });
+
+        assert_eq!(
+            std::fs::read_to_string(f.wt_path.join("feature.txt")).unwrap(),
+            "edited locally"
+        );
+        let mut wt_repo = git2::Repository::open(&f.wt_path).unwrap();
+        let mut stashes = 0;
+        wt_repo
+            .stash_foreach(|_, _, _| {
+                stashes += 1;
+                true
+            })
+            .unwrap();
+        assert_eq!(stashes, 0);
+    }
+
+    #[test]
+    fn sync_autostash_reports_conflicting_pop_and_keeps_stash() {
+        let f = setup_diverged_repo();
+        let repo_info = crate::git::discover_repo(f._repo_dir.path()).unwrap();
+        let live = crate::live_worktree::resolve("feature", &repo_info, &f.db).unwrap();
+        execute_live_resolved(&live, &repo_info, &f.db, Strategy::Rebase, false)
+            .expect("initial sync should succeed");
+
+        // Main and the worktree now both edit upstream.txt.
+        commit_file(&f._git_repo, "upstream.txt", "upstream v2", "upstream edit");
+        std::fs::write(f.wt_path.join("upstream.txt"), "local version").unwrap();
+
+        let result = execute_live_resolved(&live, &repo_info, &f.db, Strategy::Rebase, true)
+            .expect("sync itself should succeed");
+
+        assert_eq!(result.autostash, Some(AutostashStatus::Conflict));
+        assert!(result
+            .autostash_warning()
+            .unwrap()
+            .contains("git stash drop"));
+        let contents = std::fs::read_to_string(f.wt_path.join("upstream.txt")).unwrap();
+        assert!(contents.contains("upstream v2") && contents.contains("local version"));
+
+        let mut wt_repo = git2::Repository::open(&f.wt_path).unwrap();
+        let mut stashes = 0;
+        wt_repo
+            .stash_foreach(|_, _, _| {
+                stashes += 1;
+                true
+            })
+            .unwrap();
+        assert_eq!(stashes, 1, "conflicting stash must be kept");
+    }
+
     #[test]
     fn sync_rebase_uses_repo_configured_identity() {
         let f = setup_diverged_repo();
@@ -1286,12 +1613,18 @@ mod tests {
                 run: Some(vec!["echo pre_sync_ran".to_string()]),
                 shell: None,
                 timeout_secs: Some(30),
+                copy_overwrite: None,
+                copy_options: None,
+                rewrite: None,
             }),
             post_sync: Some(crate::config::HookDef {
                 copy: None,
                 run: Some(vec!["echo post_sync_ran".to_string()]),
                 shell: None,
                 timeout_secs: Some(30),
+                copy_overwrite: None,
+                copy_options: None,
+                rewrite: None,
             }),
             ..Default::default()
         }
@@ -1305,9 +1638,12 @@ mod tests {
             "feature",
             f._repo_dir.path(),
             &f.db,
-            Strategy::Rebase,
-            None,  // no hooks config
-            false, // no_hooks flag
+            SyncOptions {
+                strategy: Strategy::Rebase,
+                autostash: false,
+                no_hooks: false,
+            },
+            None, // no hooks config
             None,
         )
         .await
@@ -1328,9 +1664,12 @@ mod tests {
             "feature",
             f._repo_dir.path(),
             &f.db,
-            Strategy::Rebase,
+            SyncOptions {
+                strategy: Strategy::Rebase,
+                autostash: false,
+                no_hooks: true,
+            },
             Some(&hooks),
-            true, // no_hooks = true
             None,
         )
         .await
@@ -1370,6 +1709,9 @@ mod tests {
                 run: Some(vec!["echo pre_sync_executed".to_string()]),
                 shell: None,
                 timeout_secs: Some(30),
+                copy_overwrite: None,
+                copy_options: None,
+                rewrite: None,
             }),
             ..Default::default()
         };
@@ -1378,9 +1720,12 @@ mod tests {
             "feature",
             f._repo_dir.path(),
             &f.db,
-            Strategy::Rebase,
+            SyncOptions {
+                strategy: Strategy::Rebase,
+                autostash: false,
+                no_hooks: false,
+            },
             Some(&hooks),
-            false,
             None,
         )
         .await
@@ -1428,6 +1773,9 @@ mod tests {
                 run: Some(vec!["exit 1".to_string()]),
                 shell: None,
                 timeout_secs: Some(30),
+                copy_overwrite: None,
+                copy_options: None,
+                rewrite: None,
             }),
             ..Default::default()
         };
@@ -1436,9 +1784,12 @@ mod tests {
             "feature",
             f._repo_dir.path(),
             &f.db,
-            Strategy::Rebase,
+            SyncOptions {
+                strategy: Strategy::Rebase,
+                autostash: false,
+                no_hooks: false,
+            },
             Some(&hooks),
-            false,
             None,
         )
         .await
@@ -1485,6 +1836,9 @@ mod tests {
                 run: Some(vec![format!("echo done > {}", marker.display())]),
                 shell: None,
                 timeout_secs: Some(30),
+                copy_overwrite: None,
+                copy_options: None,
+                rewrite: None,
             }),
             ..Default::default()
         };
@@ -1493,9 +1847,12 @@ mod tests {
             "feature",
             f._repo_dir.path(),
             &f.db,
-            Strategy::Rebase,
+            SyncOptions {
+                strategy: Strategy::Rebase,
+                autostash: false,
+                no_hooks: false,
+            },
             Some(&hooks),
-            false,
             None,
         )
         .await
@@ -1536,6 +1893,9 @@ mod tests {
                 run: Some(vec!["exit 42".to_string()]),
                 shell: None,
                 timeout_secs: Some(30),
+                copy_overwrite: None,
+                copy_options: None,
+                rewrite: None,
             }),
             ..Default::default()
         };
@@ -1545,9 +1905,12 @@ mod tests {
             "feature",
             f._repo_dir.path(),
             &f.db,
-            Strategy::Rebase,
+            SyncOptions {
+                strategy: Strategy::Rebase,
+                autostash: false,
+                no_hooks: false,
+            },
             Some(&hooks),
-            false,
             None,
         )
         .await
@@ -1609,12 +1972,18 @@ mod tests {
                 run: Some(vec![format!("echo pre_sync >> {}", order_file.display())]),
                 shell: None,
                 timeout_secs: Some(30),
+                copy_overwrite: None,
+                copy_options: None,
+                rewrite: None,
             }),
             post_sync: Some(crate::config::HookDef {
                 copy: None,
                 run: Some(vec![format!("echo post_sync >> {}", order_file.display())]),
                 shell: None,
                 timeout_secs: Some(30),
+                copy_overwrite: None,
+                copy_options: None,
+                rewrite: None,
             }),
             ..Default::default()
         };
@@ -1623,9 +1992,12 @@ mod tests {
             "feature",
             f._repo_dir.path(),
             &f.db,
-            Strategy::Rebase,
+            SyncOptions {
+                strategy: Strategy::Rebase,
+                autostash: false,
+                no_hooks: false,
+            },
             Some(&hooks),
-            false,
             None,
         )
         .await
@@ -1841,13 +2213,18 @@ mod tests {
 
         assert_eq!(results.len(), 2, "should have results for both worktrees");
 
-        // feat-a should fail (dirty)
+        // feat-a is skipped (dirty)
         let feat_a = results.iter().find(|r| r.name == "feat-a").unwrap();
+        assert_eq!(feat_a.status, BatchSyncStatus::Skipped);
         assert!(
             feat_a.error.is_some(),
             "feat-a should have an error (dirty worktree)"
         );
         assert!(feat_a.result.is_none());
+        assert!(
+            !batch_has_failures(&results),
+            "dirty skips do not fail the batch"
+        );
 
         // feat-b should succeed despite feat-a failure
         let feat_b = results.iter().find(|r| r.name == "feat-b").unwrap();
@@ -1881,9 +2258,9 @@ mod tests {
         let arr = parsed.as_array().unwrap();
         assert_eq!(arr.len(), 2);
 
-        // feat-a should be "failure"
+        // feat-a is skipped (dirty)
         let feat_a = arr.iter().find(|v| v["name"] == "feat-a").unwrap();
-        assert_eq!(feat_a["status"], "failure");
+        assert_eq!(feat_a["status"], "skipped");
         assert!(feat_a["error"].is_string());
         assert!(feat_a["result"].is_null());
 
@@ -1895,6 +2272,61 @@ mod tests {
         assert_eq!(feat_b["result"]["strategy"], "rebase");
     }
 
+    #[test]
+    fn batch_sync_summary_reports_up_to_date_and_updated() {
+        let f = setup_multi_worktree_repo();
+        let repo_info = crate::git::RepoInfo {
+            name: "test-repo".to_string(),
+            path: std::path::PathBuf::from(&f.repo_path_str),
+            remote_url: None,
+            default_branch: "main".to_string(),
+        };
+        let db_repo = f.db.get_repo_by_path(&f.repo_path_str).unwrap().unwrap();
+        let worktrees = f.db.list_worktrees(db_repo.id).unwrap();
+
+        // Bring feat-a up to date first; feat-b stays behind main.
+        let feat_a: Vec<_> = worktrees
+            .iter()
+            .filter(|w| w.name == "feat-a")
+            .cloned()
+            .collect();
+        execute_all(&feat_a, &db_repo, &repo_info, &f.db, Strategy::Rebase);
+
+        let results = execute_all(&worktrees, &db_repo, &repo_info, &f.db, Strategy::Rebase);
+        let summary = format_batch_summary(&results);
+
+        assert!(summary.contains("feat-a: up-to-date"), "{summary}");
+        assert!(summary.contains("feat-b: updated"), "{summary}");
+        assert!(
+            summary.contains(
+                "1 updated, 1 up-to-date, 0 conflict, 0 skipped-dirty, 0 failed (2 total)"
+            ),
+            "{summary}"
+        );
+        assert!(!batch_has_failures(&results));
+    }
+
+    #[test]
+    fn batch_sync_entry_classifies_conflict_and_dirty_errors() {
+        let conflict: anyhow::Error = crate::git::GitError::MergeConflict {
+            branch: "wt".to_string(),
+            files: vec!["a.txt".to_string()],
+        }
+        .into();
+        let entry = BatchSyncEntry::from_error("wt".to_string(), &conflict);
+        assert_eq!(entry.status, BatchSyncStatus::Conflict);
+        assert_eq!(entry.to_json().status, "conflict");
+        assert!(batch_has_failures(&[entry]));
+
+        let dirty: anyhow::Error = SyncError::DirtyWorktree {
+            name: "wt".to_string(),
+            count: 2,
+        }
+        .into();
+        let entry = BatchSyncEntry::from_error("wt".to_string(), &dirty);
+        assert_eq!(entry.outcome(), "skipped-dirty");
+    }
+
     #[test]
     fn batch_sync_with_merge_strategy() {
         let f = setup_multi_worktree_repo();
@@ -1963,6 +2395,8 @@ mod tests {
             name: "test".to_string(),
             path: "/tmp/test".to_string(),
             default_base: Some("main".to_string()),
+            remote_url: None,
+            template: None,
             created_at: 0,
         };
         let repo_info = crate::git::RepoInfo {
@@ -1992,6 +2426,7 @@ mod tests {
                 before_behind: 1,
                 after_ahead: 0,
                 after_behind: 0,
+                autostash: None,
             }),
             error: None,
         };
@@ -2018,6 +2453,7 @@ mod tests {
                 before_behind: 1,
                 after_ahead: 0,
                 after_behind: 0,
+                autostash: None,
             }),
             error: Some("post_sync hook failed".to_string()),
         };
@@ -2114,6 +2550,39 @@ mod tests {
         assert_eq!(head_before, head_after, "dry-run must not change git HEAD");
     }
 
+    #[test]
+    fn dry_run_plan_reports_remote_to_fetch() {
+        let f = setup_diverged_repo();
+
+        let plan = execute_dry_run(
+            "feature",
+            f._repo_dir.path(),
+            Some(&f.db),
+            Strategy::Rebase,
+            None,
+            false,
+        )
+        .unwrap();
+        assert_eq!(plan.fetch_remote, None);
+
+        let main_repo = git2::Repository::open(f._repo_dir.path()).unwrap();
+        main_repo
+            .remote("origin", "https://invalid.example.com/nonexistent.git")
+            .unwrap();
+
+        let plan = execute_dry_run(
+            "feature",
+            f._repo_dir.path(),
+            Some(&f.db),
+            Strategy::Rebase,
+            None,
+            false,
+        )
+        .unwrap();
+        assert_eq!(plan.fetch_remote.as_deref(), Some("origin"));
+        assert!(format!("{plan}").contains("Fetch:     origin"));
+    }
+
     #[test]
     fn dry_run_plan_serializes_to_json_with_expected_structure() {
         let plan = SyncDryRunPlan {
@@ -2122,6 +2591,7 @@ mod tests {
             branch: "my-feature".to_string(),
             base_branch: "main".to_string(),
             strategy: "rebase".to_string(),
+            fetch_remote: None,
             hooks: None,
         };
 
@@ -2132,6 +2602,7 @@ mod tests {
         assert_eq!(json_val["branch"], "my-feature");
         assert_eq!(json_val["base_branch"], "main");
         assert_eq!(json_val["strategy"], "rebase");
+        assert!(json_val["fetch_remote"].is_null());
         assert!(
             json_val["hooks"].is_null(),
             "hooks should be null when None"
@@ -2146,6 +2617,7 @@ mod tests {
             branch: "my-feature".to_string(),
             base_branch: "main".to_string(),
             strategy: "rebase".to_string(),
+            fetch_remote: None,
             hooks: None,
         };
 
@@ -2157,6 +2629,7 @@ mod tests {
         );
         assert!(output.contains("main"), "should contain base branch");
         assert!(output.contains("rebase"), "should contain strategy");
+        assert!(output.contains("(no remote)"), "should show missing remote");
         assert!(output.contains("(none)"), "should show (none) for hooks");
     }
 
@@ -2172,12 +2645,18 @@ mod tests {
                 run: Some(vec!["echo pre".to_string()]),
                 shell: None,
                 timeout_secs: None,
+                copy_overwrite: None,
+                copy_options: None,
+                rewrite: None,
             }),
             post_sync: Some(HookDef {
                 copy: None,
                 run: Some(vec!["echo post".to_string()]),
                 shell: None,
                 timeout_secs: None,
+                copy_overwrite: None,
+                copy_options: None,
+                rewrite: None,
             }),
             ..Default::default()
         };
@@ -2214,6 +2693,9 @@ mod tests {
                 run: Some(vec!["echo pre".to_string()]),
                 shell: None,
                 timeout_secs: None,
+                copy_overwrite: None,
+                copy_options: None,
+                rewrite: None,
             }),
             ..Default::default()
         };
@@ -2307,6 +2789,7 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
         .expect("create should succeed");
@@ -1,8 +1,11 @@
 use std::path::Path;
 
 use anyhow::Result;
+use serde::Serialize;
 
 use crate::git;
+use crate::output::json::format_json;
+use crate::output::table::Table;
 use crate::state::Database;
 
 /// Parsed tag operation from CLI input.
@@ -14,7 +17,9 @@ pub enum TagOp {
 
 /// Parse raw CLI tag arguments into structured operations.
 ///
-/// `+name` → Add, `-name` → Remove. Returns error for invalid format.
+/// `+name` → Add, `-name` → Remove. Returns error for invalid format or
+/// for added names outside the charset of [`crate::state::validate_tag_name`].
+/// Removal accepts any name so tags stored before validation can be cleaned up.
 pub fn parse_tag_args(args: &[String]) -> Result<Vec<TagOp>> {
     let mut ops = Vec::new();
     for arg in args {
@@ -22,6 +27,7 @@ pub fn parse_tag_args(args: &[String]) -> Result<Vec<TagOp>> {
             if name.is_empty() {
                 anyhow::bail!("tag name cannot be empty: '{arg}'");
             }
+            crate::state::validate_tag_name(name)?;
             ops.push(TagOp::Add(name.to_string()));
         } else if let Some(name) = arg.strip_prefix('-') {
             if name.is_empty() {
@@ -78,6 +84,98 @@ pub fn execute(identifier: &str, tags: &[String], cwd: &Path, db: &Database) ->
     }
 }
 
+/// Execute `trench tag --all-matching <filter>`.
+///
+/// Applies add/remove operations to every active worktree that carries
+/// `filter` when the command starts, and reports how many were affected.
+pub fn execute_all_matching(
+    filter: &str,
+    tags: &[String],
+    cwd: &Path,
+    db: &Database,
+) -> Result<String> {
+    if tags.is_empty() {
+        anyhow::bail!("--all-matching requires at least one +tag or -tag operation");
+    }
+    let ops = parse_tag_args(tags)?;
+
+    let repo_info = git::discover_repo(cwd)?;
+    let repo_path = repo_info
+        .path
+        .to_str()
+        .ok_or_else(|| anyhow::anyhow!("repo path is not valid UTF-8"))?;
+    let worktrees = match db.get_repo_by_path(repo_path)? {
+        Some(repo) => db.list_worktrees_by_tag(repo.id, filter)?,
+        None => Vec::new(),
+    };
+
+    for wt in &worktrees {
+        for op in &ops {
+            match op {
+                TagOp::Add(name) => db.add_tag(wt.id, name)?,
+                TagOp::Remove(name) => db.remove_tag(wt.id, name)?,
+            }
+        }
+    }
+
+    Ok(format!(
+        "Updated tags on {} worktree(s) tagged '{filter}'.\n",
+        worktrees.len()
+    ))
+}
+
+/// A tag and the worktrees carrying it, as shown by `trench tags`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TagSummary {
+    pub name: String,
+    pub count: usize,
+    pub worktrees: Vec<String>,
+}
+
+fn summarize_tags(cwd: &Path, db: &Database) -> Result<Vec<TagSummary>> {
+    let repo_info = git::discover_repo(cwd)?;
+    let repo_path = repo_info
+        .path
+        .to_str()
+        .ok_or_else(|| anyhow::anyhow!("repo path is not valid UTF-8"))?;
+    let Some(repo) = db.get_repo_by_path(repo_path)? else {
+        return Ok(Vec::new());
+    };
+
+    Ok(db
+        .list_all_tags(repo.id)?
+        .into_iter()
+        .map(|(name, worktrees)| TagSummary {
+            name,
+            count: worktrees.len(),
+            worktrees,
+        })
+        .collect())
+}
+
+/// Execute the `trench tags` command.
+///
+/// Lists every tag in the current repo with the worktrees carrying it.
+pub fn execute_all(cwd: &Path, db: &Database) -> Result<String> {
+    let tags = summarize_tags(cwd, db)?;
+    if tags.is_empty() {
+        return Ok("No tags. Use `trench tag <branch> +name` to add one.\n".to_string());
+    }
+
+    let mut table = Table::new(vec!["Tag", "Count", "Worktrees"]);
+    for tag in &tags {
+        let count = tag.count.to_string();
+        let worktrees = tag.worktrees.join(", ");
+        table = table.row(vec![&tag.name, &count, &worktrees]);
+    }
+    Ok(table.render())
+}
+
+/// Execute the `trench tags --json` command.
+pub fn execute_all_json(cwd: &Path, db: &Database) -> Result<String> {
+    format_json(&summarize_tags(cwd, db)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -94,6 +192,7 @@ mod tests {
             repo_dir,
             wt_root.path(),
             crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             db,
         )
         .expect("create should succeed");
@@ -142,6 +241,30 @@ mod tests {
         assert!(err.to_string().contains("cannot be empty"));
     }
 
+    #[test]
+    fn parse_rejects_comma_and_whitespace_in_tag_name() {
+        let err = parse_tag_args(&["+a,b".to_string()]).unwrap_err();
+        assert!(
+            err.to_string().contains("invalid tag name 'a,b'"),
+            "got: {err}"
+        );
+
+        let err = parse_tag_args(&["+has space".to_string()]).unwrap_err();
+        assert!(err.to_string().contains("invalid tag name"), "got: {err}");
+    }
+
+    #[test]
+    fn parse_accepts_tag_with_digits_and_dash() {
+        let ops = parse_tag_args(&["+wip-2".to_string()]).unwrap();
+        assert_eq!(ops, vec![TagOp::Add("wip-2".to_string())]);
+    }
+
+    #[test]
+    fn parse_allows_removing_legacy_invalid_tag() {
+        let ops = parse_tag_args(&["-has space".to_string()]).unwrap();
+        assert_eq!(ops, vec![TagOp::Remove("has space".to_string())]);
+    }
+
     #[test]
     fn execute_adds_tags_to_worktree() {
         let repo_dir = tempfile::tempdir().unwrap();
@@ -263,6 +386,116 @@ mod tests {
         assert!(tags.is_empty());
     }
 
+    #[test]
+    fn execute_all_aggregates_overlapping_tags() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let _repo = init_repo_with_commit(repo_dir.path());
+        let db = Database::open_in_memory().unwrap();
+        let (_a_root, _) = create_live_worktree(repo_dir.path(), &db, "feature-a");
+        let (_b_root, _) = create_live_worktree(repo_dir.path(), &db, "feature-b");
+        execute("feature-a", &["+wip".to_string()], repo_dir.path(), &db).unwrap();
+        execute(
+            "feature-b",
+            &["+wip".to_string(), "+review".to_string()],
+            repo_dir.path(),
+            &db,
+        )
+        .unwrap();
+
+        let output = execute_all(repo_dir.path(), &db).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert!(lines[0].starts_with("Tag"), "header first: {output}");
+        assert!(lines[1].starts_with("review") && lines[1].contains("feature-b"));
+        assert!(lines[2].starts_with("wip") && lines[2].contains("feature-a, feature-b"));
+
+        let json: serde_json::Value =
+            serde_json::from_str(&execute_all_json(repo_dir.path(), &db).unwrap()).unwrap();
+        assert_eq!(json[1]["name"], "wip");
+        assert_eq!(json[1]["count"], 2);
+        assert_eq!(json[1]["worktrees"][0], "feature-a");
+    }
+
+    #[test]
+    fn execute_all_reports_no_tags_for_untracked_repo() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let _repo = init_repo_with_commit(repo_dir.path());
+        let db = Database::open_in_memory().unwrap();
+
+        let output = execute_all(repo_dir.path(), &db).unwrap();
+
+        assert!(output.starts_with("No tags."), "got: {output}");
+        assert_eq!(execute_all_json(repo_dir.path(), &db).unwrap(), "[]");
+    }
+
+    #[test]
+    fn execute_all_matching_adds_tag_to_every_matching_worktree() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let _repo = init_repo_with_commit(repo_dir.path());
+        let db = Database::open_in_memory().unwrap();
+        let (_a_root, _) = create_live_worktree(repo_dir.path(), &db, "feature-a");
+        let (_b_root, _) = create_live_worktree(repo_dir.path(), &db, "feature-b");
+        let (_c_root, _) = create_live_worktree(repo_dir.path(), &db, "feature-c");
+        for branch in ["feature-a", "feature-b"] {
+            execute(branch, &["+wip".to_string()], repo_dir.path(), &db).unwrap();
+        }
+
+        let output =
+            execute_all_matching("wip", &["+review".to_string()], repo_dir.path(), &db).unwrap();
+
+        assert_eq!(output, "Updated tags on 2 worktree(s) tagged 'wip'.\n");
+        let repo_path = repo_dir.path().canonicalize().unwrap();
+        let db_repo = db
+            .get_repo_by_path(repo_path.to_str().unwrap())
+            .unwrap()
+            .unwrap();
+        for (branch, expected) in [
+            ("feature-a", vec!["review", "wip"]),
+            ("feature-b", vec!["review", "wip"]),
+            ("feature-c", vec![]),
+        ] {
+            let wt = db
+                .find_worktree_by_identifier(db_repo.id, branch)
+                .unwrap()
+                .unwrap();
+            assert_eq!(db.list_tags(wt.id).unwrap(), expected, "{branch}");
+        }
+    }
+
+    #[test]
+    fn execute_all_matching_can_retire_the_filter_tag() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let _repo = init_repo_with_commit(repo_dir.path());
+        let db = Database::open_in_memory().unwrap();
+        let (_a_root, _) = create_live_worktree(repo_dir.path(), &db, "feature-a");
+        execute("feature-a", &["+wip".to_string()], repo_dir.path(), &db).unwrap();
+
+        let output = execute_all_matching(
+            "wip",
+            &["-wip".to_string(), "+done".to_string()],
+            repo_dir.path(),
+            &db,
+        )
+        .unwrap();
+
+        assert!(output.contains("1 worktree(s)"), "got: {output}");
+        let listed = execute("feature-a", &[], repo_dir.path(), &db).unwrap();
+        assert_eq!(listed, "done\n");
+    }
+
+    #[test]
+    fn execute_all_matching_requires_operations() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let _repo = init_repo_with_commit(repo_dir.path());
+        let db = Database::open_in_memory().unwrap();
+
+        let err = execute_all_matching("wip", &[], repo_dir.path(), &db).unwrap_err();
+
+        assert!(
+            err.to_string().contains("requires at least one"),
+            "got: {err}"
+        );
+    }
+
     fn init_repo_with_commit(dir: &Path) -> git2::Repository {
         let repo = git2::Repository::init(dir).expect("failed to init repo");
         {
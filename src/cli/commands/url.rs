@@ -0,0 +1,220 @@
+use std::path::Path;
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::git::{self, NormalizedRemote};
+use crate::output::json::format_json_value;
+use crate::state::Database;
+
+/// URL layout of a code host, selected from the remote's hostname.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HostKind {
+    GitHub,
+    GitLab,
+}
+
+impl HostKind {
+    /// Hosts with "gitlab" in their name use GitLab's `/-/` routes; everything
+    /// else gets the GitHub shape, which most forges (Gitea, Forgejo) share.
+    pub fn from_host(host: &str) -> Self {
+        if host.to_ascii_lowercase().contains("gitlab") {
+            HostKind::GitLab
+        } else {
+            HostKind::GitHub
+        }
+    }
+
+    fn route_prefix(self) -> &'static str {
+        match self {
+            HostKind::GitHub => "",
+            HostKind::GitLab => "/-",
+        }
+    }
+}
+
+/// Components of a `trench url` result, emitted as-is under `--json`.
+#[derive(Debug, Serialize)]
+pub struct UrlResult {
+    pub host: String,
+    pub host_kind: HostKind,
+    pub owner: String,
+    pub repo: String,
+    pub branch: String,
+    /// Base branch, present only for `--compare`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base: Option<String>,
+    pub url: String,
+}
+
+/// Percent-encode a branch name for a URL path, keeping `/` separators.
+fn encode_ref(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+    for byte in name.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => {
+                out.push(byte as char)
+            }
+            _ => out.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    out
+}
+
+/// Browser URL for `branch`, or for comparing it against `base`.
+pub fn build_url(remote: &NormalizedRemote, branch: &str, base: Option<&str>) -> String {
+    let prefix = HostKind::from_host(&remote.host).route_prefix();
+    match base {
+        Some(base) => format!(
+            "{}{prefix}/compare/{}...{}",
+            remote.https_url,
+            encode_ref(base),
+            encode_ref(branch)
+        ),
+        None => format!("{}{prefix}/tree/{}", remote.https_url, encode_ref(branch)),
+    }
+}
+
+fn resolve(cwd: &Path, db: &Database, identifier: &str, compare: bool) -> Result<UrlResult> {
+    let repo_info = git::discover_repo(cwd)?;
+    let remote = repo_info
+        .remote_url
+        .as_deref()
+        .and_then(git::normalize_remote_url)
+        .ok_or_else(|| {
+            anyhow::anyhow!("remote 'origin' is missing or is not a hosted repository URL")
+        })?;
+
+    // A tracked or live worktree contributes its real branch and base; any
+    // other identifier is taken as a branch name on the remote.
+    let worktree = crate::live_worktree::resolve_read_only(identifier, &repo_info, Some(db)).ok();
+    let branch = worktree
+        .as_ref()
+        .and_then(|wt| wt.entry.branch.clone())
+        .unwrap_or_else(|| identifier.to_string());
+    let base = compare.then(|| {
+        worktree
+            .as_ref()
+            .map(|wt| crate::live_worktree::base_branch(&repo_info, wt))
+            // A branch tracking its own remote copy has no useful base.
+            .filter(|base| *base != branch)
+            .or_else(|| {
+                worktree
+                    .as_ref()
+                    .and_then(|wt| wt.metadata.as_ref())
+                    .and_then(|m| m.base_branch.clone())
+            })
+            .unwrap_or_else(|| repo_info.default_branch.clone())
+    });
+
+    let url = build_url(&remote, &branch, base.as_deref());
+    Ok(UrlResult {
+        host_kind: HostKind::from_host(&remote.host),
+        host: remote.host,
+        owner: remote.owner,
+        repo: remote.repo,
+        branch,
+        base,
+        url,
+    })
+}
+
+/// Execute `trench url <branch>`, returning the browser URL.
+pub fn execute(cwd: &Path, db: &Database, identifier: &str, compare: bool) -> Result<String> {
+    Ok(resolve(cwd, db, identifier, compare)?.url)
+}
+
+/// Execute `trench url <branch> --json` as a single object.
+pub fn execute_json(cwd: &Path, db: &Database, identifier: &str, compare: bool) -> Result<String> {
+    format_json_value(&resolve(cwd, db, identifier, compare)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn init_repo_with_origin(dir: &Path, origin: &str) {
+        let repo = git2::Repository::init(dir).expect("failed to init repo");
+        let sig = git2::Signature::now("Test", "test@test.com").unwrap();
+        let tree_id = repo.index().unwrap().write_tree().unwrap();
+        let tree = repo.find_tree(tree_id).unwrap();
+        repo.commit(Some("HEAD"), &sig, &sig, "initial commit", &tree, &[])
+            .unwrap();
+        repo.remote("origin", origin).unwrap();
+    }
+
+    #[test]
+    fn github_ssh_remote_builds_tree_url() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        init_repo_with_origin(repo_dir.path(), "git@github.com:acme/app.git");
+        let db = Database::open_in_memory().unwrap();
+
+        let url = execute(repo_dir.path(), &db, "feature/login", false).unwrap();
+
+        assert_eq!(url, "https://github.com/acme/app/tree/feature/login");
+    }
+
+    #[test]
+    fn compare_uses_worktree_base_branch() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        init_repo_with_origin(repo_dir.path(), "git@github.com:acme/app.git");
+        let wt_root = tempfile::tempdir().unwrap();
+        let db = Database::open_in_memory().unwrap();
+        let base = git2::Repository::open(repo_dir.path())
+            .unwrap()
+            .head()
+            .unwrap()
+            .shorthand()
+            .unwrap()
+            .to_string();
+        crate::cli::commands::create::execute(
+            "feature/cmp",
+            None,
+            repo_dir.path(),
+            wt_root.path(),
+            crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
+            &db,
+        )
+        .unwrap();
+
+        let output = execute_json(repo_dir.path(), &db, "feature-cmp", true).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+        assert_eq!(json["host"], "github.com");
+        assert_eq!(json["host_kind"], "github");
+        assert_eq!(json["owner"], "acme");
+        assert_eq!(json["repo"], "app");
+        assert_eq!(json["branch"], "feature/cmp");
+        assert_eq!(json["base"], base.as_str());
+        assert_eq!(
+            json["url"],
+            format!("https://github.com/acme/app/compare/{base}...feature/cmp")
+        );
+    }
+
+    #[test]
+    fn gitlab_host_uses_dash_routes() {
+        let remote =
+            git::normalize_remote_url("https://gitlab.example.com/group/sub/proj").unwrap();
+        assert_eq!(
+            build_url(&remote, "fix #1", None),
+            "https://gitlab.example.com/group/sub/proj/-/tree/fix%20%231"
+        );
+        assert_eq!(
+            build_url(&remote, "topic", Some("main")),
+            "https://gitlab.example.com/group/sub/proj/-/compare/main...topic"
+        );
+    }
+
+    #[test]
+    fn missing_origin_is_an_error() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        git2::Repository::init(repo_dir.path()).unwrap();
+        let db = Database::open_in_memory().unwrap();
+
+        let err = execute(repo_dir.path(), &db, "main", false).unwrap_err();
+        assert!(err.to_string().contains("origin"), "{err}");
+    }
+}
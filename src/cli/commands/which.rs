@@ -0,0 +1,139 @@
+use std::path::Path;
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::git;
+use crate::output::json::format_json_value;
+use crate::state::Database;
+
+/// Location of a worktree, emitted as-is under `--json`.
+#[derive(Debug, Serialize)]
+pub struct WhichResult {
+    pub path: String,
+}
+
+/// Resolve a worktree's path without recording an access or adopting it.
+fn resolve(cwd: &Path, db: &Database, identifier: &str) -> Result<WhichResult> {
+    let repo_info = git::discover_repo(cwd)?;
+    let worktree = crate::live_worktree::resolve_read_only(identifier, &repo_info, Some(db))?;
+    let path = match &worktree.metadata {
+        Some(meta) => meta.path.clone(),
+        None => worktree.entry.path.to_string_lossy().into_owned(),
+    };
+    Ok(WhichResult { path })
+}
+
+/// Execute `trench which <branch>`: just the worktree path.
+pub fn execute(cwd: &Path, db: &Database, identifier: &str) -> Result<String> {
+    Ok(resolve(cwd, db, identifier)?.path)
+}
+
+/// Execute `trench which <branch> --json`.
+pub fn execute_json(cwd: &Path, db: &Database, identifier: &str) -> Result<String> {
+    format_json_value(&serde_json::to_value(resolve(cwd, db, identifier)?)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn init_repo_with_commit(dir: &Path) {
+        let repo = git2::Repository::init(dir).expect("failed to init repo");
+        let sig = git2::Signature::now("Test", "test@test.com").unwrap();
+        let tree_id = repo.index().unwrap().write_tree().unwrap();
+        let tree = repo.find_tree(tree_id).unwrap();
+        repo.commit(Some("HEAD"), &sig, &sig, "initial commit", &tree, &[])
+            .unwrap();
+    }
+
+    #[test]
+    fn prints_path_without_touching_last_accessed() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        init_repo_with_commit(repo_dir.path());
+        let wt_root = tempfile::tempdir().unwrap();
+        let db = Database::open_in_memory().unwrap();
+        let created = crate::cli::commands::create::execute(
+            "feature/which",
+            None,
+            repo_dir.path(),
+            wt_root.path(),
+            crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
+            &db,
+        )
+        .unwrap();
+
+        let output = execute(repo_dir.path(), &db, "feature/which").unwrap();
+        assert_eq!(output, created.path.to_string_lossy());
+
+        let json: serde_json::Value =
+            serde_json::from_str(&execute_json(repo_dir.path(), &db, "feature-which").unwrap())
+                .unwrap();
+        assert_eq!(json, serde_json::json!({ "path": output }));
+
+        let repo_path = repo_dir.path().canonicalize().unwrap();
+        let repo = db
+            .get_repo_by_path(repo_path.to_str().unwrap())
+            .unwrap()
+            .unwrap();
+        let wt = db
+            .find_worktree_by_identifier(repo.id, "feature-which")
+            .unwrap()
+            .unwrap();
+        assert!(
+            wt.last_accessed.is_none(),
+            "which must not record an access"
+        );
+    }
+
+    #[test]
+    fn name_of_one_worktree_and_branch_of_another_is_ambiguous() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        init_repo_with_commit(repo_dir.path());
+        let wt_root = tempfile::tempdir().unwrap();
+        let db = Database::open_in_memory().unwrap();
+        for (branch, name) in [("feature/long", "short"), ("short", "other")] {
+            crate::cli::commands::create::execute_with_options(
+                branch,
+                None,
+                repo_dir.path(),
+                wt_root.path(),
+                crate::paths::DEFAULT_WORKTREE_TEMPLATE,
+                None,
+                true,
+                Some(name),
+                &db,
+            )
+            .unwrap();
+        }
+
+        let err = execute(repo_dir.path(), &db, "short").unwrap_err();
+
+        assert!(
+            matches!(
+                err.downcast_ref::<crate::state::LookupError>(),
+                Some(crate::state::LookupError::Multiple { .. })
+            ),
+            "got: {err:#}"
+        );
+        assert!(
+            err.to_string()
+                .contains("short (branch feature/long), other (branch short)"),
+            "got: {err}"
+        );
+        assert!(execute(repo_dir.path(), &db, "other").is_ok());
+        assert!(execute(repo_dir.path(), &db, "feature/long").is_ok());
+    }
+
+    #[test]
+    fn unknown_branch_is_not_found() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        init_repo_with_commit(repo_dir.path());
+        let db = Database::open_in_memory().unwrap();
+
+        let err = execute(repo_dir.path(), &db, "no-such-branch").unwrap_err();
+
+        assert!(err.to_string().contains("not found"), "got: {err}");
+    }
+}
@@ -0,0 +1,200 @@
+use std::io::{BufRead, Write};
+
+use anyhow::Result;
+
+/// Ask `message` on `writer` and read a one-line answer from `reader`.
+///
+/// Only `y` or `yes` (any case) confirm. Anything else, including empty
+/// input and EOF, answers No.
+pub fn prompt_yes_no<R: BufRead, W: Write>(
+    message: &str,
+    reader: &mut R,
+    writer: &mut W,
+) -> Result<bool> {
+    write!(writer, "{message} [y/N] ")?;
+    writer.flush()?;
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let answer = line.trim();
+    Ok(answer.eq_ignore_ascii_case("y") || answer.eq_ignore_ascii_case("yes"))
+}
+
+/// Ask `message` on the terminal, reading stdin and writing the prompt to
+/// stderr so stdout stays clean for command output.
+pub fn confirm(message: &str) -> Result<bool> {
+    let stdin = std::io::stdin();
+    let stderr = std::io::stderr();
+    prompt_yes_no(message, &mut stdin.lock(), &mut stderr.lock())
+}
+
+/// Map a menu answer to an index into `len` options.
+///
+/// An empty answer picks `default`; `1..=len` picks that (1-based) entry.
+/// Anything else is `None`.
+pub fn parse_choice(answer: &str, len: usize, default: usize) -> Option<usize> {
+    let answer = answer.trim();
+    if answer.is_empty() {
+        return (default < len).then_some(default);
+    }
+    match answer.parse::<usize>() {
+        Ok(n) if (1..=len).contains(&n) => Some(n - 1),
+        _ => None,
+    }
+}
+
+/// Show `options` as a numbered menu on `writer` and read answers from
+/// `reader` until one is valid.
+///
+/// Returns the chosen index, or `None` on EOF (the user gave up).
+pub fn select<R: BufRead, W: Write>(
+    message: &str,
+    options: &[String],
+    default: usize,
+    reader: &mut R,
+    writer: &mut W,
+) -> Result<Option<usize>> {
+    writeln!(writer, "{message}")?;
+    for (i, option) in options.iter().enumerate() {
+        let marker = if i == default { " (default)" } else { "" };
+        writeln!(writer, "  {}) {option}{marker}", i + 1)?;
+    }
+    loop {
+        write!(writer, "Choose [1-{}]: ", options.len())?;
+        writer.flush()?;
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        match parse_choice(&line, options.len(), default) {
+            Some(index) => return Ok(Some(index)),
+            None => writeln!(writer, "Invalid choice: {}", line.trim())?,
+        }
+    }
+}
+
+/// [`select`] on the terminal: stdin for answers, stderr for the menu.
+pub fn select_on_terminal(
+    message: &str,
+    options: &[String],
+    default: usize,
+) -> Result<Option<usize>> {
+    let stdin = std::io::stdin();
+    let stderr = std::io::stderr();
+    select(
+        message,
+        options,
+        default,
+        &mut stdin.lock(),
+        &mut stderr.lock(),
+    )
+}
+
+/// Resolve a confirmation, answering Yes without calling `ask` when
+/// `assume_yes` is set (`--yes`, `TRENCH_ASSUME_YES`, or a command's own
+/// `--force`).
+pub fn confirm_or_assume(assume_yes: bool, ask: impl FnOnce() -> Result<bool>) -> Result<bool> {
+    if assume_yes {
+        return Ok(true);
+    }
+    ask()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answer(input: &str) -> (bool, String) {
+        let mut reader = input.as_bytes();
+        let mut writer = Vec::new();
+        let confirmed = prompt_yes_no("Remove?", &mut reader, &mut writer).unwrap();
+        (confirmed, String::from_utf8(writer).unwrap())
+    }
+
+    #[test]
+    fn y_confirms_and_writes_prompt() {
+        let (confirmed, written) = answer("y\n");
+        assert!(confirmed);
+        assert_eq!(written, "Remove? [y/N] ");
+    }
+
+    #[test]
+    fn uppercase_and_long_forms_confirm() {
+        assert!(answer("Y\n").0);
+        assert!(answer("yes\n").0);
+        assert!(answer("  YES  \n").0);
+    }
+
+    #[test]
+    fn n_declines() {
+        assert!(!answer("n\n").0);
+    }
+
+    #[test]
+    fn empty_line_defaults_to_no() {
+        assert!(!answer("\n").0);
+    }
+
+    #[test]
+    fn eof_defaults_to_no() {
+        assert!(!answer("").0);
+    }
+
+    #[test]
+    fn other_words_decline() {
+        assert!(!answer("yep\n").0);
+    }
+
+    #[test]
+    fn assume_yes_skips_the_question() {
+        let confirmed = confirm_or_assume(true, || panic!("should not ask")).unwrap();
+        assert!(confirmed);
+    }
+
+    #[test]
+    fn without_assume_yes_the_answer_decides() {
+        assert!(!confirm_or_assume(false, || Ok(answer("n\n").0)).unwrap());
+        assert!(confirm_or_assume(false, || Ok(answer("y\n").0)).unwrap());
+    }
+
+    fn options() -> Vec<String> {
+        ["develop", "main", "release"].map(String::from).to_vec()
+    }
+
+    #[test]
+    fn parse_choice_maps_numbers_and_empty_to_indexes() {
+        assert_eq!(parse_choice("2\n", 3, 0), Some(1));
+        assert_eq!(parse_choice("  3 ", 3, 0), Some(2));
+        assert_eq!(parse_choice("\n", 3, 1), Some(1));
+        assert_eq!(parse_choice("0", 3, 1), None);
+        assert_eq!(parse_choice("4", 3, 1), None);
+        assert_eq!(parse_choice("main", 3, 1), None);
+        assert_eq!(parse_choice("", 0, 0), None);
+    }
+
+    #[test]
+    fn select_reprompts_until_valid_and_marks_default() {
+        let mut reader = "9\nx\n3\n".as_bytes();
+        let mut writer = Vec::new();
+
+        let index = select("Base branch:", &options(), 1, &mut reader, &mut writer).unwrap();
+
+        assert_eq!(index.map(|i| options()[i].clone()), Some("release".into()));
+        let written = String::from_utf8(writer).unwrap();
+        assert!(written.contains("  2) main (default)\n"), "{written}");
+        assert_eq!(written.matches("Invalid choice").count(), 2, "{written}");
+    }
+
+    #[test]
+    fn select_returns_none_on_eof() {
+        let mut writer = Vec::new();
+        let index = select(
+            "Base branch:",
+            &options(),
+            0,
+            &mut "".as_bytes(),
+            &mut writer,
+        )
+        .unwrap();
+        assert!(index.is_none());
+    }
+}
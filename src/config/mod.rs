@@ -1,8 +1,11 @@
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 
 use anyhow::{Context, Result};
 use serde::Deserialize;
 
+use crate::git::SyncStrategy;
 use crate::paths;
 
 // --- Hook types (FR-18, FR-19) ---
@@ -13,6 +16,37 @@ fn default_timeout_secs() -> Option<u64> {
     Some(DEFAULT_HOOK_TIMEOUT_SECS)
 }
 
+/// Overwrite policy for files the copy step finds already present in the
+/// worktree.
+#[derive(Debug, Default, Deserialize, serde::Serialize, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "kebab-case")]
+pub enum CopyOverwrite {
+    /// Replace the existing file (the historical behavior).
+    #[default]
+    Always,
+    /// Keep the existing file.
+    Never,
+    /// Replace the existing file only if the source was modified more recently.
+    IfNewer,
+}
+
+/// Glob matching options for a hook's `copy` patterns, e.g.
+/// `copy_options = { case_insensitive = true }`.
+///
+/// Brace alternation (`{*.json,*.toml}`) is always available; these flags
+/// only change how patterns match. Every flag defaults to `false`.
+#[derive(Debug, Default, Deserialize, serde::Serialize, PartialEq, Eq, Clone, Copy)]
+#[serde(default)]
+pub struct CopyOptions {
+    /// Match letters regardless of case (`*.ENV` matches `.env`).
+    pub case_insensitive: bool,
+    /// Keep `*` and `?` from matching `/`, so `*.json` only matches at the
+    /// repo root and nested files need `**/`.
+    pub literal_separator: bool,
+    /// Allow empty alternatives, so `.env{,.local}` matches `.env` too.
+    pub empty_alternates: bool,
+}
+
 #[derive(Debug, Deserialize, serde::Serialize, PartialEq, Clone)]
 pub struct HookDef {
     pub copy: Option<Vec<String>>,
@@ -20,6 +54,18 @@ pub struct HookDef {
     pub shell: Option<String>,
     #[serde(default = "default_timeout_secs")]
     pub timeout_secs: Option<u64>,
+    /// What the copy step does when a destination file already exists;
+    /// unset means [`CopyOverwrite::Always`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub copy_overwrite: Option<CopyOverwrite>,
+    /// Glob matching options for `copy` patterns; unset uses the defaults
+    /// documented on [`CopyOptions`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub copy_options: Option<CopyOptions>,
+    /// `KEY = "template"` values applied to `KEY=...` lines of copied text
+    /// files; templates may use `{{ branch }}` and `{{ worktree_name }}`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rewrite: Option<BTreeMap<String, String>>,
 }
 
 impl Default for HookDef {
@@ -29,6 +75,9 @@ impl Default for HookDef {
             run: None,
             shell: None,
             timeout_secs: Some(DEFAULT_HOOK_TIMEOUT_SECS),
+            copy_overwrite: None,
+            copy_options: None,
+            rewrite: None,
         }
     }
 }
@@ -73,6 +122,10 @@ pub struct UiConfig {
     pub show_ahead_behind: Option<bool>,
     pub show_dirty_count: Option<bool>,
     pub auto_refresh: Option<bool>,
+    /// Fixed column budget for tables; unset means use the terminal width.
+    pub table_width: Option<usize>,
+    /// Command used by `trench open`; `{path}` is replaced by the worktree path.
+    pub open_cmd: Option<String>,
 }
 
 #[derive(Debug, Default, Deserialize, PartialEq)]
@@ -80,6 +133,12 @@ pub struct GitConfig {
     pub default_base: Option<String>,
     pub auto_prune: Option<bool>,
     pub fetch_on_open: Option<bool>,
+    /// Record a new worktree's base as its branch's upstream when it has one.
+    pub base_from_upstream: Option<bool>,
+    /// Strategy `trench sync` uses when `--strategy` is not given.
+    pub sync_strategy: Option<SyncStrategy>,
+    /// Ask for the base branch when `create` runs on a TTY without `--from`.
+    pub interactive_base: Option<bool>,
 }
 
 #[derive(Debug, Default, Deserialize, PartialEq)]
@@ -91,6 +150,11 @@ pub struct EditorConfig {
 pub struct WorktreesConfig {
     pub root: Option<String>,
     pub scan: Option<Vec<String>>,
+    pub sparse: Option<Vec<String>>,
+    /// Named worktree path templates selectable with `create --template`.
+    pub templates: Option<BTreeMap<String, String>>,
+    /// Symlink repointed at the target worktree on `switch` and `open`.
+    pub current_symlink: Option<String>,
 }
 
 #[derive(Debug, Default, Deserialize, PartialEq)]
@@ -133,6 +197,11 @@ pub fn load_project_config_from(path: &Path) -> Result<Option<ProjectConfig>> {
 pub struct CliConfigOverrides {
     pub default_base: Option<String>,
     pub worktree_root: Option<String>,
+    /// Name of a `[worktrees.templates]` entry to use as the path template.
+    pub worktree_template: Option<String>,
+    pub table_width: Option<usize>,
+    pub base_from_upstream: Option<bool>,
+    pub open_cmd: Option<String>,
 }
 
 #[derive(Debug, PartialEq)]
@@ -163,6 +232,8 @@ pub struct ResolvedUiConfig {
     pub show_ahead_behind: bool,
     pub show_dirty_count: bool,
     pub auto_refresh: bool,
+    pub table_width: Option<usize>,
+    pub open_cmd: Option<String>,
 }
 
 #[derive(Debug, PartialEq)]
@@ -170,12 +241,54 @@ pub struct ResolvedGitConfig {
     pub default_base: String,
     pub auto_prune: bool,
     pub fetch_on_open: bool,
+    pub base_from_upstream: bool,
+    pub sync_strategy: Option<SyncStrategy>,
+    pub interactive_base: bool,
 }
 
 #[derive(Debug, PartialEq)]
 pub struct ResolvedWorktreesConfig {
     pub root: String,
     pub scan: Vec<String>,
+    /// Directories to materialize via sparse-checkout; `None` = full checkout.
+    pub sparse: Option<Vec<String>>,
+    /// Named path templates; project entries override global ones by name.
+    pub templates: BTreeMap<String, String>,
+    /// Template name selected on the command line, if any.
+    pub selected_template: Option<String>,
+    /// "Current worktree" symlink path, unexpanded; `None` = not managed.
+    pub current_symlink: Option<String>,
+}
+
+impl ResolvedWorktreesConfig {
+    /// Return the path template to render: the selected named template when
+    /// one was requested, otherwise `root`.
+    ///
+    /// Errors if the template renders the same path for every branch.
+    pub fn path_template(&self) -> Result<&str> {
+        let template = self.selected_path_template()?;
+        crate::paths::ensure_template_varies_by_branch(template)?;
+        Ok(template)
+    }
+
+    fn selected_path_template(&self) -> Result<&str> {
+        let Some(name) = self.selected_template.as_deref() else {
+            return Ok(&self.root);
+        };
+        match self.templates.get(name) {
+            Some(template) => Ok(template),
+            None if self.templates.is_empty() => anyhow::bail!(
+                "unknown worktree template '{name}': no [worktrees.templates] are configured"
+            ),
+            None => {
+                let known: Vec<&str> = self.templates.keys().map(String::as_str).collect();
+                anyhow::bail!(
+                    "unknown worktree template '{name}' (available: {})",
+                    known.join(", ")
+                )
+            }
+        }
+    }
 }
 
 impl Default for ResolvedUiConfig {
@@ -186,6 +299,8 @@ impl Default for ResolvedUiConfig {
             show_ahead_behind: true,
             show_dirty_count: true,
             auto_refresh: true,
+            table_width: None,
+            open_cmd: None,
         }
     }
 }
@@ -196,6 +311,9 @@ impl Default for ResolvedGitConfig {
             default_base: "main".to_string(),
             auto_prune: false,
             fetch_on_open: true,
+            base_from_upstream: false,
+            sync_strategy: None,
+            interactive_base: false,
         }
     }
 }
@@ -205,6 +323,10 @@ impl Default for ResolvedWorktreesConfig {
         Self {
             root: crate::paths::DEFAULT_WORKTREE_TEMPLATE.to_string(),
             scan: Vec::new(),
+            sparse: None,
+            templates: BTreeMap::new(),
+            selected_template: None,
+            current_symlink: None,
         }
     }
 }
@@ -268,6 +390,16 @@ pub fn resolve_config(
                 .and_then(|u| u.auto_refresh)
                 .or_else(|| g_ui.and_then(|u| u.auto_refresh))
                 .unwrap_or(defaults_ui.auto_refresh),
+            table_width: cli
+                .and_then(|c| c.table_width)
+                .or_else(|| p_ui.and_then(|u| u.table_width))
+                .or_else(|| g_ui.and_then(|u| u.table_width))
+                .or(defaults_ui.table_width),
+            open_cmd: cli
+                .and_then(|c| c.open_cmd.clone())
+                .or_else(|| p_ui.and_then(|u| u.open_cmd.clone()))
+                .or_else(|| g_ui.and_then(|u| u.open_cmd.clone()))
+                .or(defaults_ui.open_cmd),
         },
         git: ResolvedGitConfig {
             default_base: cli
@@ -283,6 +415,19 @@ pub fn resolve_config(
                 .and_then(|g| g.fetch_on_open)
                 .or_else(|| g_git.and_then(|g| g.fetch_on_open))
                 .unwrap_or(defaults_git.fetch_on_open),
+            base_from_upstream: cli
+                .and_then(|c| c.base_from_upstream)
+                .or_else(|| p_git.and_then(|g| g.base_from_upstream))
+                .or_else(|| g_git.and_then(|g| g.base_from_upstream))
+                .unwrap_or(defaults_git.base_from_upstream),
+            sync_strategy: p_git
+                .and_then(|g| g.sync_strategy)
+                .or_else(|| g_git.and_then(|g| g.sync_strategy))
+                .or(defaults_git.sync_strategy),
+            interactive_base: p_git
+                .and_then(|g| g.interactive_base)
+                .or_else(|| g_git.and_then(|g| g.interactive_base))
+                .unwrap_or(defaults_git.interactive_base),
         },
         editor_command,
         shell: ResolvedShellConfig {
@@ -301,11 +446,45 @@ pub fn resolve_config(
                 .and_then(|w| w.scan.clone())
                 .or_else(|| g_wt.and_then(|w| w.scan.clone()))
                 .unwrap_or(defaults_wt.scan),
+            sparse: p_wt
+                .and_then(|w| w.sparse.clone())
+                .or_else(|| g_wt.and_then(|w| w.sparse.clone()))
+                .or(defaults_wt.sparse),
+            templates: g_wt
+                .and_then(|w| w.templates.clone())
+                .into_iter()
+                .chain(p_wt.and_then(|w| w.templates.clone()))
+                .flatten()
+                .collect(),
+            selected_template: cli.and_then(|c| c.worktree_template.clone()),
+            current_symlink: p_wt
+                .and_then(|w| w.current_symlink.clone())
+                .or_else(|| g_wt.and_then(|w| w.current_symlink.clone())),
         },
         hooks,
     }
 }
 
+/// Use the repo's stored path template (`trench config set-template`) as the
+/// worktree root unless the CLI or project config already set one.
+pub fn apply_repo_template(
+    resolved: &mut ResolvedConfig,
+    cli: Option<&CliConfigOverrides>,
+    project: Option<&ProjectConfig>,
+    repo_template: Option<&str>,
+) {
+    let Some(template) = repo_template else {
+        return;
+    };
+    let cli_root = cli.and_then(|c| c.worktree_root.as_ref());
+    let project_root = project
+        .and_then(|p| p.worktrees.as_ref())
+        .and_then(|w| w.root.as_ref());
+    if cli_root.is_none() && project_root.is_none() {
+        resolved.worktrees.root = template.to_string();
+    }
+}
+
 pub const PROJECT_CONFIG_FILENAME: &str = ".trench.toml";
 
 /// Load project config from the repo root directory.
@@ -325,21 +504,66 @@ pub fn load_global_config_from(path: &Path) -> Result<GlobalConfig> {
     load_optional_toml(path).map(|opt| opt.unwrap_or_default())
 }
 
+/// Environment variable naming an alternate global config file.
+pub const CONFIG_ENV: &str = "TRENCH_CONFIG";
+
+/// Global config file chosen with `--config`, set once at startup.
+static GLOBAL_CONFIG_OVERRIDE: OnceLock<PathBuf> = OnceLock::new();
+
+/// Use `path` as the global config file for the rest of the process
+/// (`--config`). Takes precedence over `TRENCH_CONFIG`.
+pub fn set_global_config_path(path: PathBuf) {
+    let _ = GLOBAL_CONFIG_OVERRIDE.set(path);
+}
+
 /// Return the path to the global config file (`~/.config/trench/config.toml`).
 ///
+/// `--config` wins over `TRENCH_CONFIG`, which wins over the XDG default.
 /// Uses the non-mutating path accessor so config loading never creates
 /// directories as a side effect (important for `--dry-run`).
-pub fn global_config_path() -> Result<PathBuf> {
-    Ok(paths::config_dir_path()?.join("config.toml"))
+fn global_config_path_with(
+    flag: Option<&Path>,
+    env_value: Option<std::ffi::OsString>,
+) -> Result<PathBuf> {
+    match explicit_config_path(flag, env_value) {
+        Some(path) => Ok(path),
+        None => Ok(paths::config_dir_path()?.join("config.toml")),
+    }
+}
+
+/// Config file named by `--config` or `TRENCH_CONFIG`, if any.
+fn explicit_config_path(
+    flag: Option<&Path>,
+    env_value: Option<std::ffi::OsString>,
+) -> Option<PathBuf> {
+    flag.map(Path::to_path_buf)
+        .or_else(|| env_value.filter(|v| !v.is_empty()).map(PathBuf::from))
 }
 
 /// Load global config from the XDG config directory.
 ///
-/// Reads `~/.config/trench/config.toml` (or platform equivalent).
-/// Returns defaults if the file does not exist.
+/// Reads `~/.config/trench/config.toml` (or platform equivalent), or the
+/// file named by `--config`/`TRENCH_CONFIG`. Returns defaults if the XDG
+/// file does not exist; a missing explicitly named file is an error.
 pub fn load_global_config() -> Result<GlobalConfig> {
-    let path = global_config_path()?;
-    load_global_config_from(&path)
+    load_global_config_with(
+        GLOBAL_CONFIG_OVERRIDE.get().map(PathBuf::as_path),
+        std::env::var_os(CONFIG_ENV),
+    )
+}
+
+fn load_global_config_with(
+    flag: Option<&Path>,
+    env_value: Option<std::ffi::OsString>,
+) -> Result<GlobalConfig> {
+    match explicit_config_path(flag, env_value) {
+        Some(path) if !path.exists() => anyhow::bail!(
+            "config file {} does not exist (set by --config or {CONFIG_ENV})",
+            path.display()
+        ),
+        Some(path) => load_global_config_from(&path),
+        None => load_global_config_from(&global_config_path_with(None, None)?),
+    }
 }
 
 #[cfg(test)]
@@ -602,11 +826,127 @@ show_ahead_behind = "yes"
 
     #[test]
     fn global_config_path_points_to_xdg_config() {
-        let path = global_config_path().unwrap();
+        let path = global_config_path_with(None, None).unwrap();
         assert!(path.ends_with("trench/config.toml"));
         assert!(path.starts_with(dirs::config_dir().unwrap()));
     }
 
+    #[test]
+    fn config_override_flag_beats_env_and_flows_into_resolve() {
+        let dir = TempDir::new().unwrap();
+        let custom = write_config(
+            &dir,
+            r#"
+[ui]
+theme = "custom-theme"
+
+[worktrees]
+root = "/srv/isolated"
+"#,
+        );
+
+        let path =
+            global_config_path_with(Some(&custom), Some("/elsewhere/config.toml".into())).unwrap();
+        assert_eq!(path, custom);
+        assert_eq!(
+            global_config_path_with(None, Some("/elsewhere/config.toml".into())).unwrap(),
+            PathBuf::from("/elsewhere/config.toml")
+        );
+        assert_eq!(
+            global_config_path_with(None, Some("".into())).unwrap(),
+            global_config_path_with(None, None).unwrap()
+        );
+
+        let global = load_global_config_from(&path).unwrap();
+        let resolved = resolve_config(None, None, &global);
+        assert_eq!(resolved.ui.theme, "custom-theme");
+        assert_eq!(resolved.worktrees.root, "/srv/isolated");
+    }
+
+    #[test]
+    fn explicit_config_path_must_exist() {
+        let dir = TempDir::new().unwrap();
+        let missing = dir.path().join("nope.toml");
+
+        let err = load_global_config_with(Some(&missing), None).unwrap_err();
+        assert!(err.to_string().contains("does not exist"), "{err}");
+        let err =
+            load_global_config_with(None, Some(missing.clone().into_os_string())).unwrap_err();
+        assert!(err.to_string().contains("nope.toml"), "{err}");
+
+        let custom = write_config(&dir, "[ui]\ntheme = \"custom-theme\"\n");
+        let global = load_global_config_with(Some(&custom), None).unwrap();
+        assert_eq!(global.ui.unwrap().theme.as_deref(), Some("custom-theme"));
+    }
+
+    #[test]
+    fn hook_rewrite_table_deserializes() {
+        let config: ProjectConfig = toml::from_str(
+            r#"
+[hooks.post_create]
+copy = [".env"]
+rewrite = { DB_NAME = "app_{{ worktree_name }}", PORT = "3000" }
+"#,
+        )
+        .unwrap();
+
+        let post_create = config.hooks.unwrap().post_create.unwrap();
+        let rewrite = post_create.rewrite.expect("rewrite should be present");
+        assert_eq!(rewrite["DB_NAME"], "app_{{ worktree_name }}");
+        assert_eq!(rewrite["PORT"], "3000");
+    }
+
+    #[test]
+    fn hook_copy_overwrite_deserializes_kebab_case() {
+        let config: ProjectConfig = toml::from_str(
+            r#"
+[hooks.post_create]
+copy = [".env"]
+copy_overwrite = "if-newer"
+
+[hooks.post_sync]
+copy = [".env"]
+"#,
+        )
+        .unwrap();
+
+        let hooks = config.hooks.unwrap();
+        assert_eq!(
+            hooks.post_create.unwrap().copy_overwrite,
+            Some(CopyOverwrite::IfNewer)
+        );
+        assert_eq!(hooks.post_sync.unwrap().copy_overwrite, None);
+        assert_eq!(CopyOverwrite::default(), CopyOverwrite::Always);
+    }
+
+    #[test]
+    fn hook_copy_options_default_missing_flags_to_false() {
+        let config: ProjectConfig = toml::from_str(
+            r#"
+[hooks.post_create]
+copy = ["*.ENV"]
+copy_options = { case_insensitive = true }
+"#,
+        )
+        .unwrap();
+
+        let options = config
+            .hooks
+            .unwrap()
+            .post_create
+            .unwrap()
+            .copy_options
+            .expect("copy_options should be present");
+        assert_eq!(
+            options,
+            CopyOptions {
+                case_insensitive: true,
+                ..CopyOptions::default()
+            }
+        );
+        assert!(!options.literal_separator);
+    }
+
     #[test]
     fn project_config_deserializes_with_hooks() {
         let dir = TempDir::new().unwrap();
@@ -758,15 +1098,23 @@ run = ["bun install"]
                 show_ahead_behind: Some(false),
                 show_dirty_count: None,
                 auto_refresh: None,
+                table_width: None,
+                open_cmd: None,
             }),
             git: Some(GitConfig {
                 default_base: Some("develop".to_string()),
                 auto_prune: Some(true),
                 fetch_on_open: None,
+                base_from_upstream: None,
+                sync_strategy: None,
+                interactive_base: None,
             }),
             worktrees: Some(WorktreesConfig {
                 root: Some("custom/{{ repo }}/{{ branch }}".to_string()),
                 scan: Some(vec!["/extra".to_string()]),
+                sparse: None,
+                templates: None,
+                current_symlink: None,
             }),
             ..GlobalConfig::default()
         };
@@ -796,11 +1144,16 @@ run = ["bun install"]
                 show_ahead_behind: None,
                 show_dirty_count: None,
                 auto_refresh: None,
+                table_width: None,
+                open_cmd: None,
             }),
             git: Some(GitConfig {
                 default_base: Some("develop".to_string()),
                 auto_prune: Some(true),
                 fetch_on_open: None,
+                base_from_upstream: None,
+                sync_strategy: None,
+                interactive_base: None,
             }),
             ..GlobalConfig::default()
         };
@@ -812,15 +1165,23 @@ run = ["bun install"]
                 show_ahead_behind: Some(false),
                 show_dirty_count: None,
                 auto_refresh: None,
+                table_width: None,
+                open_cmd: None,
             }),
             git: Some(GitConfig {
                 default_base: Some("staging".to_string()),
                 auto_prune: None, // fall through to global
                 fetch_on_open: Some(false),
+                base_from_upstream: None,
+                sync_strategy: None,
+                interactive_base: None,
             }),
             worktrees: Some(WorktreesConfig {
                 root: Some("proj/{{ repo }}/{{ branch }}".to_string()),
                 scan: None,
+                sparse: None,
+                templates: None,
+                current_symlink: None,
             }),
             ..ProjectConfig::default()
         };
@@ -926,6 +1287,9 @@ run = ["bun install"]
             worktrees: Some(WorktreesConfig {
                 root: Some("global/{{ repo }}".to_string()),
                 scan: None,
+                sparse: None,
+                templates: None,
+                current_symlink: None,
             }),
             ..GlobalConfig::default()
         };
@@ -938,6 +1302,9 @@ run = ["bun install"]
             worktrees: Some(WorktreesConfig {
                 root: Some("project/{{ repo }}".to_string()),
                 scan: None,
+                sparse: None,
+                templates: None,
+                current_symlink: None,
             }),
             ..ProjectConfig::default()
         };
@@ -945,6 +1312,10 @@ run = ["bun install"]
         let cli = CliConfigOverrides {
             default_base: Some("cli-branch".to_string()),
             worktree_root: Some("cli/{{ repo }}".to_string()),
+            worktree_template: None,
+            table_width: None,
+            base_from_upstream: None,
+            open_cmd: None,
         };
 
         let resolved = resolve_config(Some(&cli), Some(&project), &global);
@@ -966,6 +1337,10 @@ run = ["bun install"]
         let cli = CliConfigOverrides {
             default_base: None,
             worktree_root: Some("cli-root/{{ repo }}".to_string()),
+            worktree_template: None,
+            table_width: None,
+            base_from_upstream: None,
+            open_cmd: None,
         };
 
         let resolved = resolve_config(Some(&cli), None, &global);
@@ -976,6 +1351,219 @@ run = ["bun install"]
         assert_eq!(resolved.git.default_base, "develop");
     }
 
+    #[test]
+    fn repo_template_overrides_global_root() {
+        let global = GlobalConfig {
+            worktrees: Some(WorktreesConfig {
+                root: Some("global/{{ repo }}".to_string()),
+                ..WorktreesConfig::default()
+            }),
+            ..GlobalConfig::default()
+        };
+
+        let mut resolved = resolve_config(None, None, &global);
+        apply_repo_template(&mut resolved, None, None, Some("db/{{ branch }}"));
+
+        assert_eq!(resolved.worktrees.root, "db/{{ branch }}");
+    }
+
+    #[test]
+    fn project_and_cli_roots_win_over_repo_template() {
+        let project = ProjectConfig {
+            worktrees: Some(WorktreesConfig {
+                root: Some("project/{{ repo }}".to_string()),
+                ..WorktreesConfig::default()
+            }),
+            ..ProjectConfig::default()
+        };
+        let global = GlobalConfig::default();
+
+        let mut resolved = resolve_config(None, Some(&project), &global);
+        apply_repo_template(&mut resolved, None, Some(&project), Some("db/{{ branch }}"));
+        assert_eq!(resolved.worktrees.root, "project/{{ repo }}");
+
+        let cli = CliConfigOverrides {
+            worktree_root: Some("cli/{{ repo }}".to_string()),
+            ..CliConfigOverrides::default()
+        };
+        let mut resolved = resolve_config(Some(&cli), None, &global);
+        apply_repo_template(&mut resolved, Some(&cli), None, Some("db/{{ branch }}"));
+        assert_eq!(resolved.worktrees.root, "cli/{{ repo }}");
+    }
+
+    #[test]
+    fn named_template_selected_from_cli_with_project_overriding_global() {
+        let dir = TempDir::new().unwrap();
+        let path = write_config(
+            &dir,
+            r#"
+[worktrees.templates]
+flat = "{{ repo }}-{{ branch | sanitize }}"
+nested = "global/{{ repo }}/{{ branch }}"
+"#,
+        );
+        let global = load_global_config_from(&path).unwrap();
+        let project = ProjectConfig {
+            worktrees: Some(WorktreesConfig {
+                templates: Some(BTreeMap::from([(
+                    "nested".to_string(),
+                    "project/{{ branch }}".to_string(),
+                )])),
+                ..WorktreesConfig::default()
+            }),
+            ..ProjectConfig::default()
+        };
+        let select = |name: &str| {
+            let cli = CliConfigOverrides {
+                worktree_template: Some(name.to_string()),
+                ..CliConfigOverrides::default()
+            };
+            resolve_config(Some(&cli), Some(&project), &global)
+        };
+
+        assert_eq!(
+            select("flat").worktrees.path_template().unwrap(),
+            "{{ repo }}-{{ branch | sanitize }}"
+        );
+        assert_eq!(
+            select("nested").worktrees.path_template().unwrap(),
+            "project/{{ branch }}"
+        );
+        let unselected = resolve_config(None, Some(&project), &global);
+        assert_eq!(
+            unselected.worktrees.path_template().unwrap(),
+            paths::DEFAULT_WORKTREE_TEMPLATE
+        );
+    }
+
+    #[test]
+    fn path_template_without_branch_is_rejected() {
+        let project = ProjectConfig {
+            worktrees: Some(WorktreesConfig {
+                root: Some("{{ repo }}".to_string()),
+                ..WorktreesConfig::default()
+            }),
+            ..ProjectConfig::default()
+        };
+
+        let resolved = resolve_config(None, Some(&project), &GlobalConfig::default());
+        let err = resolved.worktrees.path_template().unwrap_err();
+        assert!(
+            err.to_string().contains("does not depend on the branch"),
+            "got: {err}"
+        );
+    }
+
+    #[test]
+    fn undefined_named_template_is_an_error() {
+        let global = GlobalConfig {
+            worktrees: Some(WorktreesConfig {
+                templates: Some(BTreeMap::from([(
+                    "flat".to_string(),
+                    "{{ repo }}-{{ branch }}".to_string(),
+                )])),
+                ..WorktreesConfig::default()
+            }),
+            ..GlobalConfig::default()
+        };
+        let cli = CliConfigOverrides {
+            worktree_template: Some("missing".to_string()),
+            ..CliConfigOverrides::default()
+        };
+
+        let resolved = resolve_config(Some(&cli), None, &global);
+        let err = resolved.worktrees.path_template().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unknown worktree template 'missing' (available: flat)"
+        );
+
+        let resolved = resolve_config(Some(&cli), None, &GlobalConfig::default());
+        let err = resolved.worktrees.path_template().unwrap_err();
+        assert!(
+            err.to_string().contains("no [worktrees.templates]"),
+            "got: {err}"
+        );
+    }
+
+    #[test]
+    fn table_width_precedence_cli_over_project_over_global() {
+        let global = GlobalConfig {
+            ui: Some(UiConfig {
+                table_width: Some(100),
+                ..UiConfig::default()
+            }),
+            ..GlobalConfig::default()
+        };
+        let project = ProjectConfig {
+            ui: Some(UiConfig {
+                table_width: Some(80),
+                ..UiConfig::default()
+            }),
+            ..ProjectConfig::default()
+        };
+        let cli = CliConfigOverrides {
+            table_width: Some(60),
+            ..CliConfigOverrides::default()
+        };
+
+        assert_eq!(
+            resolve_config(None, None, &GlobalConfig::default())
+                .ui
+                .table_width,
+            None
+        );
+        assert_eq!(
+            resolve_config(None, None, &global).ui.table_width,
+            Some(100)
+        );
+        assert_eq!(
+            resolve_config(None, Some(&project), &global).ui.table_width,
+            Some(80)
+        );
+        assert_eq!(
+            resolve_config(Some(&cli), Some(&project), &global)
+                .ui
+                .table_width,
+            Some(60)
+        );
+    }
+
+    #[test]
+    fn open_cmd_precedence_cli_over_project_over_global() {
+        let global: GlobalConfig = toml::from_str("[ui]\nopen_cmd = \"code\"\n").unwrap();
+        let project: ProjectConfig = toml::from_str("[ui]\nopen_cmd = \"zed {path}\"\n").unwrap();
+        let cli = CliConfigOverrides {
+            open_cmd: Some("subl -n".to_string()),
+            ..CliConfigOverrides::default()
+        };
+
+        assert_eq!(
+            resolve_config(None, None, &GlobalConfig::default())
+                .ui
+                .open_cmd,
+            None
+        );
+        assert_eq!(
+            resolve_config(None, None, &global).ui.open_cmd.as_deref(),
+            Some("code")
+        );
+        assert_eq!(
+            resolve_config(None, Some(&project), &global)
+                .ui
+                .open_cmd
+                .as_deref(),
+            Some("zed {path}")
+        );
+        assert_eq!(
+            resolve_config(Some(&cli), Some(&project), &global)
+                .ui
+                .open_cmd
+                .as_deref(),
+            Some("subl -n")
+        );
+    }
+
     #[test]
     fn resolve_no_hooks_anywhere() {
         let resolved = resolve_config(None, None, &GlobalConfig::default());
@@ -1284,4 +1872,62 @@ tmux = true
             "project shell.tmux should override global"
         );
     }
+
+    #[test]
+    fn git_interactive_base_defaults_off_and_project_overrides_global() {
+        assert!(
+            !resolve_config(None, None, &GlobalConfig::default())
+                .git
+                .interactive_base
+        );
+
+        let global: GlobalConfig = toml::from_str("[git]\ninteractive_base = true\n").unwrap();
+        assert!(resolve_config(None, None, &global).git.interactive_base);
+
+        let project: ProjectConfig = toml::from_str("[git]\ninteractive_base = false\n").unwrap();
+        assert!(
+            !resolve_config(None, Some(&project), &global)
+                .git
+                .interactive_base
+        );
+    }
+
+    #[test]
+    fn worktrees_sparse_deserializes_and_project_overrides_global() {
+        let dir = TempDir::new().unwrap();
+        let path = write_config(
+            &dir,
+            r#"
+[worktrees]
+sparse = ["services/api", "libs"]
+"#,
+        );
+        let global = load_global_config_from(&path).unwrap();
+        assert_eq!(
+            global.worktrees.as_ref().unwrap().sparse,
+            Some(vec!["services/api".to_string(), "libs".to_string()])
+        );
+
+        let resolved = resolve_config(None, None, &global);
+        assert_eq!(
+            resolved.worktrees.sparse,
+            Some(vec!["services/api".to_string(), "libs".to_string()])
+        );
+
+        let project = ProjectConfig {
+            worktrees: Some(WorktreesConfig {
+                sparse: Some(vec!["web".to_string()]),
+                ..WorktreesConfig::default()
+            }),
+            ..ProjectConfig::default()
+        };
+        let resolved = resolve_config(None, Some(&project), &global);
+        assert_eq!(resolved.worktrees.sparse, Some(vec!["web".to_string()]));
+    }
+
+    #[test]
+    fn worktrees_sparse_defaults_to_none() {
+        let resolved = resolve_config(None, None, &GlobalConfig::default());
+        assert!(resolved.worktrees.sparse.is_none());
+    }
 }
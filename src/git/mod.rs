@@ -9,22 +9,97 @@ pub struct RepoInfo {
     pub default_branch: String,
 }
 
+/// A remote URL reduced to its hosting coordinates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NormalizedRemote {
+    pub host: String,
+    pub owner: String,
+    pub repo: String,
+    /// Browser URL for the repository, e.g. `https://github.com/owner/repo`.
+    pub https_url: String,
+}
+
+impl NormalizedRemote {
+    /// The `owner/repo` form shown in human output.
+    pub fn slug(&self) -> String {
+        format!("{}/{}", self.owner, self.repo)
+    }
+}
+
+/// Parse an `origin` URL in `git@host:owner/repo.git`, `ssh://`, `git://`
+/// or `http(s)://` form.
+///
+/// Returns `None` for local paths and anything without both an owner and a
+/// repository segment. Nested owners (GitLab subgroups) keep their slashes.
+pub fn normalize_remote_url(url: &str) -> Option<NormalizedRemote> {
+    let url = url.trim();
+    let (host, path) = if let Some((scheme, rest)) = url.split_once("://") {
+        let (authority, path) = rest.split_once('/')?;
+        let host = authority.rsplit_once('@').map_or(authority, |(_, h)| h);
+        // An SSH port is meaningless in a browser URL; an HTTP one is not.
+        let host = match scheme {
+            "http" | "https" => host,
+            "ssh" | "git" | "git+ssh" | "ssh+git" => host.split(':').next().unwrap_or(host),
+            _ => return None,
+        };
+        (host, path)
+    } else {
+        // scp-like shorthand: [user@]host:path. A slash before the colon
+        // means it is a local path instead.
+        let (authority, path) = url.split_once(':')?;
+        if authority.contains('/') {
+            return None;
+        }
+        let host = authority.rsplit_once('@').map_or(authority, |(_, h)| h);
+        (host, path)
+    };
+
+    let path = path.trim_matches('/');
+    let path = path.strip_suffix(".git").unwrap_or(path);
+    let (owner, repo) = path.rsplit_once('/')?;
+    if host.is_empty() || owner.is_empty() || repo.is_empty() {
+        return None;
+    }
+
+    Some(NormalizedRemote {
+        host: host.to_string(),
+        owner: owner.to_string(),
+        repo: repo.to_string(),
+        https_url: format!("https://{host}/{owner}/{repo}"),
+    })
+}
+
 /// Count modified, staged, and untracked files in a worktree.
 ///
 /// Opens the repository at `worktree_path` and counts all files with
 /// non-clean status (modified, new, deleted, renamed, typechanged).
 /// Returns 0 for a clean worktree.
 pub fn dirty_count(worktree_path: &Path) -> Result<usize, GitError> {
-    let repo =
-        git2::Repository::open(worktree_path).map_err(|e| map_repo_open_error(e, worktree_path))?;
+    let repo = open_worktree_repo(worktree_path)?;
 
     let statuses = repo.statuses(Some(
         git2::StatusOptions::new()
             .include_untracked(true)
             .recurse_untracked_dirs(true),
     ))?;
+    let index = repo.index()?;
 
-    Ok(statuses.len())
+    Ok(statuses
+        .iter()
+        .filter(|entry| !is_sparse_excluded(&index, entry))
+        .count())
+}
+
+/// libgit2 honours the skip-worktree bit for files that still exist but
+/// reports removed ones as deleted, so sparse-checkout exclusions have to be
+/// filtered out of status results by hand.
+fn is_sparse_excluded(index: &git2::Index, entry: &git2::StatusEntry) -> bool {
+    entry.status() == git2::Status::WT_DELETED
+        && index
+            .get_path(Path::new(&*String::from_utf8_lossy(entry.path_bytes())), 0)
+            .is_some_and(|e| {
+                e.flags_extended & git2::IndexEntryExtendedFlag::SKIP_WORKTREE.bits() != 0
+            })
 }
 
 /// A file with changed status in a worktree.
@@ -40,8 +115,7 @@ pub struct ChangedFile {
 /// typechanged. Each entry includes the file path and a human-readable
 /// status string.
 pub fn changed_files(worktree_path: &Path) -> Result<Vec<ChangedFile>, GitError> {
-    let repo =
-        git2::Repository::open(worktree_path).map_err(|e| map_repo_open_error(e, worktree_path))?;
+    let repo = open_worktree_repo(worktree_path)?;
 
     let statuses = repo.statuses(Some(
         git2::StatusOptions::new()
@@ -49,8 +123,13 @@ pub fn changed_files(worktree_path: &Path) -> Result<Vec<ChangedFile>, GitError>
             .recurse_untracked_dirs(true),
     ))?;
 
+    let index = repo.index()?;
+
     let mut files = Vec::with_capacity(statuses.len());
     for entry in statuses.iter() {
+        if is_sparse_excluded(&index, &entry) {
+            continue;
+        }
         let path = entry.path().unwrap_or("(unknown)").to_string();
         let s = entry.status();
         let label = if s.is_index_new() || s.is_wt_new() {
@@ -87,8 +166,7 @@ pub struct CommitInfo {
 /// Opens the repository at `worktree_path` and walks HEAD to collect
 /// up to `limit` commits.
 pub fn recent_commits(worktree_path: &Path, limit: usize) -> Result<Vec<CommitInfo>, GitError> {
-    let repo =
-        git2::Repository::open(worktree_path).map_err(|e| map_repo_open_error(e, worktree_path))?;
+    let repo = open_worktree_repo(worktree_path)?;
 
     let head = match repo.head() {
         Ok(h) => h,
@@ -122,6 +200,83 @@ pub fn recent_commits(worktree_path: &Path, limit: usize) -> Result<Vec<CommitIn
     Ok(commits)
 }
 
+/// Summary of the commit at HEAD.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommitSummary {
+    pub short_id: String,
+    pub summary: String,
+    pub author: String,
+    /// Commit time in seconds since the Unix epoch.
+    pub time: i64,
+}
+
+/// Summarize the HEAD commit of the repository or worktree at `path`.
+///
+/// Returns `Ok(None)` when HEAD is unborn (no commits yet).
+pub fn commit_summary(path: &Path) -> Result<Option<CommitSummary>, GitError> {
+    let repo = open_worktree_repo(path)?;
+
+    let head = match repo.head() {
+        Ok(h) => h,
+        Err(e) if e.code() == git2::ErrorCode::UnbornBranch => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    let commit = head.peel_to_commit()?;
+    let oid_str = commit.id().to_string();
+    let author = commit.author().name().unwrap_or("unknown").to_string();
+
+    let summary = CommitSummary {
+        short_id: oid_str[..oid_str.len().min(7)].to_string(),
+        summary: commit.summary().unwrap_or("(no message)").to_string(),
+        author,
+        time: commit.time().seconds(),
+    };
+    Ok(Some(summary))
+}
+
+/// Size of a branch's changes relative to its base.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub struct DiffStat {
+    pub files_changed: usize,
+    pub insertions: usize,
+    pub deletions: usize,
+}
+
+/// Diff stat of HEAD at `path` against where it forked from `base`.
+///
+/// Compares against the merge base of HEAD and `base` (like
+/// `git diff --stat base...HEAD`), so commits that landed on the base since
+/// the fork don't count. `base` is looked up as a local branch, then
+/// `origin/<base>`, then any revision. Returns `Ok(None)` when HEAD is
+/// unborn or `base` cannot be resolved.
+pub fn diff_stat_vs_base(path: &Path, base: &str) -> Result<Option<DiffStat>, GitError> {
+    let repo = open_worktree_repo(path)?;
+    let head = match repo.head() {
+        Ok(h) => h.peel_to_commit()?,
+        Err(e) if e.code() == git2::ErrorCode::UnbornBranch => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    let base_oid = resolve_base_oid(&repo, base).or_else(|| {
+        repo.revparse_single(base)
+            .and_then(|obj| obj.peel_to_commit())
+            .ok()
+            .map(|commit| commit.id())
+    });
+    let Some(base_oid) = base_oid else {
+        return Ok(None);
+    };
+    let fork_oid = repo.merge_base(head.id(), base_oid).unwrap_or(base_oid);
+    let fork_tree = repo.find_commit(fork_oid)?.tree()?;
+
+    let diff = repo.diff_tree_to_tree(Some(&fork_tree), Some(&head.tree()?), None)?;
+    let stats = diff.stats()?;
+    Ok(Some(DiffStat {
+        files_changed: stats.files_changed(),
+        insertions: stats.insertions(),
+        deletions: stats.deletions(),
+    }))
+}
+
 /// Calculate commits ahead/behind for a branch relative to its upstream.
 ///
 /// Checks for an upstream tracking branch first, then falls back to
@@ -131,44 +286,310 @@ pub fn ahead_behind(
     branch: &str,
     base_branch: Option<&str>,
 ) -> Result<Option<(usize, usize)>, GitError> {
+    Ok(ahead_behind_batch(repo_path, &[(branch, base_branch)])?
+        .pop()
+        .flatten())
+}
+
+/// Calculate ahead/behind for many `(branch, base_branch)` pairs at once.
+///
+/// Opens the repository a single time and resolves each distinct base only
+/// once. Results are in input order, with the same semantics per item as
+/// [`ahead_behind`].
+pub fn ahead_behind_batch(
+    repo_path: &Path,
+    items: &[(&str, Option<&str>)],
+) -> Result<Vec<Option<(usize, usize)>>, GitError> {
     let repo = git2::Repository::open(repo_path).map_err(|e| map_repo_open_error(e, repo_path))?;
+    let mut base_oids: std::collections::HashMap<&str, Option<git2::Oid>> =
+        std::collections::HashMap::new();
+
+    let mut results = Vec::with_capacity(items.len());
+    for &(branch, base_branch) in items {
+        let local = match repo.find_branch(branch, git2::BranchType::Local) {
+            Ok(b) => b,
+            Err(_) => {
+                results.push(None);
+                continue;
+            }
+        };
+        let Some(local_oid) = local.get().target() else {
+            results.push(None);
+            continue;
+        };
 
-    let local = match repo.find_branch(branch, git2::BranchType::Local) {
-        Ok(b) => b,
-        Err(_) => return Ok(None),
-    };
-    let local_oid = match local.get().target() {
-        Some(oid) => oid,
-        None => return Ok(None),
-    };
+        // Try upstream tracking branch first, then fall back to base_branch
+        let upstream_oid = if let Ok(upstream) = local.upstream() {
+            upstream.get().target()
+        } else {
+            base_branch.and_then(|base| {
+                *base_oids
+                    .entry(base)
+                    .or_insert_with(|| resolve_base_oid(&repo, base))
+            })
+        };
 
-    // Try upstream tracking branch first
-    let upstream_oid = if let Ok(upstream) = local.upstream() {
-        upstream.get().target()
-    } else {
-        // Fall back to base_branch
-        base_branch.and_then(|base| {
-            repo.find_branch(base, git2::BranchType::Local)
+        match upstream_oid {
+            Some(oid) => results.push(Some(repo.graph_ahead_behind(local_oid, oid)?)),
+            None => results.push(None),
+        }
+    }
+    Ok(results)
+}
+
+/// Resolve a base branch to its tip, trying local then `origin/<base>`.
+fn resolve_base_oid(repo: &git2::Repository, base: &str) -> Option<git2::Oid> {
+    repo.find_branch(base, git2::BranchType::Local)
+        .ok()
+        .and_then(|b| b.get().target())
+        .or_else(|| {
+            let remote = format!("origin/{base}");
+            repo.find_branch(&remote, git2::BranchType::Remote)
                 .ok()
                 .and_then(|b| b.get().target())
-                .or_else(|| {
-                    let remote = format!("origin/{base}");
-                    repo.find_branch(&remote, git2::BranchType::Remote)
-                        .ok()
-                        .and_then(|b| b.get().target())
-                })
         })
+}
+
+/// Whether remote operations log progress to stderr (set from `--verbose`).
+static VERBOSE_REMOTE: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+
+/// Enable or disable verbose logging for every later remote operation.
+pub fn set_verbose(enabled: bool) {
+    VERBOSE_REMOTE.store(enabled, std::sync::atomic::Ordering::Relaxed);
+}
+
+/// Remote callbacks for fetch/push/connect on `repo`, verbose when
+/// `--verbose` is set. Credential helpers are read from the repo's config.
+fn remote_callbacks(repo: &git2::Repository) -> git2::RemoteCallbacks<'static> {
+    verbose_remote_callbacks(
+        VERBOSE_REMOTE.load(std::sync::atomic::Ordering::Relaxed),
+        repo,
+    )
+}
+
+/// Build remote callbacks for `repo` that resolve credentials (see
+/// [`CredentialAttempts`]) and, when `enabled`, log transfer progress,
+/// server messages, ref updates, and credential attempts to stderr.
+pub fn verbose_remote_callbacks(
+    enabled: bool,
+    repo: &git2::Repository,
+) -> git2::RemoteCallbacks<'static> {
+    let log: Option<RemoteLog<'static>> = enabled.then(stderr_remote_log);
+    build_remote_callbacks(log, CredentialAttempts::new(repo.config().ok()))
+}
+
+/// Sink for verbose remote log lines.
+type RemoteLog<'a> = std::rc::Rc<dyn Fn(&str) + 'a>;
+
+fn stderr_remote_log() -> RemoteLog<'static> {
+    std::rc::Rc::new(|line: &str| eprintln!("git: {line}"))
+}
+
+/// Credential sources tried, in order, when a remote asks for authentication:
+/// the ssh-agent, then private keys under `~/.ssh/id_*`, then a git
+/// credential helper for username/password. Each source is offered once so
+/// a rejected credential ends in an auth error instead of a retry loop.
+struct CredentialAttempts {
+    config: Option<git2::Config>,
+    ssh_dir: Option<PathBuf>,
+    tried_agent: bool,
+    key_files: Option<std::vec::IntoIter<PathBuf>>,
+    tried_helper: bool,
+    tried_default: bool,
+}
+
+impl CredentialAttempts {
+    fn new(config: Option<git2::Config>) -> Self {
+        Self::with_ssh_dir(config, dirs::home_dir().map(|home| home.join(".ssh")))
+    }
+
+    fn with_ssh_dir(config: Option<git2::Config>, ssh_dir: Option<PathBuf>) -> Self {
+        Self {
+            config,
+            ssh_dir,
+            tried_agent: false,
+            key_files: None,
+            tried_helper: false,
+            tried_default: false,
+        }
+    }
+
+    /// Private keys in the ssh directory: `id_*` files without a `.pub`
+    /// suffix, sorted by name.
+    fn ssh_key_files(&self) -> Vec<PathBuf> {
+        let Some(entries) = self
+            .ssh_dir
+            .as_deref()
+            .and_then(|dir| std::fs::read_dir(dir).ok())
+        else {
+            return Vec::new();
+        };
+        let mut keys: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| {
+                path.is_file()
+                    && path
+                        .file_name()
+                        .and_then(|n| n.to_str())
+                        .is_some_and(|name| name.starts_with("id_") && !name.ends_with(".pub"))
+            })
+            .collect();
+        keys.sort();
+        keys
+    }
+
+    /// Next credential to offer, or an `Auth` error once every source
+    /// allowed by the remote has been tried.
+    fn next(
+        &mut self,
+        url: &str,
+        username: Option<&str>,
+        allowed: git2::CredentialType,
+    ) -> Result<git2::Cred, git2::Error> {
+        let user = username.unwrap_or("git");
+        if allowed.contains(git2::CredentialType::USERNAME) {
+            return git2::Cred::username(user);
+        }
+        if allowed.contains(git2::CredentialType::SSH_KEY) {
+            if !self.tried_agent {
+                self.tried_agent = true;
+                return git2::Cred::ssh_key_from_agent(user);
+            }
+            if self.key_files.is_none() {
+                self.key_files = Some(self.ssh_key_files().into_iter());
+            }
+            if let Some(key) = self.key_files.as_mut().and_then(Iterator::next) {
+                return git2::Cred::ssh_key(user, None, &key, None);
+            }
+        }
+        if allowed.contains(git2::CredentialType::USER_PASS_PLAINTEXT) && !self.tried_helper {
+            self.tried_helper = true;
+            if let Some(config) = &self.config {
+                if let Ok(cred) = git2::Cred::credential_helper(config, url, username) {
+                    return Ok(cred);
+                }
+            }
+        }
+        if allowed.contains(git2::CredentialType::DEFAULT) && !self.tried_default {
+            self.tried_default = true;
+            return git2::Cred::default();
+        }
+        Err(git2::Error::new(
+            git2::ErrorCode::Auth,
+            git2::ErrorClass::Callback,
+            "no more credentials to try (ssh-agent, ~/.ssh/id_*, credential helper)",
+        ))
+    }
+
+    /// Short description of the source `next` will use, for verbose logs.
+    fn describe_next(&self, allowed: git2::CredentialType) -> &'static str {
+        if allowed.contains(git2::CredentialType::USERNAME) {
+            "username"
+        } else if allowed.contains(git2::CredentialType::SSH_KEY) && !self.tried_agent {
+            "ssh-agent"
+        } else if allowed.contains(git2::CredentialType::SSH_KEY)
+            && self
+                .key_files
+                .as_ref()
+                .is_none_or(|keys| !keys.as_slice().is_empty())
+        {
+            "ssh key file"
+        } else if allowed.contains(git2::CredentialType::USER_PASS_PLAINTEXT) && !self.tried_helper
+        {
+            "credential helper"
+        } else {
+            "default credentials"
+        }
+    }
+}
+
+fn build_remote_callbacks<'a>(
+    log: Option<RemoteLog<'a>>,
+    mut credentials: CredentialAttempts,
+) -> git2::RemoteCallbacks<'a> {
+    let mut callbacks = git2::RemoteCallbacks::new();
+
+    let credential_log = log.clone();
+    callbacks.credentials(move |url, username, allowed| {
+        if let Some(log) = &credential_log {
+            log(&format!(
+                "credentials requested for {url} (user: {}, allowed: {allowed:?}); trying {}",
+                username.unwrap_or("-"),
+                credentials.describe_next(allowed)
+            ));
+        }
+        credentials.next(url, username, allowed)
+    });
+
+    let Some(log) = log else {
+        return callbacks;
     };
 
-    match upstream_oid {
-        Some(oid) => {
-            let (ahead, behind) = repo.graph_ahead_behind(local_oid, oid)?;
-            Ok(Some((ahead, behind)))
+    // Transfer progress fires per object; only log each 10% step.
+    let mut last_decile = None;
+    let progress_log = log.clone();
+    callbacks.transfer_progress(move |stats| {
+        let total = stats.total_objects();
+        let decile = (stats.received_objects() * 10)
+            .checked_div(total)
+            .unwrap_or(10);
+        if last_decile != Some(decile) {
+            last_decile = Some(decile);
+            progress_log(&format!(
+                "received {}/{} objects ({} bytes), indexed {}",
+                stats.received_objects(),
+                total,
+                stats.received_bytes(),
+                stats.indexed_objects()
+            ));
         }
-        None => Ok(None),
+        true
+    });
+
+    let push_log = log.clone();
+    callbacks.push_transfer_progress(move |current, total, bytes| {
+        if current == total {
+            push_log(&format!("pushed {current}/{total} objects ({bytes} bytes)"));
+        }
+    });
+
+    let sideband_log = log.clone();
+    callbacks.sideband_progress(move |data| {
+        let text = String::from_utf8_lossy(data);
+        for line in text.split(['\r', '\n']).filter(|l| !l.trim().is_empty()) {
+            sideband_log(&format!("remote: {}", line.trim_end()));
+        }
+        true
+    });
+
+    callbacks.update_tips(move |refname, old, new| {
+        log(&format!("updated {refname}: {old} -> {new}"));
+        true
+    });
+
+    callbacks
+}
+
+/// Map a fetch/connect error, turning authentication failures against
+/// `url` into [`GitError::Authentication`].
+fn map_remote_error(e: git2::Error, url: &str) -> GitError {
+    if e.code() == git2::ErrorCode::Auth {
+        GitError::Authentication {
+            url: url.to_string(),
+            message: e.message().to_string(),
+        }
+    } else {
+        GitError::Git(e)
     }
 }
 
+/// Name of the remote `fetch_remote` would fetch from, if it exists.
+pub fn fetch_remote_name(repo_path: &Path) -> Option<String> {
+    let repo = git2::Repository::open(repo_path).ok()?;
+    repo.find_remote("origin").ok()?;
+    Some("origin".to_string())
+}
+
 /// Fetch from the default remote (origin).
 ///
 /// Best-effort: if no remote exists or the fetch fails, the error is
@@ -185,7 +606,190 @@ pub fn fetch_remote(repo_path: &Path) -> Result<(), GitError> {
 
     let mut fetch_opts = git2::FetchOptions::new();
     fetch_opts.prune(git2::FetchPrune::On);
-    remote.fetch(&[] as &[&str], Some(&mut fetch_opts), None)?;
+    fetch_opts.remote_callbacks(remote_callbacks(&repo));
+    remote
+        .fetch(&[] as &[&str], Some(&mut fetch_opts), None)
+        .map_err(|e| map_remote_error(e, remote.url().unwrap_or("<unknown>")))?;
+    Ok(())
+}
+
+/// Directory name of the bare repository inside a [`clone_bare`] layout.
+pub const BARE_DIR: &str = ".bare";
+
+/// Clone `url` into the bare-repo-plus-worktrees layout at `dir`.
+///
+/// The repository is cloned `--bare` into `dir/.bare`, and `dir/.git` is a
+/// `gitdir:` file pointing at it, so git commands (and trench) run from
+/// `dir` find it. Unlike a plain bare clone, origin gets the usual
+/// `refs/remotes/origin/*` fetch refspec and `origin/HEAD`, so remote
+/// branches and the default branch resolve as in a normal clone. `dir`
+/// must not exist or be empty.
+pub fn clone_bare(url: &str, dir: &Path) -> Result<(), GitError> {
+    if dir
+        .read_dir()
+        .is_ok_and(|mut entries| entries.next().is_some())
+    {
+        return Err(GitError::Io(std::io::Error::new(
+            std::io::ErrorKind::AlreadyExists,
+            format!("{} already exists and is not empty", dir.display()),
+        )));
+    }
+
+    let mut fetch_opts = git2::FetchOptions::new();
+    fetch_opts.remote_callbacks(build_remote_callbacks(
+        VERBOSE_REMOTE
+            .load(std::sync::atomic::Ordering::Relaxed)
+            .then(stderr_remote_log),
+        CredentialAttempts::new(git2::Config::open_default().ok()),
+    ));
+    let repo = git2::build::RepoBuilder::new()
+        .bare(true)
+        .fetch_options(fetch_opts)
+        .clone(url, &dir.join(BARE_DIR))
+        .map_err(|e| map_remote_error(e, url))?;
+    std::fs::write(dir.join(".git"), format!("gitdir: ./{BARE_DIR}\n"))?;
+
+    repo.config()?
+        .set_str("remote.origin.fetch", "+refs/heads/*:refs/remotes/origin/*")?;
+    fetch_remote(repo.path())?;
+    if let Some(head) = repo
+        .head()
+        .ok()
+        .and_then(|h| h.shorthand().map(String::from))
+    {
+        repo.reference_symbolic(
+            "refs/remotes/origin/HEAD",
+            &format!("refs/remotes/origin/{head}"),
+            true,
+            "trench clone: set origin/HEAD",
+        )?;
+    }
+    Ok(())
+}
+
+/// Delete remote-tracking refs for branches that no longer exist on `remote`.
+///
+/// Connects to the remote to list its branches, then removes every local
+/// ref under the remote's fetch refspecs whose source is gone. Returns the
+/// short names of the pruned refs (e.g. `origin/old-feature`), sorted. A
+/// missing remote prunes nothing.
+pub fn prune_stale_refs(repo_path: &Path, remote: &str) -> Result<Vec<String>, GitError> {
+    let repo = git2::Repository::open(repo_path).map_err(|e| map_repo_open_error(e, repo_path))?;
+    let mut remote = match repo.find_remote(remote) {
+        Ok(r) => r,
+        Err(e) if e.code() == git2::ErrorCode::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+
+    let advertised: std::collections::HashSet<String> = {
+        let url = remote.url().unwrap_or("<unknown>").to_string();
+        let connection = remote
+            .connect_auth(git2::Direction::Fetch, Some(remote_callbacks(&repo)), None)
+            .map_err(|e| map_remote_error(e, &url))?;
+        connection
+            .list()?
+            .iter()
+            .map(|head| head.name().to_string())
+            .collect()
+    };
+
+    let refspecs: Vec<git2::Refspec<'_>> = remote
+        .refspecs()
+        .filter(|spec| spec.direction() == git2::Direction::Fetch)
+        .collect();
+
+    let mut pruned = Vec::new();
+    for reference in repo.references()? {
+        let mut reference = reference?;
+        if reference.symbolic_target().is_some() {
+            continue;
+        }
+        let Some(name) = reference.name().map(str::to_string) else {
+            continue;
+        };
+        let Some(spec) = refspecs.iter().find(|spec| spec.dst_matches(&name)) else {
+            continue;
+        };
+        let source = spec.rtransform(&name)?;
+        if source.as_str().is_some_and(|src| advertised.contains(src)) {
+            continue;
+        }
+        reference.delete()?;
+        pruned.push(
+            name.strip_prefix("refs/remotes/")
+                .unwrap_or(&name)
+                .to_string(),
+        );
+    }
+    pruned.sort();
+    Ok(pruned)
+}
+
+/// How `trench sync` brings a branch up to date with its base.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SyncStrategy {
+    Rebase,
+    Merge,
+    /// Move the branch to its base only when no local commits would be lost.
+    FfOnly,
+}
+
+impl std::fmt::Display for SyncStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SyncStrategy::Rebase => write!(f, "rebase"),
+            SyncStrategy::Merge => write!(f, "merge"),
+            SyncStrategy::FfOnly => write!(f, "ff-only"),
+        }
+    }
+}
+
+/// Sync a worktree branch with its base branch using `strategy`.
+pub fn sync_worktree(
+    worktree_path: &Path,
+    branch: &str,
+    base_branch: &str,
+    strategy: SyncStrategy,
+) -> Result<(), GitError> {
+    match strategy {
+        SyncStrategy::Rebase => sync_rebase(worktree_path, branch, base_branch),
+        SyncStrategy::Merge => sync_merge(worktree_path, branch, base_branch),
+        SyncStrategy::FfOnly => sync_ff_only(worktree_path, branch, base_branch),
+    }
+}
+
+/// Fast-forward a worktree branch to its base branch.
+///
+/// Succeeds without changes when the branch already contains the base.
+/// Returns [`GitError::NotFastForward`] when the branch has commits the base
+/// lacks, leaving the worktree untouched.
+pub fn sync_ff_only(worktree_path: &Path, branch: &str, base_branch: &str) -> Result<(), GitError> {
+    let repo = open_worktree_repo(worktree_path)?;
+
+    repo.find_branch(branch, git2::BranchType::Local)
+        .map_err(|_| GitError::WorktreeNotFound {
+            name: branch.to_string(),
+        })?;
+
+    let upstream_oid = resolve_upstream_oid(&repo, base_branch)?;
+    let upstream_annotated = repo.find_annotated_commit(upstream_oid)?;
+    let (merge_analysis, _) = repo.merge_analysis(&[&upstream_annotated])?;
+
+    if merge_analysis.is_up_to_date() {
+        return Ok(());
+    }
+    if !merge_analysis.is_fast_forward() {
+        return Err(GitError::NotFastForward {
+            branch: branch.to_string(),
+            base: base_branch.to_string(),
+        });
+    }
+
+    let ref_name = format!("refs/heads/{branch}");
+    repo.find_reference(&ref_name)?
+        .set_target(upstream_oid, "trench sync: fast-forward")?;
+    repo.checkout_head(Some(git2::build::CheckoutBuilder::default().force()))?;
     Ok(())
 }
 
@@ -194,8 +798,7 @@ pub fn fetch_remote(repo_path: &Path) -> Result<(), GitError> {
 /// Opens the repository at `worktree_path` and rebases the current branch
 /// onto `origin/<base_branch>` (or local `<base_branch>` if no remote ref).
 pub fn sync_rebase(worktree_path: &Path, branch: &str, base_branch: &str) -> Result<(), GitError> {
-    let repo =
-        git2::Repository::open(worktree_path).map_err(|e| map_repo_open_error(e, worktree_path))?;
+    let repo = open_worktree_repo(worktree_path)?;
 
     let upstream_oid = resolve_upstream_oid(&repo, base_branch)?;
     let branch_oid = repo
@@ -227,9 +830,11 @@ pub fn sync_rebase(worktree_path: &Path, branch: &str, base_branch: &str) -> Res
         // Check for conflicts
         let index = repo.index()?;
         if index.has_conflicts() {
+            let files = conflicted_paths(&index)?;
             rebase.abort()?;
             return Err(GitError::MergeConflict {
                 branch: branch.to_string(),
+                files,
             });
         }
         last_commit_oid = Some(rebase.commit(None, &sig, None)?);
@@ -252,8 +857,7 @@ pub fn sync_rebase(worktree_path: &Path, branch: &str, base_branch: &str) -> Res
 /// Opens the repository at `worktree_path` and merges
 /// `origin/<base_branch>` (or local `<base_branch>`) into the current branch.
 pub fn sync_merge(worktree_path: &Path, branch: &str, base_branch: &str) -> Result<(), GitError> {
-    let repo =
-        git2::Repository::open(worktree_path).map_err(|e| map_repo_open_error(e, worktree_path))?;
+    let repo = open_worktree_repo(worktree_path)?;
 
     let _branch_ref = repo
         .find_branch(branch, git2::BranchType::Local)
@@ -285,6 +889,7 @@ pub fn sync_merge(worktree_path: &Path, branch: &str, base_branch: &str) -> Resu
     if index.has_conflicts() {
         return Err(GitError::MergeConflict {
             branch: branch.to_string(),
+            files: conflicted_paths(&index)?,
         });
     }
 
@@ -308,6 +913,79 @@ pub fn sync_merge(worktree_path: &Path, branch: &str, base_branch: &str) -> Resu
     Ok(())
 }
 
+/// Paths with unresolved conflicts in `index`, sorted and deduplicated.
+fn conflicted_paths(index: &git2::Index) -> Result<Vec<String>, GitError> {
+    let mut files = Vec::new();
+    for conflict in index.conflicts()? {
+        let conflict = conflict?;
+        let entry = conflict.our.or(conflict.their).or(conflict.ancestor);
+        if let Some(entry) = entry {
+            files.push(String::from_utf8_lossy(&entry.path).into_owned());
+        }
+    }
+    files.sort();
+    files.dedup();
+    Ok(files)
+}
+
+/// Stash uncommitted changes, including untracked files, in a worktree.
+///
+/// Returns the stash commit id, or `None` when there was nothing to stash.
+pub fn stash_save(worktree_path: &Path, message: &str) -> Result<Option<git2::Oid>, GitError> {
+    let mut repo = open_worktree_repo(worktree_path)?;
+    let sig = repo.signature()?;
+    match repo.stash_save(&sig, message, Some(git2::StashFlags::INCLUDE_UNTRACKED)) {
+        Ok(oid) => Ok(Some(oid)),
+        Err(e) if e.code() == git2::ErrorCode::NotFound => Ok(None),
+        Err(e) => Err(GitError::Git(e)),
+    }
+}
+
+/// Re-apply and drop the stash created by [`stash_save`].
+///
+/// The stash list is shared by all worktrees, so the entry is located by id
+/// rather than assumed to be `stash@{0}`. If the changes no longer apply
+/// cleanly the stash is kept (any conflicts are left in the worktree, as
+/// `git stash pop` does) and [`GitError::StashConflict`] is returned.
+pub fn stash_pop(worktree_path: &Path, stash_id: git2::Oid) -> Result<(), GitError> {
+    let mut repo = open_worktree_repo(worktree_path)?;
+    let mut index = None;
+    repo.stash_foreach(|i, _, oid| {
+        if *oid == stash_id {
+            index = Some(i);
+        }
+        index.is_none()
+    })?;
+    let Some(index) = index else {
+        return Err(GitError::Git(git2::Error::from_str(&format!(
+            "stash {stash_id} not found"
+        ))));
+    };
+
+    let conflict = GitError::StashConflict {
+        path: worktree_path.to_path_buf(),
+        index,
+    };
+    match repo.stash_apply(index, None) {
+        Ok(()) => {}
+        Err(e)
+            if matches!(
+                e.code(),
+                git2::ErrorCode::Conflict | git2::ErrorCode::MergeConflict
+            ) =>
+        {
+            return Err(conflict);
+        }
+        Err(e) => return Err(GitError::Git(e)),
+    }
+    // Like `git stash pop`, keep the stash when applying left conflicts.
+    if repo.index()?.has_conflicts() {
+        return Err(conflict);
+    }
+    repo.stash_drop(index)?;
+    Ok(())
+}
+
 /// Resolve the OID for a base branch, preferring origin/<base> over local.
 fn resolve_upstream_oid(repo: &git2::Repository, base_branch: &str) -> Result<git2::Oid, GitError> {
     let remote_ref = format!("origin/{base_branch}");
@@ -331,9 +1009,102 @@ fn resolve_upstream_oid(repo: &git2::Repository, base_branch: &str) -> Result<gi
     }
     Err(GitError::BaseBranchNotFound {
         base: base_branch.to_string(),
+        suggestions: suggest_branches(repo, base_branch),
     })
 }
 
+/// Check that `base_branch` names a branch sync can use, either
+/// `origin/<base>` or a local branch.
+pub fn ensure_base_branch(repo_path: &Path, base_branch: &str) -> Result<(), GitError> {
+    let repo = git2::Repository::open(repo_path).map_err(|e| map_repo_open_error(e, repo_path))?;
+    resolve_upstream_oid(&repo, base_branch).map(|_| ())
+}
+
+/// Most suggestions a "did you mean" hint lists.
+const MAX_BRANCH_SUGGESTIONS: usize = 3;
+
+/// Local and remote branch names close to `target`, closest first.
+///
+/// Remote branches are offered without their remote prefix, since that is
+/// how `--from` accepts them. A name qualifies when its edit distance is at
+/// most a third of the target's length (minimum 1), enough for typos like
+/// `mian` → `main` without suggesting unrelated branches.
+pub fn suggest_branches(repo: &git2::Repository, target: &str) -> Vec<String> {
+    let Ok(branches) = repo.branches(None) else {
+        return Vec::new();
+    };
+    let mut names: Vec<String> = branches
+        .flatten()
+        .filter_map(|(branch, kind)| {
+            let name = branch.name().ok().flatten()?.to_string();
+            match kind {
+                git2::BranchType::Local => Some(name),
+                git2::BranchType::Remote => name
+                    .split_once('/')
+                    .map(|(_, short)| short.to_string())
+                    .filter(|short| short != "HEAD"),
+            }
+        })
+        .collect();
+    names.sort();
+    names.dedup();
+
+    let threshold = (target.chars().count() / 3).max(1);
+    let mut ranked: Vec<(usize, String)> = names
+        .into_iter()
+        .filter(|name| name != target)
+        .map(|name| (edit_distance(target, &name), name))
+        .filter(|(distance, _)| *distance <= threshold)
+        .collect();
+    ranked.sort();
+    ranked
+        .into_iter()
+        .take(MAX_BRANCH_SUGGESTIONS)
+        .map(|(_, name)| name)
+        .collect()
+}
+
+/// Edit distance between two strings, by characters, where an insertion,
+/// deletion, substitution, or swap of two adjacent characters each cost one
+/// (optimal string alignment), so `mian` is one edit from `main`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut rows = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in rows[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    // `rows[i + 1][j + 1]` is the distance between `a[..=i]` and `b[..=j]`.
+    for (i, &ca) in a.iter().enumerate() {
+        for (j, &cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != cb);
+            let mut best = (rows[i][j + 1] + 1)
+                .min(rows[i + 1][j] + 1)
+                .min(rows[i][j] + cost);
+            if i > 0 && j > 0 && ca == b[j - 1] && a[i - 1] == cb {
+                best = best.min(rows[i - 1][j - 1] + 1);
+            }
+            rows[i + 1][j + 1] = best;
+        }
+    }
+    rows[a.len()][b.len()]
+}
+
+/// Render the "did you mean" tail of [`GitError::BaseBranchNotFound`].
+fn format_branch_suggestions(suggestions: &[String]) -> String {
+    match suggestions {
+        [] => String::new(),
+        [only] => format!("; did you mean '{only}'?"),
+        many => {
+            let quoted: Vec<String> = many.iter().map(|s| format!("'{s}'")).collect();
+            format!("; did you mean one of {}?", quoted.join(", "))
+        }
+    }
+}
+
 /// A worktree discovered via git (includes both main and additional worktrees).
 #[derive(Debug, Clone, PartialEq)]
 pub struct GitWorktreeEntry {
@@ -356,15 +1127,43 @@ pub enum GitError {
     #[error("branch already exists: {branch}")]
     BranchAlreadyExists { branch: String },
 
+    #[error(
+        "branch '{branch}' is already checked out in worktree {}; git allows a branch in only one worktree",
+        worktree.display()
+    )]
+    BranchInUse { branch: String, worktree: PathBuf },
+
     #[error("Branch '{branch}' already exists on {remote}. Use a different name.")]
     RemoteBranchAlreadyExists { branch: String, remote: String },
 
-    #[error("base branch not found: {base}")]
-    BaseBranchNotFound { base: String },
+    #[error(
+        "base branch not found: {base}{}",
+        format_branch_suggestions(suggestions)
+    )]
+    BaseBranchNotFound {
+        base: String,
+        /// Existing branches close to `base`, best match first.
+        suggestions: Vec<String>,
+    },
+
+    #[error(
+        "branch name is not valid UTF-8: '{lossy}'; trench only manages UTF-8 branch names \
+         (rename it with `git branch -m`)"
+    )]
+    NonUtf8Branch { lossy: String },
 
     #[error("worktree not found: {name}")]
     WorktreeNotFound { name: String },
 
+    #[error(
+        "worktree '{name}' is locked{}; unlock it with `git worktree unlock` or pass --force",
+        reason.as_deref().map(|r| format!(" ({r})")).unwrap_or_default()
+    )]
+    WorktreeLocked {
+        name: String,
+        reason: Option<String>,
+    },
+
     #[error("local branch not found: {branch}")]
     LocalBranchNotFound { branch: String },
 
@@ -375,7 +1174,26 @@ pub enum GitError {
     BranchDeleteBlocked { branch: String, message: String },
 
     #[error("merge conflict while syncing '{branch}': resolve conflicts manually")]
-    MergeConflict { branch: String },
+    MergeConflict { branch: String, files: Vec<String> },
+
+    #[error(
+        "cannot fast-forward '{branch}': it has diverged from '{base}'; \
+         sync with --strategy rebase or --strategy merge instead"
+    )]
+    NotFastForward { branch: String, base: String },
+
+    #[error(
+        "stashed changes conflict with the synced branch in {}; resolve the conflicts, \
+         then drop stash@{{{index}}}",
+        path.display()
+    )]
+    StashConflict { path: PathBuf, index: usize },
+
+    #[error(
+        "authentication failed for {url}: {message}; check your ssh-agent, ~/.ssh keys, \
+         or git credential helper"
+    )]
+    Authentication { url: String, message: String },
 
     #[error("I/O error: {0}")]
     Io(#[from] std::io::Error),
@@ -398,6 +1216,28 @@ fn map_repo_open_error(e: git2::Error, path: &Path) -> GitError {
     }
 }
 
+/// Convert a raw branch argument to `&str`, rejecting non-UTF-8 names up
+/// front so they never reach path rendering or the database.
+pub fn branch_name_from_os(branch: &std::ffi::OsStr) -> Result<&str, GitError> {
+    branch.to_str().ok_or_else(|| GitError::NonUtf8Branch {
+        lossy: branch.to_string_lossy().into_owned(),
+    })
+}
+
+/// Open a worktree directory as a repository.
+///
+/// A path that does not exist yields `WorktreeNotFound`; a directory that is
+/// not a repository yields `NotAGitRepo`; any other failure (e.g. a corrupt
+/// `.git` file) is surfaced as `Git`.
+pub fn open_worktree_repo(path: &Path) -> Result<git2::Repository, GitError> {
+    if !path.exists() {
+        return Err(GitError::WorktreeNotFound {
+            name: path.display().to_string(),
+        });
+    }
+    git2::Repository::open(path).map_err(|e| map_repo_open_error(e, path))
+}
+
 /// Discover a git repository by walking up from the given path.
 ///
 /// Returns a `RepoInfo` with the repo name (derived from the working directory),
@@ -423,15 +1263,26 @@ pub fn discover_repo(path: &Path) -> Result<RepoInfo, GitError> {
     let common_repo = git2::Repository::open(&common_git_dir)
         .map_err(|e| map_repo_open_error(e, &common_git_dir))?;
 
-    let workdir = common_repo
-        .workdir()
-        .ok_or_else(|| GitError::NotAGitRepo {
-            path: path.to_path_buf(),
-        })?
-        .canonicalize()
-        .map_err(|_| GitError::NotAGitRepo {
-            path: path.to_path_buf(),
-        })?;
+    // A bare repository has no checkout; the `trench clone` layout keeps it
+    // in `<root>/.bare`, so `<root>` stands in for the main checkout.
+    let workdir = match common_repo.workdir() {
+        Some(workdir) => workdir.to_path_buf(),
+        None if common_git_dir.file_name().is_some_and(|n| n == BARE_DIR) => common_git_dir
+            .parent()
+            .map(Path::to_path_buf)
+            .ok_or_else(|| GitError::NotAGitRepo {
+                path: path.to_path_buf(),
+            })?,
+        None => {
+            return Err(GitError::NotAGitRepo {
+                path: path.to_path_buf(),
+            })
+        }
+    }
+    .canonicalize()
+    .map_err(|_| GitError::NotAGitRepo {
+        path: path.to_path_buf(),
+    })?;
 
     let name = workdir
         .file_name()
@@ -444,11 +1295,14 @@ pub fn discover_repo(path: &Path) -> Result<RepoInfo, GitError> {
         .ok()
         .and_then(|r| r.url().map(String::from));
 
-    // Extract default branch from HEAD
-    let default_branch = common_repo
-        .head()
-        .ok()
-        .and_then(|r| r.shorthand().map(String::from))
+    // Prefer the remote's default branch, falling back to local HEAD
+    let default_branch = remote_default_branch(&common_repo)
+        .or_else(|| {
+            common_repo
+                .head()
+                .ok()
+                .and_then(|r| r.shorthand().map(String::from))
+        })
         .unwrap_or_else(|| String::from("main"));
 
     Ok(RepoInfo {
@@ -459,6 +1313,19 @@ pub fn discover_repo(path: &Path) -> Result<RepoInfo, GitError> {
     })
 }
 
+/// Branch that `refs/remotes/origin/HEAD` points at, if it is set.
+///
+/// This is the remote's default branch as recorded by `git clone` (or
+/// `git remote set-head`), which can differ from the local HEAD.
+fn remote_default_branch(repo: &git2::Repository) -> Option<String> {
+    let reference = repo.find_reference("refs/remotes/origin/HEAD").ok()?;
+    let target = reference.symbolic_target()?;
+    target
+        .strip_prefix("refs/remotes/origin/")
+        .filter(|branch| !branch.is_empty())
+        .map(String::from)
+}
+
 /// Resolve the current worktree root for `path`.
 ///
 /// Unlike [`discover_repo`], this returns the active checkout root for the
@@ -475,26 +1342,31 @@ pub fn current_worktree_root(path: &Path) -> Result<PathBuf, GitError> {
         })
 }
 
-/// Create a new git worktree at `target_path` for the given branch.
+/// Create a new git worktree named `worktree_name` at `target_path` for the
+/// given branch.
 ///
 /// Opens the repository at `repo_path`, resolves `base` as a local branch
-/// first, then falls back to `origin/<base>` remote tracking branch.
+/// first, then falls back to `origin/<base>` remote tracking branch, and
+/// finally to any committish (tag, SHA, `HEAD~3`).
 /// Creates the new branch from the resolved base commit and adds a
 /// worktree at `target_path`.
 ///
 /// Returns `GitError::BranchAlreadyExists` if the branch already exists.
-/// Returns `GitError::BaseBranchNotFound` if `base` is not found locally
-/// or as `origin/<base>`.
-pub fn create_worktree(
+/// Returns `GitError::BaseBranchNotFound` if `base` does not resolve to a
+/// commit.
+pub fn create_named_worktree(
     repo_path: &Path,
     branch: &str,
     base: &str,
     target_path: &Path,
+    worktree_name: &str,
 ) -> Result<(), GitError> {
     let repo = git2::Repository::open(repo_path).map_err(|e| map_repo_open_error(e, repo_path))?;
 
-    // Check if branch already exists locally
+    // Check if branch already exists locally, naming its worktree when it
+    // is checked out so the user knows where it lives.
     if repo.find_branch(branch, git2::BranchType::Local).is_ok() {
+        ensure_branch_not_checked_out(repo_path, branch)?;
         return Err(GitError::BranchAlreadyExists {
             branch: branch.to_string(),
         });
@@ -505,6 +1377,7 @@ pub fn create_worktree(
     if let Ok(mut origin) = repo.find_remote("origin") {
         let mut fetch_opts = git2::FetchOptions::new();
         fetch_opts.prune(git2::FetchPrune::On);
+        fetch_opts.remote_callbacks(remote_callbacks(&repo));
         let _ = origin.fetch(&[] as &[&str], Some(&mut fetch_opts), None);
     }
 
@@ -520,31 +1393,38 @@ pub fn create_worktree(
         });
     }
 
-    // Resolve base branch to a commit (try local, then remote tracking)
+    // Resolve base to a commit: local branch, then origin/<base>, then any
+    // committish git understands (tag, SHA, `HEAD~3`).
     let base_commit = if let Ok(local) = repo.find_branch(base, git2::BranchType::Local) {
         local.get().peel_to_commit()?
     } else {
-        // Try remote tracking branch: origin/<base>
         let remote_name = format!("origin/{base}");
         match repo.find_branch(&remote_name, git2::BranchType::Remote) {
             Ok(remote) => remote.get().peel_to_commit()?,
-            Err(e) if e.code() == git2::ErrorCode::NotFound => {
-                return Err(GitError::BaseBranchNotFound {
-                    base: base.to_string(),
-                });
-            }
+            Err(e) if e.code() == git2::ErrorCode::NotFound => repo
+                .revparse_single(base)
+                .and_then(|obj| obj.peel_to_commit())
+                .map_err(|e| match e.code() {
+                    git2::ErrorCode::NotFound
+                    | git2::ErrorCode::InvalidSpec
+                    | git2::ErrorCode::Ambiguous
+                    | git2::ErrorCode::Peel => GitError::BaseBranchNotFound {
+                        base: base.to_string(),
+                        suggestions: suggest_branches(&repo, base),
+                    },
+                    _ => GitError::Git(e),
+                })?,
             Err(e) => return Err(GitError::Git(e)),
         }
     };
 
     // Create the new branch from base and add the worktree.
     // If worktree creation fails, clean up the orphaned branch.
-    let worktree_name = crate::paths::sanitize_branch(branch);
     let worktree_result = {
         let new_branch = repo.branch(branch, &base_commit, false)?;
         let mut opts = git2::WorktreeAddOptions::new();
         opts.reference(Some(new_branch.get()));
-        repo.worktree(&worktree_name, target_path, Some(&opts))
+        repo.worktree(worktree_name, target_path, Some(&opts))
     };
 
     if let Err(e) = worktree_result {
@@ -557,6 +1437,182 @@ pub fn create_worktree(
     Ok(())
 }
 
+/// Fail with `GitError::BranchInUse` if `branch` is checked out in any
+/// worktree of the repository at `repo_path`, including the main checkout.
+pub fn ensure_branch_not_checked_out(repo_path: &Path, branch: &str) -> Result<(), GitError> {
+    match list_worktrees(repo_path)?
+        .into_iter()
+        .find(|wt| wt.branch.as_deref() == Some(branch))
+    {
+        Some(wt) => Err(GitError::BranchInUse {
+            branch: branch.to_string(),
+            worktree: wt.path,
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Restrict a worktree's checkout to the given directory paths, the way
+/// `git sparse-checkout set --cone <paths>` does.
+///
+/// Enables `extensions.worktreeConfig` on the repository (see
+/// [`enable_worktree_config`]) so the worktree's own `config.worktree` can
+/// turn on `core.sparseCheckout` and `core.sparseCheckoutCone` without making
+/// other worktrees sparse. Cone patterns are written to `info/sparse-checkout`,
+/// so git keeps excluded directories out on later merges and pulls. Files
+/// directly in the root and in the parents of each path stay checked out, as
+/// in git's cone mode; every other index entry is marked skip-worktree and
+/// removed from disk. Paths are repo-relative directories (`src/app` keeps
+/// `src/app/**`).
+pub fn apply_sparse_checkout(worktree_path: &Path, paths: &[String]) -> Result<(), GitError> {
+    let repo = open_worktree_repo(worktree_path)?;
+    let workdir = repo
+        .workdir()
+        .ok_or_else(|| GitError::NotAGitRepo {
+            path: worktree_path.to_path_buf(),
+        })?
+        .to_path_buf();
+    let cones = SparseCones::new(paths);
+
+    enable_worktree_config(&repo)?;
+    let mut own_config = git2::Config::open(&repo.path().join("config.worktree"))?;
+    own_config.set_bool("core.sparseCheckout", true)?;
+    own_config.set_bool("core.sparseCheckoutCone", true)?;
+    let info_dir = repo.path().join("info");
+    std::fs::create_dir_all(&info_dir)?;
+    std::fs::write(info_dir.join("sparse-checkout"), cones.patterns())?;
+
+    let mut index = repo.index()?;
+    let entries: Vec<git2::IndexEntry> = index.iter().collect();
+    for mut entry in entries {
+        let entry_path = String::from_utf8_lossy(&entry.path).into_owned();
+        if cones.includes(&entry_path) {
+            continue;
+        }
+        entry.flags_extended |= git2::IndexEntryExtendedFlag::SKIP_WORKTREE.bits();
+        index.add(&entry)?;
+
+        let file = workdir.join(&entry_path);
+        match std::fs::remove_file(&file) {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e.into()),
+        }
+        // Drop directories emptied by the removal, stopping at the worktree root.
+        let mut dir = file.parent();
+        while let Some(d) = dir {
+            if d == workdir || std::fs::remove_dir(d).is_err() {
+                break;
+            }
+            dir = d.parent();
+        }
+    }
+    index.write()?;
+
+    Ok(())
+}
+
+/// Turn on `extensions.worktreeConfig` in the repository's shared config,
+/// as `git sparse-checkout` does for a worktree.
+///
+/// The extension needs repository format version 1. With it enabled,
+/// `core.bare` and `core.worktree` in the shared config would apply to every
+/// worktree, so they move into the main worktree's `config.worktree`.
+fn enable_worktree_config(repo: &git2::Repository) -> Result<(), GitError> {
+    // A linked worktree's `commondir` file points at the shared git dir.
+    let common = match std::fs::read_to_string(repo.path().join("commondir")) {
+        Ok(recorded) => repo.path().join(recorded.trim()),
+        Err(_) => repo.path().to_path_buf(),
+    };
+    let mut shared = git2::Config::open(&common.join("config"))?;
+    if shared
+        .get_bool("extensions.worktreeConfig")
+        .unwrap_or(false)
+    {
+        return Ok(());
+    }
+    if shared.get_i32("core.repositoryformatversion").unwrap_or(0) < 1 {
+        shared.set_i32("core.repositoryformatversion", 1)?;
+    }
+    let mut main_own = git2::Config::open(&common.join("config.worktree"))?;
+    if shared.get_bool("core.bare").unwrap_or(false) {
+        main_own.set_bool("core.bare", true)?;
+        shared.remove("core.bare")?;
+    }
+    if let Ok(worktree) = shared.get_string("core.worktree") {
+        main_own.set_str("core.worktree", &worktree)?;
+        shared.remove("core.worktree")?;
+    }
+    shared.set_bool("extensions.worktreeConfig", true)?;
+    Ok(())
+}
+
+/// Directories of a cone-mode sparse checkout.
+struct SparseCones {
+    /// Directories checked out recursively.
+    recursive: Vec<String>,
+    /// Ancestors of `recursive` whose direct files are checked out.
+    parents: std::collections::BTreeSet<String>,
+}
+
+impl SparseCones {
+    fn new(paths: &[String]) -> Self {
+        let mut recursive: Vec<String> = paths
+            .iter()
+            .map(|p| p.trim_matches('/').to_string())
+            .filter(|p| !p.is_empty())
+            .collect();
+        recursive.sort();
+        recursive.dedup();
+        // A directory inside another cone adds nothing.
+        let nested: Vec<String> = recursive
+            .iter()
+            .filter(|p| {
+                recursive.iter().any(|q| {
+                    p.strip_prefix(q.as_str())
+                        .is_some_and(|r| r.starts_with('/'))
+                })
+            })
+            .cloned()
+            .collect();
+        recursive.retain(|p| !nested.contains(p));
+
+        let mut parents = std::collections::BTreeSet::new();
+        for path in &recursive {
+            let mut dir = path.as_str();
+            while let Some((parent, _)) = dir.rsplit_once('/') {
+                parents.insert(parent.to_string());
+                dir = parent;
+            }
+        }
+        Self { recursive, parents }
+    }
+
+    /// `info/sparse-checkout` contents in git's cone pattern format.
+    fn patterns(&self) -> String {
+        let mut out = String::from("/*\n!/*/\n");
+        for parent in &self.parents {
+            out.push_str(&format!("/{parent}/\n!/{parent}/*/\n"));
+        }
+        for dir in &self.recursive {
+            out.push_str(&format!("/{dir}/\n"));
+        }
+        out
+    }
+
+    /// Whether the index entry at `path` stays checked out.
+    fn includes(&self, path: &str) -> bool {
+        let in_cone = self.recursive.iter().any(|dir| {
+            path.strip_prefix(dir.as_str())
+                .is_some_and(|r| r.starts_with('/'))
+        });
+        match path.rsplit_once('/') {
+            None => true,
+            Some((dir, _)) => in_cone || self.parents.contains(dir),
+        }
+    }
+}
+
 /// Delete a local branch.
 ///
 /// Safe deletion refuses to remove branches that are not fully merged.
@@ -686,7 +1742,7 @@ pub fn list_worktrees(repo_path: &Path) -> Result<Vec<GitWorktreeEntry>, GitErro
                     continue;
                 }
                 // Open as repository to get HEAD branch
-                let branch = if let Ok(wt_repo) = git2::Repository::open(&canonical) {
+                let branch = if let Ok(wt_repo) = open_worktree_repo(&canonical) {
                     wt_repo
                         .head()
                         .ok()
@@ -707,6 +1763,63 @@ pub fn list_worktrees(repo_path: &Path) -> Result<Vec<GitWorktreeEntry>, GitErro
     Ok(entries)
 }
 
+/// Point each linked worktree's `.git` file back at the main checkout at `repo_path`.
+///
+/// Moving a repository on disk leaves its linked worktrees with a `gitdir:`
+/// pointer to the old location. This rewrites those pointers from the
+/// admin entries under `<repo>/.git/worktrees`, like `git worktree repair`.
+/// Returns the number of worktrees whose link was rewritten.
+pub fn repair_worktree_links(repo_path: &Path) -> Result<usize, GitError> {
+    let repo = git2::Repository::open(repo_path).map_err(|e| map_repo_open_error(e, repo_path))?;
+    let admin_root = repo.path().join("worktrees");
+    let admin_dirs = match std::fs::read_dir(&admin_root) {
+        Ok(dirs) => dirs,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+        Err(e) => return Err(e.into()),
+    };
+
+    let common_dir = canonical_or_original(repo.path());
+    let mut repaired = 0;
+    for admin in admin_dirs {
+        let admin = canonical_or_original(&admin?.path());
+        // `gitdir` holds the path of the worktree's `.git` file.
+        let Ok(dotgit) = std::fs::read_to_string(admin.join("gitdir")) else {
+            continue;
+        };
+        let dotgit = PathBuf::from(dotgit.trim_end());
+        if !dotgit.is_file() {
+            continue;
+        }
+
+        let mut changed = false;
+        // libgit2 records `commondir` as an absolute path, which goes stale
+        // on a move; git's own relative form survives future moves.
+        let commondir = admin.join("commondir");
+        if let Ok(recorded) = std::fs::read_to_string(&commondir) {
+            if canonical_or_original(&admin.join(recorded.trim_end())) != common_dir {
+                std::fs::write(&commondir, "../..\n")?;
+                changed = true;
+            }
+        }
+
+        let current = std::fs::read_to_string(&dotgit)?;
+        let target = current
+            .trim()
+            .strip_prefix("gitdir:")
+            .map(|p| canonical_or_original(Path::new(p.trim())));
+        if target.as_deref() != Some(admin.as_path()) {
+            std::fs::write(&dotgit, format!("gitdir: {}\n", admin.display()))?;
+            changed = true;
+        }
+
+        if changed {
+            repaired += 1;
+        }
+    }
+
+    Ok(repaired)
+}
+
 /// Return the short upstream branch name for a local branch in a worktree.
 ///
 /// Examples:
@@ -716,8 +1829,7 @@ pub fn upstream_branch_name(
     worktree_path: &Path,
     branch: &str,
 ) -> Result<Option<String>, GitError> {
-    let repo =
-        git2::Repository::open(worktree_path).map_err(|e| map_repo_open_error(e, worktree_path))?;
+    let repo = open_worktree_repo(worktree_path)?;
 
     let local = match repo.find_branch(branch, git2::BranchType::Local) {
         Ok(branch) => branch,
@@ -767,7 +1879,7 @@ pub fn scan_directories(scan_paths: &[String]) -> Vec<GitWorktreeEntry> {
                 continue;
             }
             // Try to open as a git repository
-            if let Ok(repo) = git2::Repository::open(&child) {
+            if let Ok(repo) = open_worktree_repo(&child) {
                 let canonical = canonical_or_original(&child);
                 let branch = repo
                     .head()
@@ -790,6 +1902,39 @@ pub fn scan_directories(scan_paths: &[String]) -> Vec<GitWorktreeEntry> {
     entries
 }
 
+/// Return the lock reason for the linked worktree `name`, if it is locked.
+///
+/// `Some("")` means the worktree is locked without a reason. The main
+/// worktree cannot be locked and is reported as `WorktreeNotFound`.
+pub fn worktree_lock_reason(repo_path: &Path, name: &str) -> Result<Option<String>, GitError> {
+    let repo = git2::Repository::open(repo_path).map_err(|e| map_repo_open_error(e, repo_path))?;
+    let wt = repo.find_worktree(name).map_err(|e| {
+        if e.code() == git2::ErrorCode::NotFound {
+            GitError::WorktreeNotFound {
+                name: name.to_string(),
+            }
+        } else {
+            GitError::Git(e)
+        }
+    })?;
+    Ok(match wt.is_locked()? {
+        git2::WorktreeLockStatus::Locked(reason) => Some(reason.unwrap_or_default()),
+        git2::WorktreeLockStatus::Unlocked => None,
+    })
+}
+
+/// Check whether the linked worktree `name` is locked (`git worktree lock`).
+pub fn is_worktree_locked(repo_path: &Path, name: &str) -> Result<bool, GitError> {
+    Ok(worktree_lock_reason(repo_path, name)?.is_some())
+}
+
+/// Unlock the linked worktree `name` so it can be removed and pruned.
+pub fn unlock_worktree(repo_path: &Path, name: &str) -> Result<(), GitError> {
+    let repo = git2::Repository::open(repo_path).map_err(|e| map_repo_open_error(e, repo_path))?;
+    repo.find_worktree(name)?.unlock()?;
+    Ok(())
+}
+
 /// Remove a git worktree at the given path.
 ///
 /// Removes the worktree directory from disk, then prunes stale worktree
@@ -824,10 +1969,78 @@ pub fn remove_worktree(repo_path: &Path, worktree_path: &Path) -> Result<(), Git
     Ok(())
 }
 
+/// Drop git's registration of the linked worktree `name` while leaving its
+/// directory on disk.
+///
+/// Unlike [`remove_worktree`], the working tree is kept; only the admin
+/// entry under `.git/worktrees/` is pruned. Locked worktrees are refused.
+pub fn unregister_worktree(repo_path: &Path, name: &str) -> Result<(), GitError> {
+    let repo = git2::Repository::open(repo_path).map_err(|e| map_repo_open_error(e, repo_path))?;
+    let wt = repo.find_worktree(name).map_err(|e| {
+        if e.code() == git2::ErrorCode::NotFound {
+            GitError::WorktreeNotFound {
+                name: name.to_string(),
+            }
+        } else {
+            GitError::Git(e)
+        }
+    })?;
+    wt.prune(Some(
+        git2::WorktreePruneOptions::new()
+            .working_tree(false)
+            .valid(true)
+            .locked(false),
+    ))?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn normalize_remote_url_handles_ssh_shorthand() {
+        let remote = normalize_remote_url("git@github.com:sadiksaifi/trench.git").unwrap();
+        assert_eq!(
+            remote,
+            NormalizedRemote {
+                host: "github.com".into(),
+                owner: "sadiksaifi".into(),
+                repo: "trench".into(),
+                https_url: "https://github.com/sadiksaifi/trench".into(),
+            }
+        );
+        assert_eq!(remote.slug(), "sadiksaifi/trench");
+    }
+
+    #[test]
+    fn normalize_remote_url_handles_https_and_ssh_scheme() {
+        let https = normalize_remote_url("https://github.com/sadiksaifi/trench.git").unwrap();
+        assert_eq!(https.host, "github.com");
+        assert_eq!(https.slug(), "sadiksaifi/trench");
+
+        let ssh = normalize_remote_url("ssh://git@gitlab.com:2222/group/sub/proj.git").unwrap();
+        assert_eq!(ssh.host, "gitlab.com");
+        assert_eq!(ssh.owner, "group/sub");
+        assert_eq!(ssh.https_url, "https://gitlab.com/group/sub/proj");
+    }
+
+    #[test]
+    fn normalize_remote_url_without_git_suffix() {
+        let remote = normalize_remote_url("https://user@example.com/acme/widgets/").unwrap();
+        assert_eq!(remote.host, "example.com");
+        assert_eq!(remote.repo, "widgets");
+        assert_eq!(remote.https_url, "https://example.com/acme/widgets");
+    }
+
+    #[test]
+    fn normalize_remote_url_rejects_local_paths() {
+        assert_eq!(normalize_remote_url("/srv/git/repo.git"), None);
+        assert_eq!(normalize_remote_url("./relative/repo"), None);
+        assert_eq!(normalize_remote_url("file:///srv/git/repo.git"), None);
+        assert_eq!(normalize_remote_url("git@github.com:trench.git"), None);
+    }
+
     /// Helper: create a temp git repo with an initial commit.
     fn init_repo_with_commit(dir: &Path) -> git2::Repository {
         let repo = git2::Repository::init(dir).expect("failed to init repo");
@@ -886,11 +2099,12 @@ mod tests {
 
     #[test]
     fn create_worktree_on_nonexistent_repo_returns_not_a_git_repo() {
-        let result = create_worktree(
+        let result = create_named_worktree(
             Path::new("/tmp/nonexistent_repo_xyz_abc"),
             "branch",
             "main",
             Path::new("/tmp/wt"),
+            "branch",
         );
 
         assert!(result.is_err());
@@ -964,8 +2178,101 @@ mod tests {
 
         // The default branch should be whatever HEAD points to
         assert!(
-            !info.default_branch.is_empty(),
-            "default_branch should not be empty"
+            !info.default_branch.is_empty(),
+            "default_branch should not be empty"
+        );
+    }
+
+    #[test]
+    fn discover_repo_prefers_origin_head_for_default_branch() {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo = init_repo_with_commit(tmp.path());
+        let head_oid = repo.head().unwrap().target().unwrap();
+        repo.set_head_detached(head_oid).unwrap();
+        repo.branch("master", &repo.find_commit(head_oid).unwrap(), true)
+            .unwrap();
+        repo.set_head("refs/heads/master").unwrap();
+        repo.remote("origin", "https://github.com/test/repo.git")
+            .unwrap();
+        repo.reference("refs/remotes/origin/main", head_oid, true, "test")
+            .unwrap();
+        repo.reference_symbolic(
+            "refs/remotes/origin/HEAD",
+            "refs/remotes/origin/main",
+            true,
+            "test",
+        )
+        .unwrap();
+
+        let info = discover_repo(tmp.path()).expect("should discover repo");
+
+        assert_eq!(info.default_branch, "main");
+    }
+
+    #[test]
+    fn discover_repo_falls_back_to_local_head_without_origin_head() {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo = init_repo_with_commit(tmp.path());
+        let local = head_branch(&repo);
+
+        let info = discover_repo(tmp.path()).expect("should discover repo");
+
+        assert_eq!(info.default_branch, local);
+    }
+
+    #[test]
+    fn branch_name_from_os_rejects_non_utf8() {
+        use std::os::unix::ffi::OsStrExt;
+
+        let raw = std::ffi::OsStr::from_bytes(b"feature/caf\xe9");
+        let err = branch_name_from_os(raw).unwrap_err();
+        assert!(
+            matches!(err, GitError::NonUtf8Branch { ref lossy } if lossy == "feature/caf\u{FFFD}"),
+            "expected NonUtf8Branch, got: {err:?}"
+        );
+        assert!(err.to_string().contains("git branch -m"));
+
+        let ok = std::ffi::OsStr::new("feature/café");
+        assert_eq!(branch_name_from_os(ok).unwrap(), "feature/café");
+    }
+
+    #[test]
+    fn open_worktree_repo_missing_path_is_worktree_not_found() {
+        let tmp = tempfile::tempdir().unwrap();
+        let missing = tmp.path().join("gone");
+
+        let err = open_worktree_repo(&missing)
+            .err()
+            .expect("missing path should fail");
+        assert!(
+            matches!(err, GitError::WorktreeNotFound { ref name } if name.ends_with("gone")),
+            "expected WorktreeNotFound, got: {err:?}"
+        );
+    }
+
+    #[test]
+    fn open_worktree_repo_opens_valid_worktree() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let repo = init_repo_with_commit(repo_dir.path());
+        let base = head_branch(&repo);
+        let wt_dir = tempfile::tempdir().unwrap();
+        let wt_path = wt_dir.path().join("feature");
+        create_named_worktree(repo_dir.path(), "feature", &base, &wt_path, "feature").unwrap();
+
+        let repo = open_worktree_repo(&wt_path).expect("worktree should open");
+        assert!(repo.is_worktree());
+        assert_eq!(repo.head().unwrap().shorthand(), Some("feature"));
+    }
+
+    #[test]
+    fn open_worktree_repo_plain_directory_is_not_a_git_repo() {
+        let tmp = tempfile::tempdir().unwrap();
+        let err = open_worktree_repo(tmp.path())
+            .err()
+            .expect("plain directory should fail");
+        assert!(
+            matches!(err, GitError::NotAGitRepo { .. }),
+            "expected NotAGitRepo, got: {err:?}"
         );
     }
 
@@ -992,7 +2299,7 @@ mod tests {
         let wt_dir = tempfile::tempdir().unwrap();
         let target = wt_dir.path().join("my-feature");
 
-        create_worktree(repo_dir.path(), "my-feature", &base, &target)
+        create_named_worktree(repo_dir.path(), "my-feature", &base, &target, "my-feature")
             .expect("should create worktree");
 
         assert!(target.exists(), "worktree directory should exist on disk");
@@ -1013,7 +2320,7 @@ mod tests {
         let wt_dir = tempfile::tempdir().unwrap();
         let target = wt_dir.path().join("new-branch");
 
-        create_worktree(repo_dir.path(), "new-branch", &base, &target)
+        create_named_worktree(repo_dir.path(), "new-branch", &base, &target, "new-branch")
             .expect("should create worktree");
 
         // The new branch should exist in the repo and point to the same commit as base
@@ -1038,8 +2345,14 @@ mod tests {
         let wt_dir = tempfile::tempdir().unwrap();
         let target = wt_dir.path().join("feature-auth");
 
-        create_worktree(repo_dir.path(), "feature/auth", &base, &target)
-            .expect("should create worktree for slash branch");
+        create_named_worktree(
+            repo_dir.path(),
+            "feature/auth",
+            &base,
+            &target,
+            "feature-auth",
+        )
+        .expect("should create worktree for slash branch");
 
         let new_branch = repo
             .find_branch("feature/auth", git2::BranchType::Local)
@@ -1067,7 +2380,8 @@ mod tests {
         // Place a regular file at the target path so worktree creation fails
         std::fs::write(&target, "blocker").unwrap();
 
-        let result = create_worktree(repo_dir.path(), "will-fail", &base, &target);
+        let result =
+            create_named_worktree(repo_dir.path(), "will-fail", &base, &target, "will-fail");
 
         assert!(result.is_err(), "should fail when target path is occupied");
 
@@ -1119,7 +2433,13 @@ mod tests {
         let target = wt_dir.path().join("my-feature");
 
         // Use "release" as base — should resolve via remote tracking
-        let result = create_worktree(repo_dir.path(), "my-feature", "release", &target);
+        let result = create_named_worktree(
+            repo_dir.path(),
+            "my-feature",
+            "release",
+            &target,
+            "my-feature",
+        );
         assert!(
             result.is_ok(),
             "should resolve base from remote tracking branch, got: {:?}",
@@ -1157,7 +2477,8 @@ mod tests {
         let wt_dir = tempfile::tempdir().unwrap();
         let target = wt_dir.path().join("feature");
 
-        let result = create_worktree(repo_dir.path(), "feature", "corrupt", &target);
+        let result =
+            create_named_worktree(repo_dir.path(), "feature", "corrupt", &target, "feature");
 
         assert!(result.is_err());
         let err = result.unwrap_err();
@@ -1174,16 +2495,111 @@ mod tests {
         let wt_dir = tempfile::tempdir().unwrap();
         let target = wt_dir.path().join("feature");
 
-        let result = create_worktree(repo_dir.path(), "feature", "nonexistent-base", &target);
+        let result = create_named_worktree(
+            repo_dir.path(),
+            "feature",
+            "nonexistent-base",
+            &target,
+            "feature",
+        );
 
         assert!(result.is_err());
         let err = result.unwrap_err();
         assert!(
-            matches!(err, GitError::BaseBranchNotFound { ref base } if base == "nonexistent-base"),
+            matches!(err, GitError::BaseBranchNotFound { ref base, .. } if base == "nonexistent-base"),
             "missing base branch should yield BaseBranchNotFound, got: {err:?}"
         );
     }
 
+    #[test]
+    fn create_worktree_suggests_close_base_branch() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let repo = init_repo_with_commit(repo_dir.path());
+        let head = repo.head().unwrap().peel_to_commit().unwrap();
+        for name in ["main", "develop", "release"] {
+            repo.branch(name, &head, true).unwrap();
+        }
+        let target = tempfile::tempdir().unwrap().path().join("feature");
+
+        let err = create_named_worktree(repo_dir.path(), "feature", "mian", &target, "feature")
+            .unwrap_err();
+
+        match &err {
+            GitError::BaseBranchNotFound { base, suggestions } => {
+                assert_eq!(base, "mian");
+                assert_eq!(suggestions.first().map(String::as_str), Some("main"));
+                assert!(!suggestions.contains(&"develop".to_string()));
+            }
+            other => panic!("expected BaseBranchNotFound, got: {other:?}"),
+        }
+        assert!(
+            err.to_string().ends_with("did you mean 'main'?"),
+            "got: {err}"
+        );
+    }
+
+    #[test]
+    fn edit_distance_counts_single_character_edits_and_swaps() {
+        assert_eq!(edit_distance("main", "main"), 0);
+        assert_eq!(edit_distance("mian", "main"), 1);
+        assert_eq!(edit_distance("mai", "main"), 1);
+        assert_eq!(edit_distance("", "dev"), 3);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn create_worktree_resolves_base_from_tag() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let repo = init_repo_with_commit(repo_dir.path());
+        let tagged = repo.head().unwrap().peel_to_commit().unwrap();
+        repo.tag_lightweight("v1.0.0", tagged.as_object(), false)
+            .unwrap();
+        let sig = git2::Signature::now("Test", "test@test.com").unwrap();
+        let tree = tagged.tree().unwrap();
+        repo.commit(Some("HEAD"), &sig, &sig, "after tag", &tree, &[&tagged])
+            .unwrap();
+        let wt_dir = tempfile::tempdir().unwrap();
+
+        create_named_worktree(
+            repo_dir.path(),
+            "hotfix",
+            "v1.0.0",
+            &wt_dir.path().join("hotfix"),
+            "hotfix",
+        )
+        .unwrap();
+
+        let branch = repo.find_branch("hotfix", git2::BranchType::Local).unwrap();
+        assert_eq!(branch.get().target(), Some(tagged.id()));
+    }
+
+    #[test]
+    fn create_worktree_resolves_base_from_short_sha() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let repo = init_repo_with_commit(repo_dir.path());
+        let first = repo.head().unwrap().peel_to_commit().unwrap();
+        let sig = git2::Signature::now("Test", "test@test.com").unwrap();
+        let tree = first.tree().unwrap();
+        repo.commit(Some("HEAD"), &sig, &sig, "second", &tree, &[&first])
+            .unwrap();
+        let short_sha = first.id().to_string()[..7].to_string();
+        let wt_dir = tempfile::tempdir().unwrap();
+
+        create_named_worktree(
+            repo_dir.path(),
+            "from-sha",
+            &short_sha,
+            &wt_dir.path().join("from-sha"),
+            "from-sha",
+        )
+        .unwrap();
+
+        let branch = repo
+            .find_branch("from-sha", git2::BranchType::Local)
+            .unwrap();
+        assert_eq!(branch.get().target(), Some(first.id()));
+    }
+
     #[test]
     fn create_worktree_errors_when_target_path_already_exists() {
         let repo_dir = tempfile::tempdir().unwrap();
@@ -1195,7 +2611,7 @@ mod tests {
         // Create a directory at the target path
         std::fs::create_dir_all(&target).unwrap();
 
-        let result = create_worktree(repo_dir.path(), "occupied", &base, &target);
+        let result = create_named_worktree(repo_dir.path(), "occupied", &base, &target, "occupied");
 
         assert!(
             result.is_err(),
@@ -1243,7 +2659,13 @@ mod tests {
         let wt_dir = tempfile::tempdir().unwrap();
         let target = wt_dir.path().join("taken-branch");
 
-        let result = create_worktree(repo_dir.path(), "taken-branch", &base, &target);
+        let result = create_named_worktree(
+            repo_dir.path(),
+            "taken-branch",
+            &base,
+            &target,
+            "taken-branch",
+        );
 
         assert!(result.is_err(), "should fail when branch exists on remote");
         let err = result.unwrap_err();
@@ -1258,7 +2680,7 @@ mod tests {
     fn create_worktree_succeeds_after_remote_branch_deleted() {
         // Setup: bare "remote" repo with a branch, clone it, delete the branch on remote.
         // The clone retains a stale remote-tracking ref (origin/stale-branch).
-        // create_worktree should fetch+prune, clearing the stale ref, and succeed.
+        // create_named_worktree should fetch+prune, clearing the stale ref, and succeed.
 
         let remote_dir = tempfile::tempdir().unwrap();
         let remote_repo = git2::Repository::init_bare(remote_dir.path()).unwrap();
@@ -1309,7 +2731,13 @@ mod tests {
         let target = wt_dir.path().join("stale-branch");
 
         // This should succeed: fetch+prune clears the stale ref
-        let result = create_worktree(clone_dir.path(), "stale-branch", &base, &target);
+        let result = create_named_worktree(
+            clone_dir.path(),
+            "stale-branch",
+            &base,
+            &target,
+            "stale-branch",
+        );
 
         assert!(
             result.is_ok(),
@@ -1318,6 +2746,195 @@ mod tests {
         assert!(target.exists(), "worktree directory should exist on disk");
     }
 
+    /// Bare remote with `main` and `stale-branch`, cloned; `stale-branch` is
+    /// then deleted on the remote so the clone holds a stale tracking ref.
+    fn clone_with_stale_remote_branch() -> (tempfile::TempDir, tempfile::TempDir) {
+        let remote_dir = tempfile::tempdir().unwrap();
+        let remote_repo = git2::Repository::init_bare(remote_dir.path()).unwrap();
+        let sig = git2::Signature::now("Test", "test@test.com").unwrap();
+        let empty_tree = remote_repo.treebuilder(None).unwrap().write().unwrap();
+        let tree = remote_repo.find_tree(empty_tree).unwrap();
+        let oid = remote_repo
+            .commit(Some("refs/heads/main"), &sig, &sig, "init", &tree, &[])
+            .unwrap();
+        remote_repo.set_head("refs/heads/main").unwrap();
+        let commit = remote_repo.find_commit(oid).unwrap();
+        remote_repo.branch("stale-branch", &commit, false).unwrap();
+
+        let clone_dir = tempfile::tempdir().unwrap();
+        git2::build::RepoBuilder::new()
+            .clone(remote_dir.path().to_str().unwrap(), clone_dir.path())
+            .unwrap();
+
+        remote_repo
+            .find_branch("stale-branch", git2::BranchType::Local)
+            .unwrap()
+            .delete()
+            .unwrap();
+        (remote_dir, clone_dir)
+    }
+
+    #[test]
+    fn prune_stale_refs_removes_deleted_remote_branches() {
+        let (_remote_dir, clone_dir) = clone_with_stale_remote_branch();
+
+        let pruned = prune_stale_refs(clone_dir.path(), "origin").unwrap();
+
+        assert_eq!(pruned, vec!["origin/stale-branch".to_string()]);
+        let clone = git2::Repository::open(clone_dir.path()).unwrap();
+        assert!(clone
+            .find_branch("origin/stale-branch", git2::BranchType::Remote)
+            .is_err());
+        assert!(clone
+            .find_branch("origin/main", git2::BranchType::Remote)
+            .is_ok());
+    }
+
+    #[test]
+    fn verbose_remote_callbacks_log_fetch_progress() {
+        let (remote_dir, clone_dir) = clone_with_stale_remote_branch();
+        let remote_repo = git2::Repository::open_bare(remote_dir.path()).unwrap();
+        let sig = git2::Signature::now("Test", "test@test.com").unwrap();
+        let head = remote_repo.head().unwrap().peel_to_commit().unwrap();
+        remote_repo
+            .commit(
+                Some("refs/heads/main"),
+                &sig,
+                &sig,
+                "second",
+                &head.tree().unwrap(),
+                &[&head],
+            )
+            .unwrap();
+
+        let lines = std::cell::RefCell::new(Vec::new());
+        {
+            let log: RemoteLog<'_> =
+                std::rc::Rc::new(|line: &str| lines.borrow_mut().push(line.to_string()));
+            let mut fetch_opts = git2::FetchOptions::new();
+            fetch_opts.remote_callbacks(build_remote_callbacks(
+                Some(log),
+                CredentialAttempts::with_ssh_dir(None, None),
+            ));
+            let clone = git2::Repository::open(clone_dir.path()).unwrap();
+            clone
+                .find_remote("origin")
+                .unwrap()
+                .fetch(&[] as &[&str], Some(&mut fetch_opts), None)
+                .unwrap();
+        }
+
+        let lines = lines.into_inner();
+        assert!(
+            lines.iter().any(|l| l.starts_with("received ")),
+            "expected transfer progress, got {lines:?}"
+        );
+        assert!(
+            lines
+                .iter()
+                .any(|l| l.starts_with("updated refs/remotes/origin/main")),
+            "expected tip update, got {lines:?}"
+        );
+    }
+
+    #[test]
+    fn credential_attempts_try_agent_then_key_files_then_fail() {
+        let ssh_dir = tempfile::tempdir().unwrap();
+        for name in ["id_rsa", "id_ed25519", "id_ed25519.pub", "known_hosts"] {
+            std::fs::write(ssh_dir.path().join(name), "key").unwrap();
+        }
+        let mut attempts =
+            CredentialAttempts::with_ssh_dir(None, Some(ssh_dir.path().to_path_buf()));
+        let ssh = git2::CredentialType::SSH_KEY;
+        let url = "git@example.com:org/repo.git";
+
+        let mut sources = Vec::new();
+        for _ in 0..3 {
+            sources.push(attempts.describe_next(ssh));
+            let cred = attempts.next(url, Some("git"), ssh).unwrap();
+            assert!(cred.has_username());
+        }
+        assert_eq!(sources, ["ssh-agent", "ssh key file", "ssh key file"]);
+        assert_eq!(
+            attempts.ssh_key_files(),
+            [
+                ssh_dir.path().join("id_ed25519"),
+                ssh_dir.path().join("id_rsa")
+            ]
+        );
+
+        let err = attempts
+            .next(url, Some("git"), ssh)
+            .err()
+            .expect("credentials should be exhausted");
+        assert_eq!(err.code(), git2::ErrorCode::Auth);
+    }
+
+    #[test]
+    fn credential_attempts_answer_username_requests_and_exhaust_helper_once() {
+        let mut attempts = CredentialAttempts::with_ssh_dir(None, None);
+        let url = "https://example.com/org/repo.git";
+
+        let cred = attempts
+            .next(url, None, git2::CredentialType::USERNAME)
+            .unwrap();
+        assert_eq!(cred.credtype(), git2::CredentialType::USERNAME.bits());
+
+        // Without a credential helper, plaintext auth ends in an Auth error.
+        let err = attempts
+            .next(url, None, git2::CredentialType::USER_PASS_PLAINTEXT)
+            .err()
+            .expect("no helper should mean no credentials");
+        assert_eq!(err.code(), git2::ErrorCode::Auth);
+    }
+
+    #[test]
+    fn auth_errors_map_to_authentication_error() {
+        let auth = git2::Error::new(
+            git2::ErrorCode::Auth,
+            git2::ErrorClass::Ssh,
+            "no more credentials to try",
+        );
+        let err = map_remote_error(auth, "git@example.com:org/repo.git");
+        assert!(matches!(
+            &err,
+            GitError::Authentication { url, .. } if url == "git@example.com:org/repo.git"
+        ));
+        assert!(err.to_string().contains("ssh-agent"), "{err}");
+
+        let other = git2::Error::from_str("connection refused");
+        assert!(matches!(
+            map_remote_error(other, "origin"),
+            GitError::Git(_)
+        ));
+    }
+
+    #[test]
+    fn prune_stale_refs_without_remote_is_noop() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        init_repo_with_commit(repo_dir.path());
+
+        assert!(prune_stale_refs(repo_dir.path(), "origin")
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn unregister_worktree_keeps_directory_but_drops_registration() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let repo = init_repo_with_commit(repo_dir.path());
+        let base = head_branch(&repo);
+        let wt_dir = tempfile::tempdir().unwrap();
+        let target = wt_dir.path().join("kept");
+        create_named_worktree(repo_dir.path(), "kept", &base, &target, "kept").unwrap();
+
+        unregister_worktree(repo_dir.path(), "kept").expect("should unregister");
+
+        assert!(target.is_dir(), "directory should survive");
+        assert!(repo.find_worktree("kept").is_err(), "admin entry is pruned");
+        assert!(repo.find_branch("kept", git2::BranchType::Local).is_ok());
+    }
+
     #[test]
     fn remove_worktree_deletes_directory_and_prunes() {
         let repo_dir = tempfile::tempdir().unwrap();
@@ -1326,7 +2943,7 @@ mod tests {
         let wt_dir = tempfile::tempdir().unwrap();
         let target = wt_dir.path().join("to-remove");
 
-        create_worktree(repo_dir.path(), "to-remove", &base, &target)
+        create_named_worktree(repo_dir.path(), "to-remove", &base, &target, "to-remove")
             .expect("should create worktree");
         assert!(target.exists(), "worktree should exist before removal");
 
@@ -1355,6 +2972,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn commit_summary_describes_head() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let repo = init_repo_with_commit(repo_dir.path());
+        let head_id = repo.head().unwrap().target().unwrap().to_string();
+
+        let summary = commit_summary(repo_dir.path())
+            .unwrap()
+            .expect("repo with commits should have a summary");
+
+        assert_eq!(summary.short_id, head_id[..7]);
+        assert_eq!(summary.summary, "initial commit");
+        assert_eq!(summary.author, "Test");
+        assert!(summary.time > 0);
+    }
+
+    #[test]
+    fn commit_summary_is_none_for_empty_repo() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        git2::Repository::init(repo_dir.path()).unwrap();
+
+        assert_eq!(commit_summary(repo_dir.path()).unwrap(), None);
+    }
+
     #[test]
     fn ahead_behind_counts_commits_ahead_of_base() {
         let tmp = tempfile::tempdir().unwrap();
@@ -1439,6 +3080,23 @@ mod tests {
         assert_eq!(result, None, "no upstream and no base should return None");
     }
 
+    #[test]
+    fn diff_stat_vs_base_is_none_for_unknown_base_and_zero_at_base() {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo = init_repo_with_commit(tmp.path());
+        let base = head_branch(&repo);
+
+        assert_eq!(diff_stat_vs_base(tmp.path(), "no-such-base").unwrap(), None);
+        assert_eq!(
+            diff_stat_vs_base(tmp.path(), &base).unwrap(),
+            Some(DiffStat {
+                files_changed: 0,
+                insertions: 0,
+                deletions: 0,
+            })
+        );
+    }
+
     #[test]
     fn dirty_count_returns_zero_for_clean_worktree() {
         let tmp = tempfile::tempdir().unwrap();
@@ -1520,7 +3178,7 @@ mod tests {
         let wt_dir = tempfile::tempdir().unwrap();
         let target = wt_dir.path().join("extra-wt");
 
-        create_worktree(repo_dir.path(), "extra-wt", &base, &target)
+        create_named_worktree(repo_dir.path(), "extra-wt", &base, &target, "extra-wt")
             .expect("should create worktree");
 
         let worktrees = list_worktrees(repo_dir.path()).expect("should list worktrees");
@@ -1548,7 +3206,7 @@ mod tests {
         let wt_dir = tempfile::tempdir().unwrap();
         let target = wt_dir.path().join("extra-wt");
 
-        create_worktree(repo_dir.path(), "extra-wt", &base, &target)
+        create_named_worktree(repo_dir.path(), "extra-wt", &base, &target, "extra-wt")
             .expect("should create worktree");
         std::fs::remove_dir_all(&target).expect("manual delete should succeed");
 
@@ -1566,7 +3224,7 @@ mod tests {
         let wt_dir = tempfile::tempdir().unwrap();
         let target = wt_dir.path().join("linked-wt");
 
-        create_worktree(repo_dir.path(), "linked-wt", &base, &target)
+        create_named_worktree(repo_dir.path(), "linked-wt", &base, &target, "linked-wt")
             .expect("should create linked worktree");
 
         let info = discover_repo(&target).expect("should discover from linked worktree");
@@ -1598,6 +3256,95 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn sparse_cones_match_git_cone_mode() {
+        let cones = SparseCones::new(&[
+            "services/api/".to_string(),
+            "services/api/v2".to_string(),
+            "libs".to_string(),
+        ]);
+        assert_eq!(
+            cones.patterns(),
+            "/*\n!/*/\n/services/\n!/services/*/\n/libs/\n/services/api/\n"
+        );
+        assert!(cones.includes("Cargo.toml"));
+        assert!(cones.includes("services/README.md"));
+        assert!(cones.includes("services/api/v2/main.rs"));
+        assert!(cones.includes("libs/a/b.rs"));
+        assert!(!cones.includes("services/web/index.js"));
+        assert!(!cones.includes("docs/guide.md"));
+        assert!(!cones.includes("libsx/a.rs"));
+    }
+
+    #[test]
+    fn apply_sparse_checkout_keeps_only_listed_directories() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let repo = init_repo_with_commit(repo_dir.path());
+        std::fs::create_dir_all(repo_dir.path().join("app/src")).unwrap();
+        std::fs::create_dir_all(repo_dir.path().join("docs")).unwrap();
+        std::fs::write(repo_dir.path().join("app/src/main.rs"), "fn main() {}").unwrap();
+        std::fs::write(repo_dir.path().join("docs/guide.md"), "# guide").unwrap();
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new("app/src/main.rs")).unwrap();
+        index.add_path(Path::new("docs/guide.md")).unwrap();
+        index.write().unwrap();
+        commit_file(&repo, "README.md", "readme", "add tree");
+        let base = head_branch(&repo);
+
+        let wt_dir = tempfile::tempdir().unwrap();
+        let target = wt_dir.path().join("sparse");
+        create_named_worktree(repo_dir.path(), "sparse", &base, &target, "sparse").unwrap();
+
+        apply_sparse_checkout(&target, &["app".to_string()]).expect("sparse should apply");
+
+        assert!(target.join("app/src/main.rs").exists());
+        assert!(!target.join("docs").exists(), "docs/ should be removed");
+        assert!(
+            target.join("README.md").exists(),
+            "top-level files stay, as in cone mode"
+        );
+        assert_eq!(
+            dirty_count(&target).unwrap(),
+            0,
+            "skipped files should not show as deleted"
+        );
+        assert!(
+            repo_dir.path().join("docs/guide.md").exists(),
+            "main checkout should be unaffected"
+        );
+
+        let git = |dir: &Path, args: &[&str]| {
+            let output = std::process::Command::new("git")
+                .args(args)
+                .current_dir(dir)
+                .output()
+                .unwrap();
+            assert!(output.status.success(), "git {args:?}: {output:?}");
+            String::from_utf8(output.stdout).unwrap()
+        };
+        assert_eq!(git(&target, &["sparse-checkout", "list"]), "app\n");
+        let main_sparse = std::process::Command::new("git")
+            .args(["sparse-checkout", "list"])
+            .current_dir(repo_dir.path())
+            .output()
+            .unwrap();
+        assert!(
+            !main_sparse.status.success(),
+            "main checkout must not be sparse"
+        );
+
+        // Git itself keeps excluded directories out when the branch moves.
+        commit_file(&repo, "docs/new.md", "new", "docs change");
+        commit_file(&repo, "app/lib.rs", "lib", "app change");
+        git(&target, &["merge", "--ff-only", &base]);
+        assert!(target.join("app/lib.rs").exists());
+        assert!(
+            !target.join("docs").exists(),
+            "merge must not restore docs/"
+        );
+        assert_eq!(dirty_count(&target).unwrap(), 0);
+    }
+
     #[test]
     fn delete_local_branch_deletes_merged_branch() {
         let repo_dir = tempfile::tempdir().unwrap();
@@ -1623,8 +3370,14 @@ mod tests {
         let wt_dir = tempfile::tempdir().unwrap();
         let target = wt_dir.path().join("feature-unmerged");
 
-        create_worktree(repo_dir.path(), "feature-unmerged", &base, &target)
-            .expect("should create worktree");
+        create_named_worktree(
+            repo_dir.path(),
+            "feature-unmerged",
+            &base,
+            &target,
+            "feature-unmerged",
+        )
+        .expect("should create worktree");
         let wt_repo = git2::Repository::open(&target).unwrap();
         commit_file(&wt_repo, "feature.txt", "unmerged change", "feature commit");
 
@@ -1644,8 +3397,14 @@ mod tests {
         let wt_dir = tempfile::tempdir().unwrap();
         let target = wt_dir.path().join("feature-force");
 
-        create_worktree(repo_dir.path(), "feature-force", &base, &target)
-            .expect("should create worktree");
+        create_named_worktree(
+            repo_dir.path(),
+            "feature-force",
+            &base,
+            &target,
+            "feature-force",
+        )
+        .expect("should create worktree");
         let wt_repo = git2::Repository::open(&target).unwrap();
         commit_file(&wt_repo, "force.txt", "force", "force commit");
 
@@ -1672,7 +3431,13 @@ mod tests {
         let wt_dir = tempfile::tempdir().unwrap();
         let target = wt_dir.path().join("existing-branch");
 
-        let result = create_worktree(repo_dir.path(), "existing-branch", &base, &target);
+        let result = create_named_worktree(
+            repo_dir.path(),
+            "existing-branch",
+            &base,
+            &target,
+            "existing-branch",
+        );
 
         assert!(result.is_err());
         let err = result.unwrap_err();
@@ -1683,6 +3448,47 @@ mod tests {
         assert!(!target.exists(), "worktree directory should NOT be created");
     }
 
+    #[test]
+    fn create_worktree_reports_branch_checked_out_in_another_worktree() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let repo = init_repo_with_commit(repo_dir.path());
+        let base = head_branch(&repo);
+        let wt_dir = tempfile::tempdir().unwrap();
+        let worktree_a = wt_dir.path().join("a");
+        create_named_worktree(repo_dir.path(), "feature", &base, &worktree_a, "feature").unwrap();
+
+        let target = wt_dir.path().join("b");
+        let err = create_named_worktree(repo_dir.path(), "feature", &base, &target, "feature")
+            .unwrap_err();
+
+        match &err {
+            GitError::BranchInUse { branch, worktree } => {
+                assert_eq!(branch, "feature");
+                assert_eq!(worktree, &worktree_a.canonicalize().unwrap());
+            }
+            other => panic!("expected BranchInUse, got: {other:?}"),
+        }
+        assert!(
+            err.to_string().contains("already checked out in worktree"),
+            "got: {err}"
+        );
+        assert!(!target.exists(), "worktree directory should NOT be created");
+    }
+
+    #[test]
+    fn ensure_branch_not_checked_out_covers_main_checkout() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let repo = init_repo_with_commit(repo_dir.path());
+        let base = head_branch(&repo);
+        let head_commit = repo.head().unwrap().peel_to_commit().unwrap();
+        repo.branch("idle", &head_commit, false).unwrap();
+
+        let err = ensure_branch_not_checked_out(repo_dir.path(), &base).unwrap_err();
+        assert!(matches!(err, GitError::BranchInUse { .. }), "got: {err:?}");
+        ensure_branch_not_checked_out(repo_dir.path(), "idle")
+            .expect("branch not checked out anywhere should pass");
+    }
+
     #[test]
     fn scan_directories_skips_nonexistent_paths_without_error() {
         let scan_paths = vec![
@@ -1710,8 +3516,14 @@ mod tests {
 
         let scan_dir = tempfile::tempdir().unwrap();
         let wt_path = scan_dir.path().join("valid-wt");
-        create_worktree(main_repo_dir.path(), "valid-wt", &base, &wt_path)
-            .expect("should create worktree");
+        create_named_worktree(
+            main_repo_dir.path(),
+            "valid-wt",
+            &base,
+            &wt_path,
+            "valid-wt",
+        )
+        .expect("should create worktree");
 
         // Mix valid scan dir with non-existent path
         let scan_paths = vec![
@@ -1759,8 +3571,14 @@ mod tests {
         // Create a worktree in a "scan" directory (outside default location)
         let scan_dir = tempfile::tempdir().unwrap();
         let wt_path = scan_dir.path().join("my-feature");
-        create_worktree(main_repo_dir.path(), "my-feature", &base, &wt_path)
-            .expect("should create worktree");
+        create_named_worktree(
+            main_repo_dir.path(),
+            "my-feature",
+            &base,
+            &wt_path,
+            "my-feature",
+        )
+        .expect("should create worktree");
 
         // scan_directories should find it
         let scan_paths = vec![scan_dir.path().to_string_lossy().into_owned()];
@@ -1817,4 +3635,103 @@ mod tests {
         };
         assert_eq!(branches, sorted, "branches should be sorted");
     }
+
+    #[test]
+    fn repair_worktree_links_follows_moved_repo() {
+        let root = tempfile::tempdir().unwrap();
+        let old_repo = root.path().join("old");
+        std::fs::create_dir_all(&old_repo).unwrap();
+        let repo = init_repo_with_commit(&old_repo);
+        let base = head_branch(&repo);
+        drop(repo);
+        let wt_path = root.path().join("wt");
+        create_named_worktree(&old_repo, "feature", &base, &wt_path, "feature").unwrap();
+
+        let new_repo = root.path().join("new");
+        std::fs::rename(&old_repo, &new_repo).unwrap();
+        assert!(git2::Repository::open(&wt_path).is_err());
+
+        assert_eq!(repair_worktree_links(&new_repo).unwrap(), 1);
+        let wt_repo = git2::Repository::open(&wt_path).expect("worktree should open again");
+        assert_eq!(wt_repo.head().unwrap().shorthand(), Some("feature"));
+
+        assert_eq!(
+            repair_worktree_links(&new_repo).unwrap(),
+            0,
+            "second repair should be a no-op"
+        );
+    }
+
+    #[test]
+    fn is_worktree_locked_detects_git_worktree_lock() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let repo = init_repo_with_commit(repo_dir.path());
+        let base = head_branch(&repo);
+        let wt_root = tempfile::tempdir().unwrap();
+        let wt_path = wt_root.path().join("locked");
+        create_named_worktree(
+            repo_dir.path(),
+            "feature/locked",
+            &base,
+            &wt_path,
+            "feature-locked",
+        )
+        .unwrap();
+        let name = crate::paths::sanitize_branch("feature/locked");
+
+        assert!(!is_worktree_locked(repo_dir.path(), &name).unwrap());
+
+        repo.find_worktree(&name)
+            .unwrap()
+            .lock(Some("on usb drive"))
+            .unwrap();
+        assert!(is_worktree_locked(repo_dir.path(), &name).unwrap());
+        assert_eq!(
+            worktree_lock_reason(repo_dir.path(), &name).unwrap(),
+            Some("on usb drive".to_string())
+        );
+
+        unlock_worktree(repo_dir.path(), &name).unwrap();
+        assert!(!is_worktree_locked(repo_dir.path(), &name).unwrap());
+    }
+
+    #[test]
+    fn ahead_behind_batch_matches_per_call_results() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let repo = init_repo_with_commit(repo_dir.path());
+        let base = head_branch(&repo);
+        let sig = git2::Signature::now("Test", "test@test.com").unwrap();
+        let root = repo.head().unwrap().peel_to_commit().unwrap();
+        let tree = root.tree().unwrap();
+
+        // ahead: two commits on top of base
+        let a1 = repo
+            .commit(None, &sig, &sig, "a1", &tree, &[&root])
+            .unwrap();
+        let a1 = repo.find_commit(a1).unwrap();
+        let a2 = repo.commit(None, &sig, &sig, "a2", &tree, &[&a1]).unwrap();
+        repo.branch("ahead", &repo.find_commit(a2).unwrap(), false)
+            .unwrap();
+        // behind: branch at root, then base moves forward
+        repo.branch("behind", &root, false).unwrap();
+        repo.commit(Some("HEAD"), &sig, &sig, "base moves", &tree, &[&root])
+            .unwrap();
+
+        let items: Vec<(&str, Option<&str>)> = vec![
+            ("ahead", Some(base.as_str())),
+            ("behind", Some(base.as_str())),
+            ("behind", None),
+            ("missing-branch", Some(base.as_str())),
+            ("ahead", Some("no-such-base")),
+        ];
+
+        let batch = ahead_behind_batch(repo_dir.path(), &items).unwrap();
+        let single: Vec<_> = items
+            .iter()
+            .map(|(b, base)| ahead_behind(repo_dir.path(), b, *base).unwrap())
+            .collect();
+
+        assert_eq!(batch, single);
+        assert_eq!(batch, vec![Some((2, 1)), Some((0, 1)), None, None, None]);
+    }
 }
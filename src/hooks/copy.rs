@@ -1,7 +1,10 @@
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
-use globset::{Glob, GlobSetBuilder};
+use globset::{Glob, GlobBuilder, GlobSetBuilder};
+
+use crate::config::{CopyOptions, CopyOverwrite};
 
 /// A single file that was copied during the copy step.
 #[derive(Debug, Clone)]
@@ -19,6 +22,9 @@ pub struct CopiedFile {
 pub struct CopyResult {
     /// Files that were copied.
     pub copied: Vec<CopiedFile>,
+    /// Matching files left alone because the destination already existed
+    /// and the overwrite policy kept it.
+    pub skipped: Vec<CopiedFile>,
 }
 
 /// Execute the copy step of a hook: resolve glob patterns against `source_dir`
@@ -26,22 +32,26 @@ pub struct CopyResult {
 ///
 /// Patterns prefixed with `!` are exclusion patterns.
 /// Execution order: includes are matched first, then excludes filter them out.
-/// Per FR-21.
+/// Existing destination files are handled per `overwrite`, and `options`
+/// controls how patterns match. Per FR-21.
 pub fn execute_copy_step(
     source_dir: &Path,
     dest_dir: &Path,
     patterns: &[String],
+    overwrite: CopyOverwrite,
+    options: CopyOptions,
 ) -> Result<CopyResult> {
     let mut include_builder = GlobSetBuilder::new();
     let mut exclude_builder = GlobSetBuilder::new();
 
     for pattern in patterns {
         if let Some(stripped) = pattern.strip_prefix('!') {
-            let glob = Glob::new(stripped)
+            let glob = build_glob(stripped, options)
                 .with_context(|| format!("invalid exclusion glob: {stripped}"))?;
             exclude_builder.add(glob);
         } else {
-            let glob = Glob::new(pattern).with_context(|| format!("invalid glob: {pattern}"))?;
+            let glob =
+                build_glob(pattern, options).with_context(|| format!("invalid glob: {pattern}"))?;
             include_builder.add(glob);
         }
     }
@@ -53,7 +63,10 @@ pub fn execute_copy_step(
         .build()
         .context("failed to build exclude glob set")?;
 
-    let mut copied = Vec::new();
+    let mut result = CopyResult {
+        copied: Vec::new(),
+        skipped: Vec::new(),
+    };
 
     collect_matching_files(
         source_dir,
@@ -61,10 +74,22 @@ pub fn execute_copy_step(
         dest_dir,
         &includes,
         &excludes,
-        &mut copied,
+        overwrite,
+        &mut result,
     )?;
+    // `read_dir` order is platform-dependent; report files by name.
+    result.copied.sort_by(|a, b| a.name.cmp(&b.name));
+    result.skipped.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(result)
+}
 
-    Ok(CopyResult { copied })
+fn build_glob(pattern: &str, options: CopyOptions) -> Result<Glob, globset::Error> {
+    GlobBuilder::new(pattern)
+        .case_insensitive(options.case_insensitive)
+        .literal_separator(options.literal_separator)
+        .empty_alternates(options.empty_alternates)
+        .build()
 }
 
 fn collect_matching_files(
@@ -73,7 +98,8 @@ fn collect_matching_files(
     dest_dir: &Path,
     includes: &globset::GlobSet,
     excludes: &globset::GlobSet,
-    copied: &mut Vec<CopiedFile>,
+    overwrite: CopyOverwrite,
+    result: &mut CopyResult,
 ) -> Result<()> {
     let entries = std::fs::read_dir(current)
         .with_context(|| format!("failed to read directory: {}", current.display()))?;
@@ -91,7 +117,7 @@ fn collect_matching_files(
         }
 
         if file_type.is_dir() {
-            collect_matching_files(root, &path, dest_dir, includes, excludes, copied)?;
+            collect_matching_files(root, &path, dest_dir, includes, excludes, overwrite, result)?;
             continue;
         }
 
@@ -105,10 +131,20 @@ fn collect_matching_files(
 
         if includes.is_match(relative) && !excludes.is_match(relative) {
             let dest_path = dest_dir.join(relative);
+            let file = CopiedFile {
+                name: relative.to_string_lossy().into_owned(),
+                source: path,
+                destination: dest_path,
+            };
+            if !should_overwrite(&file.source, &file.destination, overwrite)? {
+                result.skipped.push(file);
+                continue;
+            }
+            let (path, dest_path) = (&file.source, &file.destination);
             if let Some(parent) = dest_path.parent() {
                 std::fs::create_dir_all(parent)?;
             }
-            std::fs::copy(&path, &dest_path).with_context(|| {
+            copy_atomically(path, dest_path).with_context(|| {
                 format!(
                     "failed to copy {} → {}",
                     path.display(),
@@ -116,22 +152,350 @@ fn collect_matching_files(
                 )
             })?;
 
-            copied.push(CopiedFile {
-                name: relative.to_string_lossy().into_owned(),
-                source: path,
-                destination: dest_path,
-            });
+            result.copied.push(file);
         }
     }
 
     Ok(())
 }
 
+/// Whether `source` should be copied over `dest` under `policy`. A missing
+/// destination is always copied.
+fn should_overwrite(source: &Path, dest: &Path, policy: CopyOverwrite) -> Result<bool> {
+    let dest_meta = match std::fs::symlink_metadata(dest) {
+        Ok(meta) => meta,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(true),
+        Err(e) => {
+            return Err(e).with_context(|| format!("failed to stat {}", dest.display()));
+        }
+    };
+    match policy {
+        CopyOverwrite::Always => Ok(true),
+        CopyOverwrite::Never => Ok(false),
+        CopyOverwrite::IfNewer => {
+            let source_mtime = std::fs::metadata(source)
+                .and_then(|m| m.modified())
+                .with_context(|| format!("failed to read mtime of {}", source.display()))?;
+            let dest_mtime = dest_meta
+                .modified()
+                .with_context(|| format!("failed to read mtime of {}", dest.display()))?;
+            Ok(source_mtime > dest_mtime)
+        }
+    }
+}
+
+/// Copy `source` to `dest` so `dest` is either untouched or complete.
+///
+/// The data is written to a temp file next to `dest` (same directory, so the
+/// rename cannot cross filesystems) and renamed into place. `fs::copy` carries
+/// the source permissions over to the temp file. On failure the temp file is
+/// removed, so an interrupted copy never leaves a truncated `.env` behind.
+fn copy_atomically(source: &Path, dest: &Path) -> std::io::Result<()> {
+    copy_atomically_with(source, dest, |from, to| std::fs::copy(from, to).map(drop))
+}
+
+fn copy_atomically_with(
+    source: &Path,
+    dest: &Path,
+    copy: impl FnOnce(&Path, &Path) -> std::io::Result<()>,
+) -> std::io::Result<()> {
+    let file_name = dest.file_name().ok_or_else(|| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "destination has no file name",
+        )
+    })?;
+    let mut tmp_name = std::ffi::OsString::from(".");
+    tmp_name.push(file_name);
+    tmp_name.push(format!(".trench-tmp-{}", std::process::id()));
+    let tmp = dest.with_file_name(tmp_name);
+
+    let result = copy(source, &tmp).and_then(|()| std::fs::rename(&tmp, dest));
+    if result.is_err() {
+        let _ = std::fs::remove_file(&tmp);
+    }
+    result
+}
+
+/// Rewrite `KEY=value` lines in copied text files (opt-in via a hook's
+/// `rewrite` table).
+///
+/// For every key in `rules`, a `KEY=...` or `export KEY=...` line gets the
+/// rule's value, rendered with `{{ branch }}` and `{{ worktree_name }}`.
+/// Files that are not valid UTF-8 or contain NUL bytes are treated as binary
+/// and left untouched. Returns the names of the files that changed.
+pub fn rewrite_copied_files(
+    copied: &[CopiedFile],
+    rules: &BTreeMap<String, String>,
+    branch: &str,
+    worktree_name: &str,
+) -> Result<Vec<String>> {
+    let env = minijinja::Environment::new();
+    let mut values = BTreeMap::new();
+    for (key, template) in rules {
+        let rendered = env
+            .render_str(
+                template,
+                minijinja::context! { branch => branch, worktree_name => worktree_name },
+            )
+            .with_context(|| format!("invalid rewrite template for {key}"))?;
+        values.insert(key.as_str(), rendered);
+    }
+
+    let mut rewritten = Vec::new();
+    for file in copied {
+        let bytes = std::fs::read(&file.destination)
+            .with_context(|| format!("failed to read {}", file.destination.display()))?;
+        if bytes.contains(&0) {
+            continue;
+        }
+        let Ok(text) = String::from_utf8(bytes) else {
+            continue;
+        };
+
+        let mut changed = false;
+        let mut out = String::with_capacity(text.len());
+        for line in text.split_inclusive('\n') {
+            match rewrite_line(line, &values) {
+                Some(new_line) => {
+                    changed |= new_line != line;
+                    out.push_str(&new_line);
+                }
+                None => out.push_str(line),
+            }
+        }
+
+        if changed {
+            std::fs::write(&file.destination, out)
+                .with_context(|| format!("failed to write {}", file.destination.display()))?;
+            rewritten.push(file.name.clone());
+        }
+    }
+    Ok(rewritten)
+}
+
+/// Replace the value of a `[export ]KEY=value` line when `KEY` has a rule.
+fn rewrite_line(line: &str, values: &BTreeMap<&str, String>) -> Option<String> {
+    let body = line.trim_end_matches(['\r', '\n']);
+    let ending = &line[body.len()..];
+    let indent_len = body.len() - body.trim_start().len();
+    let (indent, rest) = body.split_at(indent_len);
+    let (export, assignment) = match rest.strip_prefix("export ") {
+        Some(stripped) => ("export ", stripped.trim_start()),
+        None => ("", rest),
+    };
+    let (key, _) = assignment.split_once('=')?;
+    let value = values.get(key.trim())?;
+    Some(format!("{indent}{export}{}={value}{ending}", key.trim()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use tempfile::TempDir;
 
+    #[test]
+    fn interrupted_copy_leaves_no_partial_destination() {
+        let source = TempDir::new().unwrap();
+        let dest = TempDir::new().unwrap();
+        let src = source.path().join(".env");
+        std::fs::write(&src, "SECRET=abc\nTOKEN=xyz\n").unwrap();
+        let target = dest.path().join(".env");
+
+        let err = copy_atomically_with(&src, &target, |_, tmp| {
+            std::fs::write(tmp, "SECRET=a")?;
+            Err(std::io::Error::other("disk full"))
+        })
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "disk full");
+        assert!(!target.exists(), "no truncated destination");
+        assert_eq!(std::fs::read_dir(dest.path()).unwrap().count(), 0);
+
+        // An existing destination is left intact by a failed copy.
+        std::fs::write(&target, "OLD=1\n").unwrap();
+        copy_atomically_with(&src, &target, |_, _| Err(std::io::Error::other("boom"))).unwrap_err();
+        assert_eq!(std::fs::read_to_string(&target).unwrap(), "OLD=1\n");
+        assert_eq!(std::fs::read_dir(dest.path()).unwrap().count(), 1);
+    }
+
+    /// Source `.env` plus a pre-existing destination `.env`, with the
+    /// destination's mtime set `dest_offset_secs` relative to the source's.
+    fn setup_existing_destination(dest_offset_secs: i64) -> (TempDir, TempDir) {
+        let source = TempDir::new().unwrap();
+        let dest = TempDir::new().unwrap();
+        std::fs::write(source.path().join(".env"), "NEW=1\n").unwrap();
+        std::fs::write(dest.path().join(".env"), "OLD=1\n").unwrap();
+
+        let base = std::time::SystemTime::now() - std::time::Duration::from_secs(3600);
+        let offset = std::time::Duration::from_secs(dest_offset_secs.unsigned_abs());
+        let dest_time = if dest_offset_secs >= 0 {
+            base + offset
+        } else {
+            base - offset
+        };
+        let set_mtime = |path: &Path, time| {
+            std::fs::File::options()
+                .write(true)
+                .open(path)
+                .unwrap()
+                .set_modified(time)
+                .unwrap();
+        };
+        set_mtime(&source.path().join(".env"), base);
+        set_mtime(&dest.path().join(".env"), dest_time);
+        (source, dest)
+    }
+
+    fn run_with_policy(policy: CopyOverwrite, dest_offset_secs: i64) -> (CopyResult, String) {
+        let (source, dest) = setup_existing_destination(dest_offset_secs);
+        let result = execute_copy_step(
+            source.path(),
+            dest.path(),
+            &[".env".to_string()],
+            policy,
+            CopyOptions::default(),
+        )
+        .unwrap();
+        let content = std::fs::read_to_string(dest.path().join(".env")).unwrap();
+        (result, content)
+    }
+
+    #[test]
+    fn case_insensitive_option_matches_differently_cased_names() {
+        let source = TempDir::new().unwrap();
+        std::fs::write(source.path().join(".env"), "KEY=1").unwrap();
+        let patterns = vec!["*.ENV".to_string()];
+
+        let dest = TempDir::new().unwrap();
+        let default = execute_copy_step(
+            source.path(),
+            dest.path(),
+            &patterns,
+            CopyOverwrite::Always,
+            CopyOptions::default(),
+        )
+        .unwrap();
+        assert!(
+            default.copied.is_empty(),
+            "matching is case-sensitive by default"
+        );
+
+        let options = CopyOptions {
+            case_insensitive: true,
+            ..CopyOptions::default()
+        };
+        let result = execute_copy_step(
+            source.path(),
+            dest.path(),
+            &patterns,
+            CopyOverwrite::Always,
+            options,
+        )
+        .unwrap();
+        assert_eq!(result.copied.len(), 1);
+        assert!(dest.path().join(".env").exists());
+    }
+
+    #[test]
+    fn brace_alternation_matches_each_alternative() {
+        let source = TempDir::new().unwrap();
+        std::fs::write(source.path().join("package.json"), "{}").unwrap();
+        std::fs::write(source.path().join("config.toml"), "").unwrap();
+        std::fs::write(source.path().join("notes.md"), "").unwrap();
+        let dest = TempDir::new().unwrap();
+
+        let result = execute_copy_step(
+            source.path(),
+            dest.path(),
+            &["{*.json,*.toml}".to_string()],
+            CopyOverwrite::Always,
+            CopyOptions::default(),
+        )
+        .unwrap();
+
+        let names: Vec<&str> = result.copied.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, ["config.toml", "package.json"]);
+    }
+
+    #[test]
+    fn overwrite_always_replaces_existing_file() {
+        let (result, content) = run_with_policy(CopyOverwrite::Always, 60);
+
+        assert_eq!(content, "NEW=1\n");
+        assert_eq!(result.copied.len(), 1);
+        assert!(result.skipped.is_empty());
+    }
+
+    #[test]
+    fn overwrite_never_keeps_existing_file_and_reports_skip() {
+        let (result, content) = run_with_policy(CopyOverwrite::Never, -60);
+
+        assert_eq!(content, "OLD=1\n");
+        assert!(result.copied.is_empty());
+        assert_eq!(result.skipped.len(), 1);
+        assert_eq!(result.skipped[0].name, ".env");
+    }
+
+    #[test]
+    fn overwrite_never_still_copies_missing_files() {
+        let source = TempDir::new().unwrap();
+        let dest = TempDir::new().unwrap();
+        std::fs::write(source.path().join(".env"), "NEW=1\n").unwrap();
+
+        let result = execute_copy_step(
+            source.path(),
+            dest.path(),
+            &[".env".to_string()],
+            CopyOverwrite::Never,
+            CopyOptions::default(),
+        )
+        .unwrap();
+
+        assert_eq!(result.copied.len(), 1);
+        assert!(dest.path().join(".env").exists());
+    }
+
+    #[test]
+    fn overwrite_if_newer_compares_mtimes() {
+        // Destination older than the source: replaced.
+        let (result, content) = run_with_policy(CopyOverwrite::IfNewer, -60);
+        assert_eq!(content, "NEW=1\n");
+        assert_eq!(result.copied.len(), 1);
+        assert!(result.skipped.is_empty());
+
+        // Destination newer than the source: kept.
+        let (result, content) = run_with_policy(CopyOverwrite::IfNewer, 60);
+        assert_eq!(content, "OLD=1\n");
+        assert!(result.copied.is_empty());
+        assert_eq!(result.skipped.len(), 1);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn atomic_copy_preserves_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let source = TempDir::new().unwrap();
+        let dest = TempDir::new().unwrap();
+        let src = source.path().join(".env");
+        std::fs::write(&src, "SECRET=abc").unwrap();
+        std::fs::set_permissions(&src, std::fs::Permissions::from_mode(0o600)).unwrap();
+
+        execute_copy_step(
+            source.path(),
+            dest.path(),
+            &[".env".to_string()],
+            CopyOverwrite::Always,
+            CopyOptions::default(),
+        )
+        .unwrap();
+
+        let meta = std::fs::metadata(dest.path().join(".env")).unwrap();
+        assert_eq!(meta.permissions().mode() & 0o777, 0o600);
+        assert_eq!(std::fs::read_dir(dest.path()).unwrap().count(), 1);
+    }
+
     #[test]
     fn copies_files_matching_glob_pattern() {
         // Setup: source dir with .env, .env.local, and unrelated file
@@ -143,7 +507,14 @@ mod tests {
         let dest = TempDir::new().unwrap();
 
         let patterns = vec![".env*".to_string()];
-        let result = execute_copy_step(source.path(), dest.path(), &patterns).unwrap();
+        let result = execute_copy_step(
+            source.path(),
+            dest.path(),
+            &patterns,
+            CopyOverwrite::Always,
+            CopyOptions::default(),
+        )
+        .unwrap();
 
         // Both .env files copied, README not copied
         assert_eq!(result.copied.len(), 2);
@@ -175,7 +546,14 @@ mod tests {
         let dest = TempDir::new().unwrap();
 
         let patterns = vec!["setup.sh".to_string()];
-        execute_copy_step(source.path(), dest.path(), &patterns).unwrap();
+        execute_copy_step(
+            source.path(),
+            dest.path(),
+            &patterns,
+            CopyOverwrite::Always,
+            CopyOptions::default(),
+        )
+        .unwrap();
 
         let dest_perms = std::fs::metadata(dest.path().join("setup.sh"))
             .unwrap()
@@ -198,7 +576,14 @@ mod tests {
         let dest = TempDir::new().unwrap();
 
         let patterns = vec![".env".to_string()];
-        let result = execute_copy_step(source.path(), dest.path(), &patterns).unwrap();
+        let result = execute_copy_step(
+            source.path(),
+            dest.path(),
+            &patterns,
+            CopyOverwrite::Always,
+            CopyOptions::default(),
+        )
+        .unwrap();
 
         assert_eq!(result.copied.len(), 1);
         let entry = &result.copied[0];
@@ -215,7 +600,14 @@ mod tests {
         let dest = TempDir::new().unwrap();
 
         let patterns = vec![".env*".to_string()];
-        let result = execute_copy_step(source.path(), dest.path(), &patterns).unwrap();
+        let result = execute_copy_step(
+            source.path(),
+            dest.path(),
+            &patterns,
+            CopyOverwrite::Always,
+            CopyOptions::default(),
+        )
+        .unwrap();
 
         assert!(result.copied.is_empty());
     }
@@ -230,7 +622,14 @@ mod tests {
         let dest = TempDir::new().unwrap();
 
         let patterns = vec![".env*".to_string(), "!.env.example".to_string()];
-        let result = execute_copy_step(source.path(), dest.path(), &patterns).unwrap();
+        let result = execute_copy_step(
+            source.path(),
+            dest.path(),
+            &patterns,
+            CopyOverwrite::Always,
+            CopyOptions::default(),
+        )
+        .unwrap();
 
         assert_eq!(result.copied.len(), 2);
         assert!(dest.path().join(".env").exists());
@@ -248,7 +647,14 @@ mod tests {
         let dest = TempDir::new().unwrap();
 
         let patterns = vec!["*.json".to_string(), "*.toml".to_string()];
-        let result = execute_copy_step(source.path(), dest.path(), &patterns).unwrap();
+        let result = execute_copy_step(
+            source.path(),
+            dest.path(),
+            &patterns,
+            CopyOverwrite::Always,
+            CopyOptions::default(),
+        )
+        .unwrap();
 
         assert_eq!(result.copied.len(), 2);
         assert!(dest.path().join("config.json").exists());
@@ -264,7 +670,14 @@ mod tests {
         let dest = TempDir::new().unwrap();
 
         let patterns: Vec<String> = vec![];
-        let result = execute_copy_step(source.path(), dest.path(), &patterns).unwrap();
+        let result = execute_copy_step(
+            source.path(),
+            dest.path(),
+            &patterns,
+            CopyOverwrite::Always,
+            CopyOptions::default(),
+        )
+        .unwrap();
 
         assert!(result.copied.is_empty());
     }
@@ -285,7 +698,14 @@ mod tests {
 
         // Match everything
         let patterns = vec!["**/*".to_string()];
-        let result = execute_copy_step(source.path(), dest.path(), &patterns).unwrap();
+        let result = execute_copy_step(
+            source.path(),
+            dest.path(),
+            &patterns,
+            CopyOverwrite::Always,
+            CopyOptions::default(),
+        )
+        .unwrap();
 
         // Only the real .env should be copied; the symlinked dir must be skipped
         assert_eq!(result.copied.len(), 1);
@@ -293,4 +713,70 @@ mod tests {
         assert!(!dest.path().join("linked").exists());
         assert!(!dest.path().join("linked/secret.env").exists());
     }
+
+    fn rewrite_rules(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn rewrite_substitutes_tokens_in_matching_env_lines() {
+        let source = TempDir::new().unwrap();
+        std::fs::write(
+            source.path().join(".env"),
+            "PORT=3000\nDB_NAME=app_dev\r\nexport API_URL=http://localhost\n# DB_NAME=comment\n",
+        )
+        .unwrap();
+        let dest = TempDir::new().unwrap();
+        let result = execute_copy_step(
+            source.path(),
+            dest.path(),
+            &[".env".to_string()],
+            CopyOverwrite::Always,
+            CopyOptions::default(),
+        )
+        .unwrap();
+
+        let rules = rewrite_rules(&[
+            ("DB_NAME", "app_{{ worktree_name }}"),
+            ("API_URL", "https://{{ branch }}.dev"),
+        ]);
+        let rewritten =
+            rewrite_copied_files(&result.copied, &rules, "feature/auth", "feature-auth").unwrap();
+
+        assert_eq!(rewritten, vec![".env".to_string()]);
+        assert_eq!(
+            std::fs::read_to_string(dest.path().join(".env")).unwrap(),
+            "PORT=3000\nDB_NAME=app_feature-auth\r\nexport API_URL=https://feature/auth.dev\n# DB_NAME=comment\n"
+        );
+        assert_eq!(
+            std::fs::read_to_string(source.path().join(".env")).unwrap(),
+            "PORT=3000\nDB_NAME=app_dev\r\nexport API_URL=http://localhost\n# DB_NAME=comment\n",
+            "source file must not change"
+        );
+    }
+
+    #[test]
+    fn rewrite_leaves_binary_files_untouched() {
+        let source = TempDir::new().unwrap();
+        let binary = b"DB_NAME=app_dev\n\0\x01\x02".to_vec();
+        std::fs::write(source.path().join(".env.bin"), &binary).unwrap();
+        let dest = TempDir::new().unwrap();
+        let result = execute_copy_step(
+            source.path(),
+            dest.path(),
+            &[".env*".to_string()],
+            CopyOverwrite::Always,
+            CopyOptions::default(),
+        )
+        .unwrap();
+
+        let rules = rewrite_rules(&[("DB_NAME", "app_{{ worktree_name }}")]);
+        let rewritten = rewrite_copied_files(&result.copied, &rules, "b", "w").unwrap();
+
+        assert!(rewritten.is_empty());
+        assert_eq!(std::fs::read(dest.path().join(".env.bin")).unwrap(), binary);
+    }
 }
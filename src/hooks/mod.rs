@@ -1,4 +1,5 @@
 pub mod copy;
+pub mod progress;
 pub mod run;
 pub mod runner;
 pub mod shell;
@@ -37,6 +38,16 @@ pub enum HookEvent {
 }
 
 impl HookEvent {
+    /// Every event, in lifecycle order.
+    pub const ALL: [HookEvent; 6] = [
+        Self::PreCreate,
+        Self::PostCreate,
+        Self::PreSync,
+        Self::PostSync,
+        Self::PreRemove,
+        Self::PostRemove,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             Self::PreCreate => "pre_create",
@@ -77,6 +88,48 @@ pub struct HookEnvContext {
     pub repo_name: String,
     pub repo_path: String,
     pub base_branch: String,
+    /// Extra `KEY=VALUE` pairs from `create --env`; never named `TRENCH_*`.
+    pub user_env: Vec<(String, String)>,
+}
+
+/// Parse a hook event name such as `post_create`.
+pub fn parse_event(arg: &str) -> Result<HookEvent, String> {
+    HookEvent::ALL
+        .into_iter()
+        .find(|event| event.as_str() == arg)
+        .ok_or_else(|| {
+            let names: Vec<&str> = HookEvent::ALL.iter().map(HookEvent::as_str).collect();
+            format!(
+                "unknown hook event '{arg}' (expected one of: {})",
+                names.join(", ")
+            )
+        })
+}
+
+/// Parse a `--env KEY=VALUE` argument.
+///
+/// Keys must be shell identifiers and may not use the reserved `TRENCH_`
+/// prefix, so user variables can never shadow the built-in ones.
+pub fn parse_env_var(arg: &str) -> Result<(String, String), String> {
+    let Some((key, value)) = arg.split_once('=') else {
+        return Err(format!("expected KEY=VALUE, got '{arg}'"));
+    };
+    let mut chars = key.chars();
+    let valid = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if !valid {
+        return Err(format!(
+            "invalid variable name '{key}': use letters, digits and '_', not starting with a digit"
+        ));
+    }
+    if key.starts_with("TRENCH_") {
+        return Err(format!(
+            "'{key}' uses the reserved TRENCH_ prefix; pick another name"
+        ));
+    }
+    Ok((key.to_string(), value.to_string()))
 }
 
 /// Retrieve the HookConfig for a specific lifecycle event from HooksConfig.
@@ -91,9 +144,11 @@ pub fn get_hook_config<'a>(hooks: &'a HooksConfig, event: &HookEvent) -> Option<
     }
 }
 
-/// Build the 7 TRENCH_* environment variables injected into hook processes (FR-23).
+/// Build the 7 TRENCH_* environment variables injected into hook processes (FR-23),
+/// plus any user variables from `--env`.
 pub fn build_env(ctx: &HookEnvContext, event: &HookEvent) -> HashMap<String, String> {
-    HashMap::from([
+    let mut env: HashMap<String, String> = ctx.user_env.iter().cloned().collect();
+    env.extend([
         ("TRENCH_WORKTREE_PATH".into(), ctx.worktree_path.clone()),
         ("TRENCH_WORKTREE_NAME".into(), ctx.worktree_name.clone()),
         ("TRENCH_BRANCH".into(), ctx.branch.clone()),
@@ -101,13 +156,22 @@ pub fn build_env(ctx: &HookEnvContext, event: &HookEvent) -> HashMap<String, Str
         ("TRENCH_REPO_PATH".into(), ctx.repo_path.clone()),
         ("TRENCH_BASE_BRANCH".into(), ctx.base_branch.clone()),
         ("TRENCH_EVENT".into(), event.as_str().to_string()),
-    ])
+    ]);
+    env
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn parse_event_round_trips_every_event() {
+        for event in HookEvent::ALL {
+            assert_eq!(parse_event(event.as_str()), Ok(event));
+        }
+        assert!(parse_event("post-create").is_err());
+    }
+
     #[test]
     fn build_env_returns_all_seven_trench_vars() {
         let ctx = HookEnvContext {
@@ -117,6 +181,7 @@ mod tests {
             repo_name: "myrepo".into(),
             repo_path: "/home/user/code/myrepo".into(),
             base_branch: "main".into(),
+            user_env: Vec::new(),
         };
 
         let env = build_env(&ctx, &HookEvent::PostCreate);
@@ -143,6 +208,7 @@ mod tests {
             repo_name: "repo".into(),
             repo_path: "/tmp/repo".into(),
             base_branch: "develop".into(),
+            user_env: Vec::new(),
         };
 
         for (event, expected) in [
@@ -165,6 +231,9 @@ mod tests {
                 run: Some(vec!["bun install".into()]),
                 shell: None,
                 timeout_secs: Some(300),
+                copy_overwrite: None,
+                copy_options: None,
+                rewrite: None,
             }),
             ..Default::default()
         };
@@ -274,6 +343,54 @@ timeout_secs = 60
         );
     }
 
+    #[test]
+    fn build_env_includes_user_vars() {
+        let ctx = HookEnvContext {
+            worktree_path: "/tmp/wt".into(),
+            worktree_name: "wt".into(),
+            branch: "main".into(),
+            repo_name: "repo".into(),
+            repo_path: "/tmp/repo".into(),
+            base_branch: "main".into(),
+            user_env: vec![("TICKET".into(), "ABC-123".into())],
+        };
+
+        let env = build_env(&ctx, &HookEvent::PostCreate);
+
+        assert_eq!(env.len(), 8);
+        assert_eq!(env["TICKET"], "ABC-123");
+        assert_eq!(env["TRENCH_BRANCH"], "main");
+    }
+
+    #[test]
+    fn parse_env_var_accepts_key_value() {
+        assert_eq!(
+            parse_env_var("TICKET=ABC-123").unwrap(),
+            ("TICKET".to_string(), "ABC-123".to_string())
+        );
+        assert_eq!(
+            parse_env_var("_X=a=b").unwrap(),
+            ("_X".to_string(), "a=b".to_string())
+        );
+        assert_eq!(
+            parse_env_var("EMPTY=").unwrap(),
+            ("EMPTY".to_string(), String::new())
+        );
+    }
+
+    #[test]
+    fn parse_env_var_rejects_malformed_and_reserved() {
+        for arg in [
+            "NOEQUALS",
+            "=value",
+            "1ABC=x",
+            "BAD-KEY=x",
+            "TRENCH_BRANCH=x",
+        ] {
+            assert!(parse_env_var(arg).is_err(), "should reject {arg}");
+        }
+    }
+
     #[test]
     fn hook_env_context_is_debug_and_clone() {
         let ctx = HookEnvContext {
@@ -283,6 +400,7 @@ timeout_secs = 60
             repo_name: "repo".into(),
             repo_path: "/tmp/repo".into(),
             base_branch: "main".into(),
+            user_env: Vec::new(),
         };
 
         // Debug
@@ -0,0 +1,201 @@
+use std::collections::VecDeque;
+use std::io::Write;
+use std::sync::mpsc::Receiver;
+use std::time::Duration;
+
+use serde::Serialize;
+
+use super::types::HookOutputMessage;
+use super::{HookConfig, HookEvent};
+
+/// Wall-clock timing of one completed hook step, reported in `--json` output.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StepTiming {
+    pub hook: String,
+    pub step: String,
+    pub success: bool,
+    pub duration_ms: u64,
+}
+
+/// A step the runner is expected to start, in execution order.
+#[derive(Debug, Clone)]
+pub struct PlannedStep {
+    hook: &'static str,
+    step: &'static str,
+    label: String,
+}
+
+/// List the steps `hooks` will run, in order (copy → run → shell per hook).
+///
+/// The runner only reports bare step names, so progress output pairs each
+/// `StepStarted` with the next planned step to recover its hook and label.
+pub fn plan(hooks: &[(HookEvent, Option<&HookConfig>)]) -> Vec<PlannedStep> {
+    let mut steps = Vec::new();
+    for (event, config) in hooks {
+        let Some(config) = config else { continue };
+        if let Some(patterns) = &config.copy {
+            steps.push(PlannedStep {
+                hook: event.as_str(),
+                step: "copy",
+                label: format!("copying {}", patterns.join(", ")),
+            });
+        }
+        if let Some(commands) = &config.run {
+            steps.push(PlannedStep {
+                hook: event.as_str(),
+                step: "run",
+                label: format!("running {}", commands.join(" && ")),
+            });
+        }
+        if let Some(script) = &config.shell {
+            let first_line = script.lines().next().unwrap_or_default().trim();
+            steps.push(PlannedStep {
+                hook: event.as_str(),
+                step: "shell",
+                label: format!("running {first_line}"),
+            });
+        }
+    }
+    steps
+}
+
+fn format_duration(d: Duration) -> String {
+    format!("{:.1}s", d.as_secs_f64())
+}
+
+/// Consume hook messages until the sender is dropped, writing a line as each
+/// step starts and finishes to `out` (when given). Returns per-step timings.
+pub fn report<W: Write>(
+    rx: Receiver<HookOutputMessage>,
+    plan: Vec<PlannedStep>,
+    mut out: Option<W>,
+) -> Vec<StepTiming> {
+    let mut pending: VecDeque<PlannedStep> = plan.into();
+    let mut current: Option<PlannedStep> = None;
+    let mut timings = Vec::new();
+
+    for msg in rx {
+        match msg {
+            HookOutputMessage::StepStarted { step } => {
+                let planned = match pending.pop_front() {
+                    Some(p) if p.step == step => p,
+                    _ => {
+                        pending.clear();
+                        PlannedStep {
+                            hook: "hook",
+                            step: "step",
+                            label: step.clone(),
+                        }
+                    }
+                };
+                if let Some(ref mut out) = out {
+                    let _ = writeln!(out, "{}: {} …", planned.hook, planned.label);
+                }
+                current = Some(planned);
+            }
+            HookOutputMessage::StepCompleted {
+                step,
+                success,
+                duration,
+            } => {
+                let Some(planned) = current.take() else {
+                    continue;
+                };
+                if let Some(ref mut out) = out {
+                    let mark = if success { "✓" } else { "✗" };
+                    let _ = writeln!(
+                        out,
+                        "{}: {} {mark} {}",
+                        planned.hook,
+                        planned.label,
+                        format_duration(duration)
+                    );
+                }
+                timings.push(StepTiming {
+                    hook: planned.hook.to_string(),
+                    step,
+                    success,
+                    duration_ms: duration.as_millis() as u64,
+                });
+            }
+            HookOutputMessage::OutputLine { .. } | HookOutputMessage::HookCompleted { .. } => {}
+        }
+    }
+
+    timings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hook(copy: Option<&[&str]>, run: Option<&[&str]>) -> HookConfig {
+        HookConfig {
+            copy: copy.map(|c| c.iter().map(|s| s.to_string()).collect()),
+            run: run.map(|r| r.iter().map(|s| s.to_string()).collect()),
+            ..HookConfig::default()
+        }
+    }
+
+    #[test]
+    fn report_labels_steps_from_plan_and_records_timings() {
+        let pre = hook(None, Some(&["make check"]));
+        let post = hook(Some(&[".env*"]), Some(&["bun install", "bun run build"]));
+        let steps = plan(&[
+            (HookEvent::PreCreate, Some(&pre)),
+            (HookEvent::PostCreate, Some(&post)),
+        ]);
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        for (step, ms) in [("run", 5), ("copy", 10), ("run", 1500)] {
+            tx.send(HookOutputMessage::StepStarted { step: step.into() })
+                .unwrap();
+            tx.send(HookOutputMessage::StepCompleted {
+                step: step.into(),
+                success: true,
+                duration: Duration::from_millis(ms),
+            })
+            .unwrap();
+        }
+        drop(tx);
+
+        let mut out = Vec::new();
+        let timings = report(rx, steps, Some(&mut out));
+        let text = String::from_utf8(out).unwrap();
+
+        assert!(text.contains("pre_create: running make check …"), "{text}");
+        assert!(text.contains("post_create: copying .env* …"), "{text}");
+        assert!(
+            text.contains("post_create: running bun install && bun run build ✓ 1.5s"),
+            "{text}"
+        );
+        assert_eq!(timings.len(), 3);
+        assert_eq!(timings[2].hook, "post_create");
+        assert_eq!(timings[2].duration_ms, 1500);
+    }
+
+    #[test]
+    fn failed_step_is_marked() {
+        let post = hook(None, Some(&["false"]));
+        let (tx, rx) = std::sync::mpsc::channel();
+        tx.send(HookOutputMessage::StepStarted { step: "run".into() })
+            .unwrap();
+        tx.send(HookOutputMessage::StepCompleted {
+            step: "run".into(),
+            success: false,
+            duration: Duration::from_millis(20),
+        })
+        .unwrap();
+        drop(tx);
+
+        let mut out = Vec::new();
+        let timings = report(
+            rx,
+            plan(&[(HookEvent::PostCreate, Some(&post))]),
+            Some(&mut out),
+        );
+
+        assert!(String::from_utf8(out).unwrap().contains("running false ✗"));
+        assert!(!timings[0].success);
+    }
+}
@@ -57,6 +57,8 @@ pub async fn execute_run_step(
             .envs(env_vars.iter())
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
+            // Killed if the step is abandoned on timeout or cancellation.
+            .kill_on_drop(true)
             .spawn()
             .with_context(|| format!("failed to spawn command: {cmd}"))?;
 
@@ -231,6 +233,7 @@ mod tests {
             repo_name: "myrepo".into(),
             repo_path: "/tmp/repo".into(),
             base_branch: "main".into(),
+            user_env: Vec::new(),
         };
         let env = build_env(&ctx, &HookEvent::PostCreate);
 
@@ -1,10 +1,13 @@
+use std::future::Future;
 use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::Sender;
+use std::sync::Arc;
 use std::time::Instant;
 
 use anyhow::{Context, Result};
 
-use super::copy::execute_copy_step;
+use super::copy::{execute_copy_step, rewrite_copied_files};
 use super::run::{execute_run_step, RunStepError};
 use super::shell::{execute_shell_step, ShellStepError};
 use super::types::HookOutputMessage;
@@ -18,6 +21,151 @@ pub struct HookTimeoutError {
     pub timeout_secs: u64,
 }
 
+/// Error returned when a hook is cancelled (e.g. Ctrl-C) before it finishes.
+#[derive(Debug, thiserror::Error)]
+#[error("{hook} hook interrupted during {step} step")]
+pub struct HookInterruptedError {
+    pub hook: &'static str,
+    pub step: &'static str,
+}
+
+/// Context attached to a failed `copy` step. `run` and `shell` failures are
+/// identified by [`RunStepError`] and [`ShellStepError`]; see [`failed_step`].
+#[derive(Debug, thiserror::Error)]
+#[error("{step} step failed")]
+pub struct HookStepError {
+    pub step: &'static str,
+}
+
+/// Name of the step (`copy`, `run`, or `shell`) whose failure produced `err`.
+///
+/// Steps run in order and the first failure stops the hook, so this also
+/// tells which later steps were skipped. Timeouts and interruptions are not
+/// step failures and return `None`.
+pub fn failed_step(err: &anyhow::Error) -> Option<&'static str> {
+    if err.downcast_ref::<RunStepError>().is_some() {
+        Some("run")
+    } else if err.downcast_ref::<ShellStepError>().is_some() {
+        Some("shell")
+    } else {
+        err.downcast_ref::<HookStepError>().map(|e| e.step)
+    }
+}
+
+/// Exit code recorded for interrupted hooks, as a shell reports SIGINT.
+const INTERRUPTED_EXIT_CODE: i32 = 130;
+
+/// Cooperative cancellation for hook execution.
+///
+/// A handle built with [`HookCancel::on_ctrl_c`] is also cancelled by Ctrl-C,
+/// but only while a hook is running.
+///
+/// Clones share state. Once [`HookCancel::cancel`] is called the running
+/// `run`/`shell` step is stopped (its processes are killed) and no further
+/// steps start.
+#[derive(Debug, Clone)]
+pub struct HookCancel {
+    tx: Arc<tokio::sync::watch::Sender<bool>>,
+    ctrl_c: Option<Arc<CtrlC>>,
+}
+
+/// Ctrl-C routing state shared by the clones of a [`HookCancel`].
+#[derive(Debug, Default)]
+struct CtrlC {
+    listening: AtomicBool,
+    hook_running: AtomicBool,
+}
+
+impl Default for HookCancel {
+    fn default() -> Self {
+        Self {
+            tx: Arc::new(tokio::sync::watch::Sender::new(false)),
+            ctrl_c: None,
+        }
+    }
+}
+
+impl HookCancel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A handle that Ctrl-C cancels while a hook runs.
+    ///
+    /// The signal listener is only installed when the first hook starts, so
+    /// work before that (e.g. the fetch in `trench create`) keeps the default
+    /// Ctrl-C behaviour. Tokio cannot hand SIGINT back once it owns it, so a
+    /// later Ctrl-C between hooks exits with status 130 as the default
+    /// handler would.
+    pub fn on_ctrl_c() -> Self {
+        Self {
+            ctrl_c: Some(Arc::default()),
+            ..Self::new()
+        }
+    }
+
+    /// Mark a hook as running for the lifetime of the returned guard.
+    fn hook_running(&self) -> Option<HookRunningGuard> {
+        let ctrl_c = self.ctrl_c.clone()?;
+        ctrl_c.hook_running.store(true, Ordering::SeqCst);
+        if !ctrl_c.listening.swap(true, Ordering::SeqCst) {
+            let cancel = self.clone();
+            tokio::spawn(async move {
+                while tokio::signal::ctrl_c().await.is_ok() {
+                    let running = cancel
+                        .ctrl_c
+                        .as_ref()
+                        .is_some_and(|c| c.hook_running.load(Ordering::SeqCst));
+                    if !running {
+                        std::process::exit(INTERRUPTED_EXIT_CODE);
+                    }
+                    cancel.cancel();
+                }
+            });
+        }
+        Some(HookRunningGuard(ctrl_c))
+    }
+
+    pub fn cancel(&self) {
+        self.tx.send_replace(true);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        *self.tx.borrow()
+    }
+
+    async fn cancelled(&self) {
+        let mut rx = self.tx.subscribe();
+        // The sender lives in `self`, so this only returns once cancelled.
+        let _ = rx.wait_for(|cancelled| *cancelled).await;
+    }
+}
+
+struct HookRunningGuard(Arc<CtrlC>);
+
+impl Drop for HookRunningGuard {
+    fn drop(&mut self) {
+        self.0.hook_running.store(false, Ordering::SeqCst);
+    }
+}
+
+fn is_cancelled(cancel: Option<&HookCancel>) -> bool {
+    cancel.is_some_and(HookCancel::is_cancelled)
+}
+
+/// Await `fut` unless `cancel` fires first, in which case `fut` is dropped
+/// and `None` is returned.
+async fn until_cancelled<F: Future>(cancel: Option<&HookCancel>, fut: F) -> Option<F::Output> {
+    let Some(cancel) = cancel else {
+        return Some(fut.await);
+    };
+    tokio::select! {
+        biased;
+        () = cancel.cancelled() => None,
+        out = fut => Some(out),
+    }
+}
+
 /// Result of a successful hook execution.
 #[derive(Debug)]
 pub struct HookResult {
@@ -25,15 +173,10 @@ pub struct HookResult {
     pub event_id: i64,
     /// Total wall-clock duration in seconds.
     pub duration_secs: f64,
+    /// Relative names of the files the copy step copied.
+    pub copied: Vec<String>,
 }
 
-/// Execute a hook lifecycle event: copy → run → shell.
-///
-/// - `copy` runs first (not subject to timeout).
-/// - `run` and `shell` share the `timeout_secs` budget.
-/// - Any step failure stops remaining steps.
-/// - All output is captured and logged to the database.
-/// - Returns `HookTimeoutError` (exit code 7) on timeout.
 /// Helper to send a message through the optional sender, ignoring errors.
 fn send_msg(tx: Option<&Sender<HookOutputMessage>>, msg: HookOutputMessage) {
     if let Some(tx) = tx {
@@ -41,6 +184,13 @@ fn send_msg(tx: Option<&Sender<HookOutputMessage>>, msg: HookOutputMessage) {
     }
 }
 
+/// Execute a hook lifecycle event: copy → run → shell.
+///
+/// - `copy` runs first (not subject to timeout).
+/// - `run` and `shell` share the `timeout_secs` budget.
+/// - Any step failure stops remaining steps; [`failed_step`] names the step.
+/// - All output is captured and logged to the database.
+/// - Returns `HookTimeoutError` (exit code 7) on timeout.
 #[allow(clippy::too_many_arguments)]
 pub async fn execute_hook(
     event: &HookEvent,
@@ -53,14 +203,60 @@ pub async fn execute_hook(
     worktree_id: Option<i64>,
     tx: Option<&Sender<HookOutputMessage>>,
 ) -> Result<HookResult> {
+    execute_hook_with_cancel(
+        event,
+        config,
+        env_ctx,
+        source_dir,
+        work_dir,
+        db,
+        repo_id,
+        worktree_id,
+        tx,
+        None,
+    )
+    .await
+}
+
+/// [`execute_hook`] that stops early when `cancel` fires.
+///
+/// An interrupted hook is recorded with exit code 130 alongside an
+/// `interrupted` event, and returns [`HookInterruptedError`].
+#[allow(clippy::too_many_arguments)]
+pub async fn execute_hook_with_cancel(
+    event: &HookEvent,
+    config: &HookConfig,
+    env_ctx: &HookEnvContext,
+    source_dir: &Path,
+    work_dir: &Path,
+    db: &Database,
+    repo_id: i64,
+    worktree_id: Option<i64>,
+    tx: Option<&Sender<HookOutputMessage>>,
+    cancel: Option<&HookCancel>,
+) -> Result<HookResult> {
+    let _running = cancel.and_then(HookCancel::hook_running);
     let start = Instant::now();
     let env_vars = build_env(env_ctx, event);
     let timeout_secs = config.timeout_secs.unwrap_or(120);
 
     let mut all_output: Vec<(String, String, String)> = Vec::new(); // (step, stream, line)
+    let mut copied_names = Vec::new();
 
     // Step 1: Copy (not subject to timeout)
     if let Some(ref patterns) = config.copy {
+        if is_cancelled(cancel) {
+            return Err(record_interrupted(
+                db,
+                repo_id,
+                worktree_id,
+                event,
+                "copy",
+                start,
+                &all_output,
+            )?
+            .into());
+        }
         let step_start = Instant::now();
         send_msg(
             tx,
@@ -68,7 +264,26 @@ pub async fn execute_hook(
                 step: "copy".into(),
             },
         );
-        if let Err(e) = execute_copy_step(source_dir, work_dir, patterns) {
+        let copied = execute_copy_step(
+            source_dir,
+            work_dir,
+            patterns,
+            config.copy_overwrite.unwrap_or_default(),
+            config.copy_options.unwrap_or_default(),
+        )
+        .and_then(|result| {
+            if let Some(rules) = &config.rewrite {
+                rewrite_copied_files(
+                    &result.copied,
+                    rules,
+                    &env_ctx.branch,
+                    &env_ctx.worktree_name,
+                )?;
+            }
+            Ok(result.copied.into_iter().map(|file| file.name).collect())
+        })
+        .map(|names| copied_names = names);
+        if let Err(e) = copied {
             let step_dur = step_start.elapsed();
             send_msg(
                 tx,
@@ -88,7 +303,7 @@ pub async fn execute_hook(
                 duration.as_secs_f64(),
                 &all_output,
             )?;
-            return Err(e.context("copy step failed"));
+            return Err(e.context(HookStepError { step: "copy" }));
         }
         let step_dur = step_start.elapsed();
         send_msg(
@@ -104,12 +319,48 @@ pub async fn execute_hook(
     // Step 2: Run (subject to timeout)
     let run_deadline = Instant::now() + std::time::Duration::from_secs(timeout_secs);
     if let Some(ref commands) = config.run {
+        if is_cancelled(cancel) {
+            return Err(record_interrupted(
+                db,
+                repo_id,
+                worktree_id,
+                event,
+                "run",
+                start,
+                &all_output,
+            )?
+            .into());
+        }
         let step_start = Instant::now();
         send_msg(tx, HookOutputMessage::StepStarted { step: "run".into() });
         let remaining = run_deadline.saturating_duration_since(Instant::now());
-        match tokio::time::timeout(remaining, execute_run_step(commands, work_dir, &env_vars)).await
+        match until_cancelled(
+            cancel,
+            tokio::time::timeout(remaining, execute_run_step(commands, work_dir, &env_vars)),
+        )
+        .await
         {
-            Ok(Ok(run_result)) => {
+            None => {
+                send_msg(
+                    tx,
+                    HookOutputMessage::StepCompleted {
+                        step: "run".into(),
+                        success: false,
+                        duration: step_start.elapsed(),
+                    },
+                );
+                return Err(record_interrupted(
+                    db,
+                    repo_id,
+                    worktree_id,
+                    event,
+                    "run",
+                    start,
+                    &all_output,
+                )?
+                .into());
+            }
+            Some(Ok(Ok(run_result))) => {
                 for cmd_output in &run_result.executed {
                     collect_output_with_sender(
                         &mut all_output,
@@ -129,7 +380,7 @@ pub async fn execute_hook(
                     },
                 );
             }
-            Ok(Err(e)) => {
+            Some(Ok(Err(e))) => {
                 let exit_code = extract_run_error_output(&e, &mut all_output, tx);
                 let step_dur = step_start.elapsed();
                 send_msg(
@@ -140,6 +391,20 @@ pub async fn execute_hook(
                         duration: step_dur,
                     },
                 );
+                // SIGINT reaches the hook's processes too, so a step killed
+                // by Ctrl-C fails before cancellation is observed.
+                if is_cancelled(cancel) {
+                    return Err(record_interrupted(
+                        db,
+                        repo_id,
+                        worktree_id,
+                        event,
+                        "run",
+                        start,
+                        &all_output,
+                    )?
+                    .into());
+                }
                 let duration = start.elapsed();
                 record_execution(
                     db,
@@ -152,7 +417,7 @@ pub async fn execute_hook(
                 )?;
                 return Err(e);
             }
-            Err(_) => {
+            Some(Err(_)) => {
                 let step_dur = step_start.elapsed();
                 send_msg(
                     tx,
@@ -179,6 +444,18 @@ pub async fn execute_hook(
 
     // Step 3: Shell (remaining timeout budget)
     if let Some(ref script) = config.shell {
+        if is_cancelled(cancel) {
+            return Err(record_interrupted(
+                db,
+                repo_id,
+                worktree_id,
+                event,
+                "shell",
+                start,
+                &all_output,
+            )?
+            .into());
+        }
         let step_start = Instant::now();
         send_msg(
             tx,
@@ -187,9 +464,33 @@ pub async fn execute_hook(
             },
         );
         let remaining = run_deadline.saturating_duration_since(Instant::now());
-        match tokio::time::timeout(remaining, execute_shell_step(script, work_dir, &env_vars)).await
+        match until_cancelled(
+            cancel,
+            tokio::time::timeout(remaining, execute_shell_step(script, work_dir, &env_vars)),
+        )
+        .await
         {
-            Ok(Ok(shell_output)) => {
+            None => {
+                send_msg(
+                    tx,
+                    HookOutputMessage::StepCompleted {
+                        step: "shell".into(),
+                        success: false,
+                        duration: step_start.elapsed(),
+                    },
+                );
+                return Err(record_interrupted(
+                    db,
+                    repo_id,
+                    worktree_id,
+                    event,
+                    "shell",
+                    start,
+                    &all_output,
+                )?
+                .into());
+            }
+            Some(Ok(Ok(shell_output))) => {
                 collect_output_with_sender(
                     &mut all_output,
                     "shell",
@@ -207,7 +508,7 @@ pub async fn execute_hook(
                     },
                 );
             }
-            Ok(Err(e)) => {
+            Some(Ok(Err(e))) => {
                 let exit_code = extract_shell_error_output(&e, &mut all_output, tx);
                 let step_dur = step_start.elapsed();
                 send_msg(
@@ -218,6 +519,20 @@ pub async fn execute_hook(
                         duration: step_dur,
                     },
                 );
+                // SIGINT reaches the hook's processes too, so a step killed
+                // by Ctrl-C fails before cancellation is observed.
+                if is_cancelled(cancel) {
+                    return Err(record_interrupted(
+                        db,
+                        repo_id,
+                        worktree_id,
+                        event,
+                        "shell",
+                        start,
+                        &all_output,
+                    )?
+                    .into());
+                }
                 let duration = start.elapsed();
                 record_execution(
                     db,
@@ -230,7 +545,7 @@ pub async fn execute_hook(
                 )?;
                 return Err(e);
             }
-            Err(_) => {
+            Some(Err(_)) => {
                 let step_dur = step_start.elapsed();
                 send_msg(
                     tx,
@@ -269,6 +584,7 @@ pub async fn execute_hook(
     Ok(HookResult {
         event_id,
         duration_secs: duration.as_secs_f64(),
+        copied: copied_names,
     })
 }
 
@@ -345,6 +661,34 @@ fn collect_output_with_sender(
     }
 }
 
+/// Record an interrupted hook run plus an `interrupted` event naming the step
+/// that was stopped, and return the error to surface.
+fn record_interrupted(
+    db: &Database,
+    repo_id: i64,
+    worktree_id: Option<i64>,
+    event: &HookEvent,
+    step: &'static str,
+    start: Instant,
+    output: &[(String, String, String)],
+) -> Result<HookInterruptedError> {
+    record_execution(
+        db,
+        repo_id,
+        worktree_id,
+        event,
+        INTERRUPTED_EXIT_CODE,
+        start.elapsed().as_secs_f64(),
+        output,
+    )?;
+    let payload = serde_json::json!({ "hook": event.as_str(), "step": step });
+    db.insert_event(repo_id, worktree_id, "interrupted", Some(&payload))?;
+    Ok(HookInterruptedError {
+        hook: event.as_str(),
+        step,
+    })
+}
+
 fn record_execution(
     db: &Database,
     repo_id: i64,
@@ -388,6 +732,7 @@ mod tests {
             repo_name: "test-repo".into(),
             repo_path: source.to_string_lossy().into_owned(),
             base_branch: "main".into(),
+            user_env: Vec::new(),
         }
     }
 
@@ -414,6 +759,9 @@ mod tests {
             run: Some(vec!["echo run_output".to_string()]),
             shell: Some("echo shell_output".to_string()),
             timeout_secs: Some(30),
+            copy_overwrite: None,
+            copy_options: None,
+            rewrite: None,
         };
 
         let env_ctx = test_env_ctx(source.path(), work.path());
@@ -459,6 +807,46 @@ mod tests {
         assert!(stdout_lines.contains(&"shell_output"));
     }
 
+    #[tokio::test(flavor = "current_thread")]
+    async fn copy_step_applies_rewrite_rules() {
+        let source = TempDir::new().unwrap();
+        let work = TempDir::new().unwrap();
+        let (db, repo_id, wt_id) = setup_db();
+        std::fs::write(source.path().join(".env"), "DB_NAME=app\nPORT=1\n").unwrap();
+
+        let config = HookDef {
+            copy: Some(vec![".env".to_string()]),
+            copy_overwrite: None,
+            copy_options: None,
+            rewrite: Some(
+                [("DB_NAME".to_string(), "app_{{ worktree_name }}".to_string())]
+                    .into_iter()
+                    .collect(),
+            ),
+            ..HookDef::default()
+        };
+        let env_ctx = test_env_ctx(source.path(), work.path());
+
+        execute_hook(
+            &HookEvent::PostCreate,
+            &config,
+            &env_ctx,
+            source.path(),
+            work.path(),
+            &db,
+            repo_id,
+            Some(wt_id),
+            None,
+        )
+        .await
+        .expect("hook should succeed");
+
+        assert_eq!(
+            std::fs::read_to_string(work.path().join(".env")).unwrap(),
+            format!("DB_NAME=app_{}\nPORT=1\n", env_ctx.worktree_name)
+        );
+    }
+
     #[tokio::test(flavor = "current_thread")]
     async fn only_configured_steps_execute() {
         let source = TempDir::new().unwrap();
@@ -471,6 +859,9 @@ mod tests {
             run: Some(vec!["echo only_run".to_string()]),
             shell: None,
             timeout_secs: Some(30),
+            copy_overwrite: None,
+            copy_options: None,
+            rewrite: None,
         };
 
         let env_ctx = test_env_ctx(source.path(), work.path());
@@ -496,6 +887,145 @@ mod tests {
         assert_eq!(logs[0].1, "only_run");
     }
 
+    #[tokio::test(flavor = "current_thread")]
+    async fn cancel_halts_running_step_and_skips_remaining() {
+        let source = TempDir::new().unwrap();
+        let work = TempDir::new().unwrap();
+        let (db, repo_id, wt_id) = setup_db();
+
+        let marker = work.path().join("shell_ran");
+        let config = HookDef {
+            copy: None,
+            run: Some(vec!["sleep 10".to_string()]),
+            shell: Some(format!("touch {}", marker.display())),
+            timeout_secs: Some(30),
+            copy_overwrite: None,
+            copy_options: None,
+            rewrite: None,
+        };
+        let env_ctx = test_env_ctx(source.path(), work.path());
+
+        let cancel = HookCancel::new();
+        let trigger = cancel.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+            trigger.cancel();
+        });
+
+        let started = Instant::now();
+        let err = execute_hook_with_cancel(
+            &HookEvent::PostCreate,
+            &config,
+            &env_ctx,
+            source.path(),
+            work.path(),
+            &db,
+            repo_id,
+            Some(wt_id),
+            None,
+            Some(&cancel),
+        )
+        .await
+        .expect_err("cancelled hook should fail");
+
+        assert!(started.elapsed() < std::time::Duration::from_secs(5));
+        let interrupted = err
+            .downcast_ref::<HookInterruptedError>()
+            .expect("should be HookInterruptedError");
+        assert_eq!(interrupted.step, "run");
+        assert!(!marker.exists(), "shell step must not run after cancel");
+
+        let events = db.list_events(wt_id, 10).unwrap();
+        let types: Vec<&str> = events.iter().map(|e| e.event_type.as_str()).collect();
+        assert!(types.contains(&"interrupted"), "{types:?}");
+        assert!(types.contains(&"hook:post_create"), "{types:?}");
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn ctrl_c_handle_listens_only_once_a_hook_starts() {
+        let source = TempDir::new().unwrap();
+        let work = TempDir::new().unwrap();
+        let (db, repo_id, wt_id) = setup_db();
+        let config = HookDef {
+            copy: None,
+            run: Some(vec!["true".to_string()]),
+            shell: None,
+            timeout_secs: Some(30),
+            copy_overwrite: None,
+            copy_options: None,
+            rewrite: None,
+        };
+        let env_ctx = test_env_ctx(source.path(), work.path());
+
+        let cancel = HookCancel::on_ctrl_c();
+        let state = cancel.ctrl_c.clone().unwrap();
+        assert!(!state.listening.load(Ordering::SeqCst));
+
+        execute_hook_with_cancel(
+            &HookEvent::PostCreate,
+            &config,
+            &env_ctx,
+            source.path(),
+            work.path(),
+            &db,
+            repo_id,
+            Some(wt_id),
+            None,
+            Some(&cancel),
+        )
+        .await
+        .unwrap();
+
+        assert!(state.listening.load(Ordering::SeqCst));
+        assert!(
+            !state.hook_running.load(Ordering::SeqCst),
+            "Ctrl-C should not be routed to the hook once it has finished"
+        );
+        assert!(!cancel.is_cancelled());
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn cancel_before_start_runs_no_steps() {
+        let source = TempDir::new().unwrap();
+        let work = TempDir::new().unwrap();
+        let (db, repo_id, wt_id) = setup_db();
+        std::fs::write(source.path().join(".env"), "X=1").unwrap();
+
+        let config = HookDef {
+            copy: Some(vec![".env".to_string()]),
+            run: Some(vec!["echo nope".to_string()]),
+            shell: None,
+            timeout_secs: Some(30),
+            copy_overwrite: None,
+            copy_options: None,
+            rewrite: None,
+        };
+        let env_ctx = test_env_ctx(source.path(), work.path());
+        let cancel = HookCancel::new();
+        cancel.cancel();
+
+        let err = execute_hook_with_cancel(
+            &HookEvent::PostCreate,
+            &config,
+            &env_ctx,
+            source.path(),
+            work.path(),
+            &db,
+            repo_id,
+            Some(wt_id),
+            None,
+            Some(&cancel),
+        )
+        .await
+        .expect_err("cancelled hook should fail");
+
+        assert_eq!(
+            err.downcast_ref::<HookInterruptedError>().unwrap().step,
+            "copy"
+        );
+        assert!(!work.path().join(".env").exists());
+    }
+
     #[tokio::test(flavor = "current_thread")]
     async fn empty_config_succeeds_with_no_output() {
         let source = TempDir::new().unwrap();
@@ -508,6 +1038,9 @@ mod tests {
             run: None,
             shell: None,
             timeout_secs: Some(30),
+            copy_overwrite: None,
+            copy_options: None,
+            rewrite: None,
         };
 
         let env_ctx = test_env_ctx(source.path(), work.path());
@@ -530,6 +1063,116 @@ mod tests {
         assert!(logs.is_empty());
     }
 
+    #[tokio::test(flavor = "current_thread")]
+    async fn copy_failure_prevents_run_and_shell() {
+        let source = TempDir::new().unwrap();
+        let work = TempDir::new().unwrap();
+        let (db, repo_id, wt_id) = setup_db();
+        std::fs::write(source.path().join(".env"), "PORT=3000\n").unwrap();
+
+        // An unparsable rewrite template fails the copy step.
+        let config = HookDef {
+            copy: Some(vec![".env".to_string()]),
+            run: Some(vec!["touch run_marker".to_string()]),
+            shell: Some("touch shell_marker".to_string()),
+            timeout_secs: Some(30),
+            copy_overwrite: None,
+            copy_options: None,
+            rewrite: Some(
+                [("PORT".to_string(), "{{ unclosed".to_string())]
+                    .into_iter()
+                    .collect(),
+            ),
+        };
+
+        let env_ctx = test_env_ctx(source.path(), work.path());
+
+        let err = execute_hook(
+            &HookEvent::PostCreate,
+            &config,
+            &env_ctx,
+            source.path(),
+            work.path(),
+            &db,
+            repo_id,
+            Some(wt_id),
+            None,
+        )
+        .await
+        .expect_err("copy failure should fail the hook");
+
+        assert_eq!(failed_step(&err), Some("copy"));
+        assert!(
+            !work.path().join("run_marker").exists(),
+            "run must not execute"
+        );
+        assert!(
+            !work.path().join("shell_marker").exists(),
+            "shell must not execute"
+        );
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn shell_runs_only_after_run_succeeds() {
+        let source = TempDir::new().unwrap();
+        let work = TempDir::new().unwrap();
+        let (db, repo_id, wt_id) = setup_db();
+
+        // shell fails unless run already left its marker behind.
+        let config = HookDef {
+            copy: None,
+            run: Some(vec!["touch run_marker".to_string()]),
+            shell: Some("test -f run_marker && touch shell_marker".to_string()),
+            timeout_secs: Some(30),
+            copy_overwrite: None,
+            copy_options: None,
+            rewrite: None,
+        };
+
+        let env_ctx = test_env_ctx(source.path(), work.path());
+
+        execute_hook(
+            &HookEvent::PostCreate,
+            &config,
+            &env_ctx,
+            source.path(),
+            work.path(),
+            &db,
+            repo_id,
+            Some(wt_id),
+            None,
+        )
+        .await
+        .expect("hook should succeed");
+
+        assert!(work.path().join("shell_marker").exists());
+
+        let failing = HookDef {
+            run: Some(vec!["exit 3".to_string()]),
+            ..config
+        };
+        std::fs::remove_file(work.path().join("shell_marker")).unwrap();
+        let err = execute_hook(
+            &HookEvent::PostCreate,
+            &failing,
+            &env_ctx,
+            source.path(),
+            work.path(),
+            &db,
+            repo_id,
+            Some(wt_id),
+            None,
+        )
+        .await
+        .expect_err("run failure should fail the hook");
+
+        assert_eq!(failed_step(&err), Some("run"));
+        assert!(
+            !work.path().join("shell_marker").exists(),
+            "shell must not execute"
+        );
+    }
+
     #[tokio::test(flavor = "current_thread")]
     async fn run_failure_stops_shell_and_records_error() {
         let source = TempDir::new().unwrap();
@@ -541,6 +1184,9 @@ mod tests {
             run: Some(vec!["echo before_fail".to_string(), "exit 42".to_string()]),
             shell: Some("echo should_not_run".to_string()),
             timeout_secs: Some(30),
+            copy_overwrite: None,
+            copy_options: None,
+            rewrite: None,
         };
 
         let env_ctx = test_env_ctx(source.path(), work.path());
@@ -598,6 +1244,9 @@ mod tests {
             run: Some(vec!["echo run_ok".to_string()]),
             shell: Some("echo shell_before; exit 1".to_string()),
             timeout_secs: Some(30),
+            copy_overwrite: None,
+            copy_options: None,
+            rewrite: None,
         };
 
         let env_ctx = test_env_ctx(source.path(), work.path());
@@ -616,6 +1265,7 @@ mod tests {
         .await
         .expect_err("hook should fail");
 
+        assert_eq!(failed_step(&err), Some("shell"));
         assert!(err.to_string().contains("exit code"), "error: {err}");
 
         // Both run and shell output should be logged
@@ -639,6 +1289,9 @@ mod tests {
             run: Some(vec!["sleep 10".to_string()]),
             shell: None,
             timeout_secs: Some(1),
+            copy_overwrite: None,
+            copy_options: None,
+            rewrite: None,
         };
 
         let env_ctx = test_env_ctx(source.path(), work.path());
@@ -684,6 +1337,9 @@ mod tests {
             run: Some(vec!["sleep 1".to_string()]),
             shell: Some("sleep 10".to_string()),
             timeout_secs: Some(2),
+            copy_overwrite: None,
+            copy_options: None,
+            rewrite: None,
         };
 
         let env_ctx = test_env_ctx(source.path(), work.path());
@@ -719,6 +1375,9 @@ mod tests {
             run: Some(vec!["echo hello".to_string()]),
             shell: None,
             timeout_secs: Some(30),
+            copy_overwrite: None,
+            copy_options: None,
+            rewrite: None,
         };
 
         let env_ctx = test_env_ctx(source.path(), work.path());
@@ -762,6 +1421,9 @@ mod tests {
             run: Some(vec!["echo out1; echo err1 >&2".to_string()]),
             shell: Some("echo out2; echo err2 >&2".to_string()),
             timeout_secs: Some(30),
+            copy_overwrite: None,
+            copy_options: None,
+            rewrite: None,
         };
 
         let env_ctx = test_env_ctx(source.path(), work.path());
@@ -806,6 +1468,9 @@ mod tests {
             run: Some(vec!["echo from_run".to_string()]),
             shell: Some("echo from_shell".to_string()),
             timeout_secs: Some(30),
+            copy_overwrite: None,
+            copy_options: None,
+            rewrite: None,
         };
 
         let env_ctx = test_env_ctx(source.path(), work.path());
@@ -856,6 +1521,9 @@ mod tests {
             run: Some(vec!["echo hello".to_string()]),
             shell: None,
             timeout_secs: Some(30),
+            copy_overwrite: None,
+            copy_options: None,
+            rewrite: None,
         };
 
         let env_ctx = test_env_ctx(source.path(), work.path());
@@ -924,6 +1592,9 @@ mod tests {
             run: Some(vec!["echo test".to_string()]),
             shell: None,
             timeout_secs: Some(30),
+            copy_overwrite: None,
+            copy_options: None,
+            rewrite: None,
         };
 
         let env_ctx = test_env_ctx(source.path(), work.path());
@@ -44,6 +44,8 @@ pub async fn execute_shell_step(
         .envs(env_vars.iter())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
+        // Killed if the step is abandoned on timeout or cancellation.
+        .kill_on_drop(true)
         .spawn()
         .context("failed to spawn shell script")?;
 
@@ -179,6 +181,7 @@ mod tests {
             repo_name: "myrepo".into(),
             repo_path: "/tmp/repo".into(),
             base_branch: "main".into(),
+            user_env: Vec::new(),
         };
         let env = build_env(&ctx, &HookEvent::PostCreate);
 
@@ -4,6 +4,9 @@ use tokio::process::{ChildStderr, ChildStdout};
 
 /// Stream stdout/stderr from a child process to the terminal in real time,
 /// capturing both into buffers. Returns `(stdout, stderr)` strings.
+///
+/// Both streams are echoed to trench's stderr: stdout is reserved for
+/// command results (a created path, JSON) that scripts capture.
 pub async fn stream_and_collect(
     stdout: ChildStdout,
     stderr: ChildStderr,
@@ -21,7 +24,7 @@ pub async fn stream_and_collect(
             result = stdout_reader.next_line(), if !stdout_done => {
                 match result? {
                     Some(line) => {
-                        println!("{line}");
+                        eprintln!("{line}");
                         if !stdout_buf.is_empty() {
                             stdout_buf.push('\n');
                         }
@@ -5,7 +5,7 @@ use anyhow::Result;
 
 use crate::git::{self, GitWorktreeEntry, RepoInfo};
 use crate::paths;
-use crate::state::{Database, Repo, Worktree};
+use crate::state::{Database, LookupError, LookupMatch, Repo, Worktree};
 
 #[derive(Debug, Clone)]
 pub struct LiveWorktree {
@@ -29,11 +29,12 @@ fn canonical_string(path: &Path) -> String {
 
 fn ensure_repo(db: &Database, repo_info: &RepoInfo) -> Result<Repo> {
     let repo_path = repo_path_str(repo_info)?;
-    if let Some(repo) = db.get_repo_by_path(repo_path)? {
-        return Ok(repo);
+    let mut repo = db.upsert_repo(&repo_info.name, repo_path, Some(&repo_info.default_branch))?;
+    if repo.remote_url != repo_info.remote_url {
+        db.set_repo_remote_url(repo.id, repo_info.remote_url.as_deref())?;
+        repo.remote_url = repo_info.remote_url.clone();
     }
-
-    db.insert_repo(&repo_info.name, repo_path, Some(&repo_info.default_branch))
+    Ok(repo)
 }
 
 fn purge_stale_metadata(
@@ -121,21 +122,40 @@ fn resolve_inner(
     purge_stale: bool,
 ) -> Result<LiveWorktree> {
     let sanitized = paths::sanitize_branch(identifier);
-    for worktree in list_inner(repo_info, db, &[], purge_stale)? {
-        let branch_match = worktree.entry.branch.as_deref() == Some(identifier);
-        let name_match = worktree.entry.name == identifier || worktree.entry.name == sanitized;
-        let sanitized_branch_match = worktree
-            .entry
-            .branch
-            .as_deref()
-            .is_some_and(|branch| paths::sanitize_branch(branch) == sanitized);
-
-        if branch_match || name_match || sanitized_branch_match {
-            return Ok(worktree);
+    let mut matches: Vec<LiveWorktree> = list_inner(repo_info, db, &[], purge_stale)?
+        .into_iter()
+        .filter(|worktree| {
+            let branch_match = worktree.entry.branch.as_deref() == Some(identifier);
+            let name_match = worktree.entry.name == identifier || worktree.entry.name == sanitized;
+            let sanitized_branch_match = worktree
+                .entry
+                .branch
+                .as_deref()
+                .is_some_and(|branch| paths::sanitize_branch(branch) == sanitized);
+            branch_match || name_match || sanitized_branch_match
+        })
+        .collect();
+
+    // One worktree's name can be another's branch; never guess between them.
+    if matches.len() > 1 {
+        return Err(LookupError::Multiple {
+            identifier: identifier.to_string(),
+            matches: matches
+                .into_iter()
+                .map(|worktree| LookupMatch {
+                    branch: worktree
+                        .entry
+                        .branch
+                        .unwrap_or_else(|| "detached HEAD".to_string()),
+                    name: worktree.entry.name,
+                })
+                .collect(),
         }
+        .into());
     }
-
-    anyhow::bail!("worktree not found: {identifier}")
+    matches
+        .pop()
+        .ok_or_else(|| anyhow::anyhow!("worktree not found: {identifier}"))
 }
 
 pub fn resolve(identifier: &str, repo_info: &RepoInfo, db: &Database) -> Result<LiveWorktree> {
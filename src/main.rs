@@ -15,13 +15,20 @@ mod tui;
 
 use anyhow::Context;
 use clap::{Parser, Subcommand, ValueEnum};
-use std::io::{BufRead, IsTerminal, Write};
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 
 use exit_code::ExitCode;
+use git::SyncStrategy;
 
+use output::pager::PagerConfig;
+use output::porcelain::PorcelainVersion;
 use output::OutputConfig;
 
 const TUI_SWITCH_PATH_FILE_ENV: &str = "TRENCH_TUI_SWITCH_PATH_FILE";
+const ASSUME_YES_ENV: &str = "TRENCH_ASSUME_YES";
+const DB_ENV: &str = "TRENCH_DB";
 
 #[derive(Parser, Debug)]
 #[command(
@@ -37,9 +44,23 @@ struct Cli {
     #[arg(long, global = true)]
     json: bool,
 
-    /// Output in porcelain format
-    #[arg(long, global = true, conflicts_with = "json")]
-    porcelain: bool,
+    /// Output in porcelain format: v1 (default) is colon-separated fields,
+    /// v2 is `key=value` pairs
+    #[arg(
+        long,
+        global = true,
+        conflicts_with = "json",
+        value_name = "VERSION",
+        value_enum,
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "v1"
+    )]
+    porcelain: Option<PorcelainVersion>,
+
+    /// Output as JSON Lines, one object per line (list, log)
+    #[arg(long, global = true, conflicts_with_all = ["json", "porcelain"])]
+    jsonl: bool,
 
     /// Disable colored output
     #[arg(long, global = true)]
@@ -56,6 +77,26 @@ struct Cli {
     /// Preview without executing
     #[arg(long, global = true)]
     dry_run: bool,
+
+    /// Never pipe long output through $PAGER
+    #[arg(long, global = true)]
+    no_pager: bool,
+
+    /// Answer yes to every confirmation prompt (also: TRENCH_ASSUME_YES=1)
+    #[arg(short = 'y', long, global = true)]
+    yes: bool,
+
+    /// Discover the repository from this path instead of the current directory
+    #[arg(long, global = true, value_name = "PATH")]
+    repo: Option<PathBuf>,
+
+    /// Use this database file instead of the default (also: TRENCH_DB)
+    #[arg(long, global = true, value_name = "PATH")]
+    db: Option<PathBuf>,
+
+    /// Read global config from this file instead of the default (also: TRENCH_CONFIG)
+    #[arg(long, global = true, value_name = "PATH")]
+    config: Option<PathBuf>,
 }
 
 #[derive(Subcommand, Debug)]
@@ -63,23 +104,64 @@ enum Commands {
     /// Create a new worktree
     Create {
         /// Branch name for the new worktree
-        branch: String,
+        branch: std::ffi::OsString,
 
-        /// Base branch to create from (defaults to repo's HEAD branch).
-        /// Falls back to origin/<base> if not found locally.
+        /// Base to create from (defaults to repo's HEAD branch).
+        /// Falls back to origin/<base>, then to any committish (tag, SHA, HEAD~3).
         #[arg(long)]
         from: Option<String>,
 
+        /// Create from a tag (shorthand for --from refs/tags/<TAG>)
+        #[arg(long, value_name = "TAG", conflicts_with = "from")]
+        from_tag: Option<String>,
+
+        /// Pick the base from a list of local branches (TTY only; also
+        /// enabled by git.interactive_base)
+        #[arg(long, conflicts_with_all = ["from", "from_tag"])]
+        interactive: bool,
+
+        /// Use the named path template from [worktrees.templates]
+        #[arg(long, value_name = "NAME")]
+        template: Option<String>,
+
+        /// Record the branch's upstream (or the base's upstream) as the worktree's base
+        #[arg(long)]
+        base_from_upstream: bool,
+
         /// Skip all lifecycle hooks (pre_create, post_create)
         #[arg(long)]
         no_hooks: bool,
+
+        /// Extra variable for hook processes (repeatable). Names starting
+        /// with TRENCH_ are reserved and rejected.
+        #[arg(long = "env", value_name = "KEY=VALUE", value_parser = hooks::parse_env_var)]
+        env: Vec<(String, String)>,
+
+        /// Create missing parent directories of the worktree path.
+        /// --parents=false errors instead.
+        #[arg(
+            long,
+            value_name = "BOOL",
+            default_value_t = true,
+            num_args = 0..=1,
+            require_equals = true,
+            default_missing_value = "true",
+            action = clap::ArgAction::Set
+        )]
+        parents: bool,
+
+        /// Worktree name and directory to use instead of the sanitized branch
+        #[arg(long, value_name = "NAME", value_parser = parse_worktree_name)]
+        name: Option<String>,
     },
     /// Remove a worktree
     Remove {
         /// Branch name or sanitized name of the worktree to remove
         branch: String,
 
-        /// Skip confirmation prompt
+        /// Skip confirmation prompt, remove even if dirty or locked, and
+        /// force-delete unmerged branches.
+        /// --yes (or TRENCH_ASSUME_YES=1) also skips the prompt, without forcing.
         #[arg(long)]
         force: bool,
 
@@ -90,6 +172,12 @@ enum Commands {
         /// Skip all lifecycle hooks (pre_remove, post_remove)
         #[arg(long)]
         no_hooks: bool,
+
+        /// Stop tracking the worktree but leave its directory on disk.
+        /// Unlike a normal remove nothing is deleted and no hooks run;
+        /// uncommitted changes stay in the kept directory.
+        #[arg(long, conflicts_with = "delete_branch")]
+        keep_dir: bool,
     },
     /// Switch to a worktree
     Switch {
@@ -103,16 +191,28 @@ enum Commands {
         /// Open worktree in a new tmux window (requires running inside tmux)
         #[arg(long)]
         tmux: bool,
+
+        /// Launch the editor in the worktree after switching (like `trench open`)
+        #[arg(short = 'e', long, conflicts_with_all = ["print_path", "tmux"])]
+        editor: bool,
     },
     /// Manage tags on a worktree
     Tag {
-        /// Branch name or sanitized name of the worktree
-        branch: String,
+        /// Branch name or sanitized name of the worktree.
+        /// Omit when using --all-matching.
+        #[arg(required_unless_present = "all_matching", allow_hyphen_values = true)]
+        branch: Option<String>,
 
         /// Tags to add (+name) or remove (-name). No arguments = list current tags
         #[arg(allow_hyphen_values = true)]
         tags: Vec<String>,
+
+        /// Apply the tag operations to every worktree currently carrying TAG
+        #[arg(long, value_name = "TAG")]
+        all_matching: Option<String>,
     },
+    /// List every tag in the repo with the worktrees carrying it
+    Tags,
     /// Open a worktree in $EDITOR
     Open {
         /// Branch name or sanitized name of the worktree
@@ -121,18 +221,109 @@ enum Commands {
         /// Open worktree in a new tmux window instead of $EDITOR (requires running inside tmux)
         #[arg(long)]
         tmux: bool,
+
+        /// Command to open the worktree with; `{path}` is replaced by the
+        /// worktree path, otherwise the path is appended (overrides ui.open_cmd)
+        #[arg(long, value_name = "CMD")]
+        open_cmd: Option<String>,
     },
     /// List all worktrees
     List {
         /// Filter worktrees by tag
         #[arg(long)]
         tag: Option<String>,
+
+        /// Show each worktree's on-disk size (slow on large checkouts)
+        #[arg(long)]
+        size: bool,
+
+        /// Render the table at this many columns instead of the terminal width
+        #[arg(long, value_name = "N")]
+        width: Option<usize>,
+
+        /// How to display worktree paths in the table
+        #[arg(long, value_enum, default_value_t = PathStyleArg::Tilde)]
+        path_style: PathStyleArg,
+
+        /// Print only the number of matching worktrees
+        #[arg(long, conflicts_with = "size")]
+        count: bool,
+
+        /// Wrap --json output in a versioned {"schema": N, "worktrees": [...]} object
+        #[arg(long, conflicts_with = "count")]
+        schema: bool,
+
+        /// Show how long ago trench created each worktree ("-" if unmanaged)
+        #[arg(long, conflicts_with = "count")]
+        age: bool,
+
+        /// Order tracked worktrees by this key instead of git's order
+        #[arg(long, value_enum, value_name = "KEY", conflicts_with = "count")]
+        sort: Option<ListSortArg>,
     },
     /// Show worktree status
     Status {
         /// Branch name or sanitized name for deep status view.
         /// Omit for summary of all worktrees.
         branch: Option<String>,
+
+        /// Print one compact line per managed worktree
+        #[arg(long, conflicts_with = "branch")]
+        all: bool,
+
+        /// Show files changed, insertions, and deletions relative to each
+        /// worktree's base (table and --json)
+        #[arg(long, conflicts_with = "all")]
+        diffstat: bool,
+    },
+    /// Show everything trench knows about a worktree
+    Info {
+        /// Branch name or sanitized name of the worktree
+        branch: String,
+    },
+    /// Print the TRENCH_* variables hooks would receive for a worktree
+    Env {
+        /// Branch name or sanitized name of the worktree
+        branch: String,
+
+        /// Hook event to report as TRENCH_EVENT
+        #[arg(long, value_name = "EVENT", default_value = "post_create", value_parser = hooks::parse_event)]
+        event: hooks::HookEvent,
+
+        /// Print `export KEY=VALUE` lines for use with eval
+        #[arg(long)]
+        export: bool,
+    },
+    /// Print the browser URL of a branch on the remote's host
+    Url {
+        /// Branch name or sanitized name of the worktree
+        branch: String,
+
+        /// Link to a comparison against the base branch instead
+        #[arg(long)]
+        compare: bool,
+    },
+    /// Print a worktree's path without switching to it
+    Which {
+        /// Branch name or sanitized name of the worktree
+        branch: String,
+    },
+    /// Print where `create` would put a worktree for a branch, without creating it
+    Path {
+        /// Branch name to render the path template for
+        branch: String,
+
+        /// Render a named template from [worktrees.templates] instead of worktrees.root
+        #[arg(long)]
+        template: Option<String>,
+    },
+    /// Change the base branch recorded for a worktree
+    SetBase {
+        /// Branch name or sanitized name of the worktree
+        branch: String,
+
+        /// New base branch (local, or present on origin)
+        new_base: String,
     },
     /// Sync a worktree with its base branch
     Sync {
@@ -140,17 +331,22 @@ enum Commands {
         /// Omit when using --all.
         branch: Option<String>,
 
-        /// Sync all active worktrees. Requires --strategy.
+        /// Sync all active worktrees. Requires --strategy (or `git.sync_strategy`).
         #[arg(long)]
         all: bool,
 
-        /// Sync strategy: rebase or merge. Prompts interactively if omitted.
+        /// Sync strategy: rebase, merge, or ff-only. Falls back to
+        /// `git.sync_strategy`, then prompts interactively.
         #[arg(long)]
         strategy: Option<SyncStrategy>,
 
         /// Skip all lifecycle hooks (pre_sync, post_sync)
         #[arg(long)]
         no_hooks: bool,
+
+        /// Stash uncommitted changes before syncing and re-apply them after
+        #[arg(long)]
+        autostash: bool,
     },
     /// View event log
     Log {
@@ -168,12 +364,71 @@ enum Commands {
         /// Show aggregate statistics (total events, hook runs, avg duration, etc.)
         #[arg(long)]
         summary: bool,
+
+        /// Show event counts per type for the repo, within --since if given
+        #[arg(
+            long,
+            conflicts_with_all = ["branch", "tail", "output", "summary", "until", "event_types"]
+        )]
+        stats: bool,
+
+        /// Only show events at or after this time (RFC 3339, date, or relative like 7d, 24h)
+        #[arg(long, value_name = "TIME")]
+        since: Option<String>,
+
+        /// Only show events at or before this time (RFC 3339, date, or relative like 7d, 24h)
+        #[arg(long, value_name = "TIME")]
+        until: Option<String>,
+
+        /// Only show events of this type (repeatable)
+        #[arg(
+            long = "type",
+            value_name = "TYPE",
+            value_parser = clap::builder::PossibleValuesParser::new(state::EVENT_TYPES)
+        )]
+        event_types: Vec<String>,
+    },
+    /// Clone a repository as a bare repo with worktrees hanging off it.
+    ///
+    /// Creates `<DIR>/.bare` (the bare clone) and a `<DIR>/.git` file pointing
+    /// at it, then registers the repository so `trench create` works in DIR.
+    Clone {
+        /// Repository URL or path to clone
+        url: String,
+
+        /// Directory to set up (defaults to the repository name from the URL)
+        dir: Option<PathBuf>,
+    },
+    /// Re-point a moved repository's stored path at the current checkout.
+    ///
+    /// Matches the tracked repo whose recorded path no longer exists by its
+    /// origin remote URL, then repairs linked worktrees.
+    Relink,
+    /// Restore DB records for trench worktrees that exist in git but are untracked.
+    ///
+    /// Scans the current repository's linked worktrees under the configured
+    /// worktree root and re-inserts managed rows, e.g. after the database
+    /// was reset.
+    Reattach,
+    /// Export or import trench's state database
+    Db {
+        #[command(subcommand)]
+        action: DbAction,
+    },
+    /// Manage per-repo settings stored in trench's database
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
     },
     /// Initialize .trench.toml in current directory
     Init {
         /// Overwrite existing .trench.toml
         #[arg(long)]
         force: bool,
+
+        /// Emit active post_create and pre_remove hooks to start from
+        #[arg(long)]
+        with_hooks: bool,
     },
     /// Output shell function definition for eval.
     ///
@@ -202,6 +457,45 @@ enum Commands {
     },
 }
 
+/// Subcommands of `trench db`
+#[derive(Subcommand, Debug)]
+enum DbAction {
+    /// Dump repos, worktrees, events, and tags as JSON
+    Export {
+        /// Read from this database file (e.g. a backup) instead of the active one
+        #[arg(long)]
+        from: Option<std::path::PathBuf>,
+
+        /// Write the dump to this file instead of stdout
+        #[arg(long)]
+        output: Option<std::path::PathBuf>,
+    },
+    /// Load a `trench db export` dump into a fresh database
+    Import {
+        /// Path to the JSON dump
+        file: std::path::PathBuf,
+    },
+}
+
+/// Subcommands of `trench config`
+#[derive(Subcommand, Debug)]
+enum ConfigAction {
+    /// Set the worktree path template for the current repo.
+    ///
+    /// Used by `create` when neither `.trench.toml` nor the CLI sets
+    /// `worktrees.root`; takes precedence over the global config.
+    #[command(name = "set-template")]
+    SetTemplate {
+        /// Path template, e.g. "{{ repo }}/{{ branch | sanitize }}"
+        template: String,
+    },
+}
+
+fn parse_worktree_name(arg: &str) -> Result<String, String> {
+    paths::validate_worktree_name(arg)?;
+    Ok(arg.to_string())
+}
+
 /// Supported shells for shell-init and completions
 #[derive(Debug, Clone, Copy, ValueEnum)]
 pub(crate) enum ShellType {
@@ -210,11 +504,36 @@ pub(crate) enum ShellType {
     Fish,
 }
 
-/// Sync strategy for `trench sync`
+/// Path display style for `trench list`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub(crate) enum PathStyleArg {
+    /// Absolute paths
+    Full,
+    /// Home directory shown as `~`
+    Tilde,
+    /// Relative to the worktree root
+    Short,
+}
+
+/// Sort key for `trench list --sort`
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
-pub(crate) enum SyncStrategy {
-    Rebase,
-    Merge,
+pub(crate) enum ListSortArg {
+    /// Oldest first
+    Created,
+    /// Alphabetical by name
+    Name,
+    /// Most recently accessed first
+    Accessed,
+}
+
+impl From<ListSortArg> for state::WorktreeOrder {
+    fn from(sort: ListSortArg) -> Self {
+        match sort {
+            ListSortArg::Created => state::WorktreeOrder::CreatedAsc,
+            ListSortArg::Name => state::WorktreeOrder::NameAsc,
+            ListSortArg::Accessed => state::WorktreeOrder::AccessedDesc,
+        }
+    }
 }
 
 impl Cli {
@@ -223,61 +542,225 @@ impl Cli {
         OutputConfig::from_env(self.no_color, self.quiet, self.verbose, is_tty)
     }
 
+    fn pager_config(&self, output_config: &OutputConfig) -> PagerConfig {
+        PagerConfig {
+            is_tty: std::io::stdout().is_terminal(),
+            no_pager: self.no_pager,
+            structured: self.json || self.jsonl || self.porcelain.is_some(),
+            quiet: output_config.is_quiet(),
+            terminal_height: crossterm::terminal::size()
+                .ok()
+                .map(|(_, rows)| rows as usize),
+        }
+    }
+
     fn should_launch_tui(&self, stdin_is_tty: bool, stdout_is_tty: bool) -> bool {
         self.command.is_none() && stdin_is_tty && stdout_is_tty
     }
 }
 
+/// Discovery root chosen with `--repo`, set once before any command runs.
+static REPO_OVERRIDE: OnceLock<PathBuf> = OnceLock::new();
+
+/// Record the `--repo` path, resolved against the real cwd so that later
+/// relative lookups do not depend on where it was spelled.
+fn set_repo_override(repo: &Path) -> anyhow::Result<()> {
+    let resolved = repo
+        .canonicalize()
+        .with_context(|| format!("--repo path '{}' is not accessible", repo.display()))?;
+    if !resolved.is_dir() {
+        anyhow::bail!("--repo path '{}' is not a directory", repo.display());
+    }
+    let _ = REPO_OVERRIDE.set(resolved);
+    Ok(())
+}
+
+/// Directory commands discover the repository from: the `--repo` path when
+/// given, otherwise the process's current directory.
+fn invocation_dir() -> anyhow::Result<PathBuf> {
+    match REPO_OVERRIDE.get() {
+        Some(repo) => Ok(repo.clone()),
+        None => std::env::current_dir().context("failed to determine current directory"),
+    }
+}
+
 fn main() -> anyhow::Result<()> {
     logging::init()?;
 
     let cli = Cli::parse();
     let output_config = cli.output_config();
+    git::set_verbose(output_config.is_verbose());
+    if let Some(repo) = &cli.repo {
+        set_repo_override(repo)?;
+    }
+    if let Some(db) = db_override_from(cli.db.as_deref(), std::env::var_os(DB_ENV)) {
+        let db = std::path::absolute(&db)
+            .with_context(|| format!("invalid database path '{}'", db.display()))?;
+        let _ = DB_OVERRIDE.set(db);
+    }
+    if let Some(config_path) = &cli.config {
+        config::set_global_config_path(
+            std::path::absolute(config_path)
+                .with_context(|| format!("invalid config path '{}'", config_path.display()))?,
+        );
+    }
 
     if cli.should_launch_tui(
         std::io::stdin().is_terminal(),
         std::io::stdout().is_terminal(),
     ) {
+        // The TUI resolves its repository from the process cwd.
+        if let Some(repo) = REPO_OVERRIDE.get() {
+            std::env::set_current_dir(repo)
+                .with_context(|| format!("failed to enter '{}'", repo.display()))?;
+        }
         if let Some(path) = tui::run()? {
             write_tui_switch_path(&path)?;
         }
         return Ok(());
     }
 
+    let pager = cli.pager_config(&output_config);
     let dry_run = cli.dry_run;
     let json = cli.json;
     let porcelain = cli.porcelain;
+    let jsonl = cli.jsonl;
+    let assume_yes = cli.yes || assume_yes_from_env();
 
     let result = match cli.command {
         Some(Commands::Create {
             branch,
             from,
+            from_tag,
+            interactive,
+            template,
+            base_from_upstream,
             no_hooks,
-        }) => run_create(&branch, from.as_deref(), dry_run, json, no_hooks),
+            env,
+            parents,
+            name,
+        }) => run_create(
+            git::branch_name_from_os(&branch)?,
+            CreateOptions {
+                from: from
+                    .or_else(|| from_tag.map(|tag| format!("refs/tags/{tag}")))
+                    .as_deref(),
+                interactive,
+                template: template.as_deref(),
+                base_from_upstream,
+                no_hooks,
+                user_env: &env,
+                parents,
+                name: name.as_deref(),
+            },
+            dry_run,
+            json,
+            output_config.is_quiet(),
+            !output_config.is_quiet() && std::io::stdout().is_terminal(),
+        ),
         Some(Commands::Remove {
             branch,
             force,
             delete_branch,
             no_hooks,
-        }) => run_remove(&branch, force, delete_branch, no_hooks, dry_run, json),
+            keep_dir,
+        }) => run_remove(
+            &branch,
+            cli::commands::remove::RemoveOptions {
+                force,
+                delete_branch,
+                no_hooks,
+                keep_dir,
+            },
+            dry_run,
+            json,
+            assume_yes,
+        ),
         Some(Commands::Switch {
             branch,
             print_path,
             tmux: tmux_flag,
-        }) => run_switch(&branch, print_path, tmux_flag),
-        Some(Commands::Tag { branch, tags }) => run_tag(&branch, &tags),
+            editor,
+        }) => run_switch(&branch, print_path, tmux_flag, editor, json),
+        Some(Commands::Tag {
+            branch,
+            tags,
+            all_matching,
+        }) => match (all_matching, branch) {
+            // With --all-matching every positional is a tag operation.
+            (Some(filter), branch) => {
+                let ops: Vec<String> = branch.into_iter().chain(tags).collect();
+                run_tag_all_matching(&filter, &ops)
+            }
+            (None, Some(branch)) => run_tag(&branch, &tags),
+            (None, None) => unreachable!("clap requires <BRANCH> without --all-matching"),
+        },
+        Some(Commands::Tags) => run_tags(json),
         Some(Commands::Open {
             branch,
             tmux: tmux_flag,
-        }) => run_open(&branch, tmux_flag),
-        Some(Commands::List { tag }) => run_list(tag.as_deref(), json, porcelain),
-        Some(Commands::Status { branch }) => run_status(
+            open_cmd,
+        }) => run_open(&branch, tmux_flag, open_cmd),
+        Some(Commands::List {
+            tag,
+            size,
+            width,
+            path_style,
+            count,
+            schema,
+            age,
+            sort,
+        }) => run_list(
+            tag.as_deref(),
+            sort.map(Into::into),
+            size,
+            age,
+            width,
+            path_style,
+            count,
+            schema,
+            json,
+            jsonl,
+            porcelain,
+            &pager,
+        ),
+        Some(Commands::Status {
+            branch,
+            all,
+            diffstat,
+        }) => run_status(
             branch.as_deref(),
+            all,
+            diffstat,
             json,
             porcelain,
             output_config.should_color(),
         ),
-        Some(Commands::Init { force }) => run_init(force),
+        Some(Commands::Info { branch }) => run_info(&branch, json),
+        Some(Commands::Env {
+            branch,
+            event,
+            export,
+        }) => run_env(&branch, event, export),
+        Some(Commands::Url { branch, compare }) => run_url(&branch, compare, json),
+        Some(Commands::Which { branch }) => run_which(&branch, json),
+        Some(Commands::SetBase { branch, new_base }) => {
+            run_set_base(&branch, &new_base, dry_run, json)
+        }
+        Some(Commands::Path { branch, template }) => run_path(&branch, template.as_deref(), json),
+        Some(Commands::Clone { url, dir }) => run_clone(&url, dir, dry_run, json),
+        Some(Commands::Relink) => run_relink(dry_run, json),
+        Some(Commands::Reattach) => run_reattach(dry_run, json),
+        Some(Commands::Db { action }) => match action {
+            DbAction::Export { from, output } => {
+                run_db_export(from.as_deref(), output.as_deref(), dry_run)
+            }
+            DbAction::Import { file } => run_db_import(&file, dry_run, json),
+        },
+        Some(Commands::Config { action }) => match action {
+            ConfigAction::SetTemplate { template } => run_config_set_template(&template, dry_run),
+        },
+        Some(Commands::Init { force, with_hooks }) => run_init(force, with_hooks),
         Some(Commands::ShellInit { shell }) => {
             print!("{}", cli::commands::shell_init::generate(shell));
             Ok(())
@@ -291,23 +774,20 @@ fn main() -> anyhow::Result<()> {
             all,
             strategy,
             no_hooks,
+            autostash,
         }) => {
             if all && branch.is_some() {
                 eprintln!("error: <BRANCH> cannot be used with --all");
                 ExitCode::GeneralError.exit();
             }
             if all {
-                if strategy.is_none() {
-                    eprintln!("error: {}", cli::commands::sync::BatchSyncMissingStrategy);
-                    ExitCode::MissingRequiredFlag.exit();
-                }
-                run_sync_all(strategy.unwrap(), json, dry_run, no_hooks)
+                run_sync_all(strategy, json, dry_run, no_hooks, autostash)
             } else {
                 let branch = branch.unwrap_or_else(|| {
                     eprintln!("error: <BRANCH> is required when --all is not set");
                     ExitCode::GeneralError.exit();
                 });
-                run_sync(&branch, strategy, json, dry_run, no_hooks)
+                run_sync(&branch, strategy, json, dry_run, no_hooks, autostash)
             }
         }
         Some(Commands::Log {
@@ -315,13 +795,23 @@ fn main() -> anyhow::Result<()> {
             tail,
             output,
             summary,
+            stats,
+            since,
+            until,
+            event_types,
         }) => run_log(
             branch.as_deref(),
             tail,
             output,
             summary,
+            stats,
+            since.as_deref(),
+            until.as_deref(),
+            event_types,
             json,
+            jsonl,
             output_config.should_color(),
+            &pager,
         ),
         None => {
             anyhow::bail!("TUI requires an interactive terminal (stdin and stdout must be a TTY)");
@@ -355,11 +845,35 @@ fn write_tui_switch_path(path: &str) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Describe a failed hook, naming the `run` or `shell` step that stopped it
+/// (a failed `copy` step already says so in its error).
+fn format_hook_failure(hook: &str, err: &anyhow::Error) -> String {
+    match hooks::runner::failed_step(err) {
+        Some(step @ ("run" | "shell")) => format!("{hook} hook failed in {step} step: {err:#}"),
+        _ => format!("{hook} hook failed: {err:#}"),
+    }
+}
+
 fn format_switch_notice(path: &str) -> String {
     format!("Switched to {path}")
 }
 
+/// Database file chosen with `--db` or `TRENCH_DB`, set once before any
+/// command runs.
+static DB_OVERRIDE: OnceLock<PathBuf> = OnceLock::new();
+
+/// Resolve the database override with precedence `--db` > `TRENCH_DB` >
+/// none (the default `trench.db` in the data directory). An empty env value
+/// counts as unset.
+fn db_override_from(flag: Option<&Path>, env_value: Option<std::ffi::OsString>) -> Option<PathBuf> {
+    flag.map(Path::to_path_buf)
+        .or_else(|| env_value.filter(|v| !v.is_empty()).map(PathBuf::from))
+}
+
 fn existing_db_path() -> anyhow::Result<Option<std::path::PathBuf>> {
+    if let Some(path) = DB_OVERRIDE.get() {
+        return Ok(db_file_is_accessible(path).then(|| path.clone()));
+    }
     let preferred = paths::data_dir_path()?.join("trench.db");
     if db_file_is_accessible(&preferred) {
         return Ok(Some(preferred));
@@ -383,6 +897,13 @@ fn db_file_is_accessible(path: &std::path::Path) -> bool {
 }
 
 fn runtime_db_path() -> anyhow::Result<std::path::PathBuf> {
+    if let Some(path) = DB_OVERRIDE.get() {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("failed to create directory: {}", parent.display()))?;
+        }
+        return Ok(path.clone());
+    }
     if let Some(existing) = existing_db_path()? {
         Ok(existing)
     } else {
@@ -390,21 +911,119 @@ fn runtime_db_path() -> anyhow::Result<std::path::PathBuf> {
     }
 }
 
+/// Look up the repo's template from `trench config set-template` without
+/// creating the database, so `--dry-run` stays side-effect free.
+fn stored_repo_template(repo_info: &git::RepoInfo) -> anyhow::Result<Option<String>> {
+    let Some(db_path) = existing_db_path()? else {
+        return Ok(None);
+    };
+    let Some(repo_path) = repo_info.path.to_str() else {
+        return Ok(None);
+    };
+    let db = state::Database::open(&db_path)?;
+    Ok(db
+        .get_repo_by_path(repo_path)?
+        .and_then(|repo| repo.template))
+}
+
+/// Ask on the terminal which local branch `create` should start from.
+///
+/// The repository's default branch is preselected. Returns `None` when the
+/// user closes the prompt without choosing.
+fn select_base_branch(repo_info: &git::RepoInfo) -> anyhow::Result<Option<String>> {
+    let branches = git::list_local_branches(&repo_info.path)?;
+    if branches.is_empty() {
+        anyhow::bail!("no local branches to choose a base from");
+    }
+    let default = branches
+        .iter()
+        .position(|b| *b == repo_info.default_branch)
+        .unwrap_or(0);
+    let choice = cli::prompt::select_on_terminal("Base branch:", &branches, default)?;
+    Ok(choice.map(|index| branches[index].clone()))
+}
+
+/// Flags of `trench create` that shape what gets created.
+#[derive(Debug, Clone, Copy, Default)]
+struct CreateOptions<'a> {
+    /// `--from` / `--from-tag`: base to create from.
+    from: Option<&'a str>,
+    /// `--interactive`: pick the base from a list of local branches.
+    interactive: bool,
+    /// `--template`: named path template to use.
+    template: Option<&'a str>,
+    /// `--base-from-upstream`: record the upstream as the worktree's base.
+    base_from_upstream: bool,
+    /// `--no-hooks`: skip `pre_create`/`post_create`.
+    no_hooks: bool,
+    /// `--env`: extra variables for hook processes.
+    user_env: &'a [(String, String)],
+    /// `--parents`: create missing parent directories of the worktree path.
+    parents: bool,
+    /// `--name`: worktree name to use instead of the sanitized branch.
+    name: Option<&'a str>,
+}
+
 fn run_create(
     branch: &str,
-    from: Option<&str>,
+    options: CreateOptions<'_>,
     dry_run: bool,
     json: bool,
-    no_hooks: bool,
+    quiet: bool,
+    show_progress: bool,
 ) -> anyhow::Result<()> {
-    let cwd = std::env::current_dir().context("failed to determine current directory")?;
+    let CreateOptions {
+        from,
+        interactive,
+        template,
+        base_from_upstream,
+        no_hooks,
+        user_env,
+        parents,
+        name,
+    } = options;
+    let cwd = invocation_dir()?;
 
     // Load config once so both dry-run and actual execution use the same
     // resolved template and hooks.
     let repo_info = git::discover_repo(&cwd)?;
     let project_config = config::load_project_config(&repo_info.path)?;
     let global_config = config::load_global_config()?;
-    let resolved = config::resolve_config(None, project_config.as_ref(), &global_config);
+    let cli_overrides = config::CliConfigOverrides {
+        worktree_template: template.map(String::from),
+        base_from_upstream: base_from_upstream.then_some(true),
+        ..config::CliConfigOverrides::default()
+    };
+    let mut resolved = config::resolve_config(
+        Some(&cli_overrides),
+        project_config.as_ref(),
+        &global_config,
+    );
+    config::apply_repo_template(
+        &mut resolved,
+        Some(&cli_overrides),
+        project_config.as_ref(),
+        stored_repo_template(&repo_info)?.as_deref(),
+    );
+    let path_template = resolved.worktrees.path_template()?;
+
+    let selected_base;
+    let from = match from {
+        Some(from) => Some(from),
+        None if (interactive || resolved.git.interactive_base)
+            && !json
+            && std::io::stdin().is_terminal()
+            && std::io::stderr().is_terminal() =>
+        {
+            let Some(base) = select_base_branch(&repo_info)? else {
+                eprintln!("Cancelled.");
+                return Ok(());
+            };
+            selected_base = base;
+            Some(selected_base.as_str())
+        }
+        None => None,
+    };
 
     if dry_run {
         // Use the non-mutating path accessor — dry-run must not create dirs.
@@ -414,7 +1033,9 @@ fn run_create(
             from,
             &cwd,
             &worktree_root,
-            &resolved.worktrees.root,
+            path_template,
+            resolved.worktrees.sparse.as_deref(),
+            name,
             resolved.hooks.as_ref(),
         )?;
 
@@ -430,28 +1051,71 @@ fn run_create(
     let worktree_root = paths::worktree_root()?;
     let db_path = runtime_db_path()?;
     let db = state::Database::open(&db_path)?;
+    prune_if_enabled(&repo_info.path, &resolved.git);
 
     let rt = tokio::runtime::Runtime::new().context("failed to create async runtime")?;
 
-    match rt.block_on(cli::commands::create::execute_with_hooks(
+    // While a hook runs, Ctrl-C stops the remaining hook steps instead of
+    // killing trench, so the interruption is recorded and reported.
+    let cancel = (!no_hooks && resolved.hooks.is_some()).then(hooks::runner::HookCancel::on_ctrl_c);
+
+    // Progress goes to stderr so the printed path stays scriptable.
+    let progress = (show_progress && !json).then(std::io::stderr);
+    match rt.block_on(cli::commands::create::execute_with_progress(
         branch,
         from,
         &cwd,
         &worktree_root,
-        &resolved.worktrees.root,
+        path_template,
+        resolved.worktrees.sparse.as_deref(),
+        parents,
+        name,
         &db,
         resolved.hooks.as_ref(),
+        user_env,
         no_hooks,
-        None,
+        progress,
+        cancel.as_ref(),
     )) {
-        Ok(outcome) => {
+        Ok((mut outcome, step_timings)) => {
+            if resolved.git.base_from_upstream {
+                cli::commands::create::record_upstream_base(
+                    &db,
+                    &repo_info.path,
+                    &mut outcome.result,
+                )?;
+            }
+
             // Report post_create hook failure to stderr
             if let Some(ref hook_err) = outcome.post_create_error {
-                eprintln!("error: post_create hook failed: {hook_err:#}");
+                if hook_err
+                    .downcast_ref::<hooks::runner::HookInterruptedError>()
+                    .is_some()
+                {
+                    eprintln!(
+                        "interrupted: worktree created at {}, but post_create hooks did not finish; \
+                         complete the remaining setup manually",
+                        outcome.result.path.display()
+                    );
+                } else {
+                    eprintln!("error: {}", format_hook_failure("post_create", hook_err));
+                }
+            }
+
+            // stdout carries exactly one result line so `$(trench create …)`
+            // is safe to capture; everything else goes to stderr.
+            if !json && !quiet {
+                if let Some(summary) =
+                    cli::commands::create::format_copy_summary(&outcome.copied_files)
+                {
+                    eprintln!("{summary}");
+                }
             }
 
             if json {
-                let json_output = outcome.result.to_json_output(outcome.hooks_status);
+                let mut json_output = outcome.result.to_json_output(outcome.hooks_status);
+                json_output.hook_steps = step_timings;
+                json_output.files_copied = outcome.copied_files.len();
                 println!("{}", output::json::format_json_value(&json_output)?);
             } else {
                 println!("{}", outcome.result.path.display());
@@ -478,16 +1142,29 @@ fn run_create(
                 eprintln!("error: {e:#}");
                 ExitCode::HookTimeout.exit();
             }
-            // Check for hook failure (pre_create) via typed error
-            if e.downcast_ref::<cli::commands::create::CreateError>()
-                .is_some()
-            {
-                eprintln!("error: {e:#}");
-                ExitCode::HookFailed.exit();
+            // Typed create errors: pre_create hook failure or duplicate branch
+            match e.downcast_ref::<cli::commands::create::CreateError>() {
+                Some(cli::commands::create::CreateError::PreCreateHookFailed(_)) => {
+                    eprintln!("error: {e:#}");
+                    ExitCode::HookFailed.exit();
+                }
+                Some(
+                    cli::commands::create::CreateError::AlreadyTracked { .. }
+                    | cli::commands::create::CreateError::NameCollision { .. },
+                ) => {
+                    eprintln!("error: {e}");
+                    ExitCode::BranchExists.exit();
+                }
+                Some(cli::commands::create::CreateError::ParentMissing { .. }) => {
+                    eprintln!("error: {e}");
+                    ExitCode::NotFound.exit();
+                }
+                _ => {}
             }
             if let Some(git_err) = e.downcast_ref::<git::GitError>() {
                 match git_err {
                     git::GitError::BranchAlreadyExists { .. }
+                    | git::GitError::BranchInUse { .. }
                     | git::GitError::RemoteBranchAlreadyExists { .. } => {
                         eprintln!("error: {e}");
                         ExitCode::BranchExists.exit();
@@ -506,13 +1183,18 @@ fn run_create(
 
 fn run_remove(
     identifier: &str,
-    force: bool,
-    delete_branch: bool,
-    no_hooks: bool,
+    options: cli::commands::remove::RemoveOptions,
     dry_run: bool,
     json: bool,
+    assume_yes: bool,
 ) -> anyhow::Result<()> {
-    let cwd = std::env::current_dir().context("failed to determine current directory")?;
+    let cli::commands::remove::RemoveOptions {
+        force,
+        delete_branch,
+        no_hooks,
+        keep_dir,
+    } = options;
+    let cwd = invocation_dir()?;
 
     let repo_info = git::discover_repo(&cwd)?;
 
@@ -536,10 +1218,8 @@ fn run_remove(
             identifier,
             &cwd,
             db.as_ref(),
-            delete_branch,
-            force,
             hooks_config.as_ref(),
-            no_hooks,
+            options,
         )?;
 
         if json {
@@ -550,14 +1230,17 @@ fn run_remove(
         return Ok(());
     }
 
-    if json && !force {
-        eprintln!("error: trench remove --json requires --force");
+    // `--force`, `--yes`, or TRENCH_ASSUME_YES skips the confirmation
+    // prompt; only `--force` also force-deletes unmerged branches.
+    let skip_confirm = remove_skips_confirmation(force, assume_yes);
+    if json && !skip_confirm {
+        eprintln!("error: trench remove --json requires --force or --yes");
         ExitCode::MissingRequiredFlag.exit();
     }
 
     let interactive = std::io::stdin().is_terminal() && std::io::stderr().is_terminal();
-    if !force && !interactive {
-        eprintln!("error: trench remove requires --force outside interactive terminals");
+    if !skip_confirm && !interactive {
+        eprintln!("error: trench remove requires --force or --yes outside interactive terminals");
         ExitCode::MissingRequiredFlag.exit();
     }
 
@@ -565,28 +1248,68 @@ fn run_remove(
     let db = state::Database::open(&db_path)?;
 
     let live = live_worktree::resolve(identifier, &repo_info, &db)?;
-    if let Some(warning) = process::format_process_warning(&live.entry.path.to_string_lossy()) {
-        eprintln!("{warning}");
+    if keep_dir {
+        cli::commands::remove::ensure_unlocked(&live, &repo_info, force)?;
+    } else {
+        cli::commands::remove::ensure_removable(&live, &repo_info, force)?;
+        if let Some(warning) = process::format_process_warning(&live.entry.path.to_string_lossy()) {
+            eprintln!("{warning}");
+        }
     }
 
-    if interactive && !force {
-        let confirmed = prompt_yes_no(&format!(
-            "Remove worktree '{}' at {}?",
-            live.entry.name,
-            live.entry.path.display()
-        ))?;
+    if interactive {
+        let question = if keep_dir {
+            format!(
+                "Stop tracking worktree '{}'? Files at {} are kept.",
+                live.entry.name,
+                live.entry.path.display()
+            )
+        } else {
+            format!(
+                "Remove worktree '{}' at {}?",
+                live.entry.name,
+                live.entry.path.display()
+            )
+        };
+        let confirmed =
+            cli::prompt::confirm_or_assume(skip_confirm, || cli::prompt::confirm(&question))?;
         if !confirmed {
             eprintln!("Cancelled.");
             return Ok(());
         }
     }
 
+    if keep_dir {
+        let result = cli::commands::remove::execute_keep_dir(&live, &repo_info, &db)?;
+        if json {
+            let hooks_status = if hooks_config
+                .as_ref()
+                .is_some_and(|h| h.pre_remove.is_some() || h.post_remove.is_some())
+            {
+                cli::commands::remove::RemoveHooksStatus::Skipped
+            } else {
+                cli::commands::remove::RemoveHooksStatus::None
+            };
+            println!(
+                "{}",
+                output::json::format_json_value(&result.to_json_output(hooks_status))?
+            );
+        } else {
+            eprintln!(
+                "Unregistered worktree '{}'; files kept at {}",
+                result.name,
+                live.entry.path.display()
+            );
+        }
+        return Ok(());
+    }
+
     let rt = tokio::runtime::Runtime::new().context("failed to create async runtime")?;
     let outcome = match rt.block_on(cli::commands::remove::execute_live_resolved_with_hooks(
         &live,
         &repo_info,
         &db,
-        force && delete_branch,
+        skip_confirm && delete_branch,
         force && delete_branch,
         hooks_config.as_ref(),
         no_hooks,
@@ -597,10 +1320,10 @@ fn run_remove(
     };
 
     if let Some(ref hook_err) = outcome.post_remove_warning {
-        eprintln!("warning: post_remove hook failed: {hook_err:#}");
+        eprintln!("warning: {}", format_hook_failure("post_remove", hook_err));
     }
 
-    let (human_outcome, incomplete_requested_outcome) = if interactive && !force {
+    let (human_outcome, incomplete_requested_outcome) = if interactive && !skip_confirm {
         match outcome.result.branch.as_deref() {
             Some(branch) => prompt_local_branch_delete(&repo_info.path, branch)?,
             None => (RemoveHumanOutcome::WorktreeOnly, false),
@@ -646,31 +1369,30 @@ enum RemoveHumanOutcome {
     BranchDeleteFailed { branch: String, error: String },
 }
 
-fn prompt_yes_no(prompt: &str) -> anyhow::Result<bool> {
-    let stdin = std::io::stdin();
-    let stderr = std::io::stderr();
-    let mut input = stdin.lock();
-    let mut output = stderr.lock();
-    prompt_yes_no_from(prompt, &mut input, &mut output)
+/// Whether `TRENCH_ASSUME_YES` is set to an affirmative value.
+fn assume_yes_from_env() -> bool {
+    assume_yes_from_env_value(std::env::var(ASSUME_YES_ENV).ok().as_deref())
+}
+
+/// Parse a `TRENCH_ASSUME_YES` value: `1`, `true`, or `yes` (any case).
+fn assume_yes_from_env_value(value: Option<&str>) -> bool {
+    value.is_some_and(|v| {
+        let v = v.trim();
+        v == "1" || v.eq_ignore_ascii_case("true") || v.eq_ignore_ascii_case("yes")
+    })
 }
 
-fn prompt_yes_no_from<R: BufRead, W: Write>(
-    prompt: &str,
-    input: &mut R,
-    output: &mut W,
-) -> anyhow::Result<bool> {
-    write!(output, "{prompt} [y/N] ")?;
-    output.flush()?;
-    let mut line = String::new();
-    input.read_line(&mut line)?;
-    Ok(line.trim().eq_ignore_ascii_case("y"))
+/// `trench remove` skips its confirmation prompt when either `--force` or
+/// `--yes` (including `TRENCH_ASSUME_YES`) is given.
+fn remove_skips_confirmation(force: bool, assume_yes: bool) -> bool {
+    force || assume_yes
 }
 
 fn prompt_local_branch_delete(
     repo_path: &std::path::Path,
     branch: &str,
 ) -> anyhow::Result<(RemoveHumanOutcome, bool)> {
-    if !prompt_yes_no(&format!("Delete local branch '{branch}' too?"))? {
+    if !cli::prompt::confirm(&format!("Delete local branch '{branch}' too?"))? {
         return Ok((RemoveHumanOutcome::BranchKept(branch.to_string()), false));
     }
 
@@ -681,7 +1403,7 @@ fn prompt_local_branch_delete(
             false,
         )),
         Err(git::GitError::BranchNotFullyMerged { .. }) => {
-            if !prompt_yes_no(&format!(
+            if !cli::prompt::confirm(&format!(
                 "Branch '{branch}' is not fully merged. Force delete?"
             ))? {
                 return Ok((RemoveHumanOutcome::BranchKept(branch.to_string()), false));
@@ -758,9 +1480,10 @@ fn handle_remove_error(e: anyhow::Error) -> anyhow::Result<()> {
         eprintln!("error: {e:#}");
         ExitCode::HookTimeout.exit();
     }
-    if e.downcast_ref::<cli::commands::remove::RemoveError>()
-        .is_some()
-    {
+    if matches!(
+        e.downcast_ref::<cli::commands::remove::RemoveError>(),
+        Some(cli::commands::remove::RemoveError::PreRemoveHookFailed(_))
+    ) {
         eprintln!("error: {e:#}");
         ExitCode::HookFailed.exit();
     }
@@ -793,13 +1516,24 @@ fn execute_tmux_command(cmd: &[String]) -> anyhow::Result<bool> {
     }
 }
 
-fn run_switch(identifier: &str, print_path: bool, tmux_flag: bool) -> anyhow::Result<()> {
-    let cwd = std::env::current_dir().context("failed to determine current directory")?;
+fn run_switch(
+    identifier: &str,
+    print_path: bool,
+    tmux_flag: bool,
+    editor: bool,
+    json: bool,
+) -> anyhow::Result<()> {
+    let cwd = invocation_dir()?;
     let db_path = runtime_db_path()?;
     let db = state::Database::open(&db_path)?;
 
     match cli::commands::switch::execute(identifier, &cwd, &db) {
         Ok(result) => {
+            // --editor repoints the symlink through `run_open_editor`.
+            if !editor {
+                update_current_symlink(&cwd, Path::new(&result.path));
+            }
+
             // --print-path must always write to stdout (shell-init depends on it),
             // so short-circuit before any tmux resolution.
             if print_path {
@@ -808,6 +1542,18 @@ fn run_switch(identifier: &str, print_path: bool, tmux_flag: bool) -> anyhow::Re
                 return Ok(());
             }
 
+            // --editor hands off to the same editor launch as `trench open`.
+            if editor {
+                let repo_info = git::discover_repo(&cwd)?;
+                let project_config = config::load_project_config(&repo_info.path)?;
+                let global_config = config::load_global_config()?;
+                let resolved =
+                    config::resolve_config(None, project_config.as_ref(), &global_config);
+                let editor_command = resolved.ui.open_cmd.or(resolved.editor_command);
+                eprintln!("{}", format_switch_notice(&result.path));
+                return run_open_editor(identifier, &cwd, &db, editor_command.as_deref());
+            }
+
             // Defer config loading until after early-exit paths so that
             // malformed config files don't break --print-path or --tmux.
             let config_tmux = if tmux_flag {
@@ -848,6 +1594,18 @@ fn run_switch(identifier: &str, print_path: bool, tmux_flag: bool) -> anyhow::Re
             Ok(())
         }
         Err(e) => {
+            if let Some(switch_err) = e.downcast_ref::<cli::commands::switch::SwitchError>() {
+                if json {
+                    let err_json = serde_json::json!({
+                        "error": switch_err.code(),
+                        "message": switch_err.to_string(),
+                    });
+                    println!("{}", output::json::format_json_value(&err_json)?);
+                } else {
+                    eprintln!("error: {e}");
+                }
+                ExitCode::NotFound.exit();
+            }
             let msg = e.to_string();
             if msg.contains("not found") || msg.contains("not tracked") {
                 eprintln!("error: {e}");
@@ -858,8 +1616,8 @@ fn run_switch(identifier: &str, print_path: bool, tmux_flag: bool) -> anyhow::Re
     }
 }
 
-fn run_open(identifier: &str, tmux_flag: bool) -> anyhow::Result<()> {
-    let cwd = std::env::current_dir().context("failed to determine current directory")?;
+fn run_open(identifier: &str, tmux_flag: bool, open_cmd: Option<String>) -> anyhow::Result<()> {
+    let cwd = invocation_dir()?;
     let db_path = runtime_db_path()?;
     let db = state::Database::open(&db_path)?;
 
@@ -868,12 +1626,21 @@ fn run_open(identifier: &str, tmux_flag: bool) -> anyhow::Result<()> {
     // Load config once. When --tmux is explicit, skip loading so malformed
     // config files don't break --tmux (same as run_switch).
     let (config_tmux, editor_command) = if tmux_flag {
-        (false, None) // --tmux overrides config; defer editor lookup to fallback
+        (false, open_cmd) // --tmux overrides config; defer editor lookup to fallback
     } else {
         let project_config = config::load_project_config(&repo_info.path)?;
         let global_config = config::load_global_config()?;
-        let resolved = config::resolve_config(None, project_config.as_ref(), &global_config);
-        (resolved.shell.tmux, resolved.editor_command)
+        let cli_overrides = config::CliConfigOverrides {
+            open_cmd,
+            ..config::CliConfigOverrides::default()
+        };
+        let resolved = config::resolve_config(
+            Some(&cli_overrides),
+            project_config.as_ref(),
+            &global_config,
+        );
+        let command = resolved.ui.open_cmd.or(resolved.editor_command);
+        (resolved.shell.tmux, command)
     };
 
     let use_tmux = tmux_flag || config_tmux;
@@ -893,6 +1660,7 @@ fn run_open(identifier: &str, tmux_flag: bool) -> anyhow::Result<()> {
             tmux::TmuxAction::TmuxNewWindow(cmd) => {
                 if execute_tmux_command(&cmd)? {
                     cli::commands::open::record_open_for_identifier(identifier, &cwd, &db)?;
+                    update_current_symlink(&cwd, &live.entry.path);
                 } else {
                     eprintln!("warning: tmux not found, falling back to $EDITOR");
                     return run_open_editor(identifier, &cwd, &db, editor_command.as_deref());
@@ -913,6 +1681,24 @@ fn run_open(identifier: &str, tmux_flag: bool) -> anyhow::Result<()> {
     run_open_editor(identifier, &cwd, &db, editor_command.as_deref())
 }
 
+/// Repoint `worktrees.current_symlink`, when configured, at `worktree_path`.
+///
+/// Best-effort: config or filesystem problems only warn, since a stale
+/// symlink must not block switching or opening.
+fn update_current_symlink(cwd: &Path, worktree_path: &Path) {
+    let update = || -> anyhow::Result<()> {
+        let repo_info = git::discover_repo(cwd)?;
+        let project_config = config::load_project_config(&repo_info.path)?;
+        let global_config = config::load_global_config()?;
+        let resolved = config::resolve_config(None, project_config.as_ref(), &global_config);
+        cli::commands::switch::update_current_symlink(&resolved, worktree_path)?;
+        Ok(())
+    };
+    if let Err(e) = update() {
+        eprintln!("warning: failed to update current_symlink: {e:#}");
+    }
+}
+
 fn run_open_editor(
     identifier: &str,
     cwd: &std::path::Path,
@@ -921,15 +1707,13 @@ fn run_open_editor(
 ) -> anyhow::Result<()> {
     match cli::commands::open::resolve(identifier, cwd, db, editor_command) {
         Ok(result) => {
-            let parts = shell_words::split(&result.editor)
-                .with_context(|| format!("invalid editor command: '{}'", result.editor))?;
+            let parts = cli::commands::open::build_open_command(&result.editor, &result.path)?;
             let (program, args) = parts
                 .split_first()
                 .ok_or_else(|| anyhow::anyhow!("editor command is empty after parsing"))?;
 
             let status = std::process::Command::new(program)
                 .args(args)
-                .arg(&result.path)
                 .status()
                 .with_context(|| format!("failed to launch editor '{}'", result.editor))?;
 
@@ -938,6 +1722,7 @@ fn run_open_editor(
             }
 
             cli::commands::open::record_open_for_identifier(identifier, cwd, db)?;
+            update_current_symlink(cwd, Path::new(&result.path));
 
             Ok(())
         }
@@ -953,7 +1738,7 @@ fn run_open_editor(
 }
 
 fn run_tag(identifier: &str, tags: &[String]) -> anyhow::Result<()> {
-    let cwd = std::env::current_dir().context("failed to determine current directory")?;
+    let cwd = invocation_dir()?;
     let db_path = runtime_db_path()?;
     let db = state::Database::open(&db_path)?;
 
@@ -962,19 +1747,199 @@ fn run_tag(identifier: &str, tags: &[String]) -> anyhow::Result<()> {
     Ok(())
 }
 
-fn run_log(
-    branch: Option<&str>,
-    tail: Option<usize>,
-    show_output: bool,
-    show_summary: bool,
-    json: bool,
-    use_color: bool,
-) -> anyhow::Result<()> {
-    // --summary and --output are mutually exclusive
-    if show_summary && show_output {
-        eprintln!("error: --summary and --output cannot be used together");
-        ExitCode::FlagConflict.exit();
-    }
+fn run_clone(url: &str, dir: Option<PathBuf>, dry_run: bool, json: bool) -> anyhow::Result<()> {
+    let dir = match dir {
+        Some(dir) => dir,
+        None => cli::commands::clone::default_clone_dir(url)
+            .map(PathBuf::from)
+            .ok_or_else(|| {
+                anyhow::anyhow!("cannot guess a directory name from '{url}'; pass DIR")
+            })?,
+    };
+    let dir = invocation_dir()?.join(dir);
+    let db = if dry_run {
+        state::Database::open_in_memory()?
+    } else {
+        state::Database::open(&runtime_db_path()?)?
+    };
+
+    let result = cli::commands::clone::execute(url, &dir, &db, dry_run)?;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&result)?);
+    } else {
+        print!("{result}");
+    }
+    Ok(())
+}
+
+fn run_relink(dry_run: bool, json: bool) -> anyhow::Result<()> {
+    let cwd = invocation_dir()?;
+    let db_path = runtime_db_path()?;
+    let db = state::Database::open(&db_path)?;
+
+    match cli::commands::relink::execute(&cwd, &db, dry_run) {
+        Ok(result) => {
+            if json {
+                println!("{}", serde_json::to_string_pretty(&result)?);
+            } else {
+                print!("{result}");
+            }
+            Ok(())
+        }
+        Err(e) => {
+            if e.to_string().contains("no moved repository") {
+                eprintln!("error: {e}");
+                ExitCode::NotFound.exit();
+            }
+            Err(e)
+        }
+    }
+}
+
+fn run_reattach(dry_run: bool, json: bool) -> anyhow::Result<()> {
+    let cwd = invocation_dir()?;
+    let repo_info = git::discover_repo(&cwd)?;
+    let project_config = config::load_project_config(&repo_info.path)?;
+    let global_config = config::load_global_config()?;
+    let mut resolved = config::resolve_config(None, project_config.as_ref(), &global_config);
+    config::apply_repo_template(
+        &mut resolved,
+        None,
+        project_config.as_ref(),
+        stored_repo_template(&repo_info)?.as_deref(),
+    );
+    let root = paths::template_root(
+        &paths::worktree_root_path()?,
+        resolved.worktrees.path_template()?,
+    );
+
+    // Dry-run reads an existing DB (or an empty one) so nothing is created.
+    let db = if dry_run {
+        match existing_db_path()? {
+            Some(db_path) => state::Database::open(&db_path)?,
+            None => state::Database::open_in_memory()?,
+        }
+    } else {
+        state::Database::open(&runtime_db_path()?)?
+    };
+    let result = cli::commands::reattach::execute(&cwd, &db, &root, dry_run)?;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&result)?);
+    } else {
+        print!("{result}");
+    }
+    Ok(())
+}
+
+fn run_db_export(
+    from: Option<&std::path::Path>,
+    output: Option<&std::path::Path>,
+    dry_run: bool,
+) -> anyhow::Result<()> {
+    let db = match from {
+        Some(path) => state::Database::open_unmigrated(path)?,
+        None => state::Database::open(&runtime_db_path()?)?,
+    };
+    let dump = cli::commands::db::export(&db)?;
+
+    match output {
+        Some(path) if dry_run => eprintln!("Would write export to {}", path.display()),
+        Some(path) => std::fs::write(path, dump)
+            .with_context(|| format!("failed to write export to {}", path.display()))?,
+        None => print!("{dump}"),
+    }
+    Ok(())
+}
+
+fn run_db_import(file: &std::path::Path, dry_run: bool, json: bool) -> anyhow::Result<()> {
+    let contents = std::fs::read_to_string(file)
+        .with_context(|| format!("failed to read {}", file.display()))?;
+    let db_path = runtime_db_path()?;
+    let db = state::Database::open(&db_path)?;
+
+    let summary = cli::commands::db::import(&db, &contents, dry_run)?;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&summary)?);
+    } else {
+        print!(
+            "{}",
+            cli::commands::db::format_import_summary(&summary, dry_run)
+        );
+    }
+    Ok(())
+}
+
+fn run_config_set_template(template: &str, dry_run: bool) -> anyhow::Result<()> {
+    let cwd = invocation_dir()?;
+    if dry_run {
+        let repo_info = git::discover_repo(&cwd)?;
+        paths::render_worktree_path(template, &repo_info.name, "branch")?;
+        println!(
+            "Would set worktree template for '{}' to '{template}'",
+            repo_info.name
+        );
+        return Ok(());
+    }
+
+    let db = state::Database::open(&runtime_db_path()?)?;
+    print!(
+        "{}",
+        cli::commands::config::execute_set_template(&cwd, &db, template)?
+    );
+    Ok(())
+}
+
+fn run_tag_all_matching(filter: &str, ops: &[String]) -> anyhow::Result<()> {
+    let cwd = invocation_dir()?;
+    let db_path = runtime_db_path()?;
+    let db = state::Database::open(&db_path)?;
+
+    let output = cli::commands::tag::execute_all_matching(filter, ops, &cwd, &db)?;
+    print!("{output}");
+    Ok(())
+}
+
+fn run_tags(json: bool) -> anyhow::Result<()> {
+    let cwd = invocation_dir()?;
+    let db_path = runtime_db_path()?;
+    let db = state::Database::open(&db_path)?;
+
+    let output = if json {
+        cli::commands::tag::execute_all_json(&cwd, &db)?
+    } else {
+        cli::commands::tag::execute_all(&cwd, &db)?
+    };
+    if output.ends_with('\n') {
+        print!("{output}");
+    } else {
+        println!("{output}");
+    }
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn run_log(
+    branch: Option<&str>,
+    tail: Option<usize>,
+    show_output: bool,
+    show_summary: bool,
+    show_stats: bool,
+    since: Option<&str>,
+    until: Option<&str>,
+    event_types: Vec<String>,
+    json: bool,
+    jsonl: bool,
+    use_color: bool,
+    pager: &PagerConfig,
+) -> anyhow::Result<()> {
+    // Summary and hook output are single objects, so --jsonl falls back to JSON.
+    let structured = json || jsonl;
+
+    // --summary and --output are mutually exclusive
+    if show_summary && show_output {
+        eprintln!("error: --summary and --output cannot be used together");
+        ExitCode::FlagConflict.exit();
+    }
 
     // --output requires a worktree argument
     if show_output && branch.is_none() {
@@ -983,7 +1948,18 @@ fn run_log(
         ExitCode::MissingRequiredFlag.exit();
     }
 
-    let cwd = std::env::current_dir().context("failed to determine current directory")?;
+    let now = state::unix_epoch_secs() as i64;
+    let filter = state::EventFilter {
+        since: since
+            .map(|s| cli::commands::log::parse_time_bound(s, now))
+            .transpose()?,
+        until: until
+            .map(|s| cli::commands::log::parse_time_bound(s, now))
+            .transpose()?,
+        event_types,
+    };
+
+    let cwd = invocation_dir()?;
     let db_path = runtime_db_path()?;
     let db = state::Database::open(&db_path)?;
 
@@ -1006,15 +1982,21 @@ fn run_log(
                 ExitCode::NotFound.exit();
             }
             // No repo tracked yet — show empty state
-            if show_summary && json {
-                let output = cli::commands::log::execute_summary_json(&db, 0, None, None)?;
+            if show_stats {
+                println!("{}", if structured { "[]" } else { "No events." });
+                return Ok(());
+            }
+            if show_summary && structured {
+                let output = cli::commands::log::execute_summary_json(&db, 0, None, None, &filter)?;
                 println!("{output}");
                 return Ok(());
             } else if show_summary {
                 println!("No events recorded yet.");
                 return Ok(());
             }
-            if json {
+            if jsonl {
+                // No events: an empty stream.
+            } else if json {
                 println!("[]");
             } else {
                 println!("No events.");
@@ -1030,12 +2012,27 @@ fn run_log(
         }
     }
 
+    // --stats mode: event counts per type over the --since window
+    if show_stats {
+        let output = if structured {
+            cli::commands::log::execute_stats_json(&db, repo_id, filter.since)?
+        } else {
+            cli::commands::log::execute_stats(&db, repo_id, filter.since)?
+        };
+        if output.ends_with('\n') {
+            print!("{output}");
+        } else {
+            println!("{output}");
+        }
+        return Ok(());
+    }
+
     // --summary mode: show aggregate statistics
     if show_summary {
-        let output = if json {
-            cli::commands::log::execute_summary_json(&db, repo_id, branch, tail)?
+        let output = if structured {
+            cli::commands::log::execute_summary_json(&db, repo_id, branch, tail, &filter)?
         } else {
-            cli::commands::log::execute_summary(&db, repo_id, branch, tail)?
+            cli::commands::log::execute_summary(&db, repo_id, branch, tail, &filter)?
         };
         if output.ends_with('\n') {
             print!("{output}");
@@ -1049,7 +2046,7 @@ fn run_log(
     if show_output {
         // branch is guaranteed Some by the check at function entry
         let b = branch.unwrap();
-        let result = if json {
+        let result = if structured {
             cli::commands::log::execute_output_json(&db, repo_id, b)
         } else {
             cli::commands::log::execute_output(&db, repo_id, b)
@@ -1070,21 +2067,38 @@ fn run_log(
         return Ok(());
     }
 
-    let output = if json {
-        cli::commands::log::execute_json(&db, repo_id, branch, tail)?
+    let output = if jsonl {
+        cli::commands::log::execute_jsonl(&db, repo_id, branch, tail, &filter)?
+    } else if json {
+        cli::commands::log::execute_json(&db, repo_id, branch, tail, &filter)?
     } else {
-        cli::commands::log::execute(&db, repo_id, use_color, branch, tail)?
+        cli::commands::log::execute(&db, repo_id, use_color, branch, tail, &filter)?
     };
-    if output.ends_with('\n') {
-        print!("{output}");
-    } else {
-        println!("{output}");
+    if output.is_empty() {
+        return Ok(());
     }
-    Ok(())
+    output::pager::maybe_page(&output, pager)
 }
 
-fn run_list(tag: Option<&str>, json: bool, porcelain: bool) -> anyhow::Result<()> {
-    let cwd = std::env::current_dir().context("failed to determine current directory")?;
+#[allow(clippy::too_many_arguments)]
+fn run_list(
+    tag: Option<&str>,
+    order: Option<state::WorktreeOrder>,
+    size: bool,
+    age: bool,
+    width: Option<usize>,
+    path_style: PathStyleArg,
+    count: bool,
+    schema: bool,
+    json: bool,
+    jsonl: bool,
+    porcelain: Option<PorcelainVersion>,
+    pager: &PagerConfig,
+) -> anyhow::Result<()> {
+    if schema && !json {
+        anyhow::bail!("--schema only applies to --json output");
+    }
+    let cwd = invocation_dir()?;
     let db_path = runtime_db_path()?;
     let db = state::Database::open(&db_path)?;
 
@@ -1092,45 +2106,99 @@ fn run_list(tag: Option<&str>, json: bool, porcelain: bool) -> anyhow::Result<()
     let repo_info = git::discover_repo(&cwd)?;
     let project_config = config::load_project_config(&repo_info.path)?;
     let global_config = config::load_global_config()?;
-    let resolved = config::resolve_config(None, project_config.as_ref(), &global_config);
+    let cli_overrides = config::CliConfigOverrides {
+        table_width: width,
+        ..config::CliConfigOverrides::default()
+    };
+    let resolved = config::resolve_config(
+        Some(&cli_overrides),
+        project_config.as_ref(),
+        &global_config,
+    );
     let scan_paths: Vec<String> = resolved
         .worktrees
         .scan
         .iter()
-        .map(|p| paths::expand_tilde(p))
+        .map(|p| paths::expand_path(p).to_string_lossy().into_owned())
         .collect();
+    let path_style = match path_style {
+        PathStyleArg::Full => paths::PathStyle::Full,
+        PathStyleArg::Tilde => paths::PathStyle::Tilde,
+        PathStyleArg::Short => paths::PathStyle::Short {
+            root: paths::template_root(&paths::worktree_root_path()?, &resolved.worktrees.root),
+        },
+    };
 
-    let output = if json {
-        cli::commands::list::execute_json(&cwd, &db, tag, &scan_paths)?
-    } else if porcelain {
-        cli::commands::list::execute_porcelain(&cwd, &db, tag, &scan_paths)?
+    if count {
+        println!(
+            "{}",
+            cli::commands::list::execute_count(&cwd, &db, tag, &scan_paths, json || jsonl)?
+        );
+        return Ok(());
+    }
+
+    let output = if jsonl {
+        cli::commands::list::execute_jsonl(&cwd, &db, tag, &scan_paths, order, size)?
+    } else if schema {
+        cli::commands::list::execute_json_envelope(&cwd, &db, tag, &scan_paths, order, size)?
+    } else if json && size {
+        cli::commands::list::execute_json_with_size(&cwd, &db, tag, &scan_paths, order)?
+    } else if json {
+        cli::commands::list::execute_json(&cwd, &db, tag, &scan_paths, order)?
+    } else if let Some(version) = porcelain {
+        cli::commands::list::execute_porcelain(&cwd, &db, tag, &scan_paths, order, version)?
+    } else if size || age {
+        cli::commands::list::execute_with_columns(
+            &cwd,
+            &db,
+            tag,
+            &scan_paths,
+            order,
+            resolved.ui.table_width,
+            &path_style,
+            cli::commands::list::ListColumns { size, age },
+        )?
     } else {
-        cli::commands::list::execute(&cwd, &db, tag, &scan_paths)?
+        cli::commands::list::execute(
+            &cwd,
+            &db,
+            tag,
+            &scan_paths,
+            order,
+            resolved.ui.table_width,
+            &path_style,
+        )?
     };
-    if output.ends_with('\n') {
-        print!("{output}");
-    } else {
-        println!("{output}");
+    if output.is_empty() {
+        return Ok(());
     }
-    Ok(())
+    output::pager::maybe_page(&output, pager)
 }
 
 fn run_status(
     branch: Option<&str>,
+    all: bool,
+    diffstat: bool,
     json: bool,
-    porcelain: bool,
+    porcelain: Option<PorcelainVersion>,
     use_color: bool,
 ) -> anyhow::Result<()> {
-    let cwd = std::env::current_dir().context("failed to determine current directory")?;
+    let cwd = invocation_dir()?;
     let db_path = runtime_db_path()?;
     let db = state::Database::open(&db_path)?;
 
-    let result = if json {
-        cli::commands::status::execute_json(&cwd, &db, branch)
-    } else if porcelain {
-        cli::commands::status::execute_porcelain(&cwd, &db, branch)
+    let result = if all && json {
+        cli::commands::status::execute_all_json(&cwd, &db)
+    } else if let (true, Some(version)) = (all, porcelain) {
+        cli::commands::status::execute_all_porcelain(&cwd, &db, version)
+    } else if all {
+        cli::commands::status::execute_all(&cwd, &db)
+    } else if json {
+        cli::commands::status::execute_json(&cwd, &db, branch, diffstat)
+    } else if let Some(version) = porcelain {
+        cli::commands::status::execute_porcelain(&cwd, &db, branch, version)
     } else {
-        cli::commands::status::execute(&cwd, &db, branch, use_color)
+        cli::commands::status::execute(&cwd, &db, branch, use_color, diffstat)
     };
 
     match result {
@@ -1153,29 +2221,211 @@ fn run_status(
     }
 }
 
+fn run_info(identifier: &str, json: bool) -> anyhow::Result<()> {
+    let cwd = invocation_dir()?;
+    let db_path = runtime_db_path()?;
+    let db = state::Database::open(&db_path)?;
+
+    let result = if json {
+        cli::commands::info::execute_json(&cwd, &db, identifier)
+    } else {
+        cli::commands::info::execute(&cwd, &db, identifier)
+    };
+
+    match result {
+        Ok(output) => {
+            if output.ends_with('\n') {
+                print!("{output}");
+            } else {
+                println!("{output}");
+            }
+            Ok(())
+        }
+        Err(e) => {
+            if e.to_string().contains("not found") {
+                eprintln!("error: {e}");
+                ExitCode::NotFound.exit();
+            }
+            Err(e)
+        }
+    }
+}
+
+fn run_env(identifier: &str, event: hooks::HookEvent, export: bool) -> anyhow::Result<()> {
+    let cwd = invocation_dir()?;
+    let db_path = runtime_db_path()?;
+    let db = state::Database::open(&db_path)?;
+
+    print!(
+        "{}",
+        cli::commands::env::execute(&cwd, &db, identifier, &event, export)?
+    );
+    Ok(())
+}
+
+fn run_url(identifier: &str, compare: bool, json: bool) -> anyhow::Result<()> {
+    let cwd = invocation_dir()?;
+    let db_path = runtime_db_path()?;
+    let db = state::Database::open(&db_path)?;
+
+    let output = if json {
+        cli::commands::url::execute_json(&cwd, &db, identifier, compare)?
+    } else {
+        cli::commands::url::execute(&cwd, &db, identifier, compare)?
+    };
+    println!("{output}");
+    Ok(())
+}
+
+/// Preview a worktree path with the same template resolution as `create`.
+/// Read-only: neither the worktree root nor the database is created.
+fn run_path(branch: &str, template: Option<&str>, json: bool) -> anyhow::Result<()> {
+    let cwd = invocation_dir()?;
+    let repo_info = git::discover_repo(&cwd)?;
+    let project_config = config::load_project_config(&repo_info.path)?;
+    let global_config = config::load_global_config()?;
+    let cli_overrides = config::CliConfigOverrides {
+        worktree_template: template.map(String::from),
+        ..config::CliConfigOverrides::default()
+    };
+    let mut resolved = config::resolve_config(
+        Some(&cli_overrides),
+        project_config.as_ref(),
+        &global_config,
+    );
+    config::apply_repo_template(
+        &mut resolved,
+        Some(&cli_overrides),
+        project_config.as_ref(),
+        stored_repo_template(&repo_info)?.as_deref(),
+    );
+    let path_template = resolved.worktrees.path_template()?;
+    let worktree_root = paths::worktree_root_path()?;
+
+    let output = if json {
+        cli::commands::path::execute_json(&cwd, &worktree_root, path_template, branch)?
+    } else {
+        cli::commands::path::execute(&cwd, &worktree_root, path_template, branch)?
+    };
+    println!("{output}");
+    Ok(())
+}
+
+fn run_which(identifier: &str, json: bool) -> anyhow::Result<()> {
+    let cwd = invocation_dir()?;
+    let db_path = runtime_db_path()?;
+    let db = state::Database::open(&db_path)?;
+
+    let result = if json {
+        cli::commands::which::execute_json(&cwd, &db, identifier)
+    } else {
+        cli::commands::which::execute(&cwd, &db, identifier)
+    };
+
+    match result {
+        Ok(output) => {
+            println!("{output}");
+            Ok(())
+        }
+        Err(e) => {
+            if e.to_string().contains("not found") {
+                eprintln!("error: {e}");
+                ExitCode::NotFound.exit();
+            }
+            Err(e)
+        }
+    }
+}
+
+fn run_set_base(identifier: &str, new_base: &str, dry_run: bool, json: bool) -> anyhow::Result<()> {
+    let cwd = invocation_dir()?;
+    // Dry-run reads an existing DB (or an empty one) so nothing is created.
+    let db = if dry_run {
+        match existing_db_path()? {
+            Some(db_path) => state::Database::open(&db_path)?,
+            None => state::Database::open_in_memory()?,
+        }
+    } else {
+        state::Database::open(&runtime_db_path()?)?
+    };
+
+    match cli::commands::set_base::execute(&cwd, &db, identifier, new_base, dry_run) {
+        Ok(result) => {
+            if json {
+                println!("{}", serde_json::to_string_pretty(&result)?);
+            } else {
+                print!("{result}");
+            }
+            Ok(())
+        }
+        Err(e) => {
+            if e.to_string().contains("not found") {
+                eprintln!("error: {e}");
+                ExitCode::NotFound.exit();
+            }
+            Err(e)
+        }
+    }
+}
+
+/// Prune remote-tracking refs for branches deleted on the remote when
+/// `git.auto_prune` is enabled. Best-effort: failures only warn, since an
+/// offline remote must not block local work.
+fn prune_if_enabled(
+    repo_path: &std::path::Path,
+    git_config: &config::ResolvedGitConfig,
+) -> Vec<String> {
+    if !git_config.auto_prune {
+        return Vec::new();
+    }
+    let Some(remote) = git::fetch_remote_name(repo_path) else {
+        return Vec::new();
+    };
+    match git::prune_stale_refs(repo_path, &remote) {
+        Ok(pruned) => {
+            for name in &pruned {
+                eprintln!("Pruned stale remote-tracking ref '{name}'");
+            }
+            pruned
+        }
+        Err(e) => {
+            eprintln!("warning: failed to prune '{remote}': {e}");
+            Vec::new()
+        }
+    }
+}
+
 fn run_sync(
     identifier: &str,
     strategy: Option<SyncStrategy>,
     json: bool,
     dry_run: bool,
     no_hooks: bool,
+    autostash: bool,
 ) -> anyhow::Result<()> {
-    let cwd = std::env::current_dir().context("failed to determine current directory")?;
+    let cwd = invocation_dir()?;
+
+    // Load config (hooks are needed for both dry-run preview and actual execution)
+    let repo_info = git::discover_repo(&cwd)?;
+    let project_config = config::load_project_config(&repo_info.path)?;
+    let global_config = config::load_global_config()?;
+    let resolved = config::resolve_config(None, project_config.as_ref(), &global_config);
+    let hooks_config = if no_hooks { None } else { resolved.hooks };
 
-    // Determine strategy: use CLI flag, or prompt interactively
-    // This runs BEFORE any DB work so dry-run can fail fast.
-    let resolved_strategy = match strategy {
+    // Determine strategy: CLI flag, then `git.sync_strategy`, or prompt
+    // interactively. This runs BEFORE any DB work so dry-run can fail fast.
+    let sync_strategy = match strategy.or(resolved.git.sync_strategy) {
         Some(s) => s,
         None => {
             if dry_run {
-                eprintln!("error: --strategy is required with --dry-run (use --strategy rebase or --strategy merge)");
+                eprintln!("error: --strategy is required with --dry-run (use --strategy rebase, --strategy merge, or --strategy ff-only)");
                 ExitCode::MissingRequiredFlag.exit();
             }
             if !std::io::stdin().is_terminal() {
-                eprintln!("error: --strategy is required in non-interactive mode (use --strategy rebase or --strategy merge)");
+                eprintln!("error: --strategy is required in non-interactive mode (use --strategy rebase, --strategy merge, or --strategy ff-only)");
                 ExitCode::MissingRequiredFlag.exit();
             }
-            eprint!("Sync strategy — (r)ebase or (m)erge? ");
+            eprint!("Sync strategy — (r)ebase, (m)erge, or (f)f-only? ");
             let mut input = String::new();
             std::io::stdin()
                 .read_line(&mut input)
@@ -1183,29 +2433,17 @@ fn run_sync(
             match input.trim().to_lowercase().as_str() {
                 "r" | "rebase" => SyncStrategy::Rebase,
                 "m" | "merge" => SyncStrategy::Merge,
+                "f" | "ff-only" => SyncStrategy::FfOnly,
                 other => {
-                    eprintln!("error: unknown strategy '{other}'. Use 'rebase' or 'merge'.");
+                    eprintln!(
+                        "error: unknown strategy '{other}'. Use 'rebase', 'merge', or 'ff-only'."
+                    );
                     ExitCode::GeneralError.exit();
                 }
             }
         }
     };
 
-    let sync_strategy = match resolved_strategy {
-        SyncStrategy::Rebase => cli::commands::sync::Strategy::Rebase,
-        SyncStrategy::Merge => cli::commands::sync::Strategy::Merge,
-    };
-
-    // Load hooks config (needed for both dry-run preview and actual execution)
-    let hooks_config = if no_hooks {
-        None
-    } else {
-        let repo_info = git::discover_repo(&cwd)?;
-        let project_config = config::load_project_config(&repo_info.path)?;
-        let global_config = config::load_global_config()?;
-        config::resolve_config(None, project_config.as_ref(), &global_config).hooks
-    };
-
     // Dry-run: open existing DB (read-only) for accurate base-branch metadata
     if dry_run {
         let db = if let Some(db_path) = existing_db_path()? {
@@ -1232,6 +2470,7 @@ fn run_sync(
     // Real execution path — open DB here (after dry-run early-return)
     let db_path = runtime_db_path()?;
     let db = state::Database::open(&db_path)?;
+    prune_if_enabled(&repo_info.path, &resolved.git);
 
     let rt = tokio::runtime::Runtime::new().context("failed to create async runtime")?;
 
@@ -1239,15 +2478,18 @@ fn run_sync(
         identifier,
         &cwd,
         &db,
-        sync_strategy,
+        cli::commands::sync::SyncOptions {
+            strategy: sync_strategy,
+            autostash: autostash,
+            no_hooks: no_hooks,
+        },
         hooks_config.as_ref(),
-        no_hooks,
         None,
     )) {
         Ok(outcome) => {
             // Report post_sync hook failure to stderr (FR-24: Report)
             if let Some(ref hook_err) = outcome.post_sync_error {
-                eprintln!("error: post_sync hook failed: {hook_err:#}");
+                eprintln!("error: {}", format_hook_failure("post_sync", hook_err));
             }
 
             if json {
@@ -1269,6 +2511,9 @@ fn run_sync(
                     outcome.result.after_ahead, outcome.result.after_behind
                 );
             }
+            if let Some(warning) = outcome.result.autostash_warning() {
+                eprintln!("{warning}");
+            }
 
             // Exit 4 if post_sync hook failed (FR-24: Report — non-zero exit but sync completed)
             if let Some(ref hook_err) = outcome.post_sync_error {
@@ -1296,8 +2541,13 @@ fn run_sync(
                 eprintln!("error: {e:#}");
                 ExitCode::HookFailed.exit();
             }
-            if let Some(git::GitError::MergeConflict { .. }) = e.downcast_ref::<git::GitError>() {
+            if let Some(git::GitError::MergeConflict { files, .. }) =
+                e.downcast_ref::<git::GitError>()
+            {
                 eprintln!("error: {e}");
+                for file in files {
+                    eprintln!("  conflicted: {file}");
+                }
                 ExitCode::GitError.exit();
             }
             let msg = e.to_string();
@@ -1311,13 +2561,26 @@ fn run_sync(
 }
 
 fn run_sync_all(
-    strategy: SyncStrategy,
+    strategy: Option<SyncStrategy>,
     json: bool,
     dry_run: bool,
     no_hooks: bool,
+    autostash: bool,
 ) -> anyhow::Result<()> {
-    let cwd = std::env::current_dir().context("failed to determine current directory")?;
+    let cwd = invocation_dir()?;
     let repo_info = git::discover_repo(&cwd)?;
+
+    // Load config (hooks are needed for both dry-run preview and actual execution)
+    let project_config = config::load_project_config(&repo_info.path)?;
+    let global_config = config::load_global_config()?;
+    let resolved = config::resolve_config(None, project_config.as_ref(), &global_config);
+    let hooks_config = if no_hooks { None } else { resolved.hooks };
+
+    // Batch sync never prompts: the strategy comes from the CLI or config.
+    let Some(sync_strategy) = strategy.or(resolved.git.sync_strategy) else {
+        eprintln!("error: {}", cli::commands::sync::BatchSyncMissingStrategy);
+        ExitCode::MissingRequiredFlag.exit();
+    };
     let db = if dry_run {
         existing_db_path()?
             .map(|db_path| state::Database::open(&db_path))
@@ -1341,20 +2604,6 @@ fn run_sync_all(
         return Ok(());
     }
 
-    let sync_strategy = match strategy {
-        SyncStrategy::Rebase => cli::commands::sync::Strategy::Rebase,
-        SyncStrategy::Merge => cli::commands::sync::Strategy::Merge,
-    };
-
-    // Load hooks config (needed for both dry-run preview and actual execution)
-    let hooks_config = if no_hooks {
-        None
-    } else {
-        let project_config = config::load_project_config(&repo_info.path)?;
-        let global_config = config::load_global_config()?;
-        config::resolve_config(None, project_config.as_ref(), &global_config).hooks
-    };
-
     // Dry-run: show per-worktree plans and exit
     if dry_run {
         let plans = cli::commands::sync::execute_all_dry_run_live(
@@ -1380,6 +2629,7 @@ fn run_sync_all(
             .map(|h| h.pre_sync.is_some() || h.post_sync.is_some())
             .unwrap_or(false);
     let db = db.expect("runtime DB should exist for mutating sync");
+    prune_if_enabled(&repo_info.path, &resolved.git);
 
     let results = if has_hooks {
         // Run with hooks per worktree
@@ -1395,9 +2645,12 @@ fn run_sync_all(
                 identifier,
                 &cwd,
                 &db,
-                sync_strategy,
+                cli::commands::sync::SyncOptions {
+                    strategy: sync_strategy,
+                    autostash: autostash,
+                    no_hooks: no_hooks,
+                },
                 hooks_config.as_ref(),
-                no_hooks,
                 None,
             )) {
                 Ok(outcome) => {
@@ -1422,59 +2675,28 @@ fn run_sync_all(
                     });
                 }
                 Err(e) => {
-                    eprintln!("error: sync failed for '{}': {e:#}", live.entry.name);
-                    entries.push(cli::commands::sync::BatchSyncEntry {
-                        name: live.entry.name.clone(),
-                        status: cli::commands::sync::BatchSyncStatus::Failure,
-                        result: None,
-                        error: Some(format!("{e:#}")),
-                    });
+                    entries.push(cli::commands::sync::BatchSyncEntry::from_error(
+                        live.entry.name.clone(),
+                        &e,
+                    ));
                 }
             }
         }
         entries
     } else {
         // No hooks — use the batch function directly
-        cli::commands::sync::execute_all_live(&worktrees, &repo_info, &db, sync_strategy)
+        cli::commands::sync::execute_all_live(&worktrees, &repo_info, &db, sync_strategy, autostash)
     };
 
     // Output results
-    let has_failures = results
-        .iter()
-        .any(|r| r.status != cli::commands::sync::BatchSyncStatus::Success);
+    let has_failures = cli::commands::sync::batch_has_failures(&results);
 
     if json {
         let json_results: Vec<cli::commands::sync::BatchSyncEntryJson> =
             results.iter().map(|e| e.to_json()).collect();
         println!("{}", output::json::format_json(&json_results)?);
     } else {
-        for entry in &results {
-            if let Some(ref result) = entry.result {
-                eprintln!("Synced '{}' via {}", entry.name, result.strategy);
-                eprintln!(
-                    "  before: ahead={}, behind={}",
-                    result.before_ahead, result.before_behind
-                );
-                eprintln!(
-                    "  after:  ahead={}, behind={}",
-                    result.after_ahead, result.after_behind
-                );
-            } else if let Some(ref err) = entry.error {
-                eprintln!("Failed '{}': {err}", entry.name);
-            }
-        }
-        let success = results
-            .iter()
-            .filter(|r| r.status == cli::commands::sync::BatchSyncStatus::Success)
-            .count();
-        let failed = results
-            .iter()
-            .filter(|r| r.status == cli::commands::sync::BatchSyncStatus::Failure)
-            .count();
-        eprintln!(
-            "\nBatch sync: {success} succeeded, {failed} failed ({} total)",
-            results.len()
-        );
+        eprint!("{}", cli::commands::sync::format_batch_summary(&results));
     }
 
     if has_failures {
@@ -1484,11 +2706,11 @@ fn run_sync_all(
     Ok(())
 }
 
-fn run_init(force: bool) -> anyhow::Result<()> {
-    let cwd = std::env::current_dir().context("failed to determine current directory")?;
+fn run_init(force: bool, with_hooks: bool) -> anyhow::Result<()> {
+    let cwd = invocation_dir()?;
     let repo_info = git::discover_repo(&cwd)?;
 
-    match cli::commands::init::execute(&repo_info.path, force) {
+    match cli::commands::init::execute(&repo_info.path, force, with_hooks) {
         Ok(path) => {
             println!("Created {}", path.display());
             Ok(())
@@ -1541,7 +2763,12 @@ mod tests {
 
         let cli2 = Cli::try_parse_from(["trench", "--porcelain"])
             .expect("porcelain flag should be accepted");
-        assert!(cli2.porcelain);
+        assert_eq!(cli2.porcelain, Some(PorcelainVersion::V1));
+
+        let cli3 = Cli::try_parse_from(["trench", "list", "--porcelain=v2"])
+            .expect("porcelain version should be accepted");
+        assert_eq!(cli3.porcelain, Some(PorcelainVersion::V2));
+        assert!(Cli::try_parse_from(["trench", "list", "--porcelain=v3"]).is_err());
     }
 
     #[test]
@@ -1555,6 +2782,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn yes_flag_is_global_with_short_form() {
+        let cli = Cli::try_parse_from(["trench", "remove", "feature", "-y"])
+            .expect("-y should be accepted after a subcommand");
+        assert!(cli.yes);
+
+        let cli = Cli::try_parse_from(["trench", "--yes", "remove", "feature"])
+            .expect("--yes should be accepted before a subcommand");
+        assert!(cli.yes);
+
+        let cli = Cli::try_parse_from(["trench", "remove", "feature"]).unwrap();
+        assert!(!cli.yes, "--yes should default to false");
+    }
+
+    #[test]
+    fn yes_flag_suppresses_remove_confirmation() {
+        let cli = Cli::try_parse_from(["trench", "remove", "feature", "--yes"]).unwrap();
+        let skip = remove_skips_confirmation(false, cli.yes);
+        assert!(skip);
+
+        let confirmed = cli::prompt::confirm_or_assume(skip, || {
+            panic!("prompt must not be invoked when --yes is set")
+        })
+        .unwrap();
+        assert!(confirmed);
+    }
+
     #[test]
     fn global_flags_short_forms() {
         let cli =
@@ -1569,11 +2823,34 @@ mod tests {
         let cli = Cli::try_parse_from(["trench"]).expect("no flags should parse fine");
 
         assert!(!cli.json);
-        assert!(!cli.porcelain);
+        assert!(cli.porcelain.is_none());
         assert!(!cli.no_color);
         assert!(!cli.quiet);
         assert!(!cli.verbose);
         assert!(!cli.dry_run);
+        assert!(!cli.no_pager);
+    }
+
+    #[test]
+    fn no_pager_flag_is_global_and_json_marks_output_structured() {
+        let cli = Cli::try_parse_from(["trench", "log", "--no-pager"]).unwrap();
+        assert!(cli.no_pager);
+        assert!(cli.pager_config(&cli.output_config()).no_pager);
+
+        let cli = Cli::try_parse_from(["trench", "list", "--json", "-q"]).unwrap();
+        let pager = cli.pager_config(&cli.output_config());
+        assert!(pager.structured);
+        assert!(pager.quiet);
+    }
+
+    #[test]
+    fn jsonl_flag_is_global_structured_and_exclusive() {
+        let cli = Cli::try_parse_from(["trench", "log", "--jsonl"]).unwrap();
+        assert!(cli.jsonl);
+        assert!(cli.pager_config(&cli.output_config()).structured);
+
+        assert!(Cli::try_parse_from(["trench", "list", "--jsonl", "--json"]).is_err());
+        assert!(Cli::try_parse_from(["trench", "list", "--jsonl", "--porcelain"]).is_err());
     }
 
     #[test]
@@ -1620,9 +2897,14 @@ mod tests {
         let cli = Cli::try_parse_from(["trench", "open", "my-feature"])
             .expect("open with branch should succeed");
         match cli.command {
-            Some(Commands::Open { branch, tmux }) => {
+            Some(Commands::Open {
+                branch,
+                tmux,
+                open_cmd,
+            }) => {
                 assert_eq!(branch, "my-feature");
                 assert!(!tmux);
+                assert!(open_cmd.is_none());
             }
             _ => panic!("expected Commands::Open"),
         }
@@ -1633,7 +2915,7 @@ mod tests {
         let cli = Cli::try_parse_from(["trench", "open", "my-feature", "--tmux"])
             .expect("open with --tmux should succeed");
         match cli.command {
-            Some(Commands::Open { branch, tmux }) => {
+            Some(Commands::Open { branch, tmux, .. }) => {
                 assert_eq!(branch, "my-feature");
                 assert!(tmux);
             }
@@ -1641,13 +2923,49 @@ mod tests {
         }
     }
 
+    #[test]
+    fn open_subcommand_accepts_open_cmd() {
+        let cli = Cli::try_parse_from(["trench", "open", "my-feature", "--open-cmd", "zed {path}"])
+            .expect("open with --open-cmd should succeed");
+        match cli.command {
+            Some(Commands::Open { open_cmd, .. }) => {
+                assert_eq!(open_cmd.as_deref(), Some("zed {path}"));
+            }
+            _ => panic!("expected Commands::Open"),
+        }
+    }
+
+    #[test]
+    fn status_all_conflicts_with_branch() {
+        let cli = Cli::try_parse_from(["trench", "status", "--all"]).expect("--all should parse");
+        match cli.command {
+            Some(Commands::Status { all, .. }) => assert!(all),
+            _ => panic!("expected Commands::Status"),
+        }
+
+        let result = Cli::try_parse_from(["trench", "status", "my-feature", "--all"]);
+        assert!(result.is_err(), "--all should conflict with a branch");
+    }
+
+    #[test]
+    fn status_diffstat_parses_and_conflicts_with_all() {
+        let cli = Cli::try_parse_from(["trench", "status", "feat", "--diffstat"])
+            .expect("--diffstat should parse");
+        match cli.command {
+            Some(Commands::Status { diffstat, .. }) => assert!(diffstat),
+            _ => panic!("expected Commands::Status"),
+        }
+
+        assert!(Cli::try_parse_from(["trench", "status", "--all", "--diffstat"]).is_err());
+    }
+
     #[test]
     fn status_subcommand_accepts_optional_branch() {
         // No branch → summary mode
         let cli = Cli::try_parse_from(["trench", "status"])
             .expect("status without branch should succeed");
         match cli.command {
-            Some(Commands::Status { branch }) => assert!(branch.is_none()),
+            Some(Commands::Status { branch, .. }) => assert!(branch.is_none()),
             _ => panic!("expected Commands::Status"),
         }
 
@@ -1655,7 +2973,7 @@ mod tests {
         let cli = Cli::try_parse_from(["trench", "status", "my-feature"])
             .expect("status with branch should succeed");
         match cli.command {
-            Some(Commands::Status { branch }) => {
+            Some(Commands::Status { branch, .. }) => {
                 assert_eq!(branch.as_deref(), Some("my-feature"));
             }
             _ => panic!("expected Commands::Status"),
@@ -1681,6 +2999,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn create_subcommand_collects_repeated_env_flags() {
+        let cli = Cli::try_parse_from([
+            "trench",
+            "create",
+            "feat",
+            "--env",
+            "TICKET=ABC-1",
+            "--env",
+            "OWNER=me",
+        ])
+        .expect("create with --env should parse");
+        match cli.command {
+            Some(Commands::Create { env, .. }) => assert_eq!(
+                env,
+                vec![
+                    ("TICKET".to_string(), "ABC-1".to_string()),
+                    ("OWNER".to_string(), "me".to_string()),
+                ]
+            ),
+            _ => panic!("expected Commands::Create"),
+        }
+
+        assert!(Cli::try_parse_from(["trench", "create", "feat", "--env", "NOVALUE"]).is_err());
+        assert!(
+            Cli::try_parse_from(["trench", "create", "feat", "--env", "TRENCH_BRANCH=x"]).is_err()
+        );
+    }
+
     #[test]
     fn create_subcommand_accepts_from_flag() {
         let cli = Cli::try_parse_from(["trench", "create", "my-feature", "--from", "develop"])
@@ -1779,6 +3126,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn reattach_subcommand_parses() {
+        let cli = Cli::try_parse_from(["trench", "--dry-run", "reattach"])
+            .expect("reattach should parse");
+        assert!(matches!(cli.command, Some(Commands::Reattach)));
+        assert!(cli.dry_run);
+    }
+
+    #[test]
+    fn clone_subcommand_parses_url_and_optional_dir() {
+        let cli = Cli::try_parse_from(["trench", "clone", "https://example.com/a/app.git"])
+            .expect("clone should parse");
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Clone { ref url, dir: None }) if url == "https://example.com/a/app.git"
+        ));
+
+        let cli = Cli::try_parse_from(["trench", "clone", "/srv/app.git", "work"])
+            .expect("clone with dir should parse");
+        match cli.command {
+            Some(Commands::Clone { dir, .. }) => assert_eq!(dir, Some(PathBuf::from("work"))),
+            _ => panic!("expected Commands::Clone"),
+        }
+    }
+
+    #[test]
+    fn format_hook_failure_names_failed_step() {
+        let timeout_err = anyhow::Error::new(hooks::runner::HookTimeoutError { timeout_secs: 5 });
+        assert_eq!(
+            format_hook_failure("post_sync", &timeout_err),
+            "post_sync hook failed: hook timed out after 5s"
+        );
+
+        let copy_err =
+            anyhow::anyhow!("no such file").context(hooks::runner::HookStepError { step: "copy" });
+        assert_eq!(
+            format_hook_failure("post_create", &copy_err),
+            "post_create hook failed: copy step failed: no such file"
+        );
+    }
+
     #[test]
     fn format_switch_notice_includes_absolute_path() {
         assert_eq!(
@@ -1806,6 +3194,26 @@ mod tests {
         assert!(cli.json);
     }
 
+    #[test]
+    fn create_non_utf8_branch_parses_then_fails_cleanly() {
+        use std::os::unix::ffi::OsStringExt;
+
+        let raw = std::ffi::OsString::from_vec(b"feat/\xff".to_vec());
+        let cli = Cli::try_parse_from([
+            std::ffi::OsString::from("trench"),
+            std::ffi::OsString::from("create"),
+            raw,
+        ])
+        .expect("non-UTF-8 branch should reach trench's own validation");
+        match cli.command {
+            Some(Commands::Create { branch, .. }) => {
+                let err = git::branch_name_from_os(&branch).unwrap_err();
+                assert!(matches!(err, git::GitError::NonUtf8Branch { .. }));
+            }
+            _ => panic!("expected Commands::Create"),
+        }
+    }
+
     #[test]
     fn create_subcommand_accepts_no_hooks_flag() {
         let cli = Cli::try_parse_from(["trench", "create", "my-feature", "--no-hooks"])
@@ -1821,6 +3229,96 @@ mod tests {
         }
     }
 
+    #[test]
+    fn create_interactive_conflicts_with_explicit_base() {
+        let cli = Cli::try_parse_from(["trench", "create", "feat", "--interactive"])
+            .expect("create with --interactive should succeed");
+        match cli.command {
+            Some(Commands::Create { interactive, .. }) => assert!(interactive),
+            _ => panic!("expected Commands::Create"),
+        }
+
+        for base in [["--from", "main"], ["--from-tag", "v1"]] {
+            let mut args = vec!["trench", "create", "feat", "--interactive"];
+            args.extend(base);
+            assert!(Cli::try_parse_from(args).is_err());
+        }
+    }
+
+    #[test]
+    fn create_from_tag_conflicts_with_from() {
+        let cli = Cli::try_parse_from(["trench", "create", "hotfix", "--from-tag", "v1.0.0"])
+            .expect("create with --from-tag should succeed");
+        match cli.command {
+            Some(Commands::Create { from_tag, .. }) => {
+                assert_eq!(from_tag.as_deref(), Some("v1.0.0"));
+            }
+            _ => panic!("expected Commands::Create"),
+        }
+
+        let result = Cli::try_parse_from([
+            "trench",
+            "create",
+            "hotfix",
+            "--from",
+            "main",
+            "--from-tag",
+            "v1.0.0",
+        ]);
+        assert!(result.is_err(), "--from and --from-tag should conflict");
+    }
+
+    #[test]
+    fn log_type_is_repeatable_and_validated() {
+        let cli = Cli::try_parse_from(["trench", "log", "--type", "created", "--type", "removed"])
+            .expect("known event types should parse");
+        match cli.command {
+            Some(Commands::Log { event_types, .. }) => {
+                assert_eq!(event_types, vec!["created", "removed"]);
+            }
+            _ => panic!("expected Commands::Log"),
+        }
+
+        let result = Cli::try_parse_from(["trench", "log", "--type", "bogus"]);
+        assert!(result.is_err(), "unknown event type should be rejected");
+    }
+
+    #[test]
+    fn log_stats_accepts_since_and_conflicts_with_other_modes() {
+        let cli = Cli::try_parse_from(["trench", "log", "--stats", "--since", "7d"])
+            .expect("--stats with --since should parse");
+        match cli.command {
+            Some(Commands::Log { stats, since, .. }) => {
+                assert!(stats);
+                assert_eq!(since.as_deref(), Some("7d"));
+            }
+            _ => panic!("expected Commands::Log"),
+        }
+
+        for args in [
+            &["trench", "log", "--stats", "--summary"][..],
+            &["trench", "log", "--stats", "my-branch"],
+            &["trench", "log", "--stats", "--type", "created"],
+        ] {
+            assert!(
+                Cli::try_parse_from(args).is_err(),
+                "{args:?} should conflict"
+            );
+        }
+    }
+
+    #[test]
+    fn create_subcommand_accepts_template_name() {
+        let cli = Cli::try_parse_from(["trench", "create", "my-feature", "--template", "flat"])
+            .expect("create with --template should succeed");
+        match cli.command {
+            Some(Commands::Create { template, .. }) => {
+                assert_eq!(template.as_deref(), Some("flat"));
+            }
+            _ => panic!("expected Commands::Create"),
+        }
+    }
+
     #[test]
     fn create_subcommand_no_hooks_defaults_to_false() {
         let cli = Cli::try_parse_from(["trench", "create", "my-feature"])
@@ -1849,8 +3347,10 @@ mod tests {
                 force,
                 delete_branch,
                 no_hooks,
+                keep_dir,
             }) => {
                 assert_eq!(branch, "my-feature");
+                assert!(!keep_dir);
                 assert!(!force);
                 assert!(!delete_branch);
                 assert!(!no_hooks);
@@ -1869,8 +3369,10 @@ mod tests {
                 force,
                 delete_branch,
                 no_hooks,
+                keep_dir,
             }) => {
                 assert_eq!(branch, "my-feature");
+                assert!(!keep_dir);
                 assert!(force);
                 assert!(!delete_branch);
                 assert!(!no_hooks);
@@ -1894,6 +3396,7 @@ mod tests {
                 branch,
                 print_path,
                 tmux,
+                ..
             }) => {
                 assert_eq!(branch, "my-feature");
                 assert!(!print_path);
@@ -1903,6 +3406,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn switch_subcommand_accepts_editor_flag() {
+        for flag in ["--editor", "-e"] {
+            let cli = Cli::try_parse_from(["trench", "switch", "my-feature", flag])
+                .expect("switch with --editor should succeed");
+            match cli.command {
+                Some(Commands::Switch {
+                    editor, print_path, ..
+                }) => {
+                    assert!(editor, "{flag} should set editor");
+                    assert!(!print_path);
+                }
+                _ => panic!("expected Commands::Switch"),
+            }
+        }
+    }
+
+    #[test]
+    fn switch_editor_conflicts_with_print_path() {
+        let err = Cli::try_parse_from(["trench", "switch", "my-feature", "-e", "--print-path"])
+            .expect_err("--editor and --print-path should be mutually exclusive");
+        assert_eq!(err.kind(), clap::error::ErrorKind::ArgumentConflict);
+    }
+
     #[test]
     fn switch_subcommand_accepts_print_path_flag() {
         let cli = Cli::try_parse_from(["trench", "switch", "my-feature", "--print-path"])
@@ -1912,6 +3439,7 @@ mod tests {
                 branch,
                 print_path,
                 tmux,
+                ..
             }) => {
                 assert_eq!(branch, "my-feature");
                 assert!(print_path);
@@ -1930,6 +3458,7 @@ mod tests {
                 branch,
                 print_path,
                 tmux,
+                ..
             }) => {
                 assert_eq!(branch, "my-feature");
                 assert!(!print_path);
@@ -1948,6 +3477,7 @@ mod tests {
                 branch,
                 print_path,
                 tmux,
+                ..
             }) => {
                 assert_eq!(branch, "my-feature");
                 assert!(print_path, "--print-path should be true");
@@ -1957,6 +3487,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn assume_yes_env_bypasses_remove_confirmation() {
+        for value in ["1", "true", "YES"] {
+            assert!(
+                remove_skips_confirmation(false, assume_yes_from_env_value(Some(value))),
+                "{ASSUME_YES_ENV}={value} should skip the prompt"
+            );
+        }
+    }
+
+    #[test]
+    fn remove_confirmation_required_without_force_or_env() {
+        for value in [None, Some(""), Some("0"), Some("no")] {
+            assert!(
+                !remove_skips_confirmation(false, assume_yes_from_env_value(value)),
+                "{value:?} should not skip the prompt"
+            );
+        }
+        assert!(remove_skips_confirmation(true, false));
+    }
+
     #[test]
     fn tag_subcommand_requires_branch() {
         let result = Cli::try_parse_from(["trench", "tag"]);
@@ -1968,8 +3519,8 @@ mod tests {
         let cli = Cli::try_parse_from(["trench", "tag", "my-feature"])
             .expect("tag with branch should succeed");
         match cli.command {
-            Some(Commands::Tag { branch, tags }) => {
-                assert_eq!(branch, "my-feature");
+            Some(Commands::Tag { branch, tags, .. }) => {
+                assert_eq!(branch.as_deref(), Some("my-feature"));
                 assert!(tags.is_empty());
             }
             _ => panic!("expected Commands::Tag"),
@@ -1981,32 +3532,259 @@ mod tests {
         let cli = Cli::try_parse_from(["trench", "tag", "my-feature", "+wip", "-old", "+review"])
             .expect("tag with +/- args should succeed");
         match cli.command {
-            Some(Commands::Tag { branch, tags }) => {
-                assert_eq!(branch, "my-feature");
+            Some(Commands::Tag { branch, tags, .. }) => {
+                assert_eq!(branch.as_deref(), Some("my-feature"));
                 assert_eq!(tags, vec!["+wip", "-old", "+review"]);
             }
             _ => panic!("expected Commands::Tag"),
         }
     }
 
+    #[test]
+    fn tag_all_matching_parses_without_branch() {
+        let cli =
+            Cli::try_parse_from(["trench", "tag", "--all-matching", "wip", "-wip", "+review"])
+                .expect("tag --all-matching should parse without a branch");
+        match cli.command {
+            Some(Commands::Tag {
+                branch,
+                tags,
+                all_matching,
+            }) => {
+                assert_eq!(all_matching.as_deref(), Some("wip"));
+                // The first operation lands in the branch slot; run_tag folds it back.
+                assert_eq!(branch.as_deref(), Some("-wip"));
+                assert_eq!(tags, vec!["+review"]);
+            }
+            _ => panic!("expected Commands::Tag"),
+        }
+    }
+
     #[test]
     fn list_subcommand_accepts_tag_filter() {
         let cli = Cli::try_parse_from(["trench", "list", "--tag", "wip"])
             .expect("list with --tag should succeed");
         match cli.command {
-            Some(Commands::List { tag }) => {
+            Some(Commands::List { tag, .. }) => {
                 assert_eq!(tag.as_deref(), Some("wip"));
             }
             _ => panic!("expected Commands::List"),
         }
     }
 
+    #[test]
+    fn list_count_parses_and_conflicts_with_size() {
+        let cli = Cli::try_parse_from(["trench", "list", "--count", "--tag", "wip"]).unwrap();
+        match cli.command {
+            Some(Commands::List { count, tag, .. }) => {
+                assert!(count);
+                assert_eq!(tag.as_deref(), Some("wip"));
+            }
+            _ => panic!("expected Commands::List"),
+        }
+
+        assert!(Cli::try_parse_from(["trench", "list", "--count", "--size"]).is_err());
+    }
+
+    #[test]
+    fn list_schema_parses_and_conflicts_with_count() {
+        let cli = Cli::try_parse_from(["trench", "list", "--json", "--schema"]).unwrap();
+        assert!(cli.json);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::List { schema: true, .. })
+        ));
+
+        assert!(Cli::try_parse_from(["trench", "list", "--schema", "--count"]).is_err());
+    }
+
+    #[test]
+    fn db_flag_overrides_env_which_overrides_default() {
+        let flag = Path::new("/tmp/flag.db");
+        assert_eq!(
+            db_override_from(Some(flag), Some("/tmp/env.db".into())),
+            Some(PathBuf::from("/tmp/flag.db"))
+        );
+        assert_eq!(
+            db_override_from(None, Some("/tmp/env.db".into())),
+            Some(PathBuf::from("/tmp/env.db"))
+        );
+        assert_eq!(db_override_from(None, Some("".into())), None);
+        assert_eq!(db_override_from(None, None), None);
+        let cli = Cli::try_parse_from(["trench", "list", "--db", "/tmp/flag.db"]).unwrap();
+        assert_eq!(cli.db.as_deref(), Some(flag));
+    }
+
+    #[test]
+    fn repo_flag_is_global() {
+        let cli = Cli::try_parse_from(["trench", "list", "--repo", "/tmp/elsewhere"]).unwrap();
+        assert_eq!(cli.repo.as_deref(), Some(Path::new("/tmp/elsewhere")));
+        let cli = Cli::try_parse_from(["trench", "--repo", "../other", "create", "feat"]).unwrap();
+        assert_eq!(cli.repo.as_deref(), Some(Path::new("../other")));
+        assert!(Cli::try_parse_from(["trench", "list"])
+            .unwrap()
+            .repo
+            .is_none());
+    }
+
+    #[test]
+    fn list_age_parses_alongside_size() {
+        let cli = Cli::try_parse_from(["trench", "list", "--age", "--size"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Commands::List {
+                age: true,
+                size: true,
+                ..
+            })
+        ));
+        assert!(Cli::try_parse_from(["trench", "list", "--age", "--count"]).is_err());
+    }
+
+    #[test]
+    fn list_sort_parses_keys() {
+        let cli = Cli::try_parse_from(["trench", "list", "--sort", "accessed"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Commands::List {
+                sort: Some(ListSortArg::Accessed),
+                ..
+            })
+        ));
+        assert_eq!(
+            state::WorktreeOrder::from(ListSortArg::Name),
+            state::WorktreeOrder::NameAsc
+        );
+        assert!(Cli::try_parse_from(["trench", "list", "--sort", "size"]).is_err());
+        assert!(Cli::try_parse_from(["trench", "list"])
+            .is_ok_and(|cli| matches!(cli.command, Some(Commands::List { sort: None, .. }))));
+    }
+
+    #[test]
+    fn config_set_template_parses_template() {
+        let cli = Cli::try_parse_from([
+            "trench",
+            "config",
+            "set-template",
+            "{{ repo }}/{{ branch }}",
+        ])
+        .expect("config set-template should parse");
+        match cli.command {
+            Some(Commands::Config {
+                action: ConfigAction::SetTemplate { template },
+            }) => assert_eq!(template, "{{ repo }}/{{ branch }}"),
+            _ => panic!("expected Commands::Config"),
+        }
+    }
+
+    #[test]
+    fn env_parses_event_and_export() {
+        let cli = Cli::try_parse_from(["trench", "env", "feat", "--event", "pre_sync", "--export"])
+            .expect("env should parse");
+        match cli.command {
+            Some(Commands::Env {
+                branch,
+                event,
+                export,
+            }) => {
+                assert_eq!(branch, "feat");
+                assert_eq!(event, hooks::HookEvent::PreSync);
+                assert!(export);
+            }
+            _ => panic!("expected Commands::Env"),
+        }
+
+        let cli = Cli::try_parse_from(["trench", "env", "feat"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Env {
+                event: hooks::HookEvent::PostCreate,
+                export: false,
+                ..
+            })
+        ));
+        assert!(Cli::try_parse_from(["trench", "env", "feat", "--event", "bogus"]).is_err());
+    }
+
+    #[test]
+    fn create_parents_defaults_true_and_accepts_false() {
+        let parents = |args: &[&str]| match Cli::try_parse_from(args).unwrap().command {
+            Some(Commands::Create { parents, .. }) => parents,
+            _ => panic!("expected Commands::Create"),
+        };
+        assert!(parents(&["trench", "create", "feat"]));
+        assert!(parents(&["trench", "create", "feat", "--parents"]));
+        assert!(!parents(&["trench", "create", "feat", "--parents=false"]));
+    }
+
+    #[test]
+    fn create_name_is_validated_at_parse_time() {
+        let cli =
+            Cli::try_parse_from(["trench", "create", "feature/long", "--name", "short"]).unwrap();
+        match cli.command {
+            Some(Commands::Create { name, .. }) => assert_eq!(name.as_deref(), Some("short")),
+            _ => panic!("expected Commands::Create"),
+        }
+        assert!(Cli::try_parse_from(["trench", "create", "feat", "--name", "a/b"]).is_err());
+    }
+
+    #[test]
+    fn url_parses_branch_and_compare() {
+        let cli = Cli::try_parse_from(["trench", "url", "feature/x", "--compare", "--json"])
+            .expect("url should parse");
+        assert!(cli.json);
+        match cli.command {
+            Some(Commands::Url { branch, compare }) => {
+                assert_eq!(branch, "feature/x");
+                assert!(compare);
+            }
+            _ => panic!("expected Commands::Url"),
+        }
+    }
+
+    #[test]
+    fn path_parses_branch_and_template() {
+        let cli = Cli::try_parse_from(["trench", "path", "feature/x", "--template", "flat"])
+            .expect("path should parse");
+        match cli.command {
+            Some(Commands::Path { branch, template }) => {
+                assert_eq!(branch, "feature/x");
+                assert_eq!(template.as_deref(), Some("flat"));
+            }
+            _ => panic!("expected Commands::Path"),
+        }
+    }
+
+    #[test]
+    fn which_parses_branch() {
+        let cli = Cli::try_parse_from(["trench", "which", "feature/x", "--json"])
+            .expect("which should parse");
+        assert!(cli.json);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Which { ref branch }) if branch == "feature/x"
+        ));
+    }
+
+    #[test]
+    fn set_base_parses_branch_and_new_base() {
+        let cli = Cli::try_parse_from(["trench", "set-base", "feature/x", "develop"])
+            .expect("set-base should parse");
+        assert!(matches!(
+            cli.command,
+            Some(Commands::SetBase { ref branch, ref new_base })
+                if branch == "feature/x" && new_base == "develop"
+        ));
+        assert!(Cli::try_parse_from(["trench", "set-base", "feature/x"]).is_err());
+    }
+
     #[test]
     fn init_subcommand_defaults_force_to_false() {
         let cli = Cli::try_parse_from(["trench", "init"]).expect("init should parse");
         match cli.command {
-            Some(Commands::Init { force }) => {
+            Some(Commands::Init { force, with_hooks }) => {
                 assert!(!force, "force should default to false");
+                assert!(!with_hooks, "with_hooks should default to false");
             }
             _ => panic!("expected Commands::Init"),
         }
@@ -2017,13 +3795,43 @@ mod tests {
         let cli =
             Cli::try_parse_from(["trench", "init", "--force"]).expect("init --force should parse");
         match cli.command {
-            Some(Commands::Init { force }) => {
+            Some(Commands::Init { force, .. }) => {
                 assert!(force, "force should be true");
             }
             _ => panic!("expected Commands::Init"),
         }
     }
 
+    #[test]
+    fn init_subcommand_accepts_with_hooks_flag() {
+        let cli = Cli::try_parse_from(["trench", "init", "--with-hooks"])
+            .expect("init --with-hooks should parse");
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Init {
+                with_hooks: true,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn remove_keep_dir_parses_and_conflicts_with_delete_branch() {
+        let cli = Cli::try_parse_from(["trench", "remove", "my-feature", "--keep-dir"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Remove { keep_dir: true, .. })
+        ));
+        assert!(Cli::try_parse_from([
+            "trench",
+            "remove",
+            "my-feature",
+            "--keep-dir",
+            "--delete-branch"
+        ])
+        .is_err());
+    }
+
     #[test]
     fn remove_subcommand_accepts_delete_branch_flag() {
         let cli = Cli::try_parse_from(["trench", "remove", "my-feature", "--delete-branch"])
@@ -2034,8 +3842,10 @@ mod tests {
                 force,
                 delete_branch,
                 no_hooks,
+                keep_dir,
             }) => {
                 assert_eq!(branch, "my-feature");
+                assert!(!keep_dir);
                 assert!(!force);
                 assert!(delete_branch);
                 assert!(!no_hooks);
@@ -2060,8 +3870,10 @@ mod tests {
                 force,
                 delete_branch,
                 no_hooks,
+                keep_dir,
             }) => {
                 assert_eq!(branch, "my-feature");
+                assert!(!keep_dir);
                 assert!(force);
                 assert!(delete_branch);
                 assert!(!no_hooks);
@@ -2235,6 +4047,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn sync_subcommand_autostash_defaults_off() {
+        let cli = Cli::try_parse_from(["trench", "sync", "foo"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Sync {
+                autostash: false,
+                ..
+            })
+        ));
+        let cli = Cli::try_parse_from(["trench", "sync", "foo", "--autostash"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Sync {
+                autostash: true,
+                ..
+            })
+        ));
+    }
+
     #[test]
     fn sync_subcommand_accepts_strategy_merge() {
         let cli = Cli::try_parse_from(["trench", "sync", "foo", "--strategy", "merge"])
@@ -2250,6 +4082,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn sync_subcommand_accepts_strategy_ff_only() {
+        let cli = Cli::try_parse_from(["trench", "sync", "foo", "--strategy", "ff-only"])
+            .expect("sync with --strategy ff-only should parse");
+        match cli.command {
+            Some(Commands::Sync { strategy, .. }) => {
+                assert_eq!(strategy, Some(SyncStrategy::FfOnly));
+            }
+            _ => panic!("expected Commands::Sync"),
+        }
+    }
+
     #[test]
     fn sync_subcommand_strategy_defaults_to_none() {
         let cli = Cli::try_parse_from(["trench", "sync", "foo"])
@@ -2369,4 +4213,45 @@ mod tests {
             _ => panic!("expected Commands::Sync"),
         }
     }
+
+    #[test]
+    fn prune_if_enabled_removes_stale_tracking_ref_only_when_on() {
+        let remote_dir = tempfile::tempdir().unwrap();
+        let remote_repo = git2::Repository::init_bare(remote_dir.path()).unwrap();
+        let sig = git2::Signature::now("Test", "test@test.com").unwrap();
+        let tree_id = remote_repo.treebuilder(None).unwrap().write().unwrap();
+        let tree = remote_repo.find_tree(tree_id).unwrap();
+        let oid = remote_repo
+            .commit(Some("refs/heads/main"), &sig, &sig, "init", &tree, &[])
+            .unwrap();
+        remote_repo.set_head("refs/heads/main").unwrap();
+        let commit = remote_repo.find_commit(oid).unwrap();
+        remote_repo.branch("gone", &commit, false).unwrap();
+
+        let clone_dir = tempfile::tempdir().unwrap();
+        let clone = git2::build::RepoBuilder::new()
+            .clone(remote_dir.path().to_str().unwrap(), clone_dir.path())
+            .unwrap();
+        remote_repo
+            .find_branch("gone", git2::BranchType::Local)
+            .unwrap()
+            .delete()
+            .unwrap();
+
+        let mut git_config =
+            config::resolve_config(None, None, &config::GlobalConfig::default()).git;
+        assert!(prune_if_enabled(clone_dir.path(), &git_config).is_empty());
+        assert!(clone
+            .find_branch("origin/gone", git2::BranchType::Remote)
+            .is_ok());
+
+        git_config.auto_prune = true;
+        assert_eq!(
+            prune_if_enabled(clone_dir.path(), &git_config),
+            vec!["origin/gone".to_string()]
+        );
+        assert!(clone
+            .find_branch("origin/gone", git2::BranchType::Remote)
+            .is_err());
+    }
 }
@@ -17,6 +17,18 @@ pub fn format_json_value<T: Serialize>(item: &T) -> Result<String> {
     Ok(serde_json::to_string_pretty(item)?)
 }
 
+/// Serialize a slice of items as JSON Lines: one compact object per line.
+///
+/// Used for `--jsonl` output, which streams into `jq` and log pipelines
+/// without buffering a whole array. An empty slice yields an empty string.
+pub fn format_jsonl<T: Serialize>(items: &[T]) -> Result<String> {
+    let lines = items
+        .iter()
+        .map(serde_json::to_string)
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(lines.join("\n"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -91,4 +103,29 @@ mod tests {
             "JSON output must not contain ANSI escape codes"
         );
     }
+
+    #[test]
+    fn format_jsonl_emits_one_parseable_object_per_line() {
+        let items: Vec<Dummy> = (0..3)
+            .map(|i| Dummy {
+                name: format!("multi\nline {i}"),
+                count: i,
+            })
+            .collect();
+
+        let output = format_jsonl(&items).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines.len(), items.len());
+        for (line, item) in lines.iter().zip(&items) {
+            let parsed: Dummy = serde_json::from_str(line).unwrap();
+            assert_eq!(&parsed, item);
+        }
+    }
+
+    #[test]
+    fn format_jsonl_empty_is_empty() {
+        let items: Vec<Dummy> = vec![];
+        assert_eq!(format_jsonl(&items).unwrap(), "");
+    }
 }
@@ -1,7 +1,22 @@
 pub mod json;
+pub mod pager;
 pub mod porcelain;
 pub mod table;
 
+/// Render a duration as a compact age in its largest whole unit, e.g.
+/// `45s`, `12m`, `2h`, or `3d`.
+pub fn humanize_duration(secs: u64) -> String {
+    const MINUTE: u64 = 60;
+    const HOUR: u64 = 60 * MINUTE;
+    const DAY: u64 = 24 * HOUR;
+    match secs {
+        s if s < MINUTE => format!("{s}s"),
+        s if s < HOUR => format!("{}m", s / MINUTE),
+        s if s < DAY => format!("{}h", s / HOUR),
+        s => format!("{}d", s / DAY),
+    }
+}
+
 /// Output verbosity level.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Verbosity {
@@ -60,6 +75,18 @@ mod tests {
     use super::*;
     use serial_test::serial;
 
+    #[test]
+    fn humanize_duration_uses_largest_whole_unit() {
+        assert_eq!(humanize_duration(0), "0s");
+        assert_eq!(humanize_duration(59), "59s");
+        assert_eq!(humanize_duration(60), "1m");
+        assert_eq!(humanize_duration(59 * 60 + 59), "59m");
+        assert_eq!(humanize_duration(2 * 3600 + 1800), "2h");
+        assert_eq!(humanize_duration(86_399), "23h");
+        assert_eq!(humanize_duration(3 * 86_400 + 7200), "3d");
+        assert_eq!(humanize_duration(400 * 86_400), "400d");
+    }
+
     #[test]
     fn no_color_flag_disables_color() {
         let config = OutputConfig::from_env(
@@ -0,0 +1,144 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use anyhow::Result;
+
+/// Pager used when `$PAGER` is unset, matching git's default.
+const DEFAULT_PAGER: &str = "less -FRX";
+
+/// Inputs to the decision whether long output goes through a pager.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PagerConfig {
+    /// Stdout is an interactive terminal.
+    pub is_tty: bool,
+    /// `--no-pager` was passed.
+    pub no_pager: bool,
+    /// Output is machine-readable (`--json` / `--porcelain`).
+    pub structured: bool,
+    /// `--quiet` was passed.
+    pub quiet: bool,
+    /// Terminal height in rows, if it could be determined.
+    pub terminal_height: Option<usize>,
+}
+
+/// Page only human output on a TTY that is taller than the terminal.
+pub fn should_page(text: &str, config: &PagerConfig) -> bool {
+    if config.no_pager || config.structured || config.quiet || !config.is_tty {
+        return false;
+    }
+    config
+        .terminal_height
+        .is_some_and(|height| text.lines().count() > height)
+}
+
+/// Split the pager command from `$PAGER` (or the default). An empty value or
+/// `cat` disables paging, as in git.
+pub fn pager_command(env_pager: Option<&str>) -> Option<Vec<String>> {
+    let raw = env_pager.unwrap_or(DEFAULT_PAGER).trim();
+    if raw.is_empty() || raw == "cat" {
+        return None;
+    }
+    shell_words::split(raw).ok().filter(|argv| !argv.is_empty())
+}
+
+/// Print `text`, piping it through the pager when [`should_page`] says so.
+///
+/// Falls back to plain stdout if the pager cannot be started.
+pub fn maybe_page(text: &str, config: &PagerConfig) -> Result<()> {
+    let text = if text.ends_with('\n') {
+        text.to_string()
+    } else {
+        format!("{text}\n")
+    };
+
+    if should_page(&text, config) {
+        let env_pager = std::env::var("PAGER").ok();
+        if let Some(argv) = pager_command(env_pager.as_deref()) {
+            if spawn_pager(&argv, &text).is_ok() {
+                return Ok(());
+            }
+        }
+    }
+
+    print!("{text}");
+    Ok(())
+}
+
+fn spawn_pager(argv: &[String], text: &str) -> std::io::Result<()> {
+    let mut child = Command::new(&argv[0])
+        .args(&argv[1..])
+        .stdin(Stdio::piped())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        // The user may quit the pager before reading everything.
+        let _ = stdin.write_all(text.as_bytes());
+    }
+    child.wait()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tty(height: usize) -> PagerConfig {
+        PagerConfig {
+            is_tty: true,
+            no_pager: false,
+            structured: false,
+            quiet: false,
+            terminal_height: Some(height),
+        }
+    }
+
+    const LONG: &str = "1\n2\n3\n4\n5\n";
+
+    #[test]
+    fn pages_when_output_exceeds_terminal_height() {
+        assert!(should_page(LONG, &tty(3)));
+        assert!(!should_page(LONG, &tty(5)), "fits exactly");
+    }
+
+    #[test]
+    fn never_pages_when_disabled_or_not_interactive() {
+        let cases = [
+            PagerConfig {
+                no_pager: true,
+                ..tty(3)
+            },
+            PagerConfig {
+                structured: true,
+                ..tty(3)
+            },
+            PagerConfig {
+                quiet: true,
+                ..tty(3)
+            },
+            PagerConfig {
+                is_tty: false,
+                ..tty(3)
+            },
+            PagerConfig {
+                terminal_height: None,
+                ..tty(3)
+            },
+        ];
+        for config in cases {
+            assert!(!should_page(LONG, &config), "should not page: {config:?}");
+        }
+    }
+
+    #[test]
+    fn pager_command_uses_env_or_default() {
+        assert_eq!(
+            pager_command(None),
+            Some(vec!["less".to_string(), "-FRX".to_string()])
+        );
+        assert_eq!(
+            pager_command(Some("most -s")),
+            Some(vec!["most".to_string(), "-s".to_string()])
+        );
+        assert_eq!(pager_command(Some("")), None);
+        assert_eq!(pager_command(Some("cat")), None);
+    }
+}
@@ -1,10 +1,24 @@
-/// Trait for types that can be rendered as porcelain (colon-separated) output.
+/// Porcelain format selected by `--porcelain[=VERSION]`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum PorcelainVersion {
+    /// Positional, colon-separated fields.
+    #[default]
+    V1,
+    /// Space-separated `key=value` pairs.
+    V2,
+}
+
+/// Trait for types that can be rendered as porcelain output.
 ///
 /// Implement this on any struct that should support `--porcelain` output.
 /// Each implementor defines its own field ordering.
 pub trait PorcelainRecord {
-    /// Return the ordered field values for this record.
+    /// Return the ordered field values for this record (v1).
     fn porcelain_fields(&self) -> Vec<String>;
+
+    /// Return the named field values for this record (v2). Keys are stable;
+    /// new keys may be added, so consumers should ignore ones they don't know.
+    fn porcelain_pairs(&self) -> Vec<(&'static str, String)>;
 }
 
 /// Format a slice of porcelain records as newline-delimited, colon-separated lines.
@@ -28,6 +42,33 @@ pub fn format_porcelain(items: &[impl PorcelainRecord]) -> String {
     out
 }
 
+/// Format porcelain records as newline-delimited `key=value` lines.
+///
+/// Pairs are separated by a single space. Values that contain whitespace or
+/// shell metacharacters are single-quoted the way a POSIX shell would read
+/// them, so a line can be split with any shell-words parser.
+pub fn format_porcelain_v2(items: &[impl PorcelainRecord]) -> String {
+    let mut out = String::new();
+    for item in items {
+        let pairs: Vec<String> = item
+            .porcelain_pairs()
+            .into_iter()
+            .map(|(key, value)| format!("{key}={}", shell_words::quote(&value)))
+            .collect();
+        out.push_str(&pairs.join(" "));
+        out.push('\n');
+    }
+    out
+}
+
+/// Format porcelain records in the requested `version`.
+pub fn format_porcelain_as(items: &[impl PorcelainRecord], version: PorcelainVersion) -> String {
+    match version {
+        PorcelainVersion::V1 => format_porcelain(items),
+        PorcelainVersion::V2 => format_porcelain_v2(items),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -46,6 +87,14 @@ mod tests {
                 self.managed.to_string(),
             ]
         }
+
+        fn porcelain_pairs(&self) -> Vec<(&'static str, String)> {
+            vec![
+                ("name", self.name.clone()),
+                ("branch", self.branch.clone()),
+                ("managed", self.managed.to_string()),
+            ]
+        }
     }
 
     #[test]
@@ -105,6 +154,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn format_porcelain_v2_emits_key_value_pairs() {
+        let items = vec![
+            TestRecord {
+                name: "feat".into(),
+                branch: "feat/x".into(),
+                managed: true,
+            },
+            TestRecord {
+                name: "my tree".into(),
+                branch: "dev".into(),
+                managed: false,
+            },
+        ];
+
+        let output = format_porcelain_v2(&items);
+
+        assert_eq!(
+            output,
+            "name=feat branch=feat/x managed=true\nname='my tree' branch=dev managed=false\n"
+        );
+        assert_eq!(
+            format_porcelain_as(&items[..1], PorcelainVersion::V1),
+            "feat:feat/x:true\n"
+        );
+    }
+
     #[test]
     fn format_porcelain_contains_no_ansi_codes() {
         let items = vec![TestRecord {
@@ -1,18 +1,56 @@
+/// Horizontal alignment of a column's cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Align {
+    #[default]
+    Left,
+    Right,
+}
+
 /// A reusable table formatter that auto-sizes columns.
 ///
 /// Not coupled to any specific data type — accepts string headers and rows.
+/// When a `max_width` is set, columns marked with [`Table::shrink_first`] give
+/// up space before the others, overlong cells end in `…`, and columns keep
+/// their [`Table::min_width`] unless nothing else can shrink.
 pub struct Table {
     headers: Vec<String>,
     rows: Vec<Vec<String>>,
     max_width: Option<usize>,
+    aligns: Vec<Align>,
+    min_widths: Vec<usize>,
+    shrink_first: Vec<usize>,
+}
+
+const GAP: usize = 2;
+const ELLIPSIS: char = '…';
+
+fn width_of(s: &str) -> usize {
+    s.chars().count()
+}
+
+/// Cut `cell` to `width` characters, ending in `…` when anything was dropped.
+fn fit(cell: &str, width: usize) -> String {
+    if width_of(cell) <= width {
+        return cell.to_string();
+    }
+    if width == 0 {
+        return String::new();
+    }
+    let mut s: String = cell.chars().take(width - 1).collect();
+    s.push(ELLIPSIS);
+    s
 }
 
 impl Table {
     pub fn new(headers: Vec<&str>) -> Self {
+        let col_count = headers.len();
         Self {
             headers: headers.into_iter().map(String::from).collect(),
             rows: Vec::new(),
             max_width: None,
+            aligns: vec![Align::Left; col_count],
+            min_widths: vec![0; col_count],
+            shrink_first: Vec::new(),
         }
     }
 
@@ -30,93 +68,117 @@ impl Table {
         self
     }
 
-    pub fn render(&self) -> String {
-        if self.rows.is_empty() {
-            return String::new();
+    /// Set the alignment of column `col` (e.g. right-align counts).
+    pub fn align(mut self, col: usize, align: Align) -> Self {
+        if let Some(a) = self.aligns.get_mut(col) {
+            *a = align;
         }
+        self
+    }
 
-        let col_count = self.headers.len();
-        let gap = 2usize;
-        let mut col_widths: Vec<usize> = self.headers.iter().map(|h| h.len()).collect();
+    /// Keep column `col` at least `width` characters wide while other
+    /// columns can still give up space.
+    pub fn min_width(mut self, col: usize, width: usize) -> Self {
+        if let Some(w) = self.min_widths.get_mut(col) {
+            *w = width;
+        }
+        self
+    }
+
+    /// Shrink column `col` (down to its header width) before any other
+    /// column. Columns registered earlier are shrunk first.
+    pub fn shrink_first(mut self, col: usize) -> Self {
+        if col < self.headers.len() && !self.shrink_first.contains(&col) {
+            self.shrink_first.push(col);
+        }
+        self
+    }
 
+    /// Natural column widths, reduced to fit `max_width` when set.
+    fn column_widths(&self) -> Vec<usize> {
+        let col_count = self.headers.len();
+        let mut widths: Vec<usize> = self.headers.iter().map(|h| width_of(h)).collect();
         for row in &self.rows {
-            for (i, cell) in row.iter().enumerate() {
-                if i < col_count {
-                    col_widths[i] = col_widths[i].max(cell.len());
-                }
+            for (i, cell) in row.iter().enumerate().take(col_count) {
+                widths[i] = widths[i].max(width_of(cell));
             }
         }
 
-        // Shrink columns to fit max_width if set
-        if let Some(max) = self.max_width {
-            let total_gap = gap * col_count.saturating_sub(1);
-            let available = max.saturating_sub(total_gap);
-            let total_content: usize = col_widths.iter().sum();
-
-            if total_content > available {
-                // Shrink widest columns first until we fit
-                while col_widths.iter().sum::<usize>() > available {
-                    let max_idx = col_widths
-                        .iter()
-                        .enumerate()
-                        .max_by_key(|(_, w)| *w)
-                        .map(|(i, _)| i)
-                        .unwrap();
-                    if col_widths[max_idx] == 0 {
-                        break;
-                    }
-                    col_widths[max_idx] -= 1;
-                }
+        let Some(max) = self.max_width else {
+            return widths;
+        };
+        let available = max.saturating_sub(GAP * col_count.saturating_sub(1));
+        let natural = widths.clone();
+        let floor = |i: usize| self.min_widths[i].min(natural[i]);
+
+        while widths.iter().sum::<usize>() > available {
+            // 1. Preferred columns, down to their header width.
+            let preferred = self
+                .shrink_first
+                .iter()
+                .copied()
+                .find(|&i| widths[i] > floor(i).max(width_of(&self.headers[i])));
+            // 2. The widest column still above its minimum.
+            // 3. Otherwise the widest column, ignoring minimums.
+            let idx = preferred
+                .or_else(|| widest(&widths, |i| widths[i] > floor(i)))
+                .or_else(|| widest(&widths, |i| widths[i] > 0));
+            match idx {
+                Some(i) => widths[i] -= 1,
+                None => break,
             }
         }
+        widths
+    }
+
+    pub fn render(&self) -> String {
+        if self.rows.is_empty() {
+            return String::new();
+        }
 
+        let col_count = self.headers.len();
+        let col_widths = self.column_widths();
         let mut out = String::new();
 
         // Render a single line, truncating cells to column widths
-        let render_line = |out: &mut String, cells: &[String], widths: &[usize]| {
+        let render_line = |out: &mut String, cells: &[String]| {
             let mut first_visible = true;
-            for (i, cell) in cells.iter().enumerate() {
-                if i >= col_count {
-                    break;
-                }
-                let w = widths[i];
+            for (i, cell) in cells.iter().enumerate().take(col_count) {
+                let w = col_widths[i];
                 if w == 0 {
                     continue;
                 }
                 if !first_visible {
-                    out.push_str(&" ".repeat(gap));
+                    out.push_str(&" ".repeat(GAP));
                 }
                 first_visible = false;
-                let truncated: String = if cell.len() > w {
-                    if w > 1 {
-                        let mut s: String = cell.chars().take(w - 1).collect();
-                        s.push('~');
-                        s
-                    } else {
-                        cell.chars().take(w).collect()
-                    }
-                } else {
-                    cell.clone()
-                };
-                if i < col_count - 1 {
-                    out.push_str(&format!("{:<width$}", truncated, width = w));
-                } else {
-                    out.push_str(&truncated);
+                let cell = fit(cell, w);
+                match self.aligns[i] {
+                    Align::Right => out.push_str(&format!("{cell:>w$}")),
+                    Align::Left if i < col_count - 1 => out.push_str(&format!("{cell:<w$}")),
+                    Align::Left => out.push_str(&cell),
                 }
             }
             out.push('\n');
         };
 
-        render_line(&mut out, &self.headers, &col_widths);
+        render_line(&mut out, &self.headers);
 
         for row in &self.rows {
-            render_line(&mut out, row, &col_widths);
+            render_line(&mut out, row);
         }
 
         out
     }
 }
 
+/// Index of the widest column among those accepted by `eligible`.
+fn widest(widths: &[usize], eligible: impl Fn(usize) -> bool) -> Option<usize> {
+    (0..widths.len())
+        .filter(|&i| eligible(i))
+        .max_by_key(|&i| (widths[i], std::cmp::Reverse(i)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -136,9 +198,9 @@ mod tests {
 
         for line in output.lines() {
             assert!(
-                line.len() <= 30,
+                line.chars().count() <= 30,
                 "line exceeds max_width: len={}, line={:?}",
-                line.len(),
+                line.chars().count(),
                 line
             );
         }
@@ -164,9 +226,9 @@ mod tests {
 
         for line in output.lines() {
             assert!(
-                line.len() <= 5,
+                line.chars().count() <= 5,
                 "line exceeds max_width of 5: len={}, line={:?}",
-                line.len(),
+                line.chars().count(),
                 line
             );
         }
@@ -227,4 +289,63 @@ mod tests {
             "Branch column should align between header and row"
         );
     }
+
+    #[test]
+    fn narrow_width_ellipsizes_path_and_keeps_names_intact() {
+        let output = Table::new(vec!["Name", "Branch", "Path"])
+            .row(vec![
+                "* feature-auth [locked]",
+                "feature/auth",
+                "/home/user/.worktrees/project/feature-auth",
+            ])
+            .row(vec![
+                "fix-bug",
+                "fix/bug",
+                "/home/user/.worktrees/project/fix-bug",
+            ])
+            .min_width(0, 23)
+            .shrink_first(2)
+            .max_width(60)
+            .render();
+
+        for line in output.lines() {
+            assert!(line.chars().count() <= 60, "line too wide: {line:?}");
+        }
+        assert!(output.contains("* feature-auth [locked]"), "{output}");
+        assert!(output.contains("fix-bug "), "{output}");
+        assert!(output.contains("feature/auth"), "branch fits: {output}");
+        assert!(output.contains("/home/user/.worktree…"), "{output}");
+    }
+
+    #[test]
+    fn min_width_yields_only_when_nothing_else_can_shrink() {
+        let output = Table::new(vec!["Name", "Path"])
+            .row(vec!["long-name", "/p"])
+            .min_width(0, 9)
+            .max_width(8)
+            .render();
+        for line in output.lines() {
+            assert!(line.chars().count() <= 8, "line too wide: {line:?}");
+        }
+    }
+
+    #[test]
+    fn right_aligned_columns_pad_on_the_left() {
+        let output = Table::new(vec!["Name", "Ahead/Behind", "Tags"])
+            .row(vec!["a", "+1/-0", "x"])
+            .row(vec!["b", "+12/-30", "y"])
+            .align(1, Align::Right)
+            .render();
+        let lines: Vec<&str> = output.lines().collect();
+        let end = lines[0].find("Ahead/Behind").unwrap() + "Ahead/Behind".len();
+        assert_eq!(&lines[1][end - 5..end], "+1/-0");
+        assert_eq!(&lines[2][end - 7..end], "+12/-30");
+    }
+
+    #[test]
+    fn ellipsis_is_used_for_truncated_cells() {
+        assert_eq!(fit("abcdef", 4), "abc…");
+        assert_eq!(fit("abc", 4), "abc");
+        assert_eq!(fit("abc", 0), "");
+    }
 }
@@ -165,41 +165,248 @@ pub const DEFAULT_WORKTREE_TEMPLATE: &str = "{{ repo }}/{{ branch | sanitize }}"
 /// The template receives `repo` and `branch` variables, and a `sanitize` filter
 /// that applies branch name sanitization (FR-17).
 ///
-/// Returns the rendered path relative to the worktree root.
+/// Returns the rendered path, relative to the worktree root unless the
+/// template itself renders an absolute path (e.g. `/mnt/wt/{{ repo }}`).
 pub fn render_worktree_path(template: &str, repo: &str, branch: &str) -> Result<PathBuf> {
+    let template = expand_path(template).to_string_lossy().into_owned();
     let mut env = minijinja::Environment::new();
     env.add_filter("sanitize", sanitize_branch);
-    env.add_template("path", template)
+    env.add_template("path", &template)
         .context("invalid worktree path template")?;
     let tmpl = env.get_template("path").unwrap();
     let rendered = tmpl
         .render(minijinja::context! { repo => repo, branch => branch })
         .context("failed to render worktree path template")?;
     let path = PathBuf::from(rendered);
-    if path.is_absolute()
-        || path
-            .components()
-            .any(|c| matches!(c, std::path::Component::ParentDir))
+    if path
+        .components()
+        .any(|c| matches!(c, std::path::Component::ParentDir))
     {
-        anyhow::bail!("worktree path template must render a relative path without '..'");
+        anyhow::bail!("worktree path template must not contain '..'");
     }
     Ok(path)
 }
 
-/// Expand a leading `~` or `~/` in a path string to the user's home directory.
+/// Reject a path template that renders the same path for every branch.
+///
+/// A template without a branch-varying token (e.g. just `{{ repo }}`) would
+/// put every worktree of a repo in one directory, so each `create` after the
+/// first collides. Detected by rendering two different branches.
+pub fn ensure_template_varies_by_branch(template: &str) -> Result<()> {
+    let first = render_worktree_path(template, "repo", "feature/one")?;
+    let second = render_worktree_path(template, "repo", "feature/two")?;
+    if first == second {
+        anyhow::bail!(
+            "worktree path template '{template}' does not depend on the branch, so every \
+             worktree would resolve to the same directory; include {{{{ branch }}}} or \
+             {{{{ branch | sanitize }}}} (e.g. '{DEFAULT_WORKTREE_TEMPLATE}')"
+        );
+    }
+    Ok(())
+}
+
+/// Resolve the on-disk path for a new worktree.
+///
+/// Relative renders are joined under `worktree_root`; absolute renders are
+/// used as-is.
+pub fn resolve_worktree_path(
+    worktree_root: &Path,
+    template: &str,
+    repo: &str,
+    branch: &str,
+) -> Result<PathBuf> {
+    let rendered = render_worktree_path(template, repo, branch)?;
+    if rendered.is_absolute() {
+        Ok(rendered)
+    } else {
+        Ok(worktree_root.join(rendered))
+    }
+}
+
+/// Expand a user-provided path: a leading `~` or `~user`, then `$VAR` and
+/// `${VAR}` occurrences.
+///
+/// Unset variables and unknown users are left literally in the result, so a
+/// typo surfaces as a visibly wrong path instead of silently collapsing to
+/// an empty segment.
+pub fn expand_path(input: &str) -> PathBuf {
+    PathBuf::from(expand_env_vars(&expand_tilde(input)))
+}
+
+/// Expand a leading `~`, `~/`, or `~user` in a path string to a home directory.
 ///
 /// Returns the original string unchanged if it doesn't start with `~` or if
 /// the home directory cannot be determined.
 pub fn expand_tilde(path: &str) -> String {
-    if path == "~" || path.starts_with("~/") {
-        if let Some(home) = dirs::home_dir() {
-            if path == "~" {
-                return home.to_string_lossy().into_owned();
+    let Some(rest) = path.strip_prefix('~') else {
+        return path.to_string();
+    };
+    let (user, tail) = match rest.find('/') {
+        Some(idx) => (&rest[..idx], &rest[idx + 1..]),
+        None => (rest, ""),
+    };
+    let home = if user.is_empty() {
+        dirs::home_dir()
+    } else {
+        user_home_dir(user)
+    };
+    match home {
+        Some(home) if tail.is_empty() => home.to_string_lossy().into_owned(),
+        Some(home) => home.join(tail).to_string_lossy().into_owned(),
+        None => path.to_string(),
+    }
+}
+
+/// Look up another user's home directory with `getpwnam_r`, so NSS sources
+/// (LDAP, systemd-homed, ...) are consulted as well as `/etc/passwd`.
+#[cfg(unix)]
+fn user_home_dir(user: &str) -> Option<PathBuf> {
+    use std::ffi::{CStr, CString, OsStr};
+    use std::os::unix::ffi::OsStrExt;
+
+    let name = CString::new(user).ok()?;
+    let mut buf = vec![0 as libc::c_char; 1024];
+    loop {
+        // SAFETY: `passwd` is plain old data, so all-zero is a valid value.
+        let mut entry: libc::passwd = unsafe { std::mem::zeroed() };
+        let mut found = std::ptr::null_mut();
+        // SAFETY: every pointer is valid for the call and `buf.len()` is the
+        // length of the buffer the strings in `entry` are written to.
+        let rc = unsafe {
+            libc::getpwnam_r(
+                name.as_ptr(),
+                &mut entry,
+                buf.as_mut_ptr(),
+                buf.len(),
+                &mut found,
+            )
+        };
+        if rc == libc::ERANGE && buf.len() < 1 << 20 {
+            buf.resize(buf.len() * 2, 0);
+            continue;
+        }
+        if rc != 0 || found.is_null() || entry.pw_dir.is_null() {
+            return None;
+        }
+        // SAFETY: on success `pw_dir` is a NUL-terminated string inside `buf`,
+        // which outlives this borrow.
+        let dir = unsafe { CStr::from_ptr(entry.pw_dir) };
+        return Some(PathBuf::from(OsStr::from_bytes(dir.to_bytes())));
+    }
+}
+
+/// `~user` is only expanded on Unix; elsewhere the path is left as written.
+#[cfg(not(unix))]
+fn user_home_dir(_user: &str) -> Option<PathBuf> {
+    None
+}
+
+/// Replace `$VAR` and `${VAR}` with environment values, leaving unset
+/// variables untouched.
+fn expand_env_vars(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(idx) = rest.find('$') {
+        out.push_str(&rest[..idx]);
+        let after = &rest[idx + 1..];
+        let (name, consumed) = if let Some(braced) = after.strip_prefix('{') {
+            match braced.find('}') {
+                Some(end) => (&braced[..end], end + 2),
+                None => ("", 0),
             }
-            return home.join(&path[2..]).to_string_lossy().into_owned();
+        } else {
+            let end = after
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(after.len());
+            (&after[..end], end)
+        };
+        let value = (!name.is_empty() && !name.starts_with(|c: char| c.is_ascii_digit()))
+            .then(|| std::env::var(name).ok())
+            .flatten();
+        match value {
+            Some(value) => out.push_str(&value),
+            None => out.push_str(&rest[idx..idx + 1 + consumed]),
         }
+        rest = &after[consumed..];
     }
-    path.to_string()
+    out.push_str(rest);
+    out
+}
+
+/// How to render worktree paths in human-oriented output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathStyle {
+    /// The absolute path, unchanged.
+    Full,
+    /// Home-prefixed paths rewritten as `~/...`.
+    Tilde,
+    /// Paths under `root` shown relative to it; others fall back to `Tilde`.
+    Short { root: PathBuf },
+}
+
+/// Directory a worktree path template renders beneath: the template's
+/// leading literal components (before any `{{`/`{%`), joined under
+/// `worktree_root` unless they are already absolute.
+pub fn template_root(worktree_root: &Path, template: &str) -> PathBuf {
+    let expanded = expand_path(template);
+    let literal: PathBuf = expanded
+        .components()
+        .take_while(|c| {
+            let part = c.as_os_str().to_string_lossy();
+            !part.contains("{{") && !part.contains("{%")
+        })
+        .collect();
+    if literal.is_absolute() {
+        literal
+    } else {
+        worktree_root.join(literal)
+    }
+}
+
+/// Render `path` for display according to `style`.
+pub fn display_path(path: &Path, style: &PathStyle) -> String {
+    display_path_with_home(path, style, dirs::home_dir().as_deref())
+}
+
+fn display_path_with_home(path: &Path, style: &PathStyle, home: Option<&Path>) -> String {
+    let tilde = || match home.and_then(|h| path.strip_prefix(h).ok()) {
+        Some(rest) if rest.as_os_str().is_empty() => "~".to_string(),
+        Some(rest) => format!("~/{}", rest.display()),
+        None => path.display().to_string(),
+    };
+    match style {
+        PathStyle::Full => path.display().to_string(),
+        PathStyle::Tilde => tilde(),
+        PathStyle::Short { root } => match path.strip_prefix(root) {
+            Ok(rest) if !rest.as_os_str().is_empty() => rest.display().to_string(),
+            _ => tilde(),
+        },
+    }
+}
+
+/// Total size in bytes of the regular files under `path`.
+///
+/// The top-level `.git` entry (the shared repository or a worktree's
+/// gitdir pointer) is skipped, and symlinks are counted as links rather
+/// than followed.
+pub fn dir_size(path: &Path) -> Result<u64> {
+    fn walk(dir: &Path, skip_git: bool) -> std::io::Result<u64> {
+        let mut total = 0;
+        for entry in std::fs::read_dir(dir)? {
+            let entry = entry?;
+            if skip_git && entry.file_name() == ".git" {
+                continue;
+            }
+            let meta = entry.path().symlink_metadata()?;
+            if meta.is_dir() {
+                total += walk(&entry.path(), false)?;
+            } else {
+                total += meta.len();
+            }
+        }
+        Ok(total)
+    }
+    walk(path, true).with_context(|| format!("failed to measure {}", path.display()))
 }
 
 /// Sanitize a branch name for use as a filesystem directory name.
@@ -211,6 +418,7 @@ pub fn expand_tilde(path: &str) -> String {
 /// - `..` → `-`
 /// - consecutive dashes collapsed
 /// - single dots preserved
+/// - U+FFFD (from a lossy decode of non-UTF-8 input) → `-`
 pub fn sanitize_branch(branch: &str) -> String {
     // Replace `..` sequences (path traversal) with dash
     let stripped = branch.replace("..", "-");
@@ -218,7 +426,7 @@ pub fn sanitize_branch(branch: &str) -> String {
     let mut result = String::with_capacity(stripped.len());
     for ch in stripped.chars() {
         match ch {
-            '/' | '@' | ' ' => {
+            '/' | '@' | ' ' | char::REPLACEMENT_CHARACTER => {
                 // Replace with dash, but avoid consecutive dashes
                 if !result.ends_with('-') {
                     result.push('-');
@@ -237,6 +445,23 @@ pub fn sanitize_branch(branch: &str) -> String {
     result.trim_matches('-').to_string()
 }
 
+/// Validate an explicit worktree name (`create --name`).
+///
+/// The name must already be in [`sanitize_branch`] form, so it is usable as
+/// a directory and identifier exactly as given.
+pub fn validate_worktree_name(name: &str) -> Result<(), String> {
+    if name.is_empty() || name == "." {
+        return Err(format!("invalid worktree name '{name}'"));
+    }
+    let sanitized = sanitize_branch(name);
+    if sanitized != name {
+        return Err(format!(
+            "invalid worktree name '{name}': use '{sanitized}' (no slashes, spaces, '@', '..' or leading/trailing dashes)"
+        ));
+    }
+    Ok(())
+}
+
 /// Validate a branch name against git ref naming rules.
 ///
 /// Returns `Ok(())` if the name is valid, or `Err(reason)` describing why it's invalid.
@@ -269,6 +494,52 @@ pub fn validate_branch_name(name: &str) -> Result<(), String> {
     Ok(())
 }
 
+/// Point the symlink at `link` to `target`, creating it if missing.
+///
+/// The swap is atomic: a temporary link is created beside `link` and
+/// renamed over it, so readers never see the link missing. Anything at
+/// `link` that is not itself a symlink is left alone and reported.
+#[cfg(unix)]
+pub fn update_symlink(link: &Path, target: &Path) -> Result<()> {
+    match std::fs::symlink_metadata(link) {
+        Ok(meta) if !meta.file_type().is_symlink() => anyhow::bail!(
+            "refusing to replace {}: it exists and is not a symlink",
+            link.display()
+        ),
+        Ok(_) => {}
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+        Err(e) => {
+            return Err(e).with_context(|| format!("failed to inspect {}", link.display()));
+        }
+    }
+
+    let name = link
+        .file_name()
+        .with_context(|| format!("symlink path has no file name: {}", link.display()))?;
+    let tmp = link.with_file_name(format!(
+        ".{}.trench-tmp-{}",
+        name.to_string_lossy(),
+        std::process::id()
+    ));
+    let _ = std::fs::remove_file(&tmp);
+    std::os::unix::fs::symlink(target, &tmp)
+        .with_context(|| format!("failed to create symlink {}", tmp.display()))?;
+    if let Err(e) = std::fs::rename(&tmp, link) {
+        let _ = std::fs::remove_file(&tmp);
+        return Err(e).with_context(|| format!("failed to replace symlink {}", link.display()));
+    }
+    Ok(())
+}
+
+/// Symlinks are only managed on Unix.
+#[cfg(not(unix))]
+pub fn update_symlink(link: &Path, _target: &Path) -> Result<()> {
+    anyhow::bail!(
+        "cannot update {}: symlinks are only supported on Unix",
+        link.display()
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -395,16 +666,46 @@ mod tests {
     }
 
     #[test]
-    fn render_template_rejects_absolute_path() {
-        let result = render_worktree_path("/absolute/{{ repo }}", "trench", "main");
-        assert!(result.is_err());
-        let msg = result.unwrap_err().to_string();
-        assert!(
-            msg.contains("relative"),
-            "expected 'relative' in error: {msg}"
+    fn render_template_allows_absolute_path() {
+        let path =
+            render_worktree_path("/mnt/fast/{{ repo }}/{{ branch }}", "trench", "main").unwrap();
+        assert_eq!(path, PathBuf::from("/mnt/fast/trench/main"));
+    }
+
+    #[test]
+    fn resolve_worktree_path_joins_relative_template_under_root() {
+        let path = resolve_worktree_path(
+            Path::new("/home/me/.worktrees"),
+            DEFAULT_WORKTREE_TEMPLATE,
+            "trench",
+            "feature/auth",
+        )
+        .unwrap();
+        assert_eq!(
+            path,
+            PathBuf::from("/home/me/.worktrees/trench/feature-auth")
         );
     }
 
+    #[test]
+    fn resolve_worktree_path_uses_absolute_template_as_is() {
+        let path = resolve_worktree_path(
+            Path::new("/home/me/.worktrees"),
+            "/mnt/fast/wt/{{ repo }}/{{ branch | sanitize }}",
+            "trench",
+            "feature/auth",
+        )
+        .unwrap();
+        assert_eq!(path, PathBuf::from("/mnt/fast/wt/trench/feature-auth"));
+    }
+
+    #[test]
+    fn resolve_worktree_path_rejects_parent_dir_in_absolute_template() {
+        let err = resolve_worktree_path(Path::new("/root"), "/mnt/../etc/{{ repo }}", "r", "b")
+            .unwrap_err();
+        assert!(err.to_string().contains("'..'"), "got: {err}");
+    }
+
     #[test]
     fn render_template_rejects_parent_dir() {
         let result = render_worktree_path("{{ repo }}/../../etc", "trench", "main");
@@ -413,6 +714,43 @@ mod tests {
         assert!(msg.contains("'..'"), "expected '..' in error: {msg}");
     }
 
+    #[test]
+    fn template_without_branch_token_is_rejected() {
+        let err = ensure_template_varies_by_branch("{{ repo }}").unwrap_err();
+        let msg = err.to_string();
+        assert!(msg.contains("does not depend on the branch"), "got: {msg}");
+        assert!(msg.contains("{{ branch | sanitize }}"), "got: {msg}");
+
+        assert!(ensure_template_varies_by_branch("/mnt/wt/fixed").is_err());
+    }
+
+    #[test]
+    fn templates_with_branch_token_pass_uniqueness_check() {
+        for template in [
+            "{{ repo }}/{{ branch }}",
+            "{{ repo }}/{{ branch | sanitize }}",
+            "{{ repo }}-{{ branch|sanitize }}",
+            DEFAULT_WORKTREE_TEMPLATE,
+        ] {
+            assert!(
+                ensure_template_varies_by_branch(template).is_ok(),
+                "{template} should pass"
+            );
+        }
+    }
+
+    #[test]
+    fn validate_worktree_name_accepts_only_sanitized_names() {
+        assert!(validate_worktree_name("short").is_ok());
+        assert!(validate_worktree_name("v1.2_fix").is_ok());
+        for bad in ["", ".", "a/b", "-lead", "trail-", "x..y", "a b", "me@x"] {
+            assert!(
+                validate_worktree_name(bad).is_err(),
+                "{bad:?} should be rejected"
+            );
+        }
+    }
+
     #[test]
     fn sanitize_slash_to_dash() {
         assert_eq!(sanitize_branch("feature/auth"), "feature-auth");
@@ -465,6 +803,13 @@ mod tests {
         assert_eq!(sanitize_branch("..."), ".");
     }
 
+    #[test]
+    fn sanitize_lossy_non_utf8_branch_is_deterministic() {
+        let lossy = String::from_utf8_lossy(b"feat/caf\xe9\xff-x");
+        assert_eq!(sanitize_branch(&lossy), "feat-caf-x");
+        assert_eq!(sanitize_branch(&lossy), sanitize_branch(&lossy));
+    }
+
     #[test]
     fn sanitize_combined_edge_cases() {
         // Multiple replaceable chars in a row collapse to single dash
@@ -478,6 +823,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn display_path_tilde_compresses_home_prefix() {
+        let home = Path::new("/home/dev");
+        let show = |p: &str| display_path_with_home(Path::new(p), &PathStyle::Tilde, Some(home));
+
+        assert_eq!(
+            show("/home/dev/.worktrees/app/feat"),
+            "~/.worktrees/app/feat"
+        );
+        assert_eq!(show("/home/dev"), "~");
+        assert_eq!(show("/home/developer/x"), "/home/developer/x");
+        assert_eq!(show("/srv/wt/app"), "/srv/wt/app");
+        assert_eq!(
+            display_path_with_home(Path::new("/home/dev/x"), &PathStyle::Full, Some(home)),
+            "/home/dev/x"
+        );
+    }
+
+    #[test]
+    fn display_path_short_relativizes_to_root() {
+        let home = Path::new("/home/dev");
+        let style = PathStyle::Short {
+            root: PathBuf::from("/home/dev/.worktrees"),
+        };
+        let show = |p: &str| display_path_with_home(Path::new(p), &style, Some(home));
+
+        assert_eq!(show("/home/dev/.worktrees/app/feat"), "app/feat");
+        // Outside the root (or the root itself) falls back to tilde form.
+        assert_eq!(show("/home/dev/src/app"), "~/src/app");
+        assert_eq!(show("/home/dev/.worktrees"), "~/.worktrees");
+    }
+
+    #[test]
+    fn template_root_uses_leading_literal_components() {
+        let root = Path::new("/home/dev/.worktrees");
+        assert_eq!(template_root(root, DEFAULT_WORKTREE_TEMPLATE), root);
+        assert_eq!(
+            template_root(root, "team/{{ repo }}/{{ branch }}"),
+            root.join("team")
+        );
+        assert_eq!(
+            template_root(root, "/mnt/wt/{{ repo }}"),
+            PathBuf::from("/mnt/wt")
+        );
+    }
+
     #[test]
     fn expand_tilde_replaces_home_prefix() {
         let expanded = expand_tilde("~/projects");
@@ -505,6 +896,82 @@ mod tests {
         assert_eq!(expanded, home.to_string_lossy().to_string());
     }
 
+    #[test]
+    fn expand_path_expands_tilde() {
+        let home = dirs::home_dir().unwrap();
+        assert_eq!(expand_path("~/trees"), home.join("trees"));
+        assert_eq!(expand_path("~"), home);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn expand_path_expands_tilde_user() {
+        let root_trees = expand_path("~root/trees");
+        assert!(root_trees.is_absolute(), "got: {}", root_trees.display());
+        assert!(root_trees.ends_with("trees"));
+        assert_eq!(
+            expand_path("~no-such-user-xyz/trees"),
+            PathBuf::from("~no-such-user-xyz/trees")
+        );
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn expand_path_expands_env_vars() {
+        std::env::set_var("TRENCH_TEST_EXPAND", "/srv/work");
+        assert_eq!(
+            expand_path("$TRENCH_TEST_EXPAND/trees"),
+            PathBuf::from("/srv/work/trees")
+        );
+        assert_eq!(
+            expand_path("${TRENCH_TEST_EXPAND}-trees"),
+            PathBuf::from("/srv/work-trees")
+        );
+        std::env::remove_var("TRENCH_TEST_EXPAND");
+    }
+
+    #[test]
+    fn expand_path_home_var_matches_tilde() {
+        let home = std::env::var("HOME").unwrap();
+        assert_eq!(expand_path("$HOME/x"), PathBuf::from(home).join("x"));
+    }
+
+    #[test]
+    fn expand_path_leaves_unset_vars_literal() {
+        assert_eq!(
+            expand_path("/a/$TRENCH_SURELY_UNSET_VAR/${TRENCH_SURELY_UNSET_VAR}/b"),
+            PathBuf::from("/a/$TRENCH_SURELY_UNSET_VAR/${TRENCH_SURELY_UNSET_VAR}/b")
+        );
+    }
+
+    #[test]
+    fn expand_path_without_expansion_is_unchanged() {
+        assert_eq!(
+            expand_path("plain/rel/path"),
+            PathBuf::from("plain/rel/path")
+        );
+        assert_eq!(expand_path("/cost$/x$"), PathBuf::from("/cost$/x$"));
+    }
+
+    #[test]
+    fn dir_size_sums_nested_files_and_skips_top_level_git() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("a.txt"), vec![0u8; 100]).unwrap();
+        std::fs::create_dir_all(dir.path().join("src/deep")).unwrap();
+        std::fs::write(dir.path().join("src/b.rs"), vec![0u8; 20]).unwrap();
+        std::fs::write(dir.path().join("src/deep/c.rs"), vec![0u8; 3]).unwrap();
+        std::fs::create_dir_all(dir.path().join(".git/objects")).unwrap();
+        std::fs::write(dir.path().join(".git/objects/pack"), vec![0u8; 5000]).unwrap();
+
+        assert_eq!(dir_size(dir.path()).unwrap(), 123);
+    }
+
+    #[test]
+    fn dir_size_errors_for_missing_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(dir_size(&dir.path().join("missing")).is_err());
+    }
+
     #[test]
     fn validate_branch_name_accepts_valid() {
         assert!(validate_branch_name("feature-auth").is_ok());
@@ -544,4 +1011,41 @@ mod tests {
         assert!(validate_branch_name("feature ").is_err());
         assert!(validate_branch_name(" feature ").is_err());
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn update_symlink_creates_then_repoints_link() {
+        let dir = tempfile::tempdir().unwrap();
+        let first = dir.path().join("first");
+        let second = dir.path().join("second");
+        std::fs::create_dir(&first).unwrap();
+        std::fs::create_dir(&second).unwrap();
+        let link = dir.path().join("repo-current");
+
+        update_symlink(&link, &first).unwrap();
+        assert_eq!(std::fs::read_link(&link).unwrap(), first);
+
+        update_symlink(&link, &second).unwrap();
+        assert_eq!(std::fs::read_link(&link).unwrap(), second);
+
+        let leftovers: Vec<_> = std::fs::read_dir(dir.path())
+            .unwrap()
+            .map(|e| e.unwrap().file_name())
+            .filter(|name| name.to_string_lossy().contains("trench-tmp"))
+            .collect();
+        assert!(leftovers.is_empty(), "temp link left behind: {leftovers:?}");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn update_symlink_refuses_to_replace_real_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let occupied = dir.path().join("repo-current");
+        std::fs::create_dir(&occupied).unwrap();
+
+        let err = update_symlink(&occupied, dir.path()).unwrap_err();
+
+        assert!(err.to_string().contains("not a symlink"), "got: {err}");
+        assert!(occupied.is_dir() && !occupied.is_symlink());
+    }
 }
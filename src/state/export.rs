@@ -0,0 +1,298 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use rusqlite::types::{Value as SqlValue, ValueRef};
+use rusqlite::{Connection, OpenFlags};
+use serde_json::{Map, Value};
+
+use super::Database;
+
+/// Version of the export document layout (not the schema version).
+pub const EXPORT_FORMAT_VERSION: i64 = 1;
+
+/// Tables carried by an export, in foreign-key order for import.
+const EXPORT_TABLES: &[&str] = &["repos", "worktrees", "events", "tags"];
+
+/// Row counts per table, as written by an import.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct ImportSummary {
+    pub repos: usize,
+    pub worktrees: usize,
+    pub events: usize,
+    pub tags: usize,
+}
+
+impl Database {
+    /// Open an existing database file read-only without running migrations.
+    ///
+    /// Used to export from backups whose schema may be newer or older than
+    /// this build's migrations.
+    pub fn open_unmigrated(path: &Path) -> Result<Self> {
+        let conn = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+            .with_context(|| format!("failed to open database at {}", path.display()))?;
+        Ok(Self { conn })
+    }
+
+    /// Dump repos, worktrees, events, and tags to a JSON document.
+    ///
+    /// Every column present in the source is exported by name, so a dump
+    /// taken from a newer schema can still be imported by an older build.
+    pub fn export_json(&self) -> Result<Value> {
+        let schema_version: i64 = self
+            .conn
+            .pragma_query_value(None, "user_version", |row| row.get(0))
+            .context("failed to read schema version")?;
+
+        let mut doc = Map::new();
+        doc.insert("format_version".into(), EXPORT_FORMAT_VERSION.into());
+        doc.insert("schema_version".into(), schema_version.into());
+        for table in EXPORT_TABLES {
+            doc.insert((*table).into(), Value::Array(self.dump_table(table)?));
+        }
+        Ok(Value::Object(doc))
+    }
+
+    /// Load a document produced by [`Database::export_json`] into this database.
+    ///
+    /// The target must not track any repos yet. Columns the current schema
+    /// doesn't know are dropped; columns missing from the dump fall back to
+    /// their defaults. All rows are written in a single transaction.
+    pub fn import_json(&self, doc: &Value) -> Result<ImportSummary> {
+        let rows = parse_export(doc)?;
+
+        let existing: i64 = self
+            .conn
+            .query_row("SELECT COUNT(*) FROM repos", [], |row| row.get(0))
+            .context("failed to count repos")?;
+        if existing > 0 {
+            bail!("database already tracks {existing} repo(s); import requires a fresh database");
+        }
+
+        let tx = self
+            .conn
+            .unchecked_transaction()
+            .context("failed to begin import transaction")?;
+        for (table, table_rows) in EXPORT_TABLES.iter().zip(&rows) {
+            let columns = table_columns(&tx, table)?;
+            for row in *table_rows {
+                insert_row(&tx, table, &columns, row)?;
+            }
+        }
+        tx.commit().context("failed to commit import")?;
+
+        Ok(summarize(&rows))
+    }
+
+    fn dump_table(&self, table: &str) -> Result<Vec<Value>> {
+        let mut stmt = self
+            .conn
+            .prepare(&format!("SELECT * FROM {table} ORDER BY id"))
+            .with_context(|| format!("failed to prepare export of {table}"))?;
+        let names: Vec<String> = stmt.column_names().into_iter().map(String::from).collect();
+
+        let mut rows = stmt
+            .query([])
+            .with_context(|| format!("failed to export {table}"))?;
+        let mut out = Vec::new();
+        while let Some(row) = rows.next().context("failed to read export row")? {
+            let mut obj = Map::new();
+            for (i, name) in names.iter().enumerate() {
+                let value = match row.get_ref(i)? {
+                    ValueRef::Null => Value::Null,
+                    ValueRef::Integer(n) => n.into(),
+                    ValueRef::Real(f) => f.into(),
+                    ValueRef::Text(t) => String::from_utf8_lossy(t).into_owned().into(),
+                    ValueRef::Blob(_) => bail!("unexpected blob in {table}.{name}"),
+                };
+                obj.insert(name.clone(), value);
+            }
+            out.push(Value::Object(obj));
+        }
+        Ok(out)
+    }
+}
+
+/// Validate the document shape and return each table's rows in import order.
+fn parse_export(doc: &Value) -> Result<Vec<&Vec<Value>>> {
+    let version = doc
+        .get("format_version")
+        .and_then(Value::as_i64)
+        .context("export is missing format_version")?;
+    if version != EXPORT_FORMAT_VERSION {
+        bail!("unsupported export format_version {version} (expected {EXPORT_FORMAT_VERSION})");
+    }
+
+    EXPORT_TABLES
+        .iter()
+        .map(|table| {
+            doc.get(*table)
+                .and_then(Value::as_array)
+                .with_context(|| format!("export is missing the '{table}' array"))
+        })
+        .collect()
+}
+
+/// Count the rows a document would import, without touching a database.
+pub fn count_export_rows(doc: &Value) -> Result<ImportSummary> {
+    Ok(summarize(&parse_export(doc)?))
+}
+
+fn summarize(rows: &[&Vec<Value>]) -> ImportSummary {
+    ImportSummary {
+        repos: rows[0].len(),
+        worktrees: rows[1].len(),
+        events: rows[2].len(),
+        tags: rows[3].len(),
+    }
+}
+
+fn table_columns(conn: &Connection, table: &str) -> Result<HashSet<String>> {
+    let mut stmt = conn
+        .prepare(&format!("PRAGMA table_info({table})"))
+        .with_context(|| format!("failed to inspect {table}"))?;
+    let columns = stmt
+        .query_map([], |row| row.get::<_, String>(1))?
+        .collect::<rusqlite::Result<HashSet<_>>>()
+        .with_context(|| format!("failed to read columns of {table}"))?;
+    Ok(columns)
+}
+
+fn insert_row(
+    conn: &Connection,
+    table: &str,
+    columns: &HashSet<String>,
+    row: &Value,
+) -> Result<()> {
+    let obj = row
+        .as_object()
+        .with_context(|| format!("{table} entries must be JSON objects"))?;
+
+    let mut names = Vec::new();
+    let mut values = Vec::new();
+    for (name, value) in obj {
+        if !columns.contains(name) {
+            continue;
+        }
+        let sql_value = match value {
+            Value::Null => SqlValue::Null,
+            Value::Bool(b) => SqlValue::Integer(i64::from(*b)),
+            Value::Number(n) => match n.as_i64() {
+                Some(i) => SqlValue::Integer(i),
+                None => SqlValue::Real(n.as_f64().unwrap_or_default()),
+            },
+            Value::String(s) => SqlValue::Text(s.clone()),
+            other => bail!("unsupported value for {table}.{name}: {other}"),
+        };
+        names.push(name.as_str());
+        values.push(sql_value);
+    }
+
+    let placeholders: Vec<String> = (1..=names.len()).map(|i| format!("?{i}")).collect();
+    conn.execute(
+        &format!(
+            "INSERT INTO {table} ({}) VALUES ({})",
+            names.join(", "),
+            placeholders.join(", ")
+        ),
+        rusqlite::params_from_iter(values),
+    )
+    .with_context(|| format!("failed to import row into {table}"))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn populated_db() -> Database {
+        let db = Database::open_in_memory().unwrap();
+        let repo = db.insert_repo("r", "/r", Some("main")).unwrap();
+        db.set_repo_remote_url(repo.id, Some("git@example.com:r.git"))
+            .unwrap();
+        let wt = db
+            .insert_worktree(repo.id, "feature-a", "feature/a", "/wt/a", Some("main"))
+            .unwrap();
+        db.add_tag(wt.id, "wip").unwrap();
+        db.insert_event(
+            repo.id,
+            Some(wt.id),
+            "created",
+            Some(&serde_json::json!({"sparse": ["app"]})),
+        )
+        .unwrap();
+        db.insert_event(repo.id, None, "relinked", None).unwrap();
+        db
+    }
+
+    #[test]
+    fn export_import_round_trip_preserves_contents() {
+        let source = populated_db();
+        let exported = source.export_json().unwrap();
+
+        let target = Database::open_in_memory().unwrap();
+        let summary = target.import_json(&exported).unwrap();
+
+        assert_eq!(
+            summary,
+            ImportSummary {
+                repos: 1,
+                worktrees: 1,
+                events: 2,
+                tags: 1,
+            }
+        );
+        assert_eq!(target.export_json().unwrap(), exported);
+    }
+
+    #[test]
+    fn import_rejects_non_empty_database() {
+        let exported = populated_db().export_json().unwrap();
+        let target = populated_db();
+
+        let err = target.import_json(&exported).unwrap_err();
+
+        assert!(err.to_string().contains("fresh database"), "got: {err}");
+    }
+
+    #[test]
+    fn import_drops_columns_unknown_to_this_schema() {
+        let mut exported = populated_db().export_json().unwrap();
+        exported["repos"][0]["from_the_future"] = "x".into();
+
+        let target = Database::open_in_memory().unwrap();
+        target.import_json(&exported).unwrap();
+
+        let repo = target.get_repo_by_path("/r").unwrap().unwrap();
+        assert_eq!(repo.remote_url.as_deref(), Some("git@example.com:r.git"));
+    }
+
+    #[test]
+    fn import_rejects_unknown_format_version() {
+        let target = Database::open_in_memory().unwrap();
+        let doc = serde_json::json!({"format_version": 99});
+
+        let err = target.import_json(&doc).unwrap_err();
+
+        assert!(err.to_string().contains("format_version 99"), "got: {err}");
+    }
+
+    #[test]
+    fn open_unmigrated_exports_a_database_that_is_ahead() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("trench.db.backup-1");
+        {
+            let db = Database::open(&path).unwrap();
+            db.insert_repo("r", "/r", None).unwrap();
+            db.conn.pragma_update(None, "user_version", 99).unwrap();
+        }
+
+        let exported = Database::open_unmigrated(&path)
+            .unwrap()
+            .export_json()
+            .unwrap();
+
+        assert_eq!(exported["schema_version"], 99);
+        assert_eq!(exported["repos"][0]["path"], "/r");
+    }
+}
@@ -1,10 +1,11 @@
+pub mod export;
 pub mod queries;
 
 use std::path::Path;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use anyhow::{Context, Result};
-use rusqlite::Connection;
+use rusqlite::{Connection, ErrorCode, TransactionBehavior};
 use rusqlite_migration::{Migrations, M};
 
 /// Return the current time as seconds since the UNIX epoch.
@@ -15,6 +16,26 @@ pub(crate) fn unix_epoch_secs() -> u64 {
         .as_secs()
 }
 
+/// Check that a tag name uses the supported charset: ASCII letters, digits,
+/// `-`, `_`, `.` and `/`. Commas, whitespace, and control characters would
+/// break the comma-joined tag display and `list --tag` filtering.
+pub fn validate_tag_name(name: &str) -> Result<()> {
+    if name.is_empty() {
+        anyhow::bail!("tag name cannot be empty");
+    }
+    if let Some(bad) = name
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '/')))
+    {
+        anyhow::bail!(
+            "invalid tag name '{}': {:?} is not allowed (use letters, digits, '-', '_', '.' or '/')",
+            name.escape_debug(),
+            bad
+        );
+    }
+    Ok(())
+}
+
 /// A repository tracked by trench.
 #[derive(Debug, Clone)]
 pub struct Repo {
@@ -22,6 +43,9 @@ pub struct Repo {
     pub name: String,
     pub path: String,
     pub default_base: Option<String>,
+    pub remote_url: Option<String>,
+    /// Worktree path template set for this repo; `None` = use config.
+    pub template: Option<String>,
     pub created_at: i64,
 }
 
@@ -41,6 +65,63 @@ pub struct Worktree {
     pub created_at: i64,
 }
 
+/// Error resolving a user-supplied worktree identifier.
+#[derive(Debug, thiserror::Error)]
+pub enum LookupError {
+    /// The identifier is one worktree's name and another's branch.
+    #[error(
+        "'{identifier}' matches multiple worktrees: {}; pass a name or branch that matches only one of them",
+        describe_matches(.matches)
+    )]
+    Multiple {
+        identifier: String,
+        matches: Vec<LookupMatch>,
+    },
+}
+
+/// One of the worktrees an ambiguous identifier matched.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LookupMatch {
+    pub name: String,
+    pub branch: String,
+}
+
+fn describe_matches(matches: &[LookupMatch]) -> String {
+    matches
+        .iter()
+        .map(|wt| format!("{} (branch {})", wt.name, wt.branch))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Row order for worktree listings.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WorktreeOrder {
+    /// Oldest first; ties broken by insertion order.
+    #[default]
+    CreatedAsc,
+    /// Alphabetical by sanitized name.
+    NameAsc,
+    /// Most recently accessed first; never-accessed worktrees last.
+    AccessedDesc,
+}
+
+impl WorktreeOrder {
+    /// SQL `ORDER BY` clause for the `worktrees` table aliased as `w`.
+    ///
+    /// Every variant ends on `w.id` so equal keys (e.g. worktrees created in
+    /// the same second) still come back in a deterministic order.
+    fn order_by(self) -> &'static str {
+        match self {
+            WorktreeOrder::CreatedAsc => "w.created_at ASC, w.id ASC",
+            WorktreeOrder::NameAsc => "w.name ASC, w.id ASC",
+            WorktreeOrder::AccessedDesc => {
+                "w.last_accessed IS NULL, w.last_accessed DESC, w.id ASC"
+            }
+        }
+    }
+}
+
 /// Partial update fields for a worktree.
 ///
 /// For nullable columns (`last_accessed`, `adopted_at`, `base_branch`):
@@ -79,6 +160,36 @@ pub struct LogEntry {
     pub created_at: i64,
 }
 
+/// Every `event_type` value trench writes to the events table.
+pub const EVENT_TYPES: &[&str] = &[
+    "created",
+    "removed",
+    "synced",
+    "switched",
+    "opened",
+    "relinked",
+    "base_changed",
+    "interrupted",
+    "hook_failed",
+    "hook:pre_create",
+    "hook:post_create",
+    "hook:pre_sync",
+    "hook:post_sync",
+    "hook:pre_remove",
+    "hook:post_remove",
+];
+
+/// Optional constraints applied to event log queries.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EventFilter {
+    /// Inclusive lower bound on `created_at` (unix seconds).
+    pub since: Option<i64>,
+    /// Inclusive upper bound on `created_at` (unix seconds).
+    pub until: Option<i64>,
+    /// Restrict to these event types; empty means all types.
+    pub event_types: Vec<String>,
+}
+
 /// A single line of hook output with metadata for replay display.
 #[derive(Debug, Clone)]
 pub struct HookOutputLine {
@@ -89,6 +200,19 @@ pub struct HookOutputLine {
     pub created_at: i64,
 }
 
+/// How long SQLite waits on another connection's lock before reporting busy.
+const BUSY_TIMEOUT_MS: u32 = 5000;
+
+/// Extra attempts a write makes after SQLite still reports the database busy.
+const BUSY_RETRIES: u32 = 3;
+
+fn is_busy(err: &rusqlite::Error) -> bool {
+    matches!(
+        err.sqlite_error_code(),
+        Some(ErrorCode::DatabaseBusy | ErrorCode::DatabaseLocked)
+    )
+}
+
 /// Core database handle wrapping a SQLite connection with migrations applied.
 #[derive(Debug)]
 pub struct Database {
@@ -104,7 +228,10 @@ impl Database {
 
     /// Open (or create) the database at the given file path.
     ///
-    /// Applies pragmas (WAL, FK, synchronous NORMAL) and runs all pending migrations.
+    /// Applies pragmas (WAL, FK, synchronous NORMAL, busy timeout), runs all
+    /// pending migrations, and checkpoints the WAL. A database that is ahead
+    /// of the known migrations, or whose file is corrupt or not a database,
+    /// is moved aside to a backup and replaced with a fresh one.
     pub fn open(path: &Path) -> Result<Self> {
         if let Some(parent) = path.parent() {
             std::fs::create_dir_all(parent).with_context(|| {
@@ -115,7 +242,12 @@ impl Database {
             .with_context(|| format!("failed to open database at {}", path.display()))?;
         match Self::init(conn) {
             Ok(db) => Ok(db),
-            Err(e) if Self::is_db_too_far_ahead(&e) => Self::backup_and_recreate(path),
+            Err(e) if Self::is_db_too_far_ahead(&e) => {
+                Self::backup_and_recreate(path, "was ahead of migrations")
+            }
+            Err(e) if Self::is_db_corrupt(&e) => {
+                Self::backup_and_recreate(path, "was corrupt or not a database")
+            }
             Err(e) => Err(e),
         }
     }
@@ -133,19 +265,75 @@ impl Database {
              PRAGMA synchronous = NORMAL;",
         )
         .context("failed to set database pragmas")?;
+        conn.pragma_update(None, "busy_timeout", BUSY_TIMEOUT_MS)
+            .context("failed to set database busy timeout")?;
 
         Self::migrations()
             .to_latest(&mut conn)
             .context("failed to run database migrations")?;
 
+        // Fold any WAL left behind by a crashed process into the main file.
+        // PASSIVE never waits on other connections, so a long-lived reader
+        // (e.g. the TUI) cannot stall every command's open.
+        conn.query_row("PRAGMA wal_checkpoint(PASSIVE)", [], |_| Ok(()))
+            .context("failed to checkpoint database WAL")?;
+
         Ok(Self { conn })
     }
 
+    /// Run a write in its own `BEGIN IMMEDIATE` transaction, retrying when
+    /// another connection still holds the lock after the busy timeout.
+    ///
+    /// Inside an already-open transaction `f` runs directly and the caller's
+    /// transaction decides whether it commits.
+    fn write<T>(
+        &self,
+        mut f: impl FnMut(&Connection) -> rusqlite::Result<T>,
+    ) -> rusqlite::Result<T> {
+        if !self.conn.is_autocommit() {
+            return f(&self.conn);
+        }
+
+        let mut attempt = 0;
+        loop {
+            let result =
+                rusqlite::Transaction::new_unchecked(&self.conn, TransactionBehavior::Immediate)
+                    .and_then(|tx| {
+                        let value = f(&tx)?;
+                        tx.commit()?;
+                        Ok(value)
+                    });
+            match result {
+                Err(e) if is_busy(&e) && attempt < BUSY_RETRIES => {
+                    attempt += 1;
+                    std::thread::sleep(Duration::from_millis(50 * u64::from(attempt)));
+                }
+                other => return other,
+            }
+        }
+    }
+
+    /// Run `f` in one transaction so all of its writes land, or none do when
+    /// it returns `Err`. Nested calls join the outer transaction.
+    pub fn with_transaction<T>(&self, f: impl FnOnce(&Self) -> Result<T>) -> Result<T> {
+        if !self.conn.is_autocommit() {
+            return f(self);
+        }
+
+        let tx = rusqlite::Transaction::new_unchecked(&self.conn, TransactionBehavior::Immediate)
+            .context("failed to begin transaction")?;
+        let value = f(self)?;
+        tx.commit().context("failed to commit transaction")?;
+        Ok(value)
+    }
+
     fn migrations() -> Migrations<'static> {
         Migrations::new(vec![
             M::up(include_str!("sql/001_initial_schema.sql")),
             M::up(include_str!("sql/002_add_removed_at.sql")),
             M::up(include_str!("sql/003_add_step_to_logs.sql")),
+            M::up(include_str!("sql/004_add_remote_url_to_repos.sql")),
+            M::up(include_str!("sql/005_add_template_to_repos.sql")),
         ])
     }
 
@@ -162,12 +350,21 @@ impl Database {
         false
     }
 
-    fn backup_and_recreate(path: &Path) -> Result<Self> {
+    fn is_db_corrupt(err: &anyhow::Error) -> bool {
+        err.chain().any(|cause| {
+            cause
+                .downcast_ref::<rusqlite::Error>()
+                .and_then(rusqlite::Error::sqlite_error_code)
+                .is_some_and(|code| {
+                    matches!(code, ErrorCode::NotADatabase | ErrorCode::DatabaseCorrupt)
+                })
+        })
+    }
+
+    fn backup_and_recreate(path: &Path, reason: &str) -> Result<Self> {
         let ts = unix_epoch_secs();
-        let backup = path.with_file_name(format!(
-            "{}.backup-{ts}",
-            path.file_name().unwrap_or_default().to_string_lossy()
-        ));
+        let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+        let backup = path.with_file_name(format!("{file_name}.backup-{ts}"));
         std::fs::rename(path, &backup).with_context(|| {
             format!(
                 "failed to back up database from {} to {}",
@@ -175,8 +372,19 @@ impl Database {
                 backup.display()
             )
         })?;
+        // WAL side files belong to the old database; replaying them into the
+        // fresh one would corrupt it, so they travel with the backup.
+        for suffix in ["-wal", "-shm"] {
+            let side = path.with_file_name(format!("{file_name}{suffix}"));
+            if side.exists() {
+                let side_backup = backup.with_file_name(format!("{file_name}.backup-{ts}{suffix}"));
+                std::fs::rename(&side, &side_backup)
+                    .with_context(|| format!("failed to move aside {}", side.display()))?;
+            }
+        }
         eprintln!(
-            "warning: database was ahead of migrations; backed up to {}",
+            "warning: database {reason}; backed up to {} \
+             (recover with `trench db export --from <backup>` and `trench db import`)",
             backup.display()
         );
         let conn = Connection::open(path)
@@ -223,6 +431,87 @@ mod tests {
         }
     }
 
+    #[test]
+    fn open_sets_busy_timeout() {
+        let db = Database::open_in_memory().unwrap();
+        let timeout: i64 = db
+            .conn
+            .pragma_query_value(None, "busy_timeout", |row| row.get(0))
+            .unwrap();
+        assert_eq!(timeout, i64::from(BUSY_TIMEOUT_MS));
+    }
+
+    #[test]
+    fn concurrent_inserts_from_two_connections_both_succeed() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("trench.db");
+        let repo_id = Database::open(&path)
+            .unwrap()
+            .insert_repo("repo", "/tmp/repo", None)
+            .unwrap()
+            .id;
+
+        let handles: Vec<_> = (0..2)
+            .map(|t| {
+                let path = path.clone();
+                std::thread::spawn(move || {
+                    let db = Database::open(&path).unwrap();
+                    for i in 0..25 {
+                        let name = format!("wt-{t}-{i}");
+                        db.insert_worktree(repo_id, &name, &name, &format!("/tmp/{name}"), None)
+                            .expect("insert should not fail with a lock error");
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let db = Database::open(&path).unwrap();
+        assert_eq!(db.list_worktrees(repo_id).unwrap().len(), 50);
+    }
+
+    #[test]
+    fn with_transaction_commits_all_writes_on_success() {
+        let db = Database::open_in_memory().unwrap();
+        let repo = db.insert_repo("repo", "/tmp/repo", None).unwrap();
+
+        let wt = db
+            .with_transaction(|tx| {
+                let wt = tx.insert_worktree(repo.id, "feat", "feat", "/tmp/feat", None)?;
+                tx.insert_event(repo.id, Some(wt.id), "created", None)?;
+                Ok(wt)
+            })
+            .unwrap();
+
+        assert!(db.get_worktree(wt.id).unwrap().is_some());
+        assert_eq!(db.list_events(wt.id, 10).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn with_transaction_rolls_back_when_closure_fails() {
+        let db = Database::open_in_memory().unwrap();
+        let repo = db.insert_repo("repo", "/tmp/repo", None).unwrap();
+
+        let err = db
+            .with_transaction(|tx| -> Result<()> {
+                let wt = tx.insert_worktree(repo.id, "feat", "feat", "/tmp/feat", None)?;
+                tx.insert_event(repo.id, Some(wt.id), "created", None)?;
+                anyhow::bail!("simulated failure after inserts")
+            })
+            .unwrap_err();
+
+        assert!(err.to_string().contains("simulated failure"));
+        assert!(db.list_worktrees(repo.id).unwrap().is_empty());
+        let events: i64 = db
+            .conn
+            .query_row("SELECT COUNT(*) FROM events", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(events, 0, "no partial event rows should remain");
+        assert!(db.conn.is_autocommit(), "transaction should be closed");
+    }
+
     #[test]
     fn insert_and_get_repo_round_trip() {
         let db = Database::open_in_memory().unwrap();
@@ -310,6 +599,55 @@ mod tests {
         assert_eq!(list_b[0].name, "wt-3");
     }
 
+    #[test]
+    fn list_worktrees_ordering_is_stable() {
+        let db = Database::open_in_memory().unwrap();
+        let repo = db.insert_repo("r", "/r", None).unwrap();
+        let mut ids = Vec::new();
+        for name in ["charlie", "alpha", "bravo"] {
+            let wt = db
+                .insert_worktree(repo.id, name, name, &format!("/r/{name}"), None)
+                .unwrap();
+            ids.push(wt.id);
+        }
+        for (id, accessed) in [(ids[1], 200), (ids[0], 100)] {
+            db.update_worktree(
+                id,
+                &WorktreeUpdate {
+                    last_accessed: Some(Some(accessed)),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        }
+
+        let names = |order| -> Vec<String> {
+            db.list_worktrees_ordered(repo.id, order)
+                .unwrap()
+                .into_iter()
+                .map(|w| w.name)
+                .collect()
+        };
+
+        // Inserted within the same second: creation order falls back to id.
+        assert_eq!(
+            names(WorktreeOrder::CreatedAsc),
+            ["charlie", "alpha", "bravo"]
+        );
+        assert_eq!(names(WorktreeOrder::NameAsc), ["alpha", "bravo", "charlie"]);
+        assert_eq!(
+            names(WorktreeOrder::AccessedDesc),
+            ["alpha", "charlie", "bravo"]
+        );
+        let default: Vec<String> = db
+            .list_worktrees(repo.id)
+            .unwrap()
+            .into_iter()
+            .map(|w| w.name)
+            .collect();
+        assert_eq!(default, names(WorktreeOrder::CreatedAsc));
+    }
+
     #[test]
     fn update_worktree_modifies_fields() {
         let db = Database::open_in_memory().unwrap();
@@ -508,6 +846,61 @@ mod tests {
         );
     }
 
+    #[test]
+    fn open_recovers_from_garbage_file_with_backup() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("trench.db");
+        std::fs::write(&db_path, "this is not a sqlite database\n".repeat(64)).unwrap();
+        std::fs::write(dir.path().join("trench.db-wal"), b"stale wal").unwrap();
+
+        let db = Database::open(&db_path).expect("open should recover from a corrupt file");
+
+        db.insert_repo("test", "/test", Some("main")).unwrap();
+        let names: Vec<String> = std::fs::read_dir(dir.path())
+            .unwrap()
+            .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        let backup = names
+            .iter()
+            .find(|n| n.starts_with("trench.db.backup-") && !n.ends_with("-wal"))
+            .unwrap_or_else(|| panic!("no backup file in {names:?}"));
+        assert!(
+            std::fs::read_to_string(dir.path().join(backup))
+                .unwrap()
+                .starts_with("this is not a sqlite database"),
+            "backup should hold the original bytes"
+        );
+        assert_ne!(
+            std::fs::read(dir.path().join("trench.db-wal")).unwrap_or_default(),
+            b"stale wal",
+            "the fresh database must not inherit the stale WAL"
+        );
+    }
+
+    #[test]
+    fn open_does_not_wait_for_readers_to_checkpoint() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("trench.db");
+        let writer = Database::open(&db_path).unwrap();
+        writer.insert_repo("test", "/test", Some("main")).unwrap();
+
+        // Another process (e.g. the TUI) holding a read snapshot keeps the
+        // WAL in use; opening must not block on it.
+        let reader = Connection::open(&db_path).unwrap();
+        reader
+            .execute_batch("BEGIN; SELECT count(*) FROM repos;")
+            .unwrap();
+
+        let started = std::time::Instant::now();
+        let db = Database::open(&db_path).unwrap();
+        assert!(
+            started.elapsed() < Duration::from_millis(u64::from(BUSY_TIMEOUT_MS) / 2),
+            "open waited {:?} on a concurrent reader",
+            started.elapsed()
+        );
+        assert!(db.get_repo_by_path("/test").unwrap().is_some());
+    }
+
     #[test]
     fn open_recovered_db_is_functional() {
         let dir = tempfile::tempdir().unwrap();
@@ -622,6 +1015,44 @@ mod tests {
         assert!(found.is_none());
     }
 
+    #[test]
+    fn find_worktree_by_identifier_rejects_name_and_branch_of_different_worktrees() {
+        let db = Database::open_in_memory().unwrap();
+        let repo = db.insert_repo("r", "/r", None).unwrap();
+        db.insert_worktree(repo.id, "short", "feature/long", "/wt/short", None)
+            .unwrap();
+        db.insert_worktree(repo.id, "other", "short", "/wt/other", None)
+            .unwrap();
+
+        let err = db
+            .find_worktree_by_identifier(repo.id, "short")
+            .expect_err("identifier matching two worktrees must not pick one");
+
+        match err.downcast_ref::<LookupError>() {
+            Some(LookupError::Multiple {
+                identifier,
+                matches,
+            }) => {
+                assert_eq!(identifier, "short");
+                let names: Vec<&str> = matches.iter().map(|wt| wt.name.as_str()).collect();
+                assert_eq!(names, ["short", "other"]);
+            }
+            None => panic!("expected LookupError::Multiple, got: {err:#}"),
+        }
+        assert!(
+            err.to_string()
+                .contains("short (branch feature/long), other (branch short)"),
+            "{err}"
+        );
+
+        // The unambiguous identifiers still resolve.
+        let other = db
+            .find_worktree_by_identifier(repo.id, "other")
+            .unwrap()
+            .unwrap();
+        assert_eq!(other.branch, "short");
+    }
+
     #[test]
     fn find_worktree_by_identifier_excludes_removed() {
         let db = Database::open_in_memory().unwrap();
@@ -668,6 +1099,55 @@ mod tests {
         assert_eq!(fetched.removed_at, Some(ts));
     }
 
+    #[test]
+    fn migrations_are_valid() {
+        Database::migrations()
+            .validate()
+            .expect("migration set should validate");
+    }
+
+    #[test]
+    fn forward_migration_from_v1_preserves_rows() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        Database::migrations().to_version(&mut conn, 1).unwrap();
+        conn.execute_batch(
+            "INSERT INTO repos (id, name, path, default_base, created_at)
+                 VALUES (1, 'r', '/r', 'main', 100);
+             INSERT INTO worktrees (id, repo_id, name, branch, path, created_at)
+                 VALUES (1, 1, 'wt', 'feature/wt', '/wt', 100);
+             INSERT INTO events (id, worktree_id, repo_id, event_type, created_at)
+                 VALUES (1, 1, 1, 'created', 100);
+             INSERT INTO logs (event_id, stream, line, line_number, created_at)
+                 VALUES (1, 'stdout', 'hello', 1, 100);",
+        )
+        .unwrap();
+
+        let db = Database::init(conn).expect("v1 database should migrate to latest");
+
+        let version: i64 = db
+            .conn
+            .pragma_query_value(None, "user_version", |row| row.get(0))
+            .unwrap();
+        assert_eq!(version, 5, "user_version should match the migration count");
+
+        let repo = db.get_repo_by_path("/r").unwrap().expect("repo survives");
+        assert_eq!(repo.default_base.as_deref(), Some("main"));
+        assert!(repo.remote_url.is_none(), "new column defaults to NULL");
+
+        let wt = db.get_worktree(1).unwrap().expect("worktree survives");
+        assert_eq!(wt.branch, "feature/wt");
+        assert!(wt.removed_at.is_none(), "new column defaults to NULL");
+
+        let logs = db.get_hook_output(1).unwrap();
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].line, "hello");
+        assert!(logs[0].step.is_none(), "new column defaults to NULL");
+
+        // The path uniqueness from v1 still backs upsert after migrating.
+        let upserted = db.upsert_repo("renamed", "/r", Some("main")).unwrap();
+        assert_eq!(upserted.id, repo.id);
+    }
+
     #[test]
     fn add_and_list_tags_for_worktree() {
         let db = Database::open_in_memory().unwrap();
@@ -930,7 +1410,7 @@ mod tests {
     }
 
     #[test]
-    fn list_events_filtered_returns_events_for_repo_most_recent_first() {
+    fn list_events_matching_returns_events_for_repo_most_recent_first() {
         let db = Database::open_in_memory().unwrap();
         let repo = db.insert_repo("r", "/r", None).unwrap();
         let wt1 = db
@@ -952,7 +1432,9 @@ mod tests {
             .insert_event(repo.id, Some(wt1.id), "hook:post_create", Some(&payload))
             .unwrap();
 
-        let entries = db.list_events_filtered(repo.id, None, None).unwrap();
+        let entries = db
+            .list_events_matching(repo.id, None, None, &EventFilter::default())
+            .unwrap();
         assert_eq!(entries.len(), 3, "should return all 3 events");
 
         // Verify exact ordering: most recent (highest id) first,
@@ -965,21 +1447,23 @@ mod tests {
     }
 
     #[test]
-    fn list_events_filtered_includes_events_without_worktree() {
+    fn list_events_matching_includes_events_without_worktree() {
         let db = Database::open_in_memory().unwrap();
         let repo = db.insert_repo("r", "/r", None).unwrap();
 
         // Event with no worktree_id
         db.insert_event(repo.id, None, "init", None).unwrap();
 
-        let entries = db.list_events_filtered(repo.id, None, None).unwrap();
+        let entries = db
+            .list_events_matching(repo.id, None, None, &EventFilter::default())
+            .unwrap();
         assert_eq!(entries.len(), 1);
         assert!(entries[0].worktree_name.is_none());
         assert_eq!(entries[0].event_type, "init");
     }
 
     #[test]
-    fn list_events_filtered_scoped_to_repo() {
+    fn list_events_matching_scoped_to_repo() {
         let db = Database::open_in_memory().unwrap();
         let repo_a = db.insert_repo("a", "/a", None).unwrap();
         let repo_b = db.insert_repo("b", "/b", None).unwrap();
@@ -995,13 +1479,15 @@ mod tests {
         db.insert_event(repo_b.id, Some(wt_b.id), "created", None)
             .unwrap();
 
-        let entries_a = db.list_events_filtered(repo_a.id, None, None).unwrap();
+        let entries_a = db
+            .list_events_matching(repo_a.id, None, None, &EventFilter::default())
+            .unwrap();
         assert_eq!(entries_a.len(), 1, "should only return repo_a events");
         assert_eq!(entries_a[0].worktree_name.as_deref(), Some("wt-a"));
     }
 
     #[test]
-    fn list_events_filtered_returns_all_events_unbounded() {
+    fn list_events_matching_returns_all_events_unbounded() {
         let db = Database::open_in_memory().unwrap();
         let repo = db.insert_repo("r", "/r", None).unwrap();
         let wt = db
@@ -1013,7 +1499,9 @@ mod tests {
                 .unwrap();
         }
 
-        let entries = db.list_events_filtered(repo.id, None, None).unwrap();
+        let entries = db
+            .list_events_matching(repo.id, None, None, &EventFilter::default())
+            .unwrap();
         assert_eq!(
             entries.len(),
             1500,
@@ -1022,7 +1510,7 @@ mod tests {
     }
 
     #[test]
-    fn list_events_filtered_does_not_leak_cross_repo_worktree_name() {
+    fn list_events_matching_does_not_leak_cross_repo_worktree_name() {
         let db = Database::open_in_memory().unwrap();
         let repo_a = db.insert_repo("a", "/a", None).unwrap();
         let repo_b = db.insert_repo("b", "/b", None).unwrap();
@@ -1046,7 +1534,9 @@ mod tests {
         )
         .unwrap();
 
-        let entries = db.list_events_filtered(repo_a.id, None, None).unwrap();
+        let entries = db
+            .list_events_matching(repo_a.id, None, None, &EventFilter::default())
+            .unwrap();
         assert_eq!(entries.len(), 1);
         // The worktree belongs to repo_b, so it should NOT resolve to a name
         // when querying repo_a's events.
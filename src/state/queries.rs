@@ -1,38 +1,65 @@
 use anyhow::{bail, Context, Result};
 use rusqlite::OptionalExtension;
 
-use super::{unix_epoch_secs, Database, Event, LogEntry, Repo, Worktree, WorktreeUpdate};
+use super::{
+    unix_epoch_secs, Database, Event, EventFilter, LogEntry, LookupError, LookupMatch, Repo,
+    Worktree, WorktreeOrder, WorktreeUpdate,
+};
 
 fn now() -> i64 {
     unix_epoch_secs() as i64
 }
 
 impl Database {
-    /// Insert a new repo and return the populated struct.
+    /// Insert a new repo and return the populated struct. Production code
+    /// goes through [`Database::upsert_repo`]; tests use this for fixtures.
+    #[cfg(test)]
     pub fn insert_repo(&self, name: &str, path: &str, default_base: Option<&str>) -> Result<Repo> {
         let created_at = now();
-        self.conn
-            .execute(
-                "INSERT INTO repos (name, path, default_base, created_at) VALUES (?1, ?2, ?3, ?4)",
-                rusqlite::params![name, path, default_base, created_at],
-            )
+        let id = self
+            .write(|conn| {
+                conn.execute(
+                    "INSERT INTO repos (name, path, default_base, created_at) VALUES (?1, ?2, ?3, ?4)",
+                    rusqlite::params![name, path, default_base, created_at],
+                )?;
+                Ok(conn.last_insert_rowid())
+            })
             .context("failed to insert repo")?;
-
-        let id = self.conn.last_insert_rowid();
         Ok(Repo {
             id,
             name: name.to_string(),
             path: path.to_string(),
             default_base: default_base.map(String::from),
+            remote_url: None,
+            template: None,
             created_at,
         })
     }
 
+    /// Insert a repo, or reconcile the existing row at `path` with the given
+    /// name and default base. Returns the row as stored.
+    pub fn upsert_repo(&self, name: &str, path: &str, default_base: Option<&str>) -> Result<Repo> {
+        self.write(|conn| {
+            conn.execute(
+                "INSERT INTO repos (name, path, default_base, created_at) VALUES (?1, ?2, ?3, ?4)
+                 ON CONFLICT(path) DO UPDATE SET
+                     name = excluded.name,
+                     default_base = excluded.default_base
+                 WHERE name IS NOT excluded.name OR default_base IS NOT excluded.default_base",
+                rusqlite::params![name, path, default_base, now()],
+            )
+        })
+        .context("failed to upsert repo")?;
+
+        self.get_repo_by_path(path)?
+            .with_context(|| format!("repo at {path} missing after upsert"))
+    }
+
     /// Get a repo by id. Returns `None` if not found.
     pub fn get_repo(&self, id: i64) -> Result<Option<Repo>> {
         let mut stmt = self
             .conn
-            .prepare("SELECT id, name, path, default_base, created_at FROM repos WHERE id = ?1")
+            .prepare("SELECT id, name, path, default_base, remote_url, created_at, template FROM repos WHERE id = ?1")
             .context("failed to prepare get_repo query")?;
 
         let repo = stmt
@@ -42,7 +69,9 @@ impl Database {
                     name: row.get(1)?,
                     path: row.get(2)?,
                     default_base: row.get(3)?,
-                    created_at: row.get(4)?,
+                    remote_url: row.get(4)?,
+                    template: row.get(6)?,
+                    created_at: row.get(5)?,
                 })
             })
             .optional()
@@ -55,7 +84,7 @@ impl Database {
     pub fn get_repo_by_path(&self, path: &str) -> Result<Option<Repo>> {
         let mut stmt = self
             .conn
-            .prepare("SELECT id, name, path, default_base, created_at FROM repos WHERE path = ?1")
+            .prepare("SELECT id, name, path, default_base, remote_url, created_at, template FROM repos WHERE path = ?1")
             .context("failed to prepare get_repo_by_path query")?;
 
         let repo = stmt
@@ -65,7 +94,9 @@ impl Database {
                     name: row.get(1)?,
                     path: row.get(2)?,
                     default_base: row.get(3)?,
-                    created_at: row.get(4)?,
+                    remote_url: row.get(4)?,
+                    template: row.get(6)?,
+                    created_at: row.get(5)?,
                 })
             })
             .optional()
@@ -74,6 +105,78 @@ impl Database {
         Ok(repo)
     }
 
+    /// List all tracked repos, ordered by id.
+    pub fn list_repos(&self) -> Result<Vec<Repo>> {
+        let mut stmt = self
+            .conn
+            .prepare(
+                "SELECT id, name, path, default_base, remote_url, created_at, template FROM repos ORDER BY id",
+            )
+            .context("failed to prepare list_repos query")?;
+
+        let rows = stmt
+            .query_map([], |row| {
+                Ok(Repo {
+                    id: row.get(0)?,
+                    name: row.get(1)?,
+                    path: row.get(2)?,
+                    default_base: row.get(3)?,
+                    remote_url: row.get(4)?,
+                    template: row.get(6)?,
+                    created_at: row.get(5)?,
+                })
+            })
+            .context("failed to list repos")?;
+
+        let mut repos = Vec::new();
+        for row in rows {
+            repos.push(row.context("failed to read repo row")?);
+        }
+        Ok(repos)
+    }
+
+    /// Record the origin remote URL for a repo (`None` clears it).
+    pub fn set_repo_remote_url(&self, repo_id: i64, remote_url: Option<&str>) -> Result<()> {
+        self.write(|conn| {
+            conn.execute(
+                "UPDATE repos SET remote_url = ?1 WHERE id = ?2",
+                rusqlite::params![remote_url, repo_id],
+            )
+        })
+        .context("failed to update repo remote URL")?;
+        Ok(())
+    }
+
+    /// Store the worktree path template for a repo (`None` clears it).
+    pub fn set_repo_template(&self, repo_id: i64, template: Option<&str>) -> Result<()> {
+        self.write(|conn| {
+            conn.execute(
+                "UPDATE repos SET template = ?1 WHERE id = ?2",
+                rusqlite::params![template, repo_id],
+            )
+        })
+        .context("failed to update repo template")?;
+        Ok(())
+    }
+
+    /// Point a repo row at a new filesystem path and directory name, e.g.
+    /// after the repo was moved on disk. Worktrees and events stay attached
+    /// to the same id.
+    pub fn update_repo_path(&self, repo_id: i64, new_path: &str, new_name: &str) -> Result<()> {
+        let rows = self
+            .write(|conn| {
+                conn.execute(
+                    "UPDATE repos SET path = ?1, name = ?2 WHERE id = ?3",
+                    rusqlite::params![new_path, new_name, repo_id],
+                )
+            })
+            .with_context(|| format!("failed to update repo path to {new_path}"))?;
+        if rows == 0 {
+            bail!("repo with id {repo_id} not found");
+        }
+        Ok(())
+    }
+
     /// Adopt an externally-created worktree by inserting it with `adopted_at` set.
     ///
     /// Like `insert_worktree`, but marks the worktree as adopted (sets
@@ -88,15 +191,16 @@ impl Database {
         base_branch: Option<&str>,
     ) -> Result<Worktree> {
         let created_at = now();
-        self.conn
-            .execute(
-                "INSERT INTO worktrees (repo_id, name, branch, path, base_branch, managed, adopted_at, created_at)
-                 VALUES (?1, ?2, ?3, ?4, ?5, 1, ?6, ?6)",
-                rusqlite::params![repo_id, name, branch, path, base_branch, created_at],
-            )
+        let id = self
+            .write(|conn| {
+                conn.execute(
+                    "INSERT INTO worktrees (repo_id, name, branch, path, base_branch, managed, adopted_at, created_at)
+                     VALUES (?1, ?2, ?3, ?4, ?5, 1, ?6, ?6)",
+                    rusqlite::params![repo_id, name, branch, path, base_branch, created_at],
+                )?;
+                Ok(conn.last_insert_rowid())
+            })
             .context("failed to adopt worktree")?;
-
-        let id = self.conn.last_insert_rowid();
         Ok(Worktree {
             id,
             repo_id,
@@ -122,15 +226,16 @@ impl Database {
         base_branch: Option<&str>,
     ) -> Result<Worktree> {
         let created_at = now();
-        self.conn
-            .execute(
-                "INSERT INTO worktrees (repo_id, name, branch, path, base_branch, managed, created_at)
-                 VALUES (?1, ?2, ?3, ?4, ?5, 1, ?6)",
-                rusqlite::params![repo_id, name, branch, path, base_branch, created_at],
-            )
+        let id = self
+            .write(|conn| {
+                conn.execute(
+                    "INSERT INTO worktrees (repo_id, name, branch, path, base_branch, managed, created_at)
+                     VALUES (?1, ?2, ?3, ?4, ?5, 1, ?6)",
+                    rusqlite::params![repo_id, name, branch, path, base_branch, created_at],
+                )?;
+                Ok(conn.last_insert_rowid())
+            })
             .context("failed to insert worktree")?;
-
-        let id = self.conn.last_insert_rowid();
         Ok(Worktree {
             id,
             repo_id,
@@ -175,12 +280,23 @@ impl Database {
         Ok(wt)
     }
 
-    /// List all worktrees belonging to a repo.
+    /// List all worktrees belonging to a repo, oldest first.
     pub fn list_worktrees(&self, repo_id: i64) -> Result<Vec<Worktree>> {
-        let mut stmt = self.conn.prepare(
-            "SELECT id, repo_id, name, branch, path, base_branch, managed, adopted_at, last_accessed, removed_at, created_at
-             FROM worktrees WHERE repo_id = ?1 AND removed_at IS NULL ORDER BY created_at",
-        ).context("failed to prepare list_worktrees query")?;
+        self.list_worktrees_ordered(repo_id, WorktreeOrder::CreatedAsc)
+    }
+
+    /// List all worktrees belonging to a repo in the given order.
+    pub fn list_worktrees_ordered(
+        &self,
+        repo_id: i64,
+        order: WorktreeOrder,
+    ) -> Result<Vec<Worktree>> {
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT w.id, w.repo_id, w.name, w.branch, w.path, w.base_branch, w.managed, w.adopted_at, w.last_accessed, w.removed_at, w.created_at
+             FROM worktrees w WHERE w.repo_id = ?1 AND w.removed_at IS NULL ORDER BY {}",
+            order.order_by()
+        ))
+        .context("failed to prepare list_worktrees query")?;
 
         let rows = stmt
             .query_map(rusqlite::params![repo_id], |row| {
@@ -242,8 +358,7 @@ impl Database {
         let param_refs: Vec<&dyn rusqlite::types::ToSql> =
             params.iter().map(|p| p.as_ref()).collect();
         let affected = self
-            .conn
-            .execute(&sql, param_refs.as_slice())
+            .write(|conn| conn.execute(&sql, param_refs.as_slice()))
             .context("failed to update worktree")?;
 
         if affected == 0 {
@@ -256,7 +371,9 @@ impl Database {
     /// Find an active worktree by its sanitized name or branch name.
     ///
     /// Only returns worktrees that have not been removed (`removed_at IS NULL`).
-    /// Checks the `name` column first (sanitized), then `branch` (original).
+    /// Matches the `name` column (sanitized) or `branch` (original). When the
+    /// identifier is one worktree's name and a different worktree's branch,
+    /// fails with [`LookupError::Multiple`] rather than guessing.
     pub fn find_worktree_by_identifier(
         &self,
         repo_id: i64,
@@ -266,11 +383,59 @@ impl Database {
             "SELECT id, repo_id, name, branch, path, base_branch, managed, adopted_at, last_accessed, removed_at, created_at
              FROM worktrees
              WHERE repo_id = ?1 AND (name = ?2 OR branch = ?2) AND removed_at IS NULL
-             LIMIT 1",
+             ORDER BY id",
         ).context("failed to prepare find_worktree_by_identifier query")?;
 
+        let mut matches = stmt
+            .query_map(rusqlite::params![repo_id, identifier], |row| {
+                Ok(Worktree {
+                    id: row.get(0)?,
+                    repo_id: row.get(1)?,
+                    name: row.get(2)?,
+                    branch: row.get(3)?,
+                    path: row.get(4)?,
+                    base_branch: row.get(5)?,
+                    managed: row.get::<_, i64>(6)? != 0,
+                    adopted_at: row.get(7)?,
+                    last_accessed: row.get(8)?,
+                    removed_at: row.get(9)?,
+                    created_at: row.get(10)?,
+                })
+            })
+            .context("failed to find worktree by identifier")?
+            .collect::<rusqlite::Result<Vec<_>>>()
+            .context("failed to read worktree row")?;
+
+        if matches.len() > 1 {
+            return Err(LookupError::Multiple {
+                identifier: identifier.to_string(),
+                matches: matches
+                    .into_iter()
+                    .map(|wt| LookupMatch {
+                        name: wt.name,
+                        branch: wt.branch,
+                    })
+                    .collect(),
+            }
+            .into());
+        }
+        Ok(matches.pop())
+    }
+
+    /// Find an active worktree tracking exactly `branch`.
+    ///
+    /// Unlike `find_worktree_by_identifier`, this never matches on the
+    /// sanitized `name`, so `feature/a` and `feature-a` stay distinct.
+    pub fn find_worktree_by_branch(&self, repo_id: i64, branch: &str) -> Result<Option<Worktree>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, repo_id, name, branch, path, base_branch, managed, adopted_at, last_accessed, removed_at, created_at
+             FROM worktrees
+             WHERE repo_id = ?1 AND branch = ?2 AND removed_at IS NULL
+             LIMIT 1",
+        ).context("failed to prepare find_worktree_by_branch query")?;
+
         let wt = stmt
-            .query_row(rusqlite::params![repo_id, identifier], |row| {
+            .query_row(rusqlite::params![repo_id, branch], |row| {
                 Ok(Worktree {
                     id: row.get(0)?,
                     repo_id: row.get(1)?,
@@ -286,7 +451,7 @@ impl Database {
                 })
             })
             .optional()
-            .context("failed to find worktree by identifier")?;
+            .context("failed to find worktree by branch")?;
 
         Ok(wt)
     }
@@ -332,27 +497,31 @@ impl Database {
     ) -> Result<i64> {
         let created_at = now();
         let payload_str = payload.map(|v| v.to_string());
-        self.conn
-            .execute(
-                "INSERT INTO events (repo_id, worktree_id, event_type, payload, created_at)
-                 VALUES (?1, ?2, ?3, ?4, ?5)",
-                rusqlite::params![repo_id, worktree_id, event_type, payload_str, created_at],
-            )
+        let id = self
+            .write(|conn| {
+                conn.execute(
+                    "INSERT INTO events (repo_id, worktree_id, event_type, payload, created_at)
+                     VALUES (?1, ?2, ?3, ?4, ?5)",
+                    rusqlite::params![repo_id, worktree_id, event_type, payload_str, created_at],
+                )?;
+                Ok(conn.last_insert_rowid())
+            })
             .context("failed to insert event")?;
 
-        Ok(self.conn.last_insert_rowid())
+        Ok(id)
     }
 
     /// Set a session key-value pair (upsert).
     pub fn set_session(&self, key: &str, value: &str) -> Result<()> {
         let updated_at = now();
-        self.conn
-            .execute(
+        self.write(|conn| {
+            conn.execute(
                 "INSERT INTO session (key, value, updated_at) VALUES (?1, ?2, ?3)
                  ON CONFLICT(key) DO UPDATE SET value = excluded.value, updated_at = excluded.updated_at",
                 rusqlite::params![key, value, updated_at],
             )
-            .context("failed to set session key")?;
+        })
+        .context("failed to set session key")?;
         Ok(())
     }
 
@@ -385,19 +554,15 @@ impl Database {
         let updated_at = now();
         let sql = "INSERT INTO session (key, value, updated_at) VALUES (?1, ?2, ?3)
                    ON CONFLICT(key) DO UPDATE SET value = excluded.value, updated_at = excluded.updated_at";
-        let tx = self
-            .conn
-            .unchecked_transaction()
-            .context("failed to begin session transaction")?;
-        tx.execute(sql, rusqlite::params![key_name, worktree_name, updated_at])
-            .context("failed to set selected_worktree")?;
-        tx.execute(
-            sql,
-            rusqlite::params![key_scroll, scroll_position.to_string(), updated_at],
-        )
-        .context("failed to set scroll_position")?;
-        tx.commit().context("failed to commit session")?;
-        Ok(())
+        self.write(|conn| {
+            conn.execute(sql, rusqlite::params![key_name, worktree_name, updated_at])?;
+            conn.execute(
+                sql,
+                rusqlite::params![key_scroll, scroll_position.to_string(), updated_at],
+            )?;
+            Ok(())
+        })
+        .context("failed to save list session")
     }
 
     /// Load TUI list session state for a repo. Returns `(worktree_name, scroll_position)`.
@@ -416,14 +581,18 @@ impl Database {
     }
 
     /// Add a tag to a worktree. Idempotent — duplicate adds are silently ignored.
+    ///
+    /// Rejects names outside the charset accepted by [`super::validate_tag_name`].
     pub fn add_tag(&self, worktree_id: i64, name: &str) -> Result<()> {
+        super::validate_tag_name(name)?;
         let created_at = now();
-        self.conn
-            .execute(
+        self.write(|conn| {
+            conn.execute(
                 "INSERT OR IGNORE INTO tags (worktree_id, name, created_at) VALUES (?1, ?2, ?3)",
                 rusqlite::params![worktree_id, name, created_at],
             )
-            .context("failed to add tag")?;
+        })
+        .context("failed to add tag")?;
         Ok(())
     }
 
@@ -452,7 +621,7 @@ impl Database {
              FROM worktrees w
              INNER JOIN tags t ON t.worktree_id = w.id
              WHERE w.repo_id = ?1 AND t.name = ?2 AND w.removed_at IS NULL
-             ORDER BY w.created_at",
+             ORDER BY w.created_at, w.id",
         ).context("failed to prepare list_worktrees_by_tag query")?;
 
         let rows = stmt
@@ -480,14 +649,62 @@ impl Database {
         Ok(worktrees)
     }
 
+    /// Count active worktrees in a repo carrying `tag`.
+    pub fn count_worktrees_by_tag(&self, repo_id: i64, tag: &str) -> Result<usize> {
+        let count: i64 = self
+            .conn
+            .query_row(
+                "SELECT COUNT(DISTINCT w.id)
+                 FROM worktrees w
+                 INNER JOIN tags t ON t.worktree_id = w.id
+                 WHERE w.repo_id = ?1 AND t.name = ?2 AND w.removed_at IS NULL",
+                rusqlite::params![repo_id, tag],
+                |row| row.get(0),
+            )
+            .context("failed to count worktrees by tag")?;
+        Ok(count as usize)
+    }
+
+    /// List every distinct tag in a repo with the names of the active
+    /// worktrees carrying it, both sorted by name.
+    pub fn list_all_tags(&self, repo_id: i64) -> Result<Vec<(String, Vec<String>)>> {
+        let mut stmt = self
+            .conn
+            .prepare(
+                "SELECT t.name, w.name
+                 FROM tags t
+                 INNER JOIN worktrees w ON t.worktree_id = w.id
+                 WHERE w.repo_id = ?1 AND w.removed_at IS NULL
+                 ORDER BY t.name, w.name",
+            )
+            .context("failed to prepare list_all_tags query")?;
+
+        let rows = stmt
+            .query_map(rusqlite::params![repo_id], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })
+            .context("failed to list all tags")?;
+
+        let mut tags: Vec<(String, Vec<String>)> = Vec::new();
+        for row in rows {
+            let (tag, worktree) = row.context("failed to read tag row")?;
+            match tags.last_mut() {
+                Some((last, worktrees)) if *last == tag => worktrees.push(worktree),
+                _ => tags.push((tag, vec![worktree])),
+            }
+        }
+        Ok(tags)
+    }
+
     /// Remove a tag from a worktree. No-op if the tag doesn't exist.
     pub fn remove_tag(&self, worktree_id: i64, name: &str) -> Result<()> {
-        self.conn
-            .execute(
+        self.write(|conn| {
+            conn.execute(
                 "DELETE FROM tags WHERE worktree_id = ?1 AND name = ?2",
                 rusqlite::params![worktree_id, name],
             )
-            .context("failed to remove tag")?;
+        })
+        .context("failed to remove tag")?;
         Ok(())
     }
 
@@ -501,13 +718,14 @@ impl Database {
         step: Option<&str>,
     ) -> Result<()> {
         let created_at = now();
-        self.conn
-            .execute(
+        self.write(|conn| {
+            conn.execute(
                 "INSERT INTO logs (event_id, stream, line, line_number, step, created_at)
                  VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
                 rusqlite::params![event_id, stream, line, line_number, step, created_at],
             )
-            .context("failed to insert log line")?;
+        })
+        .context("failed to insert log line")?;
         Ok(())
     }
 
@@ -565,35 +783,34 @@ impl Database {
 
     /// Delete all metadata for a worktree, including tags, events, and logs.
     pub fn delete_worktree_metadata(&self, worktree_id: i64) -> Result<()> {
-        let tx = self
-            .conn
-            .unchecked_transaction()
-            .context("failed to begin delete_worktree_metadata transaction")?;
-
-        tx.execute(
-            "DELETE FROM logs WHERE event_id IN (SELECT id FROM events WHERE worktree_id = ?1)",
-            rusqlite::params![worktree_id],
-        )
-        .context("failed to delete logs for worktree metadata purge")?;
-        tx.execute(
-            "DELETE FROM events WHERE worktree_id = ?1",
-            rusqlite::params![worktree_id],
-        )
-        .context("failed to delete events for worktree metadata purge")?;
-        tx.execute(
-            "DELETE FROM tags WHERE worktree_id = ?1",
-            rusqlite::params![worktree_id],
-        )
-        .context("failed to delete tags for worktree metadata purge")?;
-        tx.execute(
-            "DELETE FROM worktrees WHERE id = ?1",
-            rusqlite::params![worktree_id],
-        )
-        .context("failed to delete worktree metadata row")?;
-
-        tx.commit()
-            .context("failed to commit delete_worktree_metadata transaction")?;
-        Ok(())
+        // `write` needs a plain rusqlite error to spot SQLITE_BUSY, so track
+        // which statement is running and attach its context afterwards.
+        let mut context = "failed to begin delete_worktree_metadata transaction";
+        let result = self.write(|conn| {
+            context = "failed to delete logs for worktree metadata purge";
+            conn.execute(
+                "DELETE FROM logs WHERE event_id IN (SELECT id FROM events WHERE worktree_id = ?1)",
+                rusqlite::params![worktree_id],
+            )?;
+            context = "failed to delete events for worktree metadata purge";
+            conn.execute(
+                "DELETE FROM events WHERE worktree_id = ?1",
+                rusqlite::params![worktree_id],
+            )?;
+            context = "failed to delete tags for worktree metadata purge";
+            conn.execute(
+                "DELETE FROM tags WHERE worktree_id = ?1",
+                rusqlite::params![worktree_id],
+            )?;
+            context = "failed to delete worktree metadata row";
+            conn.execute(
+                "DELETE FROM worktrees WHERE id = ?1",
+                rusqlite::params![worktree_id],
+            )?;
+            context = "failed to commit delete_worktree_metadata transaction";
+            Ok(())
+        });
+        result.context(context)
     }
 
     /// Archive a removed worktree row so history stays readable without
@@ -605,13 +822,14 @@ impl Database {
         removed_at: i64,
     ) -> Result<()> {
         let affected = self
-            .conn
-            .execute(
-                "UPDATE worktrees
-                 SET path = ?2, removed_at = ?3
-                 WHERE id = ?1",
-                rusqlite::params![worktree_id, archived_path, removed_at],
-            )
+            .write(|conn| {
+                conn.execute(
+                    "UPDATE worktrees
+                     SET path = ?2, removed_at = ?3
+                     WHERE id = ?1",
+                    rusqlite::params![worktree_id, archived_path, removed_at],
+                )
+            })
             .context("failed to archive removed worktree")?;
 
         if affected == 0 {
@@ -642,17 +860,17 @@ impl Database {
         Ok(count)
     }
 
-    /// List events for a repo with optional worktree filter and limit.
+    /// List events for a repo matching `filter`, most recent first.
     ///
     /// When `worktree_identifier` is `Some`, only events for the matching
     /// worktree (by name or branch) are returned.
     /// When `limit` is `Some`, at most that many events are returned.
-    /// Results are ordered most recent first.
-    pub fn list_events_filtered(
+    pub fn list_events_matching(
         &self,
         repo_id: i64,
         worktree_identifier: Option<&str>,
         limit: Option<usize>,
+        filter: &EventFilter,
     ) -> Result<Vec<LogEntry>> {
         let mut sql = String::from(
             "SELECT e.id, e.event_type, w.name, e.payload, e.created_at
@@ -662,40 +880,49 @@ impl Database {
               AND e.repo_id = w.repo_id
              WHERE e.repo_id = ?1",
         );
+        let mut params: Vec<Box<dyn rusqlite::types::ToSql>> = vec![Box::new(repo_id)];
 
-        // Parameter layout:
-        //   ?1 = repo_id (always)
-        //   ?2 = worktree_identifier (if Some) or limit (if worktree is None)
-        //   ?3 = limit (only when worktree_identifier is also Some)
-        if worktree_identifier.is_some() {
-            sql.push_str(" AND (w.name = ?2 OR w.branch = ?2)");
+        // Placeholders are numbered in the order their values are pushed.
+        if let Some(id) = worktree_identifier {
+            params.push(Box::new(id.to_string()));
+            let n = params.len();
+            sql.push_str(&format!(" AND (w.name = ?{n} OR w.branch = ?{n})"));
+        }
+        if let Some(since) = filter.since {
+            params.push(Box::new(since));
+            sql.push_str(&format!(" AND e.created_at >= ?{}", params.len()));
+        }
+        if let Some(until) = filter.until {
+            params.push(Box::new(until));
+            sql.push_str(&format!(" AND e.created_at <= ?{}", params.len()));
+        }
+        if !filter.event_types.is_empty() {
+            let placeholders: Vec<String> = filter
+                .event_types
+                .iter()
+                .map(|t| {
+                    params.push(Box::new(t.clone()));
+                    format!("?{}", params.len())
+                })
+                .collect();
+            sql.push_str(&format!(
+                " AND e.event_type IN ({})",
+                placeholders.join(", ")
+            ));
         }
 
         sql.push_str(" ORDER BY e.created_at DESC, e.id DESC");
 
-        if limit.is_some() {
-            if worktree_identifier.is_some() {
-                sql.push_str(" LIMIT ?3");
-            } else {
-                sql.push_str(" LIMIT ?2");
-            }
+        if let Some(lim) = limit {
+            params.push(Box::new(lim as i64));
+            sql.push_str(&format!(" LIMIT ?{}", params.len()));
         }
 
         let mut stmt = self
             .conn
             .prepare(&sql)
-            .context("failed to prepare list_events_filtered query")?;
+            .context("failed to prepare list_events_matching query")?;
 
-        let params: Vec<Box<dyn rusqlite::types::ToSql>> = {
-            let mut p: Vec<Box<dyn rusqlite::types::ToSql>> = vec![Box::new(repo_id)];
-            if let Some(id) = worktree_identifier {
-                p.push(Box::new(id.to_string()));
-            }
-            if let Some(lim) = limit {
-                p.push(Box::new(lim as i64));
-            }
-            p
-        };
         let param_refs: Vec<&dyn rusqlite::types::ToSql> =
             params.iter().map(|p| p.as_ref()).collect();
 
@@ -718,6 +945,36 @@ impl Database {
         Ok(entries)
     }
 
+    /// Count a repo's events per event type, optionally only those at or
+    /// after `since`. Sorted by count (descending), then type name.
+    pub fn event_counts_by_type(
+        &self,
+        repo_id: i64,
+        since: Option<i64>,
+    ) -> Result<Vec<(String, i64)>> {
+        let mut stmt = self
+            .conn
+            .prepare(
+                "SELECT event_type, COUNT(*) AS n
+                 FROM events
+                 WHERE repo_id = ?1 AND (?2 IS NULL OR created_at >= ?2)
+                 GROUP BY event_type
+                 ORDER BY n DESC, event_type ASC",
+            )
+            .context("failed to prepare event_counts_by_type query")?;
+        let rows = stmt
+            .query_map(rusqlite::params![repo_id, since], |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })
+            .context("failed to count events by type")?;
+
+        let mut counts = Vec::new();
+        for row in rows {
+            counts.push(row.context("failed to read event count row")?);
+        }
+        Ok(counts)
+    }
+
     /// Check whether any worktree (active or removed) exists for the given
     /// identifier (name or branch) in a repo.
     pub fn worktree_exists_any(&self, repo_id: i64, identifier: &str) -> Result<bool> {
@@ -876,7 +1133,7 @@ mod tests {
     }
 
     #[test]
-    fn list_events_filtered_with_limit_returns_n_most_recent() {
+    fn list_events_matching_with_limit_returns_n_most_recent() {
         let db = Database::open_in_memory().unwrap();
         let repo = db.insert_repo("r", "/r", None).unwrap();
         let wt = db
@@ -890,16 +1147,20 @@ mod tests {
         }
 
         // Limit to 3
-        let entries = db.list_events_filtered(repo.id, None, Some(3)).unwrap();
+        let entries = db
+            .list_events_matching(repo.id, None, Some(3), &EventFilter::default())
+            .unwrap();
         assert_eq!(entries.len(), 3, "should return exactly 3 events");
 
         // No limit returns all
-        let all = db.list_events_filtered(repo.id, None, None).unwrap();
+        let all = db
+            .list_events_matching(repo.id, None, None, &EventFilter::default())
+            .unwrap();
         assert_eq!(all.len(), 5, "no limit should return all 5 events");
     }
 
     #[test]
-    fn list_events_filtered_by_worktree_name() {
+    fn list_events_matching_by_worktree_name() {
         let db = Database::open_in_memory().unwrap();
         let repo = db.insert_repo("r", "/r", None).unwrap();
         let wt_a = db
@@ -921,23 +1182,179 @@ mod tests {
 
         // Filter by sanitized name
         let alpha_events = db
-            .list_events_filtered(repo.id, Some("alpha"), None)
+            .list_events_matching(repo.id, Some("alpha"), None, &EventFilter::default())
             .unwrap();
         assert_eq!(alpha_events.len(), 3);
 
         // Filter by branch name
         let beta_events = db
-            .list_events_filtered(repo.id, Some("feature/beta"), None)
+            .list_events_matching(repo.id, Some("feature/beta"), None, &EventFilter::default())
             .unwrap();
         assert_eq!(beta_events.len(), 2);
 
         // Combined: filter + limit
         let limited = db
-            .list_events_filtered(repo.id, Some("alpha"), Some(2))
+            .list_events_matching(repo.id, Some("alpha"), Some(2), &EventFilter::default())
             .unwrap();
         assert_eq!(limited.len(), 2);
     }
 
+    #[test]
+    fn list_events_matching_respects_time_window() {
+        let db = Database::open_in_memory().unwrap();
+        let repo = db.insert_repo("r", "/r", None).unwrap();
+
+        for ts in [100, 200, 300, 400] {
+            let id = db.insert_event(repo.id, None, "created", None).unwrap();
+            db.conn_for_test()
+                .execute(
+                    "UPDATE events SET created_at = ?1 WHERE id = ?2",
+                    rusqlite::params![ts, id],
+                )
+                .unwrap();
+        }
+
+        let filter = EventFilter {
+            since: Some(200),
+            until: Some(300),
+            ..Default::default()
+        };
+        let entries = db
+            .list_events_matching(repo.id, None, None, &filter)
+            .unwrap();
+        let times: Vec<i64> = entries.iter().map(|e| e.created_at).collect();
+        assert_eq!(times, vec![300, 200], "bounds are inclusive");
+
+        let since_only = EventFilter {
+            since: Some(250),
+            ..Default::default()
+        };
+        let entries = db
+            .list_events_matching(repo.id, None, Some(1), &since_only)
+            .unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].created_at, 400);
+    }
+
+    #[test]
+    fn event_counts_by_type_groups_within_window() {
+        let db = Database::open_in_memory().unwrap();
+        let repo = db.insert_repo("r", "/r", None).unwrap();
+        let other = db.insert_repo("o", "/o", None).unwrap();
+
+        for (event_type, ts) in [
+            ("created", 100),
+            ("created", 200),
+            ("created", 300),
+            ("synced", 250),
+            ("removed", 300),
+            ("synced", 50),
+        ] {
+            let id = db.insert_event(repo.id, None, event_type, None).unwrap();
+            db.conn_for_test()
+                .execute(
+                    "UPDATE events SET created_at = ?1 WHERE id = ?2",
+                    rusqlite::params![ts, id],
+                )
+                .unwrap();
+        }
+        db.insert_event(other.id, None, "created", None).unwrap();
+
+        let all = db.event_counts_by_type(repo.id, None).unwrap();
+        assert_eq!(
+            all,
+            vec![
+                ("created".to_string(), 3),
+                ("synced".to_string(), 2),
+                ("removed".to_string(), 1),
+            ]
+        );
+
+        let recent = db.event_counts_by_type(repo.id, Some(200)).unwrap();
+        assert_eq!(
+            recent,
+            vec![
+                ("created".to_string(), 2),
+                ("removed".to_string(), 1),
+                ("synced".to_string(), 1),
+            ]
+        );
+        assert!(db
+            .event_counts_by_type(repo.id, Some(1000))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn list_events_matching_filters_by_event_type() {
+        let db = Database::open_in_memory().unwrap();
+        let repo = db.insert_repo("r", "/r", None).unwrap();
+        let wt = db
+            .insert_worktree(repo.id, "feat", "feat", "/wt/feat", None)
+            .unwrap();
+        db.insert_event(repo.id, Some(wt.id), "created", None)
+            .unwrap();
+        db.insert_event(repo.id, Some(wt.id), "switched", None)
+            .unwrap();
+        db.insert_event(repo.id, Some(wt.id), "removed", None)
+            .unwrap();
+
+        let created_only = EventFilter {
+            event_types: vec!["created".to_string()],
+            ..Default::default()
+        };
+        let entries = db
+            .list_events_matching(repo.id, Some("feat"), None, &created_only)
+            .unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].event_type, "created");
+        assert!(!entries.iter().any(|e| e.event_type == "removed"));
+
+        let two_types = EventFilter {
+            event_types: vec!["created".to_string(), "removed".to_string()],
+            ..Default::default()
+        };
+        let mut types: Vec<String> = db
+            .list_events_matching(repo.id, None, None, &two_types)
+            .unwrap()
+            .into_iter()
+            .map(|e| e.event_type)
+            .collect();
+        types.sort();
+        assert_eq!(types, vec!["created", "removed"]);
+    }
+
+    #[test]
+    fn find_worktree_by_branch_matches_branch_only() {
+        let db = Database::open_in_memory().unwrap();
+        let repo = db.insert_repo("r", "/r", None).unwrap();
+        let wt = db
+            .insert_worktree(repo.id, "feature-a", "feature/a", "/wt/a", None)
+            .unwrap();
+
+        let found = db.find_worktree_by_branch(repo.id, "feature/a").unwrap();
+        assert_eq!(found.map(|w| w.id), Some(wt.id));
+        assert!(
+            db.find_worktree_by_branch(repo.id, "feature-a")
+                .unwrap()
+                .is_none(),
+            "sanitized name must not match"
+        );
+
+        db.update_worktree(
+            wt.id,
+            &WorktreeUpdate {
+                removed_at: Some(Some(1)),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert!(db
+            .find_worktree_by_branch(repo.id, "feature/a")
+            .unwrap()
+            .is_none());
+    }
+
     #[test]
     fn save_and_load_list_session_round_trip() {
         let db = Database::open_in_memory().unwrap();
@@ -1038,4 +1455,207 @@ mod tests {
             "removed worktree should still be found"
         );
     }
+
+    #[test]
+    fn update_repo_path_keeps_worktrees_attached() {
+        let db = Database::open_in_memory().unwrap();
+        let repo = db.insert_repo("r", "/old/r", Some("main")).unwrap();
+        let wt = db
+            .insert_worktree(repo.id, "wt", "feature/wt", "/wt/r/wt", None)
+            .unwrap();
+
+        db.update_repo_path(repo.id, "/new/r2", "r2").unwrap();
+
+        assert!(db.get_repo_by_path("/old/r").unwrap().is_none());
+        let moved = db.get_repo_by_path("/new/r2").unwrap().unwrap();
+        assert_eq!(moved.id, repo.id);
+        assert_eq!(moved.name, "r2");
+        let worktrees = db.list_worktrees(moved.id).unwrap();
+        assert_eq!(worktrees.len(), 1);
+        assert_eq!(worktrees[0].id, wt.id);
+    }
+
+    #[test]
+    fn update_repo_path_errors_for_unknown_repo() {
+        let db = Database::open_in_memory().unwrap();
+        let err = db.update_repo_path(42, "/new/r", "r").unwrap_err();
+        assert!(err.to_string().contains("not found"), "got: {err}");
+    }
+
+    #[test]
+    fn set_repo_template_persists_and_clears() {
+        let db = Database::open_in_memory().unwrap();
+        let repo = db.insert_repo("r", "/r", None).unwrap();
+        assert!(repo.template.is_none());
+
+        db.set_repo_template(repo.id, Some("{{ repo }}-{{ branch }}"))
+            .unwrap();
+        let stored = db.get_repo_by_path("/r").unwrap().unwrap();
+        assert_eq!(stored.template.as_deref(), Some("{{ repo }}-{{ branch }}"));
+
+        db.set_repo_template(repo.id, None).unwrap();
+        assert!(db.get_repo(repo.id).unwrap().unwrap().template.is_none());
+    }
+
+    #[test]
+    fn set_repo_remote_url_round_trips_through_list_repos() {
+        let db = Database::open_in_memory().unwrap();
+        let a = db.insert_repo("a", "/a", None).unwrap();
+        let b = db.insert_repo("b", "/b", None).unwrap();
+        assert!(a.remote_url.is_none());
+
+        db.set_repo_remote_url(b.id, Some("git@example.com:b.git"))
+            .unwrap();
+
+        let repos = db.list_repos().unwrap();
+        assert_eq!(repos.len(), 2);
+        assert_eq!(repos[0].id, a.id);
+        assert!(repos[0].remote_url.is_none());
+        assert_eq!(
+            repos[1].remote_url.as_deref(),
+            Some("git@example.com:b.git")
+        );
+    }
+
+    #[test]
+    fn upsert_repo_inserts_then_reconciles_same_path() {
+        let db = Database::open_in_memory().unwrap();
+        let first = db.upsert_repo("old-name", "/r", Some("main")).unwrap();
+        let second = db.upsert_repo("new-name", "/r", Some("trunk")).unwrap();
+
+        assert_eq!(second.id, first.id, "same path should reuse the row");
+        assert_eq!(second.name, "new-name");
+        assert_eq!(second.default_base.as_deref(), Some("trunk"));
+        assert_eq!(second.created_at, first.created_at);
+
+        let repos = db.list_repos().unwrap();
+        assert_eq!(repos.len(), 1, "upsert must not duplicate the repo row");
+        assert_eq!(repos[0].name, "new-name");
+        assert_eq!(repos[0].default_base.as_deref(), Some("trunk"));
+    }
+
+    #[test]
+    fn upsert_repo_keeps_remote_url_when_unchanged() {
+        let db = Database::open_in_memory().unwrap();
+        let repo = db.upsert_repo("r", "/r", None).unwrap();
+        db.set_repo_remote_url(repo.id, Some("git@example.com:r.git"))
+            .unwrap();
+
+        let again = db.upsert_repo("r", "/r", None).unwrap();
+
+        assert_eq!(again.id, repo.id);
+        assert!(again.default_base.is_none());
+        assert_eq!(again.remote_url.as_deref(), Some("git@example.com:r.git"));
+    }
+
+    #[test]
+    fn list_all_tags_aggregates_worktrees_per_tag() {
+        let db = Database::open_in_memory().unwrap();
+        let repo = db.insert_repo("r", "/r", None).unwrap();
+        let other = db.insert_repo("o", "/o", None).unwrap();
+        let a = db
+            .insert_worktree(repo.id, "a", "a", "/wt/a", None)
+            .unwrap();
+        let b = db
+            .insert_worktree(repo.id, "b", "b", "/wt/b", None)
+            .unwrap();
+        let gone = db
+            .insert_worktree(repo.id, "gone", "gone", "/wt/gone", None)
+            .unwrap();
+        let foreign = db
+            .insert_worktree(other.id, "x", "x", "/wt/x", None)
+            .unwrap();
+        db.add_tag(a.id, "wip").unwrap();
+        db.add_tag(b.id, "wip").unwrap();
+        db.add_tag(b.id, "review").unwrap();
+        db.add_tag(gone.id, "review").unwrap();
+        db.add_tag(foreign.id, "wip").unwrap();
+        db.update_worktree(
+            gone.id,
+            &crate::state::WorktreeUpdate {
+                removed_at: Some(Some(1000)),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        let tags = db.list_all_tags(repo.id).unwrap();
+
+        assert_eq!(
+            tags,
+            vec![
+                ("review".to_string(), vec!["b".to_string()]),
+                ("wip".to_string(), vec!["a".to_string(), "b".to_string()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn count_worktrees_by_tag_excludes_removed_and_other_repos() {
+        let db = Database::open_in_memory().unwrap();
+        let repo = db.insert_repo("r", "/r", None).unwrap();
+        let other = db.insert_repo("o", "/o", None).unwrap();
+        let a = db
+            .insert_worktree(repo.id, "a", "a", "/wt/a", None)
+            .unwrap();
+        let b = db
+            .insert_worktree(repo.id, "b", "b", "/wt/b", None)
+            .unwrap();
+        let foreign = db
+            .insert_worktree(other.id, "x", "x", "/wt/x", None)
+            .unwrap();
+        db.add_tag(a.id, "wip").unwrap();
+        db.add_tag(b.id, "wip").unwrap();
+        db.add_tag(foreign.id, "wip").unwrap();
+        assert_eq!(db.count_worktrees_by_tag(repo.id, "wip").unwrap(), 2);
+
+        db.update_worktree(
+            b.id,
+            &crate::state::WorktreeUpdate {
+                removed_at: Some(Some(1000)),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(db.count_worktrees_by_tag(repo.id, "wip").unwrap(), 1);
+        assert_eq!(db.count_worktrees_by_tag(repo.id, "none").unwrap(), 0);
+    }
+
+    #[test]
+    fn add_tag_rejects_invalid_names() {
+        let db = Database::open_in_memory().unwrap();
+        let repo = db.insert_repo("r", "/r", None).unwrap();
+        let wt = db.insert_worktree(repo.id, "wt", "b", "/wt", None).unwrap();
+
+        for bad in ["a,b", "has space", "tab\there", "bell\u{7}"] {
+            let err = db.add_tag(wt.id, bad).unwrap_err();
+            assert!(err.to_string().contains("invalid tag name"), "got: {err}");
+        }
+        assert!(db.list_tags(wt.id).unwrap().is_empty());
+
+        db.add_tag(wt.id, "wip-2").unwrap();
+        assert_eq!(db.list_tags(wt.id).unwrap(), vec!["wip-2"]);
+    }
+
+    #[test]
+    fn delete_worktree_metadata_names_the_failing_statement() {
+        let db = Database::open_in_memory().unwrap();
+        let repo = db.insert_repo("r", "/r", None).unwrap();
+        let wt = db.insert_worktree(repo.id, "wt", "b", "/wt", None).unwrap();
+        db.add_tag(wt.id, "wip").unwrap();
+        db.conn_for_test()
+            .execute_batch(
+                "CREATE TRIGGER fail_tag_delete BEFORE DELETE ON tags
+                 BEGIN SELECT RAISE(ABORT, 'injected failure'); END;",
+            )
+            .unwrap();
+
+        let err = db.delete_worktree_metadata(wt.id).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "failed to delete tags for worktree metadata purge"
+        );
+        assert!(format!("{err:#}").contains("injected failure"), "{err:#}");
+        assert!(db.get_worktree(wt.id).unwrap().is_some());
+    }
 }
@@ -0,0 +1,4 @@
+-- Migration 004: Add remote_url column to repos table.
+-- Records the origin URL so a repo moved on disk can be matched and relinked.
+
+ALTER TABLE repos ADD COLUMN remote_url TEXT;
@@ -0,0 +1,4 @@
+-- Migration 005: Add template column to repos table.
+-- Stores a per-repo worktree path template set with `trench config set-template`.
+
+ALTER TABLE repos ADD COLUMN template TEXT;
@@ -1,9 +1,11 @@
 pub mod chrome;
 pub mod screens;
+pub mod statusbar;
 pub mod theme;
 pub mod watcher;
 
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use anyhow::Result;
 use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
@@ -42,6 +44,9 @@ type PanicHook = dyn Fn(&std::panic::PanicHookInfo<'_>) + Send + Sync;
 /// Stores the pre-TUI panic hook so `restore_panic_hook` can put it back.
 static PREV_PANIC_HOOK: Mutex<Option<Arc<PanicHook>>> = Mutex::new(None);
 
+/// Interval between background ticks of the event loop.
+pub const TICK_RATE: Duration = Duration::from_millis(50);
+
 /// Launch the TUI. This is the single public entry point.
 pub fn run() -> Result<Option<String>> {
     install_panic_hook();
@@ -87,24 +92,27 @@ pub fn run() -> Result<Option<String>> {
     app.restore_list_session();
 
     let result = (|| -> Result<Option<String>> {
+        let mut last_tick = Instant::now();
         while app.is_running() {
-            // Process any pending hook output messages
-            app.process_hook_messages();
-
-            // Check filesystem watcher for auto-refresh
-            app.check_watcher();
-
             terminal.draw(|frame| app.ui(frame))?;
 
-            // Non-blocking poll: wait up to 50ms for key events, allowing
-            // hook messages to be processed between frames for live streaming.
-            if event::poll(std::time::Duration::from_millis(50))? {
+            // Run a confirmed sync now that its "syncing…" frame is on screen.
+            app.process_pending_sync();
+
+            // Wait for input until the next tick is due, so hook output and
+            // watcher refreshes keep flowing while the user is idle.
+            let timeout = TICK_RATE.saturating_sub(last_tick.elapsed());
+            if event::poll(timeout)? {
                 if let Event::Key(key) = event::read()? {
                     if key.kind == KeyEventKind::Press {
                         app.handle_key_event(key);
                     }
                 }
             }
+            if last_tick.elapsed() >= TICK_RATE {
+                app.on_tick();
+                last_tick = Instant::now();
+            }
 
             if let Some(path) = app.editor_request.take() {
                 ratatui::restore();
@@ -142,17 +150,33 @@ fn restore_panic_hook() {
     }
 }
 
+/// Runs a hook-less sync for the named worktree. Swappable so tests can
+/// exercise the TUI flow without touching git.
+pub type SyncRunner = fn(
+    &str,
+    crate::cli::commands::sync::Strategy,
+) -> Result<crate::cli::commands::sync::SyncResult>;
+
 pub struct App {
     running: bool,
     nav_stack: Vec<Screen>,
     pub theme: theme::Theme,
     pub ui_options: chrome::UiOptions,
     pub list_state: screens::list::ListState,
+    /// Live filter query typed after `/` on the list screen.
+    pub filter: String,
+    /// Whether keystrokes currently edit `filter`.
+    pub filtering: bool,
+    /// Unfiltered rows, kept while a filter narrows `list_state.rows`.
+    filter_source: Vec<screens::list::WorktreeRow>,
+    /// Results from the background git-status worker, applied on tick.
+    pub status_rx: Option<std::sync::mpsc::Receiver<screens::list::GitStatus>>,
     pub detail_state: Option<screens::detail::DetailState>,
     pub detail_status_message: Option<screens::list::StatusMessage>,
     pub create_state: Option<screens::create::CreateState>,
     pub sync_picker_state: Option<screens::sync_picker::SyncPickerState>,
     pub sync_return_screen: Option<Screen>,
+    pub sync_runner: SyncRunner,
     pub delete_confirm_state: Option<screens::delete_confirm::DeleteConfirmState>,
     pub hook_log_state: Option<screens::hook_log::HookLogState>,
     pub hook_rx: Option<std::sync::mpsc::Receiver<screens::hook_log::HookOutputMessage>>,
@@ -171,6 +195,48 @@ pub struct PendingStatusMessage {
     pub status: screens::list::StatusMessage,
 }
 
+/// Default [`SyncRunner`]: sync against the current repo and state database.
+fn run_sync(
+    name: &str,
+    strategy: crate::cli::commands::sync::Strategy,
+) -> Result<crate::cli::commands::sync::SyncResult> {
+    let (cwd, db) = App::open_db().ok_or_else(|| anyhow::anyhow!("Failed to open database"))?;
+    crate::cli::commands::sync::execute(name, &cwd, &db, strategy)
+}
+
+/// Status line for a completed sync, e.g. "Synced 'x' via rebase (2 commits replayed)".
+fn sync_success_text(result: &crate::cli::commands::sync::SyncResult) -> String {
+    use crate::cli::commands::sync::Strategy;
+    let (count, verb) = match result.strategy {
+        Strategy::Rebase => (result.before_ahead, "replayed"),
+        Strategy::Merge => (result.before_behind, "merged in"),
+        Strategy::FfOnly => (result.before_behind, "fast-forwarded"),
+    };
+    let noun = if count == 1 { "commit" } else { "commits" };
+    format!(
+        "Synced '{}' via {} ({count} {noun} {verb})",
+        result.name, result.strategy
+    )
+}
+
+/// Result text for a failed sync; conflicts list the conflicted files.
+fn sync_failure_text(err: &anyhow::Error) -> String {
+    match err.downcast_ref::<crate::git::GitError>() {
+        Some(crate::git::GitError::MergeConflict { branch, files }) => {
+            let mut text = format!("Conflict while syncing '{branch}'");
+            if !files.is_empty() {
+                text.push_str(" in:");
+                for file in files {
+                    text.push_str(&format!("\n{file}"));
+                }
+            }
+            text.push_str("\nResolve manually, then sync again.");
+            text
+        }
+        _ => format!("Sync failed: {err:#}"),
+    }
+}
+
 impl App {
     pub fn new() -> Self {
         Self {
@@ -179,11 +245,16 @@ impl App {
             theme: theme::from_name("ops"),
             ui_options: chrome::UiOptions::default(),
             list_state: screens::list::ListState::new(vec![]),
+            filter: String::new(),
+            filtering: false,
+            filter_source: Vec::new(),
+            status_rx: None,
             detail_state: None,
             detail_status_message: None,
             create_state: None,
             sync_picker_state: None,
             sync_return_screen: None,
+            sync_runner: run_sync,
             delete_confirm_state: None,
             hook_log_state: None,
             hook_rx: None,
@@ -278,14 +349,40 @@ impl App {
                 auto_refresh: self.auto_refresh,
             },
         );
+        let [content_area, status_area] = ratatui::layout::Layout::vertical([
+            ratatui::layout::Constraint::Min(1),
+            ratatui::layout::Constraint::Length(1),
+        ])
+        .areas(content_area);
+        statusbar::render(
+            frame,
+            status_area,
+            theme,
+            self.active_screen(),
+            repo_name,
+            self.status_counts(),
+        );
         match self.active_screen() {
-            Screen::List => screens::list::render_with_options(
-                &self.list_state,
-                frame,
-                content_area,
-                theme,
-                &self.ui_options,
-            ),
+            Screen::List => {
+                let list_area = if self.is_filter_active() {
+                    let [prompt_area, list_area] = ratatui::layout::Layout::vertical([
+                        ratatui::layout::Constraint::Length(1),
+                        ratatui::layout::Constraint::Min(1),
+                    ])
+                    .areas(content_area);
+                    self.render_filter_prompt(frame, prompt_area);
+                    list_area
+                } else {
+                    content_area
+                };
+                screens::list::render_with_options(
+                    &self.list_state,
+                    frame,
+                    list_area,
+                    theme,
+                    &self.ui_options,
+                )
+            }
             Screen::Detail => {
                 if let Some(ref detail) = self.detail_state {
                     screens::detail::render_with_options_and_status(
@@ -350,6 +447,40 @@ impl App {
         }
     }
 
+    fn render_filter_prompt(&self, frame: &mut Frame, area: ratatui::layout::Rect) {
+        let theme = &self.theme;
+        let cursor = if self.filtering { "▏" } else { "" };
+        let line = ratatui::text::Line::from(vec![
+            ratatui::text::Span::styled(
+                format!("/{}{cursor}", self.filter),
+                ratatui::style::Style::default().fg(theme.accent),
+            ),
+            ratatui::text::Span::styled(
+                format!(
+                    "  {} of {} match · Esc clears",
+                    self.list_state.rows.len(),
+                    self.filter_source.len()
+                ),
+                ratatui::style::Style::default().fg(theme.fg_muted),
+            ),
+        ]);
+        frame.render_widget(
+            Paragraph::new(line).style(theme.with_bg(
+                ratatui::style::Style::default().fg(theme.fg),
+                theme.bg_elevated,
+            )),
+            area,
+        );
+    }
+
+    fn status_counts(&self) -> statusbar::StatusCounts {
+        let rows = self.unfiltered_rows();
+        statusbar::StatusCounts {
+            active: rows.iter().filter(|r| !r.processes.is_empty()).count(),
+            total: rows.len(),
+        }
+    }
+
     /// Render the screen underneath the current overlay (e.g. for Help).
     fn render_underlying_screen(&self, frame: &mut Frame, area: ratatui::layout::Rect) {
         let theme = &self.theme;
@@ -432,7 +563,7 @@ impl App {
 
     fn open_db() -> Option<(std::path::PathBuf, Database)> {
         let cwd = std::env::current_dir().ok()?;
-        let db_path = paths::data_dir().ok()?.join("trench.db");
+        let db_path = crate::runtime_db_path().ok()?;
         let db = Database::open(&db_path).ok()?;
         Some((cwd, db))
     }
@@ -489,9 +620,15 @@ impl App {
                 self.repo_path = Some(repo_info.path.to_string_lossy().to_string());
             }
         }
-        if let Ok(rows) = screens::list::load_worktrees(&cwd, &db, &[]) {
+        if let Ok((rows, jobs)) = screens::list::load_worktrees_deferred(&cwd, &db, &[]) {
+            self.status_rx = Some(screens::list::spawn_status_worker(jobs));
             let prev_selected = self.list_state.selected;
-            self.list_state = screens::list::ListState::new(rows);
+            if self.is_filter_active() {
+                self.filter_source = rows;
+                self.apply_filter();
+            } else {
+                self.list_state = screens::list::ListState::new(rows);
+            }
             if self.list_state.rows.len() > prev_selected {
                 self.list_state.selected = prev_selected;
             }
@@ -586,6 +723,39 @@ impl App {
         self.push_screen(Screen::HookLog);
     }
 
+    /// Periodic background work, run once per [`TICK_RATE`] independent of
+    /// input: drains hook output and applies watcher-triggered refreshes.
+    pub fn on_tick(&mut self) {
+        self.process_hook_messages();
+        self.process_status_updates();
+        self.check_watcher();
+    }
+
+    /// Apply git-status results from the background worker to matching rows.
+    pub fn process_status_updates(&mut self) {
+        let Some(ref rx) = self.status_rx else { return };
+        loop {
+            match rx.try_recv() {
+                Ok(update) => {
+                    for row in self
+                        .list_state
+                        .rows
+                        .iter_mut()
+                        .chain(self.filter_source.iter_mut())
+                        .filter(|r| r.path == update.path)
+                    {
+                        row.apply_status(update.clone());
+                    }
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => break,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    self.status_rx = None;
+                    break;
+                }
+            }
+        }
+    }
+
     /// Drain pending messages from the hook output channel and update state.
     /// Continues draining even after dismiss so late completions can refresh
     /// the source screen and apply pending status messages.
@@ -724,6 +894,18 @@ impl App {
 
     pub fn handle_key_event(&mut self, key: KeyEvent) {
         self.clear_visible_status_for_active_screen();
+        let key = self.map_confirm_shortcut(key);
+
+        if self.active_screen() == Screen::List {
+            if self.filtering && key.modifiers != KeyModifiers::CONTROL {
+                self.handle_filter_key(key);
+                return;
+            }
+            if key.code == KeyCode::Esc && self.is_filter_active() {
+                self.clear_filter();
+                return;
+            }
+        }
 
         // Global keys handled at app level
         match (key.code, key.modifiers) {
@@ -773,6 +955,21 @@ impl App {
         }
     }
 
+    /// On a pending delete prompt, `y` confirms like Enter and `n` cancels
+    /// like Esc. Result views keep their Enter-to-dismiss behaviour.
+    fn map_confirm_shortcut(&self, key: KeyEvent) -> KeyEvent {
+        let prompting = self.active_screen() == Screen::DeleteConfirm
+            && self
+                .delete_confirm_state
+                .as_ref()
+                .is_some_and(|s| !s.is_result_mode());
+        match key.code {
+            KeyCode::Char('y') if prompting => KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE),
+            KeyCode::Char('n') if prompting => KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE),
+            _ => key,
+        }
+    }
+
     fn handle_screen_key(&mut self, key: KeyEvent) {
         match self.active_screen() {
             Screen::List => self.handle_list_key(key),
@@ -855,7 +1052,11 @@ impl App {
                     return;
                 }
             };
-            let resolve = crate::live_worktree::resolve(&worktree_name, &repo_info, &db);
+            let resolve =
+                crate::live_worktree::resolve(&worktree_name, &repo_info, &db).and_then(|live| {
+                    crate::cli::commands::remove::ensure_removable(&live, &repo_info, false)?;
+                    Ok(live)
+                });
             let live = match resolve {
                 Ok(live) => live,
                 Err(e) => {
@@ -1128,15 +1329,29 @@ impl App {
         }
 
         if let Some(ref mut picker) = self.sync_picker_state {
+            if picker.syncing {
+                return;
+            }
             match key.code {
                 KeyCode::Down | KeyCode::Char('j') => picker.select_next(),
                 KeyCode::Up | KeyCode::Char('k') => picker.select_previous(),
-                KeyCode::Enter => self.execute_sync(),
+                KeyCode::Enter => picker.syncing = true,
                 _ => {}
             }
         }
     }
 
+    /// Execute a sync confirmed on the picker, if one is waiting.
+    pub fn process_pending_sync(&mut self) {
+        let pending = self
+            .sync_picker_state
+            .as_mut()
+            .is_some_and(|p| std::mem::take(&mut p.syncing));
+        if pending {
+            self.execute_sync();
+        }
+    }
+
     fn execute_sync(&mut self) {
         let picker = match self.sync_picker_state.as_ref() {
             Some(p) => p,
@@ -1145,24 +1360,25 @@ impl App {
         let strategy = picker.confirmed_strategy();
         let worktree_name = picker.worktree_name.clone();
 
-        let Some((cwd, db)) = Self::open_db() else {
-            if let Some(ref mut p) = self.sync_picker_state {
-                p.result = Some(screens::sync_picker::SyncResultMessage {
-                    success: false,
-                    message: "Failed to open database".into(),
-                });
-            }
-            return;
-        };
-
         // Check for hooks
-        let hooks_config = Self::load_hooks_config(&cwd);
+        let hooks_config = std::env::current_dir()
+            .ok()
+            .and_then(|cwd| Self::load_hooks_config(&cwd));
         let has_hooks = hooks_config
             .as_ref()
             .map(|h| h.pre_sync.is_some() || h.post_sync.is_some())
             .unwrap_or(false);
 
         if has_hooks {
+            let Some((cwd, db)) = Self::open_db() else {
+                if let Some(ref mut p) = self.sync_picker_state {
+                    p.result = Some(screens::sync_picker::SyncResultMessage {
+                        success: false,
+                        message: "Failed to open database".into(),
+                    });
+                }
+                return;
+            };
             let (tx, rx) = std::sync::mpsc::channel();
             let hooks = hooks_config.unwrap();
             let return_screen = self.sync_return_screen.unwrap_or(Screen::List);
@@ -1189,9 +1405,12 @@ impl App {
                     &worktree_name,
                     &cwd,
                     &db,
-                    strategy,
+                    crate::cli::commands::sync::SyncOptions {
+                        strategy: strategy,
+                        autostash: false,
+                        no_hooks: false,
+                    },
                     Some(&hooks),
-                    false,
                     Some(&tx),
                 ));
                 let (success, error) = match result {
@@ -1206,14 +1425,14 @@ impl App {
             });
             self.start_hook_log("sync hooks", rx, return_screen);
         } else {
-            match crate::cli::commands::sync::execute(&worktree_name, &cwd, &db, strategy) {
+            match (self.sync_runner)(&worktree_name, strategy) {
                 Ok(result) => {
                     let target = self.sync_return_screen.unwrap_or(Screen::List);
                     self.return_to_screen(target, true);
                     self.set_status_message(
                         target,
                         screens::list::StatusMessage {
-                            text: format!("Synced '{}' via {}", result.name, result.strategy),
+                            text: sync_success_text(&result),
                             success: true,
                         },
                     );
@@ -1222,7 +1441,7 @@ impl App {
                     if let Some(ref mut p) = self.sync_picker_state {
                         p.result = Some(screens::sync_picker::SyncResultMessage {
                             success: false,
-                            message: format!("Sync failed: {e:#}"),
+                            message: sync_failure_text(&e),
                         });
                     }
                 }
@@ -1263,8 +1482,72 @@ impl App {
         }
     }
 
+    fn is_filter_active(&self) -> bool {
+        self.filtering || !self.filter.is_empty()
+    }
+
+    /// All rows on the list screen, ignoring any active filter.
+    fn unfiltered_rows(&self) -> &[screens::list::WorktreeRow] {
+        if self.is_filter_active() {
+            &self.filter_source
+        } else {
+            &self.list_state.rows
+        }
+    }
+
+    fn start_filter(&mut self) {
+        if !self.is_filter_active() {
+            self.filter_source = self.list_state.rows.clone();
+        }
+        self.filtering = true;
+    }
+
+    /// Narrow the list to rows whose name or branch contains the query
+    /// (case-insensitive).
+    fn apply_filter(&mut self) {
+        let query = self.filter.to_lowercase();
+        self.list_state.rows = self
+            .filter_source
+            .iter()
+            .filter(|r| {
+                r.name.to_lowercase().contains(&query) || r.branch.to_lowercase().contains(&query)
+            })
+            .cloned()
+            .collect();
+        if self.list_state.selected >= self.list_state.rows.len() {
+            self.list_state.selected = 0;
+        }
+    }
+
+    fn clear_filter(&mut self) {
+        self.filter.clear();
+        self.filtering = false;
+        self.list_state.rows = std::mem::take(&mut self.filter_source);
+        self.list_state.selected = 0;
+    }
+
+    fn handle_filter_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc => self.clear_filter(),
+            KeyCode::Enter => self.filtering = false,
+            KeyCode::Backspace => {
+                self.filter.pop();
+                self.apply_filter();
+            }
+            KeyCode::Down => self.list_state.select_next(),
+            KeyCode::Up => self.list_state.select_previous(),
+            KeyCode::Char(c) => {
+                self.filter.push(c);
+                self.list_state.selected = 0;
+                self.apply_filter();
+            }
+            _ => {}
+        }
+    }
+
     fn handle_list_key(&mut self, key: KeyEvent) {
         match key.code {
+            KeyCode::Char('/') => self.start_filter(),
             KeyCode::Enter => {
                 if let Some(row) = self.list_state.rows.get(self.list_state.selected) {
                     let name = row.name.clone();
@@ -1556,10 +1839,15 @@ impl App {
                     &cwd,
                     &worktree_root,
                     &template,
+                    None,
+                    true,
+                    None,
                     &db,
                     Some(&hooks),
+                    &[],
                     false,
                     Some(&tx),
+                    None,
                 ));
                 let (success, error) = match result {
                     Ok(_) => (true, None),
@@ -1581,6 +1869,7 @@ impl App {
                 &cwd,
                 &worktree_root,
                 &template,
+                None,
                 &db,
             ) {
                 Ok(result) => {
@@ -2269,6 +2558,73 @@ mod tests {
         assert_eq!(app.list_state.selected, 0);
     }
 
+    fn type_keys(app: &mut App, text: &str) {
+        for c in text.chars() {
+            app.handle_key_event(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+    }
+
+    fn row_names(app: &App) -> Vec<&str> {
+        app.list_state
+            .rows
+            .iter()
+            .map(|r| r.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn slash_filter_narrows_rows_and_esc_restores_them() {
+        let mut app = app_with_rows();
+
+        type_keys(&mut app, "/feat");
+        assert!(app.filtering);
+        assert_eq!(app.filter, "feat");
+        assert_eq!(row_names(&app), vec!["feat-a", "feat-b"]);
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert!(app.is_running(), "Esc should clear the filter, not quit");
+        assert!(!app.filtering);
+        assert!(app.filter.is_empty());
+        assert_eq!(row_names(&app), vec!["feat-a", "feat-b", "main"]);
+    }
+
+    #[test]
+    fn filter_matches_branch_case_insensitively() {
+        let mut app = app_with_rows();
+        type_keys(&mut app, "/FEAT/B");
+        assert_eq!(row_names(&app), vec!["feat-b"]);
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Backspace, KeyModifiers::NONE));
+        app.handle_key_event(KeyEvent::new(KeyCode::Backspace, KeyModifiers::NONE));
+        assert_eq!(row_names(&app), vec!["feat-a", "feat-b"]);
+    }
+
+    #[test]
+    fn filter_keys_are_not_treated_as_commands() {
+        let mut app = app_with_rows();
+        type_keys(&mut app, "/qsD?");
+        assert!(app.is_running(), "q while filtering must not quit");
+        assert_eq!(app.active_screen(), Screen::List);
+        assert_eq!(app.filter, "qsD?");
+        assert!(app.list_state.rows.is_empty());
+    }
+
+    #[test]
+    fn enter_keeps_filter_and_actions_target_filtered_rows() {
+        let mut app = app_with_rows();
+        type_keys(&mut app, "/b");
+        app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert!(!app.filtering);
+        assert_eq!(row_names(&app), vec!["feat-b"]);
+        assert!(render_app_text(&app).contains("/b  1 of 3 match"));
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::NONE));
+        assert_eq!(
+            app.sync_picker_state.as_ref().unwrap().worktree_name,
+            "feat-b"
+        );
+    }
+
     #[test]
     fn s_on_list_pushes_sync_picker() {
         let mut app = app_with_rows();
@@ -2405,6 +2761,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn status_bar_reflects_active_screen_hints_and_counts() {
+        let mut app = app_with_rows();
+        app.list_state.rows[0].processes = "node".into();
+
+        let text = render_app_text(&app);
+        assert!(text.contains("1/3 active"), "got: {text:?}");
+        assert!(
+            text.contains("n:new o:open s:sync D:delete /:filter ?:help"),
+            "got: {text:?}"
+        );
+
+        app.detail_state = Some(sample_detail_state());
+        app.push_screen(Screen::Detail);
+        let text = render_app_text(&app);
+        assert!(
+            text.contains("s:sync o:open l:log esc:back"),
+            "got: {text:?}"
+        );
+        assert!(
+            !text.contains("n:new"),
+            "list hints should not show on detail"
+        );
+    }
+
     #[test]
     fn create_screen_renders_placeholder() {
         let mut app = App::new();
@@ -2699,6 +3080,7 @@ mod tests {
             hook_status: "success".into(),
             hook_timestamp: "2026-03-10".into(),
             changed_files: vec![("file.rs".into(), "modified".into())],
+            last_commit: "abc1234 test commit (Test)".into(),
             commits: vec![("abc1234".into(), "test commit".into())],
         }
     }
@@ -2871,6 +3253,11 @@ mod tests {
         app.push_screen(Screen::SyncPicker);
 
         app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert!(
+            app.sync_picker_state.as_ref().unwrap().syncing,
+            "Enter should enter the syncing state before running git"
+        );
+        app.process_pending_sync();
 
         // Should still be on SyncPicker (showing result)
         assert_eq!(app.active_screen(), Screen::SyncPicker);
@@ -2882,6 +3269,105 @@ mod tests {
         assert!(picker.result.is_some());
     }
 
+    thread_local! {
+        static SYNCED: std::cell::RefCell<Vec<(String, crate::cli::commands::sync::Strategy)>> =
+            const { std::cell::RefCell::new(Vec::new()) };
+    }
+
+    fn fake_sync_ok(
+        name: &str,
+        strategy: crate::cli::commands::sync::Strategy,
+    ) -> Result<crate::cli::commands::sync::SyncResult> {
+        SYNCED.with(|s| s.borrow_mut().push((name.to_string(), strategy)));
+        Ok(crate::cli::commands::sync::SyncResult {
+            name: name.to_string(),
+            strategy,
+            before_ahead: 2,
+            before_behind: 3,
+            after_ahead: 2,
+            after_behind: 0,
+            autostash: None,
+        })
+    }
+
+    fn fake_sync_conflict(
+        name: &str,
+        _strategy: crate::cli::commands::sync::Strategy,
+    ) -> Result<crate::cli::commands::sync::SyncResult> {
+        Err(crate::git::GitError::MergeConflict {
+            branch: name.to_string(),
+            files: vec!["src/lib.rs".into(), "README.md".into()],
+        }
+        .into())
+    }
+
+    fn render_app_text(app: &App) -> String {
+        let backend = ratatui::backend::TestBackend::new(100, 24);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal.draw(|frame| app.ui(frame)).unwrap();
+        let buffer = terminal.backend().buffer().clone();
+        buffer.content().iter().map(|cell| cell.symbol()).collect()
+    }
+
+    #[test]
+    fn s_then_enter_syncs_selected_entry_and_shows_status_line() {
+        SYNCED.with(|s| s.borrow_mut().clear());
+        let mut app = app_with_rows();
+        app.sync_runner = fake_sync_ok;
+        app.list_state.selected = 1;
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::NONE));
+        app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert!(
+            render_app_text(&app).contains("Syncing via rebase"),
+            "syncing state should be drawn before the sync runs"
+        );
+        assert!(
+            SYNCED.with(|s| s.borrow().is_empty()),
+            "sync must wait for the next frame"
+        );
+
+        app.process_pending_sync();
+
+        assert_eq!(
+            SYNCED.with(|s| s.borrow().clone()),
+            vec![(
+                "feat-b".to_string(),
+                crate::cli::commands::sync::Strategy::Rebase
+            )]
+        );
+        assert_eq!(app.active_screen(), Screen::List);
+        let text = render_app_text(&app);
+        assert!(
+            text.contains("Synced 'feat-b' via rebase (2 commits replayed)"),
+            "status line should report the sync result, got: {text:?}"
+        );
+    }
+
+    #[test]
+    fn sync_conflict_lists_conflicted_files() {
+        let mut app = app_with_rows();
+        app.sync_runner = fake_sync_conflict;
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::NONE));
+        app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        app.process_pending_sync();
+
+        assert_eq!(app.active_screen(), Screen::SyncPicker);
+        let result = app
+            .sync_picker_state
+            .as_ref()
+            .unwrap()
+            .result
+            .clone()
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.message.contains("Conflict while syncing 'feat-a'"));
+        let text = render_app_text(&app);
+        assert!(text.contains("src/lib.rs"), "got: {text:?}");
+        assert!(text.contains("README.md"), "got: {text:?}");
+    }
+
     #[test]
     fn enter_in_result_mode_pops_back_to_list() {
         let mut app = App::new();
@@ -3022,7 +3508,7 @@ mod tests {
     }
 
     #[test]
-    fn y_on_delete_confirm_does_not_trigger_delete() {
+    fn y_on_delete_confirm_triggers_delete() {
         let mut app = App::new();
         app.delete_confirm_state = Some(screens::delete_confirm::DeleteConfirmState::new(
             "feat-auth",
@@ -3033,31 +3519,54 @@ mod tests {
 
         app.handle_key_event(KeyEvent::new(KeyCode::Char('y'), KeyModifiers::NONE));
 
+        // Without a real repo the removal fails, which proves it was dispatched.
         assert_eq!(app.active_screen(), Screen::DeleteConfirm);
         let state = app.delete_confirm_state.as_ref().unwrap();
-        assert!(!state.is_result_mode(), "y should not trigger delete");
+        assert!(state.is_result_mode(), "y should dispatch the removal");
+    }
+
+    #[test]
+    fn n_on_delete_confirm_cancels_without_side_effects() {
+        let mut app = app_with_rows();
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('D'), KeyModifiers::SHIFT));
+        assert_eq!(app.active_screen(), Screen::DeleteConfirm);
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('n'), KeyModifiers::NONE));
+
+        assert_eq!(
+            app.active_screen(),
+            Screen::List,
+            "n should dismiss the dialog"
+        );
+        assert!(app.delete_confirm_state.is_none());
+        assert_eq!(
+            app.list_state.rows.len(),
+            3,
+            "no worktree should be removed"
+        );
+        assert!(app.hook_log_state.is_none());
     }
 
     #[test]
-    fn n_on_delete_confirm_does_not_cancel_dialog() {
+    fn y_and_n_in_delete_result_mode_do_nothing() {
         let mut app = App::new();
-        app.delete_confirm_state = Some(screens::delete_confirm::DeleteConfirmState::new(
+        let mut state = screens::delete_confirm::DeleteConfirmState::new(
             "feat-auth",
             "/tmp/wt/feat-auth",
             "feature/auth",
-        ));
+        );
+        state.result = Some(screens::delete_confirm::DeleteResultMessage {
+            success: false,
+            message: "Delete failed".into(),
+        });
+        app.delete_confirm_state = Some(state);
         app.push_screen(Screen::DeleteConfirm);
 
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('y'), KeyModifiers::NONE));
         app.handle_key_event(KeyEvent::new(KeyCode::Char('n'), KeyModifiers::NONE));
 
-        assert!(
-            app.active_screen() == Screen::DeleteConfirm,
-            "n should not dismiss the delete dialog"
-        );
-        assert!(
-            app.delete_confirm_state.is_some(),
-            "state should remain after pressing n"
-        );
+        assert_eq!(app.active_screen(), Screen::DeleteConfirm);
+        assert!(app.delete_confirm_state.is_some());
     }
 
     #[test]
@@ -3363,6 +3872,61 @@ mod tests {
         assert!(!state.success);
     }
 
+    #[test]
+    fn on_tick_applies_background_updates_without_consuming_input() {
+        use screens::hook_log::{HookLogState, HookOutputMessage};
+
+        let mut app = app_with_rows();
+        type_keys(&mut app, "/fe");
+        let (tx, rx) = std::sync::mpsc::channel();
+        app.hook_log_state = Some(HookLogState::new("post_sync"));
+        app.hook_rx = Some(rx);
+        tx.send(HookOutputMessage::StepStarted { step: "run".into() })
+            .unwrap();
+
+        app.on_tick();
+
+        let state = app.hook_log_state.as_ref().unwrap();
+        assert_eq!(state.sections.len(), 1, "tick should drain hook output");
+        assert!(app.filtering, "tick must not disturb in-progress input");
+        assert_eq!(app.filter, "fe");
+        assert_eq!(app.active_screen(), Screen::List);
+        assert!(app.is_running());
+    }
+
+    #[test]
+    fn status_updates_fill_placeholders_on_tick() {
+        let mut app = app_with_rows();
+        for row in &mut app.list_state.rows {
+            row.status = screens::list::PENDING_STATUS.into();
+            row.ahead_behind = screens::list::PENDING_STATUS.into();
+        }
+        let (tx, rx) = std::sync::mpsc::channel();
+        app.status_rx = Some(rx);
+        tx.send(screens::list::GitStatus {
+            path: "/tmp/wt/feat-b".into(),
+            status: "~4".into(),
+            ahead_behind: "+2/-1".into(),
+        })
+        .unwrap();
+
+        app.on_tick();
+
+        let rows = &app.list_state.rows;
+        assert_eq!(rows[1].status, "~4");
+        assert_eq!(rows[1].ahead_behind, "+2/-1");
+        assert_eq!(rows[0].status, screens::list::PENDING_STATUS);
+        assert!(app.status_rx.is_some(), "worker may still be sending");
+
+        drop(tx);
+        app.on_tick();
+        assert!(
+            app.status_rx.is_none(),
+            "finished worker should be released"
+        );
+        assert!(render_app_text(&app).contains("~4"));
+    }
+
     #[test]
     fn process_hook_messages_no_op_without_receiver() {
         let mut app = App::new();
@@ -3771,6 +4335,7 @@ mod tests {
             hook_status: "-".into(),
             hook_timestamp: "-".into(),
             changed_files: vec![],
+            last_commit: "-".into(),
             commits: vec![],
         });
         app.push_screen(Screen::Detail);
@@ -115,7 +115,7 @@ fn render_worktree_confirm(
         frame,
         chunks[6],
         theme,
-        &[("Enter", "confirm"), ("Esc", "cancel")],
+        &[("y/Enter", "confirm"), ("n/Esc", "cancel")],
     );
 }
 
@@ -155,7 +155,7 @@ fn render_branch_confirm(
         frame,
         chunks[4],
         theme,
-        &[("Enter", "confirm"), ("Esc", "keep")],
+        &[("y/Enter", "confirm"), ("n/Esc", "keep")],
     );
 }
 
@@ -192,7 +192,7 @@ fn render_force_branch_confirm(
         frame,
         chunks[4],
         theme,
-        &[("Enter", "confirm"), ("Esc", "keep")],
+        &[("y/Enter", "confirm"), ("n/Esc", "keep")],
     );
 }
 
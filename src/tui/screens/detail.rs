@@ -25,6 +25,8 @@ pub struct DetailState {
     pub hook_status: String,
     pub hook_timestamp: String,
     pub changed_files: Vec<(String, String)>,
+    /// HEAD commit as "<short id> <subject> (<author>)", or "-" when unborn.
+    pub last_commit: String,
     pub commits: Vec<(String, String)>,
 }
 
@@ -104,6 +106,12 @@ pub fn load_detail(name: &str, cwd: &Path, db: &Database, date_format: &str) ->
         vec![]
     };
 
+    let last_commit = wt_path
+        .as_ref()
+        .and_then(|p| git::commit_summary(Path::new(p)).ok().flatten())
+        .map(|c| format_last_commit(&c))
+        .unwrap_or_else(|| "-".to_string());
+
     let commits = if let Some(ref wt_path) = wt_path {
         git::recent_commits(Path::new(wt_path), 10)
             .unwrap_or_default()
@@ -125,6 +133,7 @@ pub fn load_detail(name: &str, cwd: &Path, db: &Database, date_format: &str) ->
         hook_status,
         hook_timestamp,
         changed_files,
+        last_commit,
         commits,
     }
 }
@@ -146,10 +155,15 @@ pub fn fallback_from_row(row: &WorktreeRow) -> DetailState {
         hook_status: "none".to_string(),
         hook_timestamp: "-".to_string(),
         changed_files: vec![],
+        last_commit: "-".to_string(),
         commits: vec![],
     }
 }
 
+fn format_last_commit(commit: &git::CommitSummary) -> String {
+    format!("{} {} ({})", commit.short_id, commit.summary, commit.author)
+}
+
 fn format_timestamp(ts: i64, format: &str) -> String {
     if ts < 0 {
         return "-".to_string();
@@ -252,7 +266,12 @@ pub fn render(
     }
     frame.render_widget(Paragraph::new(file_lines), body_chunks[0]);
 
-    let mut commit_lines: Vec<Line> = vec![Line::from(Span::styled("Recent Commits", bold))];
+    let mut commit_lines: Vec<Line> = vec![Line::from(vec![
+        Span::styled("Recent Commits", bold),
+        Span::raw("  "),
+        Span::styled("HEAD: ", bold),
+        Span::raw(&state.last_commit),
+    ])];
     if state.commits.is_empty() {
         commit_lines.push(Line::from("  No commits"));
     } else {
@@ -431,11 +450,14 @@ fn render_commit_card(
     let inner = block.inner(area);
     frame.render_widget(block, area);
 
-    let mut lines = vec![Line::from(vec![crate::tui::chrome::pill(
-        theme,
-        &format!("{} commits", state.commits.len()),
-        crate::tui::chrome::Tone::Muted,
-    )])];
+    let mut lines = vec![Line::from(vec![
+        crate::tui::chrome::pill(
+            theme,
+            &format!("{} commits", state.commits.len()),
+            crate::tui::chrome::Tone::Muted,
+        ),
+        Span::raw(format!("  HEAD {}", state.last_commit)),
+    ])];
     if state.commits.is_empty() {
         lines.push(Line::from(""));
         lines.push(Line::from("No commits"));
@@ -496,6 +518,7 @@ mod tests {
                 ("src/auth.rs".into(), "modified".into()),
                 ("tests/auth_test.rs".into(), "new".into()),
             ],
+            last_commit: "abc1234 feat: add auth module (Ada)".into(),
             commits: vec![
                 ("abc1234".into(), "feat: add auth module".into()),
                 ("def5678".into(), "test: add auth tests".into()),
@@ -555,6 +578,7 @@ mod tests {
             hook_status: "none".into(),
             hook_timestamp: "-".into(),
             changed_files: vec![],
+            last_commit: "-".into(),
             commits: vec![],
         };
         assert!(state.changed_files.is_empty());
@@ -644,6 +668,17 @@ mod tests {
         assert!(text.contains("def5678"), "should show second commit hash");
     }
 
+    #[test]
+    fn renders_last_commit_summary() {
+        let state = sample_detail();
+        let buf = render_to_buffer(&state, 100, 30);
+        let text = buffer_text(&buf);
+        assert!(
+            text.contains("HEAD: abc1234 feat: add auth module (Ada)"),
+            "should show HEAD summary, got: {text}"
+        );
+    }
+
     #[test]
     fn renders_no_commits_when_empty() {
         let mut state = sample_detail();
@@ -735,6 +770,7 @@ mod tests {
         assert_eq!(state.hook_status, "none");
         assert_eq!(state.hook_timestamp, "-");
         assert!(state.changed_files.is_empty());
+        assert_eq!(state.last_commit, "-");
         assert!(state.commits.is_empty());
     }
 }
@@ -83,6 +83,10 @@ pub fn keybinding_groups() -> &'static [KeybindingGroup] {
                     key: "l",
                     description: "View hook log",
                 },
+                KeybindingEntry {
+                    key: "/",
+                    description: "Filter by name / branch",
+                },
             ],
         },
         KeybindingGroup {
@@ -140,11 +144,11 @@ pub fn keybinding_groups() -> &'static [KeybindingGroup] {
             context: "Delete",
             bindings: &[
                 KeybindingEntry {
-                    key: "Enter",
+                    key: "y / Enter",
                     description: "Confirm / dismiss",
                 },
                 KeybindingEntry {
-                    key: "Esc",
+                    key: "n / Esc",
                     description: "Cancel / back",
                 },
             ],
@@ -1,4 +1,5 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::Receiver;
 
 use anyhow::Result;
 
@@ -81,23 +82,92 @@ impl ListState {
     }
 }
 
+/// Placeholder shown until the background worker reports a row's git status.
+pub const PENDING_STATUS: &str = "…";
+
+/// Git status work for one row, run off the render thread.
+#[derive(Debug, Clone)]
+pub struct StatusJob {
+    pub repo_path: PathBuf,
+    pub branch: String,
+    pub base_branch: Option<String>,
+    /// Worktree path; identifies the row the result belongs to.
+    pub path: String,
+}
+
+/// Computed dirty and ahead/behind display values for the row at `path`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GitStatus {
+    pub path: String,
+    pub status: String,
+    pub ahead_behind: String,
+}
+
+impl StatusJob {
+    pub fn run(&self) -> GitStatus {
+        let (status, ahead_behind) = compute_status(
+            &self.repo_path,
+            &self.branch,
+            self.base_branch.as_deref(),
+            &self.path,
+        );
+        GitStatus {
+            path: self.path.clone(),
+            status,
+            ahead_behind,
+        }
+    }
+}
+
+/// Compute `jobs` on a worker thread, sending each result as it completes.
+///
+/// The worker stops early once the receiver is dropped (e.g. after a newer
+/// refresh replaced it).
+pub fn spawn_status_worker(jobs: Vec<StatusJob>) -> Receiver<GitStatus> {
+    let (tx, rx) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        for job in jobs {
+            if tx.send(job.run()).is_err() {
+                break;
+            }
+        }
+    });
+    rx
+}
+
 /// Load worktree data from the database and git, returning rows for the list view.
 ///
 /// Additional directories in `scan_paths` are scanned for worktrees that
-/// may live outside the default location (FR-30).
+/// may live outside the default location (FR-30). Computes git status
+/// inline; the TUI itself uses [`load_worktrees_deferred`].
+#[cfg(test)]
 pub fn load_worktrees(
     cwd: &Path,
     db: &Database,
     scan_paths: &[String],
 ) -> Result<Vec<WorktreeRow>> {
+    let (mut rows, jobs) = load_worktrees_deferred(cwd, db, scan_paths)?;
+    for (row, job) in rows.iter_mut().zip(&jobs) {
+        row.apply_status(job.run());
+    }
+    Ok(rows)
+}
+
+/// Like [`load_worktrees`], but leaves dirty and ahead/behind as
+/// [`PENDING_STATUS`] and returns the jobs that compute them, one per row.
+pub fn load_worktrees_deferred(
+    cwd: &Path,
+    db: &Database,
+    scan_paths: &[String],
+) -> Result<(Vec<WorktreeRow>, Vec<StatusJob>)> {
     let repo_info = git::discover_repo(cwd)?;
-    let repo_path = &repo_info.path;
     let current_path = git::current_worktree_root(cwd)
         .ok()
         .map(|path| path.to_string_lossy().to_string());
     let live_worktrees = crate::live_worktree::list(&repo_info, db, scan_paths)?;
 
     let mut rows = Vec::new();
+    let mut jobs = Vec::new();
 
     for worktree in live_worktrees {
         let branch = worktree
@@ -107,19 +177,24 @@ pub fn load_worktrees(
             .unwrap_or_else(|| "(detached)".to_string());
         let path = worktree.entry.path.to_string_lossy().to_string();
         let base_branch = Some(crate::live_worktree::base_branch(&repo_info, &worktree));
-        let status = compute_status(repo_path, &branch, base_branch.as_deref(), &path);
         let procs = crate::process::detect_processes(&path);
         let processes = procs
             .iter()
             .map(|p| p.name.clone())
             .collect::<Vec<_>>()
             .join(", ");
+        jobs.push(StatusJob {
+            repo_path: repo_info.path.clone(),
+            branch: branch.clone(),
+            base_branch,
+            path: path.clone(),
+        });
         rows.push(WorktreeRow {
             name: worktree.entry.name.clone(),
             branch,
             path,
-            status: status.0,
-            ahead_behind: status.1,
+            status: PENDING_STATUS.to_string(),
+            ahead_behind: PENDING_STATUS.to_string(),
             managed: true,
             is_current: current_path
                 .as_deref()
@@ -128,7 +203,14 @@ pub fn load_worktrees(
         });
     }
 
-    Ok(rows)
+    Ok((rows, jobs))
+}
+
+impl WorktreeRow {
+    pub fn apply_status(&mut self, status: GitStatus) {
+        self.status = status.status;
+        self.ahead_behind = status.ahead_behind;
+    }
 }
 
 fn rowsafe_path(path: &Path) -> String {
@@ -462,7 +544,7 @@ fn metric_line(label: &str, value: &str, theme: &crate::tui::theme::Theme) -> Li
 }
 
 fn display_status(raw: &str, show_dirty_count: bool) -> String {
-    if show_dirty_count || raw == "clean" {
+    if show_dirty_count || raw == "clean" || raw == PENDING_STATUS {
         raw.to_string()
     } else {
         "dirty".to_string()
@@ -470,7 +552,9 @@ fn display_status(raw: &str, show_dirty_count: bool) -> String {
 }
 
 fn status_tone(status: &str) -> crate::tui::chrome::Tone {
-    if status == "clean" {
+    if status == PENDING_STATUS {
+        crate::tui::chrome::Tone::Muted
+    } else if status == "clean" {
         crate::tui::chrome::Tone::Success
     } else {
         crate::tui::chrome::Tone::Warning
@@ -830,6 +914,7 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
         .expect("create should succeed");
@@ -860,6 +945,7 @@ mod tests {
             repo_dir.path(),
             wt_root.path(),
             paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
             &db,
         )
         .expect("create should succeed");
@@ -872,6 +958,37 @@ mod tests {
         assert!(current.is_current, "current checkout should be marked");
     }
 
+    #[test]
+    fn deferred_load_uses_placeholders_and_worker_fills_them() {
+        use crate::cli::commands::create;
+        use crate::paths;
+
+        let repo_dir = tempfile::tempdir().unwrap();
+        let _repo = init_repo_with_commit(repo_dir.path());
+        let wt_root = tempfile::tempdir().unwrap();
+        let db = Database::open_in_memory().unwrap();
+        let created = create::execute(
+            "slow-status",
+            None,
+            repo_dir.path(),
+            wt_root.path(),
+            paths::DEFAULT_WORKTREE_TEMPLATE,
+            None,
+            &db,
+        )
+        .unwrap();
+        std::fs::write(created.path.join("wip.txt"), "wip").unwrap();
+
+        let (rows, jobs) = load_worktrees_deferred(repo_dir.path(), &db, &[]).unwrap();
+        assert_eq!(rows.len(), jobs.len());
+        assert!(rows.iter().all(|r| r.status == PENDING_STATUS));
+
+        let results: Vec<GitStatus> = spawn_status_worker(jobs).iter().collect();
+        let row = rows.iter().find(|r| r.name == "slow-status").unwrap();
+        let status = results.iter().find(|s| s.path == row.path).unwrap();
+        assert_eq!(status.status, "~1");
+    }
+
     #[test]
     fn renders_process_info_in_table() {
         let rows = vec![
@@ -20,6 +20,9 @@ pub struct SyncPickerState {
     pub worktree_name: String,
     /// Currently selected option: 0 = Rebase, 1 = Merge.
     pub selected: usize,
+    /// Set once a strategy is confirmed; the sync runs after the next frame
+    /// so the "syncing…" state is drawn before the blocking git call.
+    pub syncing: bool,
     /// Result message after sync execution. None = picker mode, Some = result mode.
     pub result: Option<SyncResultMessage>,
 }
@@ -36,6 +39,7 @@ impl SyncPickerState {
         Self {
             worktree_name: worktree_name.to_string(),
             selected: 0,
+            syncing: false,
             result: None,
         }
     }
@@ -81,11 +85,32 @@ pub fn render(
 ) {
     if let Some(ref result) = state.result {
         render_result(state, result, frame, area, theme);
+    } else if state.syncing {
+        render_syncing(state, frame, area, theme);
     } else {
         render_picker(state, frame, area, theme);
     }
 }
 
+fn render_syncing(
+    state: &SyncPickerState,
+    frame: &mut Frame,
+    area: Rect,
+    theme: &crate::tui::theme::Theme,
+) {
+    let chunks = Layout::vertical([Constraint::Length(3), Constraint::Min(1)]).split(area);
+    let line = Line::from(vec![
+        Span::styled(
+            format!("Syncing via {}… ", state.confirmed_strategy()),
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(&state.worktree_name),
+    ]);
+    frame.render_widget(Paragraph::new(line).alignment(Alignment::Center), chunks[0]);
+}
+
 fn render_result(
     state: &SyncPickerState,
     result: &SyncResultMessage,
@@ -293,6 +318,16 @@ mod tests {
         assert!(text.contains("feat-auth"), "should show worktree name");
     }
 
+    #[test]
+    fn renders_syncing_state_instead_of_options() {
+        let mut state = SyncPickerState::new("feat-auth");
+        state.syncing = true;
+        let buf = render_to_buffer(&state, 80, 15);
+        let text = buffer_text(&buf);
+        assert!(text.contains("Syncing via rebase"), "got: {text:?}");
+        assert!(!text.contains("Replay your commits"));
+    }
+
     #[test]
     fn renders_rebase_and_merge_options() {
         let state = SyncPickerState::new("feat-auth");
@@ -0,0 +1,123 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+use crate::tui::theme::Theme;
+use crate::tui::Screen;
+
+/// Worktree counts shown in the status bar.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StatusCounts {
+    /// Worktrees with at least one running process.
+    pub active: usize,
+    pub total: usize,
+}
+
+/// Compact key hints for `screen`, as (key, action) pairs.
+pub fn hints(screen: Screen) -> &'static [(&'static str, &'static str)] {
+    match screen {
+        Screen::List => &[
+            ("n", "new"),
+            ("o", "open"),
+            ("s", "sync"),
+            ("D", "delete"),
+            ("/", "filter"),
+            ("?", "help"),
+        ],
+        Screen::Detail => &[("s", "sync"), ("o", "open"), ("l", "log"), ("esc", "back")],
+        Screen::Create => &[("tab", "next"), ("enter", "create"), ("esc", "cancel")],
+        Screen::Help => &[("?", "close"), ("esc", "back")],
+        Screen::SyncPicker => &[("enter", "sync"), ("esc", "cancel")],
+        Screen::DeleteConfirm => &[("y", "confirm"), ("n", "cancel")],
+        Screen::HookLog => &[("j/k", "scroll"), ("esc", "back")],
+    }
+}
+
+/// Render the one-line status bar: repo and counts on the left, the active
+/// screen's key hints on the right.
+pub fn render(
+    frame: &mut Frame,
+    area: Rect,
+    theme: &Theme,
+    screen: Screen,
+    repo_name: Option<&str>,
+    counts: StatusCounts,
+) {
+    let base = theme.with_bg(Style::default().fg(theme.fg_muted), theme.bg_elevated);
+    frame.render_widget(Paragraph::new("").style(base), area);
+
+    let [left, right] = Layout::horizontal([Constraint::Min(0), Constraint::Min(0)]).areas(area);
+
+    let summary = Line::from(vec![
+        Span::styled(
+            format!(" {} ", repo_name.unwrap_or("no repo")),
+            Style::default().fg(theme.fg).add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(format!("{}/{} active", counts.active, counts.total)),
+    ]);
+    frame.render_widget(Paragraph::new(summary).style(base), left);
+
+    let hint_text = hints(screen)
+        .iter()
+        .map(|(key, action)| format!("{key}:{action}"))
+        .collect::<Vec<_>>()
+        .join(" ");
+    frame.render_widget(
+        Paragraph::new(format!("{hint_text} "))
+            .alignment(Alignment::Right)
+            .style(base),
+        right,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::backend::TestBackend;
+
+    fn render_to_text(screen: Screen, counts: StatusCounts) -> String {
+        let backend = TestBackend::new(100, 1);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        let theme = crate::tui::theme::from_name("ops");
+        terminal
+            .draw(|frame| render(frame, frame.area(), &theme, screen, Some("repo-x"), counts))
+            .unwrap();
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect()
+    }
+
+    #[test]
+    fn shows_repo_and_counts() {
+        let text = render_to_text(
+            Screen::List,
+            StatusCounts {
+                active: 2,
+                total: 5,
+            },
+        );
+        assert!(text.contains("repo-x"), "got: {text:?}");
+        assert!(text.contains("2/5 active"), "got: {text:?}");
+    }
+
+    #[test]
+    fn hints_follow_active_screen() {
+        let list = render_to_text(Screen::List, StatusCounts::default());
+        assert!(
+            list.contains("n:new o:open s:sync D:delete /:filter ?:help"),
+            "got: {list:?}"
+        );
+
+        let detail = render_to_text(Screen::Detail, StatusCounts::default());
+        assert!(detail.contains("l:log esc:back"), "got: {detail:?}");
+        assert!(!detail.contains("n:new"), "got: {detail:?}");
+    }
+}
@@ -0,0 +1,116 @@
+//! Integration tests for the stdout contract of `trench create`: shell
+//! wrappers capture `$(trench create …)`, so stdout must hold exactly the
+//! worktree path and one newline, with all other output on stderr.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn trench_bin() -> PathBuf {
+    PathBuf::from(env!("CARGO_BIN_EXE_trench"))
+}
+
+/// Initialize a temporary git repo with an initial commit.
+fn init_git_repo(dir: &Path) {
+    for args in [
+        &["init", "-b", "main"][..],
+        &["config", "user.email", "test@test.com"],
+        &["config", "user.name", "Test"],
+    ] {
+        git(dir, args);
+    }
+    std::fs::write(dir.join("README.md"), "# test\n").unwrap();
+    git(dir, &["add", "."]);
+    git(dir, &["commit", "-m", "init"]);
+}
+
+fn git(dir: &Path, args: &[&str]) {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .expect("git command failed");
+    assert!(
+        output.status.success(),
+        "git {:?} failed: {}",
+        args,
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+/// Run trench with HOME and XDG dirs pointed at `home`, isolating the
+/// database, config, and worktree root from the real user environment.
+fn trench(repo: &Path, home: &Path, args: &[&str]) -> std::process::Output {
+    Command::new(trench_bin())
+        .args(args)
+        .current_dir(repo)
+        .env("HOME", home)
+        .env("XDG_CONFIG_HOME", home.join(".config"))
+        .env("XDG_DATA_HOME", home.join(".local/share"))
+        .env("XDG_STATE_HOME", home.join(".local/state"))
+        .output()
+        .expect("failed to run trench")
+}
+
+#[test]
+fn create_stdout_is_exactly_the_path_line() {
+    let repo = tempfile::tempdir().unwrap();
+    let home = tempfile::tempdir().unwrap();
+    init_git_repo(repo.path());
+
+    // A chatty post_create hook must not leak into captured stdout.
+    std::fs::write(
+        repo.path().join(".trench.toml"),
+        r#"
+[hooks.post_create]
+run = ["echo hook-stdout-noise", "echo hook-stderr-noise >&2"]
+timeout_secs = 10
+"#,
+    )
+    .unwrap();
+
+    let output = trench(repo.path(), home.path(), &["create", "quiet-path"]);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(output.status.success(), "create failed, stderr: {stderr}");
+    assert_eq!(stdout.matches('\n').count(), 1, "stdout: {stdout:?}");
+    let path = Path::new(stdout.strip_suffix('\n').unwrap());
+    assert!(path.is_absolute() && path.is_dir(), "stdout: {stdout:?}");
+    assert!(path.ends_with("quiet-path"), "stdout: {stdout:?}");
+    assert!(stderr.contains("hook-stdout-noise"), "stderr: {stderr}");
+    assert!(stderr.contains("hook-stderr-noise"), "stderr: {stderr}");
+}
+
+#[test]
+fn create_reports_copied_files_on_stderr_unless_quiet() {
+    let repo = tempfile::tempdir().unwrap();
+    let home = tempfile::tempdir().unwrap();
+    init_git_repo(repo.path());
+    std::fs::write(repo.path().join(".env"), "KEY=1\n").unwrap();
+    std::fs::write(repo.path().join(".env.local"), "LOCAL=1\n").unwrap();
+    std::fs::write(
+        repo.path().join(".trench.toml"),
+        "[hooks.post_create]\ncopy = [\".env*\"]\n",
+    )
+    .unwrap();
+
+    let output = trench(repo.path(), home.path(), &["create", "copy-summary"]);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(output.status.success(), "create failed, stderr: {stderr}");
+    assert!(
+        stderr.contains("copied 2 files (.env, .env.local)"),
+        "stderr: {stderr}"
+    );
+    assert_eq!(stdout.matches('\n').count(), 1, "stdout: {stdout:?}");
+
+    let output = trench(
+        repo.path(),
+        home.path(),
+        &["--quiet", "create", "copy-summary-quiet"],
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "create failed, stderr: {stderr}");
+    assert!(!stderr.contains("copied"), "stderr: {stderr}");
+}
@@ -149,6 +149,26 @@ fn exit_code_2_switch_nonexistent() {
     );
 }
 
+#[test]
+fn exit_code_2_which_nonexistent() {
+    let tmp = tempfile::tempdir().unwrap();
+    init_git_repo(tmp.path());
+
+    let output = Command::new(trench_bin())
+        .args(["which", "nonexistent-branch-xyz"])
+        .current_dir(tmp.path())
+        .output()
+        .expect("failed to run trench");
+
+    assert_eq!(
+        output.status.code(),
+        Some(2),
+        "which nonexistent should exit 2, stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(output.stdout.is_empty(), "no path on stdout when not found");
+}
+
 // ── Exit code 3: Branch exists ─────────────────────────────────────────
 
 #[test]
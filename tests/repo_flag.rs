@@ -0,0 +1,192 @@
+//! Integration tests for the global `--repo`, `--db`, and `--config` flags,
+//! which point trench at a repository other than the one containing the
+//! current directory and at a database or global config other than the
+//! default ones.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn trench_bin() -> PathBuf {
+    PathBuf::from(env!("CARGO_BIN_EXE_trench"))
+}
+
+/// Initialize a temporary git repo with an initial commit.
+fn init_git_repo(dir: &Path) {
+    for args in [
+        &["init", "-b", "main"][..],
+        &["config", "user.email", "test@test.com"],
+        &["config", "user.name", "Test"],
+    ] {
+        git(dir, args);
+    }
+    std::fs::write(dir.join("README.md"), "# test\n").unwrap();
+    git(dir, &["add", "."]);
+    git(dir, &["commit", "-m", "init"]);
+}
+
+fn git(dir: &Path, args: &[&str]) {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .expect("git command failed");
+    assert!(
+        output.status.success(),
+        "git {:?} failed: {}",
+        args,
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+/// Run trench from `cwd` with HOME and XDG dirs pointed at `home`.
+fn trench(cwd: &Path, home: &Path, args: &[&str]) -> std::process::Output {
+    Command::new(trench_bin())
+        .args(args)
+        .current_dir(cwd)
+        .env("HOME", home)
+        .env("XDG_CONFIG_HOME", home.join(".config"))
+        .env("XDG_DATA_HOME", home.join(".local/share"))
+        .env("XDG_STATE_HOME", home.join(".local/state"))
+        .output()
+        .expect("failed to run trench")
+}
+
+#[test]
+fn list_with_repo_flag_reads_the_named_repo() {
+    let repo = tempfile::tempdir().unwrap();
+    let home = tempfile::tempdir().unwrap();
+    // Run from a directory that is not inside any git repository.
+    let outside = tempfile::tempdir().unwrap();
+    init_git_repo(repo.path());
+    let repo_arg = repo.path().to_str().unwrap();
+
+    let created = trench(
+        outside.path(),
+        home.path(),
+        &["--repo", repo_arg, "create", "feature/elsewhere"],
+    );
+    assert!(
+        created.status.success(),
+        "create failed: {}",
+        String::from_utf8_lossy(&created.stderr)
+    );
+
+    let listed = trench(
+        outside.path(),
+        home.path(),
+        &["list", "--repo", repo_arg, "--json"],
+    );
+    assert!(
+        listed.status.success(),
+        "list failed: {}",
+        String::from_utf8_lossy(&listed.stderr)
+    );
+    let json: serde_json::Value = serde_json::from_slice(&listed.stdout).unwrap();
+    let branches: Vec<&str> = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|wt| wt["branch"].as_str())
+        .collect();
+    assert!(branches.contains(&"feature/elsewhere"), "got: {json}");
+
+    // Without --repo the same cwd has no repository to discover.
+    let plain = trench(outside.path(), home.path(), &["list"]);
+    assert!(!plain.status.success());
+}
+
+#[test]
+fn repo_flag_rejects_missing_path() {
+    let home = tempfile::tempdir().unwrap();
+    let cwd = tempfile::tempdir().unwrap();
+    let missing = cwd.path().join("nope");
+
+    let output = trench(
+        cwd.path(),
+        home.path(),
+        &["--repo", missing.to_str().unwrap(), "list"],
+    );
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("--repo path"), "got: {stderr}");
+}
+
+#[test]
+fn db_flag_routes_writes_away_from_default_db() {
+    let repo = tempfile::tempdir().unwrap();
+    let home = tempfile::tempdir().unwrap();
+    init_git_repo(repo.path());
+    let custom = home.path().join("custom/alt.db");
+    let default_db = home.path().join(".local/share/trench/trench.db");
+
+    let created = trench(
+        repo.path(),
+        home.path(),
+        &["create", "feature/db", "--db", custom.to_str().unwrap()],
+    );
+    assert!(
+        created.status.success(),
+        "create failed: {}",
+        String::from_utf8_lossy(&created.stderr)
+    );
+    assert!(custom.exists(), "--db file should be created");
+    assert!(!default_db.exists(), "default DB must be untouched");
+
+    // TRENCH_DB selects the same file; the flag would win if both were set.
+    let logged = Command::new(trench_bin())
+        .args(["log", "--json"])
+        .current_dir(repo.path())
+        .env("HOME", home.path())
+        .env("XDG_DATA_HOME", home.path().join(".local/share"))
+        .env("XDG_STATE_HOME", home.path().join(".local/state"))
+        .env("TRENCH_DB", &custom)
+        .output()
+        .expect("failed to run trench");
+    assert!(logged.status.success());
+    let events: serde_json::Value = serde_json::from_slice(&logged.stdout).unwrap();
+    assert!(
+        !events.as_array().unwrap().is_empty(),
+        "events should come from the TRENCH_DB file, got: {events}"
+    );
+    assert!(!default_db.exists(), "default DB must be untouched");
+}
+
+#[test]
+fn config_flag_replaces_global_config() {
+    let repo = tempfile::tempdir().unwrap();
+    let home = tempfile::tempdir().unwrap();
+    init_git_repo(repo.path());
+    let custom_root = home.path().join("isolated-root");
+    let config = home.path().join("sandbox.toml");
+    std::fs::write(
+        &config,
+        format!(
+            "[worktrees]\nroot = \"{}/{{{{ branch | sanitize }}}}\"\n",
+            custom_root.display()
+        ),
+    )
+    .unwrap();
+
+    let output = trench(
+        repo.path(),
+        home.path(),
+        &[
+            "--config",
+            config.to_str().unwrap(),
+            "create",
+            "feature/cfg",
+        ],
+    );
+
+    assert!(
+        output.status.success(),
+        "create failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        Path::new(stdout.trim()).starts_with(&custom_root),
+        "worktree should live under the --config root, got: {stdout}"
+    );
+}
@@ -0,0 +1,77 @@
+//! Live SSH authentication check for remote operations. Ignored by default
+//! because it needs network access and a real key or ssh-agent; run with
+//!
+//!     TRENCH_TEST_SSH_REMOTE=git@github.com:org/repo.git \
+//!         cargo test --test ssh_remote -- --ignored
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn trench_bin() -> PathBuf {
+    PathBuf::from(env!("CARGO_BIN_EXE_trench"))
+}
+
+fn git(dir: &Path, args: &[&str]) {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .expect("git command failed");
+    assert!(
+        output.status.success(),
+        "git {:?} failed: {}",
+        args,
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+#[ignore = "needs network access and TRENCH_TEST_SSH_REMOTE"]
+fn create_authenticates_against_ssh_remote() {
+    let url = std::env::var("TRENCH_TEST_SSH_REMOTE")
+        .expect("set TRENCH_TEST_SSH_REMOTE to an SSH remote you can read");
+    let repo = tempfile::tempdir().unwrap();
+    let state = tempfile::tempdir().unwrap();
+    git(repo.path(), &["init", "-b", "main"]);
+    git(repo.path(), &["remote", "add", "origin", &url]);
+    git(
+        repo.path(),
+        &[
+            "-c",
+            "user.email=test@test.com",
+            "-c",
+            "user.name=Test",
+            "commit",
+            "--allow-empty",
+            "-m",
+            "init",
+        ],
+    );
+    // auto_prune connects to the remote and reports auth failures.
+    std::fs::write(
+        repo.path().join(".trench.toml"),
+        format!(
+            "[git]\nauto_prune = true\n\n[worktrees]\nroot = \"{}\"\n",
+            state.path().join("worktrees").display()
+        ),
+    )
+    .unwrap();
+
+    // HOME stays real so the ssh-agent and ~/.ssh keys are visible.
+    let output = Command::new(trench_bin())
+        .args(["--verbose", "create", "ssh-auth-probe"])
+        .current_dir(repo.path())
+        .env("XDG_CONFIG_HOME", state.path().join("config"))
+        .env("XDG_DATA_HOME", state.path().join("data"))
+        .env("XDG_STATE_HOME", state.path().join("state"))
+        .output()
+        .expect("failed to run trench");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(output.status.success(), "create failed, stderr: {stderr}");
+    assert!(
+        !stderr.contains("authentication failed"),
+        "stderr: {stderr}"
+    );
+    assert!(stderr.contains("credentials requested"), "stderr: {stderr}");
+}